@@ -18,6 +18,27 @@ fn bench_indicator<T: IndicatorConfig + Default>(b: &mut test::Bencher) {
 	b.iter(|| indicator.next(iter.next().unwrap()))
 }
 
+fn bench_indicator_over<T: IndicatorConfig + Default>(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut indicator = T::default().init(&candles[0]).unwrap();
+	let mut results = Vec::with_capacity(candles.len());
+
+	b.iter(|| {
+		results.clear();
+		indicator.over_into(&candles, &mut results);
+	})
+}
+
+#[bench]
+fn bench_over_macd(b: &mut test::Bencher) {
+	bench_indicator_over::<MACD>(b);
+}
+
+#[bench]
+fn bench_over_bollinger_bands(b: &mut test::Bencher) {
+	bench_indicator_over::<BollingerBands>(b);
+}
+
 #[bench]
 fn bench_indicator_aroon(b: &mut test::Bencher) {
 	bench_indicator::<Aroon>(b);
@@ -28,11 +49,21 @@ fn bench_indicator_average_directional_index(b: &mut test::Bencher) {
 	bench_indicator::<AverageDirectionalIndex>(b);
 }
 
+#[bench]
+fn bench_average_true_range(b: &mut test::Bencher) {
+	bench_indicator::<AverageTrueRange>(b);
+}
+
 #[bench]
 fn bench_awesome_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<AwesomeOscillator>(b);
 }
 
+#[bench]
+fn bench_balance_of_power(b: &mut test::Bencher) {
+	bench_indicator::<BalanceOfPower>(b);
+}
+
 #[bench]
 fn bench_bollinger_bands(b: &mut test::Bencher) {
 	bench_indicator::<BollingerBands>(b);
@@ -63,6 +94,11 @@ fn bench_commodity_channel_index(b: &mut test::Bencher) {
 	bench_indicator::<CommodityChannelIndex>(b);
 }
 
+#[bench]
+fn bench_connors_rsi(b: &mut test::Bencher) {
+	bench_indicator::<ConnorsRSI>(b);
+}
+
 #[bench]
 fn bench_coppock_curve(b: &mut test::Bencher) {
 	bench_indicator::<CoppockCurve>(b);
@@ -83,6 +119,11 @@ fn bench_ease_of_movement(b: &mut test::Bencher) {
 	bench_indicator::<EaseOfMovement>(b);
 }
 
+#[bench]
+fn bench_elder_ray(b: &mut test::Bencher) {
+	bench_indicator::<ElderRay>(b);
+}
+
 #[bench]
 fn bench_elders_force_index(b: &mut test::Bencher) {
 	bench_indicator::<EldersForceIndex>(b);
@@ -93,11 +134,26 @@ fn bench_envelopes(b: &mut test::Bencher) {
 	bench_indicator::<Envelopes>(b);
 }
 
+#[bench]
+fn bench_fib_retracement(b: &mut test::Bencher) {
+	bench_indicator::<FibRetracement>(b);
+}
+
 #[bench]
 fn bench_fisher_transform(b: &mut test::Bencher) {
 	bench_indicator::<FisherTransform>(b);
 }
 
+#[bench]
+fn bench_gmma(b: &mut test::Bencher) {
+	bench_indicator::<GMMA>(b);
+}
+
+#[bench]
+fn bench_heikin_ashi_trend(b: &mut test::Bencher) {
+	bench_indicator::<HeikinAshiTrend>(b);
+}
+
 #[bench]
 fn bench_hull_moving_average(b: &mut test::Bencher) {
 	bench_indicator::<HullMovingAverage>(b);
@@ -108,6 +164,11 @@ fn bench_ichimoku_cloud(b: &mut test::Bencher) {
 	bench_indicator::<IchimokuCloud>(b);
 }
 
+#[bench]
+fn bench_imbalance(b: &mut test::Bencher) {
+	bench_indicator::<Imbalance>(b);
+}
+
 #[bench]
 fn bench_kaufman(b: &mut test::Bencher) {
 	bench_indicator::<Kaufman>(b);
@@ -128,11 +189,26 @@ fn bench_know_sure_thing(b: &mut test::Bencher) {
 	bench_indicator::<KnowSureThing>(b);
 }
 
+#[bench]
+fn bench_ma_cross(b: &mut test::Bencher) {
+	bench_indicator::<MACross>(b);
+}
+
+#[bench]
+fn bench_ma_ribbon(b: &mut test::Bencher) {
+	bench_indicator::<MARibbon>(b);
+}
+
 #[bench]
 fn bench_macd(b: &mut test::Bencher) {
 	bench_indicator::<MACD>(b);
 }
 
+#[bench]
+fn bench_market_structure(b: &mut test::Bencher) {
+	bench_indicator::<MarketStructure>(b);
+}
+
 #[bench]
 fn bench_momentum_index(b: &mut test::Bencher) {
 	bench_indicator::<MomentumIndex>(b);
@@ -153,6 +229,11 @@ fn bench_pivot_reversal_strategy(b: &mut test::Bencher) {
 	bench_indicator::<PivotReversalStrategy>(b);
 }
 
+#[bench]
+fn bench_quantitative_qualitative_estimation(b: &mut test::Bencher) {
+	bench_indicator::<QuantitativeQualitativeEstimation>(b);
+}
+
 #[bench]
 fn bench_relative_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeStrengthIndex>(b);
@@ -163,11 +244,21 @@ fn bench_relative_vigor_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeVigorIndex>(b);
 }
 
+#[bench]
+fn bench_schaff_trend_cycle(b: &mut test::Bencher) {
+	bench_indicator::<SchaffTrendCycle>(b);
+}
+
 #[bench]
 fn bench_smi_ergodic_indicator(b: &mut test::Bencher) {
 	bench_indicator::<SMIErgodicIndicator>(b);
 }
 
+#[bench]
+fn bench_squeeze_momentum(b: &mut test::Bencher) {
+	bench_indicator::<SqueezeMomentum>(b);
+}
+
 #[bench]
 fn bench_stochastic_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<StochasticOscillator>(b);
@@ -178,6 +269,11 @@ fn bench_trend_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrendStrengthIndex>(b);
 }
 
+#[bench]
+fn bench_triple_screen(b: &mut test::Bencher) {
+	bench_indicator::<TripleScreen>(b);
+}
+
 #[bench]
 fn bench_trix(b: &mut test::Bencher) {
 	bench_indicator::<Trix>(b);
@@ -188,6 +284,21 @@ fn bench_true_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrueStrengthIndex>(b);
 }
 
+#[bench]
+fn bench_ulcer_index(b: &mut test::Bencher) {
+	bench_indicator::<UlcerIndex>(b);
+}
+
+#[bench]
+fn bench_volume_profile(b: &mut test::Bencher) {
+	bench_indicator::<VolumeProfile>(b);
+}
+
+#[bench]
+fn bench_vortex_indicator(b: &mut test::Bencher) {
+	bench_indicator::<VortexIndicator>(b);
+}
+
 #[bench]
 fn bench_woodies_cci(b: &mut test::Bencher) {
 	bench_indicator::<WoodiesCCI>(b);
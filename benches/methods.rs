@@ -89,6 +89,88 @@ fn bench_cross_under(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+#[bench]
+fn bench_zero_cross(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let avg = candles.iter().sum::<ValueType>() / candles.len() as ValueType;
+	let mut iter = candles.iter().cycle().map(|&x| x - avg);
+	let mut method = ZeroCross::new((), candles[0] - avg).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// CrossCount -----------------------------------------------------------------------------------
+#[bench]
+fn bench_cross_count_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = CrossCount::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_cross_count_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = CrossCount::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// BarsSinceCross -----------------------------------------------------------------------------------
+#[bench]
+fn bench_bars_since_cross(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| c.close)
+		.zip(RandomCandles::new().skip(15).take(1000).map(|c| c.close))
+		.collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = BarsSinceCross::new((), candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Divergence -----------------------------------------------------------------------------------
+#[bench]
+fn bench_divergence_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| (c.close, c.volume))
+		.collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Divergence::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_divergence_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| (c.close, c.volume))
+		.collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Divergence::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // SMA -----------------------------------------------------------------------------------
 #[bench]
 fn bench_sma_w10(b: &mut test::Bencher) {
@@ -296,6 +378,29 @@ fn bench_hma_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// Slope -----------------------------------------------------------------------------------
+#[bench]
+fn bench_slope_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Slope::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_slope_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Slope::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // LinReg -----------------------------------------------------------------------------------
 #[bench]
 fn bench_lin_reg_w10(b: &mut test::Bencher) {
@@ -319,6 +424,29 @@ fn bench_lin_reg_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// LinRegFull -----------------------------------------------------------------------------------
+#[bench]
+fn bench_lin_reg_full_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = LinRegFull::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_lin_reg_full_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = LinRegFull::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // Derivative -----------------------------------------------------------------------------------
 #[bench]
 fn bench_derivative_w10(b: &mut test::Bencher) {
@@ -365,6 +493,15 @@ fn bench_integral_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// Streak -----------------------------------------------------------------------------------
+#[bench]
+fn bench_streak(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Streak::new((), candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // Momentum -----------------------------------------------------------------------------------
 #[bench]
 fn bench_momentum_w10(b: &mut test::Bencher) {
@@ -649,6 +786,22 @@ fn bench_vwma_w100(b: &mut test::Bencher) {
 	})
 }
 
+// VWStDev -----------------------------------------------------------------------------------
+#[bench]
+fn bench_vw_st_dev_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = VWStDev::new(10, (candles[0].close, candles[0].volume)).unwrap();
+	for _ in 0..10 {
+		let candle = iter.next().unwrap();
+		method.next((candle.close, candle.volume));
+	}
+	b.iter(|| {
+		let candle = iter.next().unwrap();
+		method.next((candle.close, candle.volume))
+	})
+}
+
 // Highest -----------------------------------------------------------------------------------
 #[bench]
 fn bench_highest_w10(b: &mut test::Bencher) {
@@ -764,6 +917,52 @@ fn bench_lowest_index_w100(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// NewHighest -----------------------------------------------------------------------------------
+#[bench]
+fn bench_new_highest_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = NewHighest::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_new_highest_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = NewHighest::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// NewLowest -----------------------------------------------------------------------------------
+#[bench]
+fn bench_new_lowest_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = NewLowest::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_new_lowest_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = NewLowest::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // MeanAbsDev -----------------------------------------------------------------------------------
 #[bench]
 fn bench_mean_abs_dev_w10(b: &mut test::Bencher) {
@@ -864,6 +1063,24 @@ fn bench_tr(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// GapDetector -----------------------------------------------------------------------------------
+#[bench]
+fn bench_gap_detector(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = GapDetector::new((14, 0.5), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// ATRTrailingStop -------------------------------------------------------------------------------
+#[bench]
+fn bench_atr_trailing_stop(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ATRTrailingStop::new((14, 2.0, PositionSide::Long), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
 // Vidya  -----------------------------------------------------------------------------------
 #[bench]
 fn bench_vidya_w10(b: &mut test::Bencher) {
@@ -894,3 +1111,138 @@ fn bench_heikin_ashi(b: &mut test::Bencher) {
 	let mut method = HeikinAshi::new((), &candles[0]).unwrap();
 	b.iter(|| method.next(&iter.next().unwrap()))
 }
+
+// DominantCycle -----------------------------------------------------------------------------------
+#[bench]
+fn bench_dominant_cycle_10_48(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = DominantCycle::new((10, 48), candles[0]).unwrap();
+	for _ in 0..48 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Clamp -----------------------------------------------------------------------------------
+#[bench]
+fn bench_clamp(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Clamp::new((candles[0] * 0.99, candles[0] * 1.01), candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RateLimit -----------------------------------------------------------------------------------
+#[bench]
+fn bench_rate_limit(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = RateLimit::new(candles[0] * 0.001, candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Identity -----------------------------------------------------------------------------------
+#[bench]
+fn bench_identity(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Identity::new(1, candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Constant -----------------------------------------------------------------------------------
+#[bench]
+fn bench_constant(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Constant::new(1, candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// VWAP -----------------------------------------------------------------------------------
+#[bench]
+fn bench_vwap_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = VWAP::new(10, &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_vwap_anchored(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = VWAP::new(0, &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// EnvelopeOf -----------------------------------------------------------------------------------
+#[bench]
+fn bench_envelope_of_sma_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = EnvelopeOf::<SMA>::new((10, 0.1), candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// PercentRank -----------------------------------------------------------------------------------
+#[bench]
+fn bench_percent_rank_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = PercentRank::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_percent_rank_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = PercentRank::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RollingVWAP -----------------------------------------------------------------------------------
+#[bench]
+fn bench_rolling_vwap_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| (c.close, c.volume))
+		.collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = RollingVWAP::new(10, candles[0])
+		.unwrap()
+		.with_bands(2.0)
+		.unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Drawdown --------------------------------------------------------------------------------------
+#[bench]
+fn bench_drawdown_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Drawdown::new(10, candles[0]).unwrap();
+	for _ in 0..10 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_drawdown_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle().copied();
+	let mut method = Drawdown::new(100, candles[0]).unwrap();
+	for _ in 0..100 {
+		method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
@@ -145,6 +145,17 @@ impl Candle {
 			volume: src.volume(),
 		}
 	}
+
+	/// Marks the candle as having no volume data by setting its volume to `NaN`
+	///
+	/// See [`OHLCV::has_volume`].
+	#[must_use]
+	pub const fn without_volume(self) -> Self {
+		Self {
+			volume: ValueType::NAN,
+			..self
+		}
+	}
 }
 
 /// Just an alias for [Candle]
@@ -0,0 +1,81 @@
+/// Lightweight state checkpoints for speculative evaluation
+///
+/// [`save`](Checkpoint::save) creates a cheap checkpoint of the current state and [`restore`](Checkpoint::restore) rolls the state back to it.
+///
+/// ## Important!
+///
+/// Checkpoint covers only **one** step: it stays valid while at most one `next` call is made after `save`.
+/// That is enough for speculative evaluation (*what if the next value is X?*) and for provisional (intrabar) updates,
+/// and it allows window-based methods to save only a single window slot, which is going to be overwritten, instead of copying the whole window.
+///
+/// Restoring a checkpoint after more than one `next` call is a logic error: [`Window`](crate::core::Window) and every window-based method
+/// panic in that case instead of silently corrupting the state.
+///
+/// The same checkpoint may be restored many times.
+///
+/// Implemented for [`Window`](crate::core::Window), for most of the [`methods`](crate::methods) and for indicator instances.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Checkpoint;
+/// use yata::methods::SMA;
+///
+/// let mut sma = SMA::new(3, 1.0).unwrap();
+/// sma.next(4.0);
+///
+/// let checkpoint = sma.save();
+///
+/// // what if the next value is 10.0?
+/// assert_eq!(sma.next(10.0), 5.0);
+/// sma.restore(&checkpoint);
+///
+/// // what if the next value is 7.0?
+/// assert_eq!(sma.next(7.0), 4.0);
+/// sma.restore(&checkpoint);
+///
+/// assert_eq!(sma.next(1.0), 2.0);
+/// ```
+pub trait Checkpoint {
+	/// Saved state type
+	type State;
+
+	/// Saves current state into a checkpoint
+	fn save(&self) -> Self::State;
+
+	/// Rolls the state back to the `state` checkpoint
+	fn restore(&mut self, state: &Self::State);
+}
+
+impl<T: Checkpoint> Checkpoint for Option<T> {
+	type State = Option<T::State>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.as_ref().map(T::save)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		if let (Some(this), Some(state)) = (self, state) {
+			this.restore(state);
+		}
+	}
+}
+
+impl<T: Checkpoint> Checkpoint for Vec<T> {
+	type State = Vec<T::State>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.iter().map(T::save).collect()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.iter_mut()
+			.zip(state)
+			.for_each(|(this, state)| this.restore(state));
+	}
+}
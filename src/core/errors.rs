@@ -1,3 +1,6 @@
+use std::fmt;
+use std::sync::Arc;
+
 /// Crate errors enum
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -6,10 +9,21 @@ pub enum Error {
 	SourceParse(String),
 
 	/// Error parsing indicator parameter
+	///
+	/// When the value of a known parameter is rejected by its parser, [`Error::parameter_parse`] is used instead to keep the parse error as a `source`.
 	ParameterParse(String, String),
 
 	/// Invalid parameters for method creation
-	WrongMethodParameters,
+	WrongMethodParameters {
+		/// Name of the wrong parameter
+		name: &'static str,
+
+		/// Human readable range of the expected parameter values
+		expected: &'static str,
+
+		/// Offending parameter value
+		value: String,
+	},
 
 	/// Invalid indicator config error
 	WrongConfig,
@@ -17,27 +31,151 @@ pub enum Error {
 	/// Invalid candles error
 	InvalidCandles,
 
+	/// Non-finite (`NaN` or infinite) input value error
+	NonFinite,
+
+	/// Volume-based indicator or method got a candle without volume data
+	///
+	/// See [`OHLCV::has_volume`](crate::core::OHLCV::has_volume).
+	NoVolume,
+
+	/// Binary state data is malformed
+	InvalidState,
+
+	/// Binary state data was saved with another state format version
+	WrongStateVersion {
+		/// Supported state format version
+		expected: u16,
+
+		/// State format version of the data
+		found: u16,
+	},
+
 	/// Any other error
 	Other(String),
+
+	/// Error with some context, caused by another `source` error
+	Wrapped {
+		/// Context description
+		context: String,
+
+		/// Original error
+		source: Arc<dyn std::error::Error + Send + Sync + 'static>,
+	},
+}
+
+impl Error {
+	/// Creates [`Error::WrongMethodParameters`] for the parameter `name`, which `value` is out of `expected` range
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Error;
+	///
+	/// let error = Error::wrong_method_parameter("length", "> 0", 0);
+	/// assert_eq!(error.to_string(), "Wrong method parameter `length`: expected > 0, got 0");
+	/// ```
+	pub fn wrong_method_parameter<V: fmt::Display>(
+		name: &'static str,
+		expected: &'static str,
+		value: V,
+	) -> Self {
+		Self::WrongMethodParameters {
+			name,
+			expected,
+			value: value.to_string(),
+		}
+	}
+
+	/// Creates an error of parsing the `value` of indicator parameter `name`, caused by the `source` parse error
+	///
+	/// The result is [`Error::Wrapped`], so the parse error is available through [`source`](std::error::Error::source).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::error::Error as StdError;
+	/// use yata::core::Error;
+	///
+	/// let source = "abc".parse::<u8>().unwrap_err();
+	/// let error = Error::parameter_parse("period", "abc", source);
+	///
+	/// assert_eq!(
+	///     error.to_string(),
+	///     "Unable to parse into period: \"abc\": invalid digit found in string"
+	/// );
+	/// assert!(error.source().is_some());
+	/// ```
+	pub fn parameter_parse<E>(name: &str, value: &str, source: E) -> Self
+	where
+		E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+	{
+		Self::Wrapped {
+			context: Self::ParameterParse(name.to_string(), value.to_string()).to_string(),
+			source: Arc::from(source.into()),
+		}
+	}
+
+	/// Wraps any `source` error with the `context` description
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use std::error::Error as StdError;
+	/// use yata::core::Error;
+	///
+	/// let source = "abc".parse::<u8>().unwrap_err();
+	/// let error = Error::wrap("Unable to read period", source);
+	///
+	/// assert!(error.source().is_some());
+	/// ```
+	pub fn wrap<C, E>(context: C, source: E) -> Self
+	where
+		C: Into<String>,
+		E: std::error::Error + Send + Sync + 'static,
+	{
+		Self::Wrapped {
+			context: context.into(),
+			source: Arc::new(source),
+		}
+	}
 }
 
-impl std::fmt::Display for Error {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Self::SourceParse(value) => write!(f, "Unable to parse value as Source: {:?}", value),
 			Self::ParameterParse(name, value) => {
 				write!(f, "Unable to parse into {}: {:?}", name, value)
 			}
-			Self::WrongMethodParameters => write!(f, "Wrong method parameters"),
+			Self::WrongMethodParameters {
+				name,
+				expected,
+				value,
+			} => write!(
+				f,
+				"Wrong method parameter `{name}`: expected {expected}, got {value}"
+			),
 			Self::WrongConfig => write!(f, "Wrong config"),
 			Self::InvalidCandles => write!(f, "Invalid candles"),
+			Self::NonFinite => write!(f, "Non-finite input value"),
+			Self::NoVolume => write!(f, "Candle has no volume data"),
+			Self::InvalidState => write!(f, "Invalid state data"),
+			Self::WrongStateVersion { expected, found } => write!(
+				f,
+				"Wrong state format version: expected {expected}, found {found}"
+			),
 			Self::Other(reason) => write!(f, "{}", reason),
+			Self::Wrapped { context, source } => write!(f, "{context}: {source}"),
 		}
 	}
 }
 
 impl std::error::Error for Error {
 	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-		None
+		match self {
+			Self::Wrapped { source, .. } => Some(source.as_ref()),
+			_ => None,
+		}
 	}
 }
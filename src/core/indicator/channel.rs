@@ -0,0 +1,83 @@
+use super::{IndicatorConfig, IndicatorResult};
+use crate::core::ValueType;
+
+/// Common interface of the channel (band) indicators
+///
+/// Channel indicators, like [`BollingerBands`](crate::indicators::BollingerBands), [`KeltnerChannel`](crate::indicators::KeltnerChannel),
+/// [`DonchianChannel`](crate::indicators::DonchianChannel) or [`Envelopes`](crate::indicators::Envelopes), return their bounds at different value indexes.
+/// This trait knows where the bounds are placed in the [`IndicatorResult`], so any code (f.e. squeeze detectors or breakout strategies)
+/// may be written once for any channel type.
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Channel, IndicatorResult};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{BollingerBands, KeltnerChannel};
+///
+/// // squeeze happens when the bands of the first channel are inside the second channel
+/// fn is_squeeze<A: Channel, B: Channel>(a: &IndicatorResult, b: &IndicatorResult) -> bool {
+///     A::upper(a) < B::upper(b) && A::lower(a) > B::lower(b)
+/// }
+///
+/// let mut candles = RandomCandles::new();
+/// let mut bb = BollingerBands::default().init(&candles.first()).unwrap();
+/// let mut kc = KeltnerChannel::default().init(&candles.first()).unwrap();
+///
+/// for candle in candles.take(100) {
+///     let bb_result = bb.next(&candle);
+///     let kc_result = kc.next(&candle);
+///
+///     assert!(BollingerBands::width(&bb_result) >= 0.0);
+///     let _squeeze = is_squeeze::<BollingerBands, KeltnerChannel>(&bb_result, &kc_result);
+/// }
+/// ```
+pub trait Channel: IndicatorConfig {
+	/// Returns `upper bound` of the channel from the indicator's `result`
+	fn upper(result: &IndicatorResult) -> ValueType;
+
+	/// Returns `lower bound` of the channel from the indicator's `result`
+	fn lower(result: &IndicatorResult) -> ValueType;
+
+	/// Returns `middle line` of the channel from the indicator's `result`
+	///
+	/// Default implementation returns the value exactly between `upper bound` and `lower bound`.
+	#[must_use]
+	fn middle(result: &IndicatorResult) -> ValueType {
+		(Self::upper(result) + Self::lower(result)) * 0.5
+	}
+
+	/// Returns width of the channel: `upper bound` - `lower bound`
+	#[must_use]
+	fn width(result: &IndicatorResult) -> ValueType {
+		Self::upper(result) - Self::lower(result)
+	}
+
+	/// Returns width of the channel relative to the `middle line`: (`upper bound` - `lower bound`) / `middle line`
+	///
+	/// It is not multiplied by `100`, so `0.01` means `1%`.
+	#[must_use]
+	fn relative_width(result: &IndicatorResult) -> ValueType {
+		Self::width(result) / Self::middle(result)
+	}
+
+	/// Returns relative position of the `value` inside the channel (also known as `%b`)
+	///
+	/// Returns `0.0` when `value` is on the `lower bound`, `1.0` when `value` is on the `upper bound` and `0.5` if the channel has zero width.
+	/// Values outside of the channel give results outside of \[`0.0`; `1.0`\] range.
+	#[must_use]
+	fn position(result: &IndicatorResult, value: ValueType) -> ValueType {
+		let width = Self::width(result);
+
+		if width == 0.0 {
+			0.5
+		} else {
+			(value - Self::lower(result)) / width
+		}
+	}
+
+	/// Checks if the `value` is inside the channel (bounds are included)
+	#[must_use]
+	fn contains(result: &IndicatorResult, value: ValueType) -> bool {
+		value >= Self::lower(result) && value <= Self::upper(result)
+	}
+}
@@ -1,4 +1,4 @@
-use super::{IndicatorInstance, IndicatorResult};
+use super::{IndicatorInstance, IndicatorMetadata, IndicatorResult};
 use crate::core::{Error, OHLCV};
 
 /// Each indicator has it's own **Configuration** with parameters
@@ -14,6 +14,11 @@ pub trait IndicatorConfig: Clone {
 	/// Name of an indicator
 	const NAME: &'static str;
 
+	/// Static metadata of an indicator: category, volume usage and documented ranges of the values
+	///
+	/// Default is [`IndicatorMetadata::DEFAULT`].
+	const METADATA: IndicatorMetadata = IndicatorMetadata::DEFAULT;
+
 	/// Validates if **Configuration** is OK
 	fn validate(&self) -> bool;
 
@@ -31,6 +36,11 @@ pub trait IndicatorConfig: Clone {
 		Self::NAME
 	}
 
+	/// Returns static metadata of the indicator
+	fn metadata(&self) -> IndicatorMetadata {
+		Self::METADATA
+	}
+
 	/// Creates an `IndicatorInstance` function from this `IndicatorConfig`.
 	fn init_fn<'a, T: OHLCV>(
 		self,
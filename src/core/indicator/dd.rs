@@ -1,4 +1,4 @@
-use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use super::{IndicatorConfig, IndicatorInstance, IndicatorMetadata, IndicatorResult};
 use crate::core::{Error, OHLCV};
 
 /// Dynamically dispatchable [`IndicatorConfig`](crate::core::IndicatorConfig)
@@ -23,6 +23,9 @@ pub trait IndicatorConfigDyn<T: OHLCV> {
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str;
 
+	/// Returns static metadata of the indicator
+	fn metadata(&self) -> IndicatorMetadata;
+
 	/// Validates if **Configuration** is OK
 	fn validate(&self) -> bool;
 
@@ -52,6 +55,10 @@ where
 		<Self as IndicatorConfig>::NAME
 	}
 
+	fn metadata(&self) -> IndicatorMetadata {
+		<Self as IndicatorConfig>::METADATA
+	}
+
 	fn validate(&self) -> bool {
 		IndicatorConfig::validate(self)
 	}
@@ -86,6 +93,12 @@ pub trait IndicatorInstanceDyn<T: OHLCV> {
 	/// ```
 	fn over(&mut self, inputs: &dyn AsRef<[T]>) -> Vec<IndicatorResult>;
 
+	/// Evaluates the **State** over the given sequence of candles and appends results to the `results`.
+	///
+	/// The whole batch is evaluated by a single dynamic call.
+	/// See more at [`IndicatorInstance::over_into`](crate::core::IndicatorInstance::over_into)
+	fn over_into(&mut self, inputs: &dyn AsRef<[T]>, results: &mut Vec<IndicatorResult>);
+
 	/// Returns a reference to dynamically dispatched **Configuration**, associated with the current **State**
 	fn config(&self) -> &dyn IndicatorConfigDyn<T>;
 
@@ -111,6 +124,10 @@ where
 		IndicatorInstance::over(self, inputs)
 	}
 
+	fn over_into(&mut self, inputs: &dyn AsRef<[T]>, results: &mut Vec<IndicatorResult>) {
+		IndicatorInstance::over_into(self, inputs, results);
+	}
+
 	fn config(&self) -> &dyn IndicatorConfigDyn<T> {
 		self.config()
 	}
@@ -0,0 +1,347 @@
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::{Action, OHLCV};
+use std::fmt;
+use std::sync::mpsc::Sender;
+
+/// Non-empty signal, produced by some indicator, which [`SignalDispatcher`] passes to subscribers
+#[derive(Debug, Clone, Copy)]
+pub struct SignalEvent<'a> {
+	/// Name of the indicator, under which the result was dispatched
+	pub indicator: &'a str,
+
+	/// Index of the signal in the indicator's result
+	pub signal: usize,
+
+	/// The signal itself. Never [`Action::None`]
+	pub action: Action,
+
+	/// Full result of the indicator, which holds the signal
+	pub result: &'a IndicatorResult,
+}
+
+/// Owned version of [`SignalEvent`], which is sent to channels
+///
+/// See [`SignalDispatcher::subscribe_channel`].
+#[derive(Debug, Clone)]
+pub struct OwnedSignalEvent {
+	/// Name of the indicator, under which the result was dispatched
+	pub indicator: String,
+
+	/// Index of the signal in the indicator's result
+	pub signal: usize,
+
+	/// The signal itself. Never [`Action::None`]
+	pub action: Action,
+
+	/// Full result of the indicator, which holds the signal
+	pub result: IndicatorResult,
+}
+
+impl From<&SignalEvent<'_>> for OwnedSignalEvent {
+	fn from(event: &SignalEvent<'_>) -> Self {
+		Self {
+			indicator: event.indicator.to_string(),
+			signal: event.signal,
+			action: event.action,
+			result: *event.result,
+		}
+	}
+}
+
+/// Identifier of the subscription in the [`SignalDispatcher`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(usize);
+
+type Callback<'a> = Box<dyn FnMut(&SignalEvent<'_>) + 'a>;
+
+struct Subscription<'a> {
+	id: SubscriptionId,
+	indicator: String,
+	signal: Option<usize>,
+	callback: Callback<'a>,
+}
+
+/// Dispatcher of indicators signals to subscribed callbacks and channels
+///
+/// Strategy code subscribes to the signals of specific indicators (and optionally specific signal indexes) by indicator name.
+/// Signal generation code just passes every indicator result to the dispatcher with [`dispatch`](SignalDispatcher::dispatch)
+/// or [`dispatch_next`](SignalDispatcher::dispatch_next). Every non-[`None`](Action::None) signal is passed to every
+/// matching subscriber. Subscribers are invoked in the order of subscription, and each of them gets its signals in the order of signal indexes.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::SignalDispatcher;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{MACD, RSI};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(300).collect();
+/// let mut macd = MACD::default().init(&candles[0]).unwrap();
+/// let mut rsi = RSI::default().init(&candles[0]).unwrap();
+///
+/// let mut crosses = 0;
+/// let (sender, receiver) = std::sync::mpsc::channel();
+///
+/// let mut dispatcher = SignalDispatcher::new();
+/// dispatcher.subscribe("macd", 0, |event| {
+///     assert_eq!(event.indicator, "macd");
+///     crosses += 1;
+/// });
+/// dispatcher.subscribe_channel("rsi", None, sender);
+///
+/// for candle in &candles {
+///     dispatcher.dispatch_next("macd", &mut macd, candle);
+///     dispatcher.dispatch_next("rsi", &mut rsi, candle);
+/// }
+///
+/// drop(dispatcher);
+/// assert!(crosses > 0);
+/// assert!(receiver.try_iter().all(|event| event.indicator == "rsi" && event.action.is_some()));
+/// ```
+#[derive(Default)]
+pub struct SignalDispatcher<'a> {
+	subscriptions: Vec<Subscription<'a>>,
+	next_id: usize,
+}
+
+impl<'a> SignalDispatcher<'a> {
+	/// Creates a new dispatcher without any subscriptions
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Subscribes `callback` to the `signal`-th signal of the `indicator`
+	pub fn subscribe<S, F>(&mut self, indicator: S, signal: usize, callback: F) -> SubscriptionId
+	where
+		S: Into<String>,
+		F: FnMut(&SignalEvent<'_>) + 'a,
+	{
+		self.add(indicator.into(), Some(signal), Box::new(callback))
+	}
+
+	/// Subscribes `callback` to every signal of the `indicator`
+	pub fn subscribe_all<S, F>(&mut self, indicator: S, callback: F) -> SubscriptionId
+	where
+		S: Into<String>,
+		F: FnMut(&SignalEvent<'_>) + 'a,
+	{
+		self.add(indicator.into(), None, Box::new(callback))
+	}
+
+	/// Subscribes `sender` to the `signal`-th signal (or to every signal when `signal` is `None`) of the `indicator`
+	///
+	/// Events are silently dropped when the receiving side of the channel is disconnected.
+	pub fn subscribe_channel<S>(
+		&mut self,
+		indicator: S,
+		signal: Option<usize>,
+		sender: Sender<OwnedSignalEvent>,
+	) -> SubscriptionId
+	where
+		S: Into<String>,
+	{
+		let callback = move |event: &SignalEvent<'_>| {
+			let _ = sender.send(event.into());
+		};
+
+		self.add(indicator.into(), signal, Box::new(callback))
+	}
+
+	/// Removes the subscription. Returns `false` if there is no such subscription
+	pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+		let len = self.subscriptions.len();
+		self.subscriptions
+			.retain(|subscription| subscription.id != id);
+
+		self.subscriptions.len() != len
+	}
+
+	/// Passes every non-empty signal of the `result` of the `indicator` to the matching subscribers
+	///
+	/// Returns count of invoked callbacks.
+	pub fn dispatch(&mut self, indicator: &str, result: &IndicatorResult) -> usize {
+		let mut count = 0;
+
+		for subscription in &mut self.subscriptions {
+			if subscription.indicator != indicator {
+				continue;
+			}
+
+			for (signal, &action) in result.signals().iter().enumerate() {
+				if action.is_none() || subscription.signal.unwrap_or(signal) != signal {
+					continue;
+				}
+
+				let event = SignalEvent {
+					indicator,
+					signal,
+					action,
+					result,
+				};
+
+				(subscription.callback)(&event);
+				count += 1;
+			}
+		}
+
+		count
+	}
+
+	/// Evaluates the `instance` over the `candle`, dispatches the result under the `indicator` name and returns it
+	pub fn dispatch_next<I, T>(
+		&mut self,
+		indicator: &str,
+		instance: &mut I,
+		candle: &T,
+	) -> IndicatorResult
+	where
+		I: IndicatorInstance,
+		T: OHLCV,
+	{
+		let result = instance.next(candle);
+		self.dispatch(indicator, &result);
+
+		result
+	}
+
+	/// Returns count of the subscriptions
+	#[must_use]
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn len(&self) -> usize {
+		self.subscriptions.len()
+	}
+
+	/// Checks if there are no subscriptions
+	#[must_use]
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn is_empty(&self) -> bool {
+		self.subscriptions.is_empty()
+	}
+
+	fn add(
+		&mut self,
+		indicator: String,
+		signal: Option<usize>,
+		callback: Callback<'a>,
+	) -> SubscriptionId {
+		let id = SubscriptionId(self.next_id);
+		self.next_id += 1;
+
+		self.subscriptions.push(Subscription {
+			id,
+			indicator,
+			signal,
+			callback,
+		});
+
+		id
+	}
+}
+
+impl fmt::Debug for SignalDispatcher<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let subscriptions: Vec<(&str, Option<usize>)> = self
+			.subscriptions
+			.iter()
+			.map(|subscription| (subscription.indicator.as_str(), subscription.signal))
+			.collect();
+
+		f.debug_struct("SignalDispatcher")
+			.field("subscriptions", &subscriptions)
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SignalDispatcher;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorResult};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+	use std::cell::RefCell;
+	use std::sync::mpsc::channel;
+
+	#[test]
+	fn test_signal_dispatcher() {
+		let result =
+			IndicatorResult::new(&[1.0], &[Action::BUY_ALL, Action::None, Action::SELL_ALL]);
+
+		let mut first = Vec::new();
+		let mut all = Vec::new();
+		let (sender, receiver) = channel();
+
+		let mut dispatcher = SignalDispatcher::new();
+		dispatcher.subscribe("a", 0, |event| first.push(event.action));
+		let id = dispatcher.subscribe("a", 1, |_| panic!("empty signals must not be dispatched"));
+		dispatcher.subscribe_all("a", |event| all.push(event.signal));
+		dispatcher.subscribe_channel("b", Some(2), sender);
+		assert_eq!(dispatcher.len(), 4);
+
+		assert_eq!(dispatcher.dispatch("a", &result), 3);
+		assert_eq!(dispatcher.dispatch("b", &result), 1);
+		assert_eq!(dispatcher.dispatch("c", &result), 0);
+
+		assert!(dispatcher.unsubscribe(id));
+		assert!(!dispatcher.unsubscribe(id));
+		assert_eq!(dispatcher.len(), 3);
+		drop(dispatcher);
+
+		assert_eq!(first, vec![Action::BUY_ALL]);
+		assert_eq!(all, vec![0, 2]);
+
+		let events: Vec<_> = receiver.try_iter().collect();
+		assert_eq!(events.len(), 1);
+		assert_eq!(events[0].indicator, "b");
+		assert_eq!(events[0].signal, 2);
+		assert_eq!(events[0].action, Action::SELL_ALL);
+		assert_eq!(events[0].result.values(), result.values());
+	}
+
+	#[test]
+	fn test_signal_dispatcher_over() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let results = MACD::default().over(&candles).unwrap();
+		let expected = results
+			.iter()
+			.filter(|result| result.signal(1).is_some())
+			.count();
+
+		let mut count = 0;
+		let mut state = MACD::default().init(&candles[0]).unwrap();
+		let mut dispatcher = SignalDispatcher::new();
+		dispatcher.subscribe("macd", 1, |event| {
+			assert_eq!(event.signal, 1);
+			assert!(event.action.is_some());
+			count += 1;
+		});
+
+		for (candle, result) in candles.iter().zip(&results) {
+			let next = dispatcher.dispatch_next("macd", &mut state, candle);
+			assert_eq!(next.signals(), result.signals());
+		}
+
+		drop(dispatcher);
+		assert_eq!(count, expected);
+	}
+
+	#[test]
+	fn test_signal_dispatcher_order() {
+		let result = IndicatorResult::new(&[1.0], &[Action::BUY_ALL, Action::SELL_ALL]);
+		let calls = RefCell::new(Vec::new());
+
+		let mut dispatcher = SignalDispatcher::new();
+		dispatcher.subscribe_all("a", |event| calls.borrow_mut().push((1, event.signal)));
+		dispatcher.subscribe("a", 1, |event| calls.borrow_mut().push((2, event.signal)));
+		dispatcher.subscribe_all("a", |event| calls.borrow_mut().push((3, event.signal)));
+
+		assert_eq!(dispatcher.dispatch("a", &result), 5);
+		drop(dispatcher);
+
+		assert_eq!(
+			calls.into_inner(),
+			vec![(1, 0), (1, 1), (2, 1), (3, 0), (3, 1)]
+		);
+	}
+}
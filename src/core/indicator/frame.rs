@@ -0,0 +1,241 @@
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::{Action, ValueType, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Column-oriented storage of [`IndicatorResult`]s
+///
+/// Every raw value and every signal of the indicator is stored in its own column (`Vec`),
+/// so a long history takes only as much memory as the indicator really returns
+/// instead of [`IndicatorResult::VALUES_SIZE`] values and [`IndicatorResult::SIGNALS_SIZE`] signals per each candle.
+///
+/// Columns may be accessed as slices by [`values`](ResultFrame::values) and [`signals`](ResultFrame::signals).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{ResultFrame, ValueType};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+/// let mut state = MACD::default().init(&candles[0]).unwrap();
+///
+/// let mut frame = ResultFrame::with_capacity(state.size(), candles.len());
+/// frame.extend_over(&mut state, &candles);
+///
+/// assert_eq!(frame.len(), 1000);
+///
+/// let macd: &[ValueType] = frame.values(0);
+/// let crosses = frame.signals(0).iter().filter(|signal| signal.is_some()).count();
+/// println!("{} {}", macd[999], crosses);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ResultFrame {
+	values: Vec<Vec<ValueType>>,
+	signals: Vec<Vec<Action>>,
+	len: usize,
+}
+
+impl ResultFrame {
+	/// Creates an empty frame of the specified `size` (count of raw values, count of signals)
+	///
+	/// Usually `size` is taken from [`IndicatorConfig::size`](crate::core::IndicatorConfig::size)
+	/// or [`IndicatorInstance::size`](crate::core::IndicatorInstance::size).
+	#[must_use]
+	pub fn new(size: (u8, u8)) -> Self {
+		Self::with_capacity(size, 0)
+	}
+
+	/// Creates an empty frame of the specified `size` with space for at least `capacity` results in every column
+	#[must_use]
+	pub fn with_capacity((values, signals): (u8, u8), capacity: usize) -> Self {
+		Self {
+			values: (0..values).map(|_| Vec::with_capacity(capacity)).collect(),
+			signals: (0..signals).map(|_| Vec::with_capacity(capacity)).collect(),
+			len: 0,
+		}
+	}
+
+	/// Appends a single `result` to the frame
+	///
+	/// # Panics
+	///
+	/// Panics if `result` has less values or signals, than the frame has columns.
+	/// Extra values and signals of the `result` are ignored.
+	pub fn push(&mut self, result: &IndicatorResult) {
+		for (index, column) in self.values.iter_mut().enumerate() {
+			column.push(result.value(index));
+		}
+
+		for (index, column) in self.signals.iter_mut().enumerate() {
+			column.push(result.signal(index));
+		}
+
+		self.len += 1;
+	}
+
+	/// Evaluates the `instance` over `inputs` and appends every result to the frame
+	///
+	/// See also [`IndicatorInstance::over_into`].
+	pub fn extend_over<I, T, S>(&mut self, instance: &mut I, inputs: S)
+	where
+		I: IndicatorInstance,
+		T: OHLCV,
+		S: AsRef<[T]>,
+	{
+		let inputs = inputs.as_ref();
+
+		self.reserve(inputs.len());
+		for candle in inputs {
+			self.push(&instance.next(candle));
+		}
+	}
+
+	/// Reserves space for at least `additional` results in every column
+	pub fn reserve(&mut self, additional: usize) {
+		self.values.iter_mut().for_each(|c| c.reserve(additional));
+		self.signals.iter_mut().for_each(|c| c.reserve(additional));
+	}
+
+	/// Removes all the results, keeping the columns and their allocated memory
+	pub fn clear(&mut self) {
+		self.values.iter_mut().for_each(Vec::clear);
+		self.signals.iter_mut().for_each(Vec::clear);
+		self.len = 0;
+	}
+
+	/// Returns a column of raw values at `index`
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of count of raw values.
+	#[must_use]
+	pub fn values(&self, index: usize) -> &[ValueType] {
+		&self.values[index]
+	}
+
+	/// Returns a column of signals at `index`
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of count of signals.
+	#[must_use]
+	pub fn signals(&self, index: usize) -> &[Action] {
+		&self.signals[index]
+	}
+
+	/// Returns a raw value at `index` of the `row`-th result or `None` if there is no such value
+	#[must_use]
+	pub fn value(&self, row: usize, index: usize) -> Option<ValueType> {
+		self.values
+			.get(index)
+			.and_then(|column| column.get(row).copied())
+	}
+
+	/// Returns a signal at `index` of the `row`-th result or `None` if there is no such signal
+	#[must_use]
+	pub fn signal(&self, row: usize, index: usize) -> Option<Action> {
+		self.signals
+			.get(index)
+			.and_then(|column| column.get(row).copied())
+	}
+
+	/// Collects the `row`-th result back into [`IndicatorResult`] or returns `None` if `row` is out of the frame
+	#[must_use]
+	pub fn result(&self, row: usize) -> Option<IndicatorResult> {
+		if row >= self.len {
+			return None;
+		}
+
+		let mut values = [0 as ValueType; IndicatorResult::VALUES_SIZE];
+		let mut signals = [Action::None; IndicatorResult::SIGNALS_SIZE];
+
+		for (value, column) in values.iter_mut().zip(&self.values) {
+			*value = column[row];
+		}
+
+		for (signal, column) in signals.iter_mut().zip(&self.signals) {
+			*signal = column[row];
+		}
+
+		let values_length = self.values.len().min(IndicatorResult::VALUES_SIZE);
+		let signals_length = self.signals.len().min(IndicatorResult::SIGNALS_SIZE);
+
+		Some(IndicatorResult::new(
+			&values[..values_length],
+			&signals[..signals_length],
+		))
+	}
+
+	/// Returns count of the stored results
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if there are no stored results
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns a tuple of count of raw values columns and count of signals columns
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation, clippy::missing_const_for_fn)]
+	pub fn size(&self) -> (u8, u8) {
+		(self.values.len() as u8, self.signals.len() as u8)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ResultFrame;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_result_frame() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let config = MACD::default();
+		let results = config.over(&candles).unwrap();
+
+		let mut state = config.init(&candles[0]).unwrap();
+		let mut frame = ResultFrame::new(state.size());
+		frame.extend_over(&mut state, &candles[..100]);
+		frame.extend_over(&mut state, &candles[100..]);
+
+		assert_eq!(frame.len(), results.len());
+		assert_eq!(frame.size(), config.size());
+
+		for (row, result) in results.iter().enumerate() {
+			for (index, &value) in result.values().iter().enumerate() {
+				assert_eq!(frame.values(index)[row].to_bits(), value.to_bits());
+				assert_eq!(
+					frame.value(row, index).map(ValueType::to_bits),
+					Some(value.to_bits())
+				);
+			}
+
+			for (index, &signal) in result.signals().iter().enumerate() {
+				assert_eq!(frame.signals(index)[row], signal);
+				assert_eq!(frame.signal(row, index), Some(signal));
+			}
+
+			let restored = frame.result(row).unwrap();
+			assert_eq!(restored.size(), result.size());
+			assert_eq!(restored.signals(), result.signals());
+		}
+
+		assert!(frame.result(results.len()).is_none());
+		assert!(frame.value(0, 2).is_none());
+
+		frame.clear();
+		assert!(frame.is_empty());
+		assert_eq!(frame.size(), config.size());
+	}
+}
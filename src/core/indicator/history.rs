@@ -0,0 +1,250 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Candle, Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+
+/// Ring buffer of the last `N` [`IndicatorResult`]s and source candles, attached to any [`IndicatorInstance`]
+///
+/// Useful for divergence checks and rules like *"signal happened within the last `K` bars"*.
+///
+/// Every accessor takes `index_back` as the number of bars ago: `0` means the last evaluated candle.
+/// Accessors return `None` when there is no such bar in the history yet.
+///
+/// Usually created by [`IndicatorInstance::with_history`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let mut candles = RandomCandles::new();
+/// let mut state = MACD::default().init(&candles.first()).unwrap().with_history(10).unwrap();
+///
+/// for candle in candles.take(20) {
+///     state.next(&candle);
+/// }
+///
+/// assert_eq!(state.len(), 10);
+/// assert!(state.value(9, 0).is_some());
+/// assert!(state.value(10, 0).is_none());
+///
+/// // did MACD cross its signal line within the last 5 bars?
+/// if let Some((bars_ago, signal)) = state.last_signal(0, 5) {
+///     println!("{:?} signal {} bars ago", signal, bars_ago);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ResultHistory<I: IndicatorInstance> {
+	instance: I,
+	results: Window<IndicatorResult>,
+	candles: Window<Candle>,
+	len: PeriodType,
+}
+
+impl<I: IndicatorInstance> ResultHistory<I> {
+	/// Creates a new `ResultHistory` of the `length` last results over the `instance`
+	///
+	/// `length` must be in range \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub fn new(instance: I, length: PeriodType) -> Result<Self, Error> {
+		if length == 0 || length == PeriodType::MAX {
+			return Err(Error::wrong_method_parameter(
+				"length",
+				"in [1; PeriodType::MAX)",
+				length,
+			));
+		}
+
+		Ok(Self {
+			instance,
+			results: Window::new(length, IndicatorResult::new(&[], &[])),
+			candles: Window::new(length, Candle::default()),
+			len: 0,
+		})
+	}
+
+	/// Evaluates the `candle` by the inner instance and stores its result into the history
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		self.results.push(result);
+		self.candles.push(Candle::from(candle));
+		self.len = self.results.len().min(self.len + 1);
+
+		result
+	}
+
+	/// Returns the result, evaluated `index_back` bars ago
+	#[must_use]
+	pub fn result(&self, index_back: PeriodType) -> Option<IndicatorResult> {
+		if index_back < self.len {
+			self.results.get_back(index_back)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the source candle of `index_back` bars ago
+	#[must_use]
+	pub fn candle(&self, index_back: PeriodType) -> Option<Candle> {
+		if index_back < self.len {
+			self.candles.get_back(index_back)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the raw value at `output` index of the result, evaluated `index_back` bars ago
+	#[must_use]
+	pub fn value(&self, index_back: PeriodType, output: usize) -> Option<ValueType> {
+		self.result(index_back)
+			.and_then(|result| result.values().get(output).copied())
+	}
+
+	/// Returns the signal at `output` index of the result, evaluated `index_back` bars ago
+	#[must_use]
+	pub fn signal(&self, index_back: PeriodType, output: usize) -> Option<Action> {
+		self.result(index_back)
+			.and_then(|result| result.signals().get(output).copied())
+	}
+
+	/// Searches for the latest non-empty signal at `output` index within the last `bars` bars.
+	///
+	/// Returns how many bars ago it happened and the signal itself.
+	#[must_use]
+	pub fn last_signal(&self, output: usize, bars: PeriodType) -> Option<(PeriodType, Action)> {
+		(0..bars.min(self.len)).find_map(|index_back| {
+			self.signal(index_back, output)
+				.filter(|signal| signal.is_some())
+				.map(|signal| (index_back, signal))
+		})
+	}
+
+	/// Returns count of the stored results
+	#[must_use]
+	pub const fn len(&self) -> PeriodType {
+		self.len
+	}
+
+	/// Checks if there are no stored results yet
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns maximum count of the stored results
+	#[must_use]
+	pub fn capacity(&self) -> PeriodType {
+		self.results.len()
+	}
+
+	/// Returns heap size of the stored results and candles together with the inner instance's heap size
+	#[must_use]
+	pub fn heap_size(&self) -> usize {
+		self.instance.heap_size() + self.results.heap_size() + self.candles.heap_size()
+	}
+
+	/// Returns a reference to the inner indicator instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Returns the inner indicator instance
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+}
+
+impl<I: IndicatorInstance + Checkpoint> Checkpoint for ResultHistory<I> {
+	type State = (
+		I::State,
+		WindowCheckpoint<IndicatorResult>,
+		WindowCheckpoint<Candle>,
+		PeriodType,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.instance.save(),
+			self.results.save(),
+			self.candles.save(),
+			self.len,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.instance.restore(&state.0);
+		self.results.restore(&state.1);
+		self.candles.restore(&state.2);
+		self.len = state.3;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ResultHistory;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, PeriodType, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_result_history() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+
+		for length in &[1, 2, 5, 50, 254] {
+			let length: PeriodType = *length;
+			let mut reference = MACD::default().init(&candles[0]).unwrap();
+			let mut history = MACD::default()
+				.init(&candles[0])
+				.unwrap()
+				.with_history(length)
+				.unwrap();
+			let mut results = Vec::new();
+
+			for (i, candle) in candles.iter().enumerate() {
+				results.push(reference.next(candle));
+				history.next(candle);
+
+				assert_eq!(history.len() as usize, (i + 1).min(length as usize));
+
+				for index_back in 0..=length {
+					let expected = i
+						.checked_sub(index_back as usize)
+						.filter(|_| index_back < length)
+						.map(|index| (results[index], candles[index]));
+
+					let value = history.value(index_back, 0);
+					assert_eq!(
+						value.map(ValueType::to_bits),
+						expected.map(|(r, _)| r.value(0).to_bits())
+					);
+					assert_eq!(
+						history.signal(index_back, 1),
+						expected.map(|(r, _)| r.signal(1))
+					);
+					assert_eq!(history.candle(index_back), expected.map(|(_, c)| c));
+				}
+
+				let last_signal = (0..history.len()).find_map(|index_back| {
+					let signal = results[i - index_back as usize].signal(0);
+
+					if signal.is_some() {
+						Some((index_back, signal))
+					} else {
+						None
+					}
+				});
+				assert_eq!(history.last_signal(0, length), last_signal);
+			}
+		}
+
+		assert!(ResultHistory::new(MACD::default().init(&candles[0]).unwrap(), 0).is_err());
+	}
+}
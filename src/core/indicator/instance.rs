@@ -1,5 +1,6 @@
-use super::{IndicatorConfig, IndicatorResult};
-use crate::core::OHLCV;
+use super::{IndicatorConfig, IndicatorResult, Provisional, ResultHistory, SignalTrace};
+use crate::core::{Checkpoint, Error, PeriodType, OHLCV};
+use std::mem::size_of_val;
 
 /// Base trait for implementing indicators **State**
 pub trait IndicatorInstance: Sized {
@@ -25,14 +26,48 @@ pub trait IndicatorInstance: Sized {
 	/// let results = state.over(&candles);
 	/// println!("{:?}", results);
 	/// ```
+	///
+	/// The output `Vec` is allocated once for the whole batch. Use [`over_into`](IndicatorInstance::over_into) to reuse an allocated `Vec` between batches.
 	#[inline]
 	fn over<T, S>(&mut self, inputs: S) -> Vec<IndicatorResult>
+	where
+		T: OHLCV,
+		S: AsRef<[T]>,
+	{
+		let mut results = Vec::new();
+		self.over_into(inputs, &mut results);
+		results
+	}
+
+	/// Evaluates the **State** over the given sequence of candles and appends results to the `results`.
+	///
+	/// Reserves space for the whole batch at once, so feeding a lot of candles by batches into the same `results` does not reallocate it on every batch.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+	/// let mut state = Trix::default().init(&candles[0]).unwrap();
+	/// let mut results = Vec::new();
+	///
+	/// for batch in candles.chunks(10) {
+	///     results.clear();
+	///     state.over_into(batch, &mut results);
+	///     assert_eq!(results.len(), batch.len());
+	/// }
+	/// ```
+	#[inline]
+	fn over_into<T, S>(&mut self, inputs: S, results: &mut Vec<IndicatorResult>)
 	where
 		T: OHLCV,
 		S: AsRef<[T]>,
 	{
 		let inputs_ref = inputs.as_ref();
-		inputs_ref.iter().map(|x| self.next(x)).collect()
+
+		results.reserve(inputs_ref.len());
+		results.extend(inputs_ref.iter().map(|x| self.next(x)));
 	}
 
 	/// Returns count of indicator's raw values and count of indicator's signals.
@@ -42,11 +77,57 @@ pub trait IndicatorInstance: Sized {
 		self.config().size()
 	}
 
+	/// Returns count of bytes, allocated by the instance on the heap (f.e. buffers of the inner methods)
+	///
+	/// Instances, which own heap allocated buffers, override it.
+	/// Default implementation returns `0`.
+	fn heap_size(&self) -> usize {
+		0
+	}
+
+	/// Returns estimated total memory size of the instance in bytes, including heap allocated buffers
+	///
+	/// It is useful for capacity planning, when a lot of instances are kept in memory.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::BollingerBands;
+	///
+	/// let candle = RandomCandles::new().first();
+	/// let state = BollingerBands::default().init(&candle).unwrap();
+	///
+	/// assert!(state.memory_size() > std::mem::size_of_val(&state));
+	/// ```
+	fn memory_size(&self) -> usize {
+		size_of_val(self) + self.heap_size()
+	}
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str {
 		Self::Config::NAME
 	}
 
+	/// Wraps the instance into [`Provisional`](crate::core::Provisional) for evaluating in-progress (still-open) candles.
+	///
+	/// See more at [`Provisional::next_provisional`](crate::core::Provisional::next_provisional) and [`Provisional::commit`](crate::core::Provisional::commit).
+	fn provisional(self) -> Provisional<Self>
+	where
+		Self: Checkpoint,
+	{
+		Provisional::new(self)
+	}
+
+	/// Attaches [`ResultHistory`](crate::core::ResultHistory) of the `length` last results to the instance.
+	fn with_history(self, length: PeriodType) -> Result<ResultHistory<Self>, Error> {
+		ResultHistory::new(self, length)
+	}
+
+	/// Attaches [`SignalTrace`](crate::core::SignalTrace) to the instance, which records every produced signal.
+	fn with_trace(self) -> SignalTrace<Self> {
+		SignalTrace::new(self)
+	}
+
 	/// Creates a function from `IndicatorInstance`
 	fn into_fn<'a, T>(mut self) -> Box<dyn FnMut(&'a T) -> IndicatorResult>
 	where
@@ -0,0 +1,137 @@
+use crate::core::{Error, ValueType, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Category of an indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum IndicatorCategory {
+	/// Trend following indicators (moving averages, stops, directional indexes)
+	Trend,
+
+	/// Momentum indicators and oscillators
+	Momentum,
+
+	/// Indicators, based on the volume of the candles
+	Volume,
+
+	/// Volatility indicators (channels and bands)
+	Volatility,
+
+	/// Any other indicator
+	Other,
+}
+
+/// Documented range of the indicator's raw value
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ValueRange {
+	/// Range of the value is the same as the range of the source (price) values, so it may be drawn over the price chart
+	Source,
+
+	/// Value is in range \[`min`; `max`\]
+	Bounded(ValueType, ValueType),
+
+	/// Value is in range \(`-inf`; `+inf`\)
+	Unbounded,
+}
+
+impl ValueRange {
+	/// Checks if the `value` is inside the range
+	///
+	/// Values of [`ValueRange::Source`] range can not be checked without the source values, so it always returns `true` for any finite value.
+	#[must_use]
+	pub fn contains(self, value: ValueType) -> bool {
+		match self {
+			Self::Source => value.is_finite(),
+			Self::Bounded(min, max) => value >= min && value <= max,
+			Self::Unbounded => !value.is_nan(),
+		}
+	}
+}
+
+/// Static metadata of an indicator
+///
+/// It is provided by [`IndicatorConfig::METADATA`](crate::core::IndicatorConfig::METADATA) and may be used by front-ends for presenting indicators
+/// and by engines for validating the data.
+///
+/// ```
+/// use yata::core::{IndicatorCategory, IndicatorConfig, ValueRange};
+/// use yata::indicators::{MoneyFlowIndex, RelativeStrengthIndex};
+///
+/// let metadata = RelativeStrengthIndex::METADATA;
+///
+/// assert_eq!(metadata.category, IndicatorCategory::Momentum);
+/// assert!(!metadata.requires_volume);
+/// assert_eq!(metadata.ranges, &[ValueRange::Bounded(0.0, 1.0)]);
+///
+/// assert!(MoneyFlowIndex::METADATA.requires_volume);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IndicatorMetadata {
+	/// Category of the indicator
+	pub category: IndicatorCategory,
+
+	/// Indicator uses [`volume`](crate::core::OHLCV::volume) of the candles, so it can not be evaluated over volume-less data
+	pub requires_volume: bool,
+
+	/// Documented ranges of the indicator's raw values in the same order as the values are
+	///
+	/// Some indicators may be configured to return less values, than there are ranges.
+	pub ranges: &'static [ValueRange],
+}
+
+impl IndicatorMetadata {
+	/// Metadata of an indicator without any specific information
+	pub const DEFAULT: Self = Self {
+		category: IndicatorCategory::Other,
+		requires_volume: false,
+		ranges: &[],
+	};
+
+	/// Creates metadata with specified `category` and `ranges` for an indicator, that does not use volume
+	#[must_use]
+	pub const fn new(category: IndicatorCategory, ranges: &'static [ValueRange]) -> Self {
+		Self {
+			category,
+			requires_volume: false,
+			ranges,
+		}
+	}
+
+	/// Marks an indicator as using volume of the candles
+	#[must_use]
+	pub const fn with_volume(self) -> Self {
+		Self {
+			requires_volume: true,
+			..self
+		}
+	}
+
+	/// Checks if the indicator may be evaluated over the `candle`
+	///
+	/// Returns `false` if the indicator requires volume, but the `candle` [has no volume](crate::core::OHLCV::has_volume).
+	pub fn is_applicable<T: OHLCV>(&self, candle: &T) -> bool {
+		!self.requires_volume || candle.has_volume()
+	}
+
+	/// Checks if the indicator may be initialized by the `candle`
+	///
+	/// Returns [`Error::NoVolume`] if the indicator requires volume, but the `candle` [has no volume](crate::core::OHLCV::has_volume).
+	pub fn check<T: OHLCV>(&self, candle: &T) -> Result<(), Error> {
+		if self.is_applicable(candle) {
+			Ok(())
+		} else {
+			Err(Error::NoVolume)
+		}
+	}
+}
+
+impl Default for IndicatorMetadata {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
@@ -4,12 +4,32 @@
 //!
 //! Every indicator **State** must implement [`IndicatorInstance`].
 
+mod channel;
 mod config;
 mod dd;
+mod dispatcher;
+mod frame;
+mod history;
 mod instance;
+mod metadata;
+mod oscillator;
+mod preset;
+mod provisional;
 mod result;
+mod trace;
+mod writer;
 
+pub use channel::*;
 pub use config::*;
 pub use dd::*;
+pub use dispatcher::*;
+pub use frame::*;
+pub use history::*;
 pub use instance::*;
+pub use metadata::*;
+pub use oscillator::*;
+pub use preset::*;
+pub use provisional::*;
 pub use result::*;
+pub use trace::*;
+pub use writer::*;
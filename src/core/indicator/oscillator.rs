@@ -0,0 +1,87 @@
+use super::{IndicatorConfig, IndicatorResult, ValueRange};
+use crate::core::ValueType;
+
+/// Common interface of the oscillator indicators
+///
+/// Oscillators, like [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex), [`StochasticOscillator`](crate::indicators::StochasticOscillator),
+/// [`CommodityChannelIndex`](crate::indicators::CommodityChannelIndex) or [`MoneyFlowIndex`](crate::indicators::MoneyFlowIndex), return their main value
+/// at different value indexes and have different overbought/oversold levels.
+/// This trait knows where the values are placed in the [`IndicatorResult`] and how the config's zone is interpreted,
+/// so overbought/oversold or divergence tools may be written once for any oscillator type.
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, IndicatorResult, Oscillator};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{RelativeStrengthIndex, StochasticOscillator};
+/// use yata::methods::Divergence;
+///
+/// // counts divergences of any oscillator in the overbought/oversold zones
+/// fn count_divergences<O: Oscillator + Copy>(config: O, candles: &[Candle]) -> usize {
+///     let mut divergence = Divergence::new(10, (candles[0].close, 0.0)).unwrap();
+///     let results = config.over(candles).unwrap();
+///
+///     candles.iter().zip(&results).filter(|(candle, result)| {
+///         let signal = divergence.next((candle.close, O::main(result)));
+///
+///         signal.is_some() && (config.is_overbought(result) || config.is_oversold(result))
+///     }).count()
+/// }
+///
+/// let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+///
+/// count_divergences(RelativeStrengthIndex::default(), &candles);
+/// count_divergences(StochasticOscillator::default(), &candles);
+/// ```
+pub trait Oscillator: IndicatorConfig {
+	/// Index of the `main` value in the [`IndicatorResult`]
+	const MAIN: usize;
+
+	/// Index of the `signal line` value in the [`IndicatorResult`], if the oscillator has one
+	const SIGNAL_LINE: Option<usize> = None;
+
+	/// Returns lower bound of the overbought zone for the current configuration
+	fn overbought(&self) -> ValueType;
+
+	/// Returns upper bound of the oversold zone for the current configuration
+	fn oversold(&self) -> ValueType;
+
+	/// Returns documented range of the `main` value
+	#[must_use]
+	fn range() -> ValueRange {
+		Self::METADATA.ranges[Self::MAIN]
+	}
+
+	/// Returns `main` value from the indicator's `result`
+	#[must_use]
+	fn main(result: &IndicatorResult) -> ValueType {
+		result.value(Self::MAIN)
+	}
+
+	/// Returns `signal line` value from the indicator's `result`, if the oscillator has one
+	#[must_use]
+	fn signal_line(result: &IndicatorResult) -> Option<ValueType> {
+		Self::SIGNAL_LINE.map(|index| result.value(index))
+	}
+
+	/// Returns `main` value mapped into \[`0.0`; `1.0`\] range, if the oscillator is bounded
+	#[must_use]
+	fn normalized(result: &IndicatorResult) -> Option<ValueType> {
+		match Self::range() {
+			ValueRange::Bounded(min, max) => Some((Self::main(result) - min) / (max - min)),
+			_ => None,
+		}
+	}
+
+	/// Checks if `main` value from the indicator's `result` is in the overbought zone
+	#[must_use]
+	fn is_overbought(&self, result: &IndicatorResult) -> bool {
+		Self::main(result) >= self.overbought()
+	}
+
+	/// Checks if `main` value from the indicator's `result` is in the oversold zone
+	#[must_use]
+	fn is_oversold(&self, result: &IndicatorResult) -> bool {
+		Self::main(result) <= self.oversold()
+	}
+}
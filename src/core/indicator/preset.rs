@@ -0,0 +1,140 @@
+use super::IndicatorConfig;
+use crate::core::Error;
+use std::fmt;
+
+/// Named indicator configuration
+///
+/// See [`Presets`].
+pub struct Preset<C> {
+	name: &'static str,
+	description: &'static str,
+	config: fn() -> C,
+}
+
+impl<C> Preset<C> {
+	/// Creates a new preset with `name`, short `description` and function, which creates the `config`
+	#[must_use]
+	pub const fn new(name: &'static str, description: &'static str, config: fn() -> C) -> Self {
+		Self {
+			name,
+			description,
+			config,
+		}
+	}
+
+	/// Returns name of the preset
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	/// Returns short description of the preset
+	#[must_use]
+	pub const fn description(&self) -> &'static str {
+		self.description
+	}
+
+	/// Creates the config of the preset
+	#[must_use]
+	pub fn config(&self) -> C {
+		(self.config)()
+	}
+}
+
+impl<C> fmt::Debug for Preset<C> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Preset")
+			.field("name", &self.name)
+			.field("description", &self.description)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<C> Clone for Preset<C> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<C> Copy for Preset<C> {}
+
+/// Registry of named configuration presets of the indicator
+///
+/// Presets are curated parameter combinations (f.e. `classic` [`MACD`](crate::indicators::MACD) or `crypto_fast` [`RSI`](crate::indicators::RSI)),
+/// which applications may list at runtime and offer to users instead of hardcoding parameters.
+///
+/// Preset names are matched case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{IndicatorConfig, Presets};
+/// use yata::indicators::{MACD, RSI};
+///
+/// let rsi = RSI::preset("crypto_fast").unwrap();
+/// assert_eq!(rsi.period, 7);
+///
+/// let macd = MACD::preset("Classic").unwrap();
+/// assert_eq!((macd.period1, macd.period2, macd.period3), (12, 26, 9));
+///
+/// for preset in MACD::PRESETS {
+///     println!("{}: {}", preset.name(), preset.description());
+///     assert!(preset.config().validate());
+/// }
+///
+/// assert!(RSI::preset("unknown").is_err());
+/// ```
+pub trait Presets: IndicatorConfig + 'static {
+	/// List of available presets
+	const PRESETS: &'static [Preset<Self>];
+
+	/// Creates config of the preset with the specified `name`
+	///
+	/// Returns [`Error::ParameterParse`] if there is no such preset.
+	fn preset(name: &str) -> Result<Self, Error> {
+		Self::PRESETS
+			.iter()
+			.find(|preset| preset.name.eq_ignore_ascii_case(name))
+			.map(Preset::config)
+			.ok_or_else(|| Error::ParameterParse("preset".to_string(), name.to_string()))
+	}
+
+	/// Returns names of all the available presets
+	#[must_use]
+	fn preset_names() -> Vec<&'static str> {
+		Self::PRESETS.iter().map(Preset::name).collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Presets;
+	use crate::indicators::{BollingerBands, StochasticOscillator, MACD, RSI};
+
+	fn test_presets<P: Presets>() {
+		let names = P::preset_names();
+		assert!(!names.is_empty());
+
+		for (index, preset) in P::PRESETS.iter().enumerate() {
+			assert!(preset.config().validate(), "{} {}", P::NAME, preset.name());
+			assert!(!preset.description().is_empty());
+			assert!(P::preset(&preset.name().to_uppercase()).is_ok());
+			assert!(
+				!names[..index].contains(&preset.name()),
+				"{} {}",
+				P::NAME,
+				preset.name()
+			);
+		}
+
+		assert!(P::preset("").is_err());
+	}
+
+	#[test]
+	fn test_indicator_presets() {
+		test_presets::<BollingerBands>();
+		test_presets::<MACD>();
+		test_presets::<RSI>();
+		test_presets::<StochasticOscillator>();
+	}
+}
@@ -0,0 +1,137 @@
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::{Candle, Checkpoint, OHLCV};
+
+/// Wrapper for evaluating in-progress (still-open) candles of any [`IndicatorInstance`]
+///
+/// Every [`next_provisional`](Provisional::next_provisional) call computes the result for an in-progress candle without committing the state,
+/// so it may be called on every tick. When the bar closes, call [`commit`](Provisional::commit) to apply the last provisional candle to the state.
+///
+/// Provisional candles are evaluated in place and then rolled back with the instance's [`Checkpoint`], so the instance is never cloned.
+///
+/// Usually created by [`IndicatorInstance::provisional`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Candle;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let mut candles = RandomCandles::new();
+/// let mut state = MACD::default().init(&candles.first()).unwrap().provisional();
+///
+/// let bar = candles.next().unwrap();
+///
+/// // ticks of the still-open bar
+/// for close in &[bar.open, bar.high, bar.low] {
+///     let tick = Candle { close: *close, ..bar };
+///     let result = state.next_provisional(&tick);
+///     println!("{:?}", result);
+/// }
+///
+/// let last_tick = state.next_provisional(&bar);
+///
+/// // bar is closed now
+/// let closed = state.commit().unwrap();
+///
+/// assert_eq!(closed.values(), last_tick.values());
+/// assert!(state.commit().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Provisional<I: IndicatorInstance> {
+	instance: I,
+	candle: Option<Candle>,
+}
+
+impl<I: IndicatorInstance + Checkpoint> Provisional<I> {
+	/// Creates a new `Provisional` wrapper over the `instance`
+	pub const fn new(instance: I) -> Self {
+		Self {
+			instance,
+			candle: None,
+		}
+	}
+
+	/// Evaluates an in-progress `candle` without committing the state.
+	///
+	/// Replaces any previous provisional candle.
+	pub fn next_provisional<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		self.candle = Some(Candle::from(candle));
+
+		let checkpoint = self.instance.save();
+		let result = self.instance.next(candle);
+		self.instance.restore(&checkpoint);
+
+		result
+	}
+
+	/// Commits the last provisional candle into the state and returns its result.
+	///
+	/// Returns `None` if there is no provisional candle since the last commit.
+	pub fn commit(&mut self) -> Option<IndicatorResult> {
+		self.candle.take().map(|candle| self.instance.next(&candle))
+	}
+
+	/// Drops the last provisional candle without committing it
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn discard(&mut self) {
+		self.candle = None;
+	}
+
+	/// Returns the last provisional candle, which is not committed yet
+	pub const fn pending(&self) -> Option<&Candle> {
+		self.candle.as_ref()
+	}
+
+	/// Returns a reference to the inner (committed) indicator instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Returns the inner (committed) indicator instance
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::core::{Candle, Checkpoint, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{StochasticOscillator, MACD};
+
+	fn test_provisional<C: IndicatorConfig + Default>()
+	where
+		C::Instance: Checkpoint,
+	{
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let expected = C::default().over(&candles).unwrap();
+
+		let mut state = C::default().init(&candles[0]).unwrap().provisional();
+
+		for (candle, expected) in candles.iter().zip(&expected) {
+			for close in &[candle.open, candle.high, candle.low] {
+				state.next_provisional(&Candle {
+					close: *close,
+					..*candle
+				});
+			}
+
+			state.next_provisional(candle);
+			assert_eq!(
+				state.commit().unwrap().values(),
+				expected.values(),
+				"{}",
+				C::NAME
+			);
+		}
+	}
+
+	#[test]
+	fn test_provisional_commit() {
+		test_provisional::<MACD>();
+		test_provisional::<StochasticOscillator>();
+	}
+}
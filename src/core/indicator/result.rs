@@ -6,20 +6,36 @@ use serde::{Deserialize, Serialize};
 
 /// Every `Indicator` proceed an input of [`OHLCV`](crate::core::OHLCV) and returns an `IndicatorResult` which consist of some returned raw values and some calculated signals.
 ///
-/// `Indicator` may return up to 4 signals and 4 raw values at each step
+/// `Indicator` may return up to [`IndicatorResult::VALUES_SIZE`] raw values and [`IndicatorResult::SIGNALS_SIZE`] signals at each step.
+///
+/// The actual count of values and signals is defined by [`IndicatorConfig::size`](crate::core::IndicatorConfig::size),
+/// so composite indicators (which size depends on their configuration, f.e. [`GMMA`](crate::indicators::GMMA) or [`MARibbon`](crate::indicators::MARibbon))
+/// may return more than just a few values.
 #[derive(Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[allow(clippy::use_self)]
 pub struct IndicatorResult {
-	signals: [Action; IndicatorResult::SIZE],
-	values: [ValueType; IndicatorResult::SIZE],
+	signals: [Action; IndicatorResult::SIGNALS_SIZE],
+	values: [ValueType; IndicatorResult::VALUES_SIZE],
 	length: (u8, u8),
 }
 
 impl IndicatorResult {
-	/// Size of pre-allocated result array
+	/// Size of pre-allocated raw values array
+	/// For the most of cases it should not be used anywhere outside this crate
+	pub const VALUES_SIZE: usize = 16;
+
+	/// Size of pre-allocated signals array
 	/// For the most of cases it should not be used anywhere outside this crate
-	pub const SIZE: usize = 4;
+	pub const SIGNALS_SIZE: usize = 4;
+
+	/// Size of pre-allocated result array
+	///
+	/// It is the same as [`IndicatorResult::SIGNALS_SIZE`]. Raw values array is larger now, see [`IndicatorResult::VALUES_SIZE`].
+	#[deprecated(
+		note = "use `IndicatorResult::VALUES_SIZE` or `IndicatorResult::SIGNALS_SIZE` instead"
+	)]
+	pub const SIZE: usize = Self::SIGNALS_SIZE;
 
 	/// Returns a slice of signals of current indicator result
 	#[must_use]
@@ -78,17 +94,28 @@ impl IndicatorResult {
 	}
 
 	/// Creates a new instance of `IndicatorResult` with provided *values* and *signals*
+	///
+	/// # Panics
+	///
+	/// Panics if there are more than [`IndicatorResult::VALUES_SIZE`] values or more than [`IndicatorResult::SIGNALS_SIZE`] signals
 	#[inline]
 	#[must_use]
 	pub fn new(values_slice: &[ValueType], signals_slice: &[Action]) -> Self {
-		let mut values = [0 as ValueType; Self::SIZE];
-		let mut signals = [Action::default(); Self::SIZE];
+		assert!(
+			values_slice.len() <= Self::VALUES_SIZE && signals_slice.len() <= Self::SIGNALS_SIZE,
+			"IndicatorResult cannot hold more than {} values and {} signals",
+			Self::VALUES_SIZE,
+			Self::SIGNALS_SIZE
+		);
 
-		let values_length = Self::SIZE.min(values_slice.len());
-		values[..values_length].copy_from_slice(&values_slice[..values_length]);
+		let mut values = [0 as ValueType; Self::VALUES_SIZE];
+		let mut signals = [Action::default(); Self::SIGNALS_SIZE];
 
-		let signals_length = Self::SIZE.min(signals_slice.len());
-		signals[..signals_length].copy_from_slice(&signals_slice[..signals_length]);
+		let values_length = values_slice.len();
+		values[..values_length].copy_from_slice(values_slice);
+
+		let signals_length = signals_slice.len();
+		signals[..signals_length].copy_from_slice(signals_slice);
 
 		#[allow(clippy::cast_possible_truncation)]
 		let length = (values_length as u8, signals_length as u8);
@@ -99,6 +126,284 @@ impl IndicatorResult {
 			length,
 		}
 	}
+
+	/// Combines all the signals into a single [`Action`], weighted by `weights`
+	///
+	/// Every signal is taken as its [`ratio`](Action::ratio), where no signal counts as `0.0`.
+	/// The result is a sum of the signals multiplied by their weights, divided by the sum of the absolute weights.
+	/// Signals without weights are ignored. Returns [`Action::None`] when the sum of the absolute weights is zero.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult};
+	///
+	/// let result = IndicatorResult::new(&[], &[Action::BUY_ALL, Action::None, Action::SELL_ALL]);
+	///
+	/// assert_eq!(result.weighted_signal(&[3.0, 1.0, 1.0]), Action::from(0.4));
+	/// assert_eq!(result.weighted_signal(&[1.0, 1.0]), Action::from(0.5));
+	/// assert_eq!(result.weighted_signal(&[]), Action::None);
+	/// ```
+	#[must_use]
+	pub fn weighted_signal(&self, weights: &[ValueType]) -> Action {
+		let (sum, weights_sum) = self.signals().iter().zip(weights).fold(
+			(0.0, 0.0),
+			|(sum, weights_sum): (ValueType, ValueType), (signal, &weight)| {
+				let ratio = signal.ratio().unwrap_or(0.0);
+				(ratio.mul_add(weight, sum), weights_sum + weight.abs())
+			},
+		);
+
+		if weights_sum > 0.0 {
+			Action::from(sum / weights_sum)
+		} else {
+			Action::None
+		}
+	}
+
+	/// Returns a new `IndicatorResult` with every raw value mapped by `f` and the same signals
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult};
+	///
+	/// let result = IndicatorResult::new(&[0.25, 0.5], &[Action::BUY_ALL]);
+	/// let percents = result.map_values(|x| x * 100.0);
+	///
+	/// assert_eq!(percents.values(), &[25.0, 50.0]);
+	/// assert_eq!(percents.signals(), result.signals());
+	/// ```
+	#[must_use]
+	pub fn map_values<F>(&self, mut f: F) -> Self
+	where
+		F: FnMut(ValueType) -> ValueType,
+	{
+		let mut result = *self;
+
+		let len = self.length.0 as usize;
+		result.values[..len].iter_mut().for_each(|x| *x = f(*x));
+
+		result
+	}
+
+	/// Merges two results into a new one, which holds values and signals of `self` followed by values and signals of `other`
+	///
+	/// # Panics
+	///
+	/// Panics if merged result does not fit into [`IndicatorResult::VALUES_SIZE`] values and [`IndicatorResult::SIGNALS_SIZE`] signals
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult};
+	///
+	/// let a = IndicatorResult::new(&[1.0], &[Action::BUY_ALL]);
+	/// let b = IndicatorResult::new(&[2.0, 3.0], &[Action::None, Action::SELL_ALL]);
+	/// let merged = a.merge(&b);
+	///
+	/// assert_eq!(merged.values(), &[1.0, 2.0, 3.0]);
+	/// assert_eq!(merged.signals(), &[Action::BUY_ALL, Action::None, Action::SELL_ALL]);
+	/// ```
+	#[must_use]
+	pub fn merge(&self, other: &Self) -> Self {
+		let mut values = [0 as ValueType; Self::VALUES_SIZE * 2];
+		let mut signals = [Action::default(); Self::SIGNALS_SIZE * 2];
+
+		let (values_length, signals_length) = (self.values().len(), self.signals().len());
+		let (other_values_length, other_signals_length) =
+			(other.values().len(), other.signals().len());
+
+		values[..values_length].copy_from_slice(self.values());
+		values[values_length..values_length + other_values_length].copy_from_slice(other.values());
+
+		signals[..signals_length].copy_from_slice(self.signals());
+		signals[signals_length..signals_length + other_signals_length]
+			.copy_from_slice(other.signals());
+
+		Self::new(
+			&values[..values_length + other_values_length],
+			&signals[..signals_length + other_signals_length],
+		)
+	}
+
+	/// Returns a configurable formatter of the result
+	///
+	/// See [`ResultDisplay`] for details.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult};
+	///
+	/// let result = IndicatorResult::new(&[1.0, 0.5], &[Action::BUY_ALL, Action::None]);
+	///
+	/// let line = result.display().precision(2).names(&["macd", "signal"]).compact().to_string();
+	/// assert_eq!(line, "macd=1.00 signal=0.50 signal0=+1.00 signal1=N");
+	/// ```
+	#[must_use]
+	pub const fn display(&self) -> ResultDisplay<'_> {
+		ResultDisplay {
+			result: self,
+			precision: ResultDisplay::DEFAULT_PRECISION,
+			names: &[],
+			signal_names: &[],
+			compact: false,
+		}
+	}
+}
+
+/// Configurable formatter of [`IndicatorResult`], created by [`IndicatorResult::display`]
+///
+/// Every raw value and every signal is written as a `name` and its value.
+/// Columns without provided names are named `value0`, `value1`, ... and `signal0`, `signal1`, ...
+/// Raw values are written with [`precision`](ResultDisplay::precision) digits after the decimal point,
+/// signals are written the same way as [`Action`] displays them.
+///
+/// By default every column is written on its own line as `name: value`.
+/// In [`compact`](ResultDisplay::compact) mode all the columns are written on a single line as `name=value`
+/// separated by spaces, so the output may be easily put into logs and parsed back.
+///
+/// [`IndicatorResult`] itself implements [`Display`](std::fmt::Display) in compact mode,
+/// taking precision from the formatter (f.e. `{:.2}`). Alternate flag (`{:#}`) turns compact mode off.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Action, IndicatorResult};
+///
+/// let result = IndicatorResult::new(&[1.0, -0.26], &[Action::SELL_ALL]);
+///
+/// assert_eq!(format!("{result:.1}"), "value0=1.0 value1=-0.3 signal0=-1.00");
+/// assert_eq!(
+///     result.display().names(&["upper"]).signal_names(&["cross"]).to_string(),
+///     "upper: 1.0000\nvalue1: -0.2600\ncross: -1.00"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ResultDisplay<'a> {
+	result: &'a IndicatorResult,
+	precision: usize,
+	names: &'a [&'a str],
+	signal_names: &'a [&'a str],
+	compact: bool,
+}
+
+impl<'a> ResultDisplay<'a> {
+	/// Default count of digits after the decimal point for raw values
+	pub const DEFAULT_PRECISION: usize = 4;
+
+	/// Sets count of digits after the decimal point for raw values. Default is [`DEFAULT_PRECISION`](ResultDisplay::DEFAULT_PRECISION).
+	#[must_use]
+	pub const fn precision(mut self, precision: usize) -> Self {
+		self.precision = precision;
+		self
+	}
+
+	/// Sets names of raw values columns
+	///
+	/// Extra names are ignored.
+	#[must_use]
+	pub const fn names(mut self, names: &'a [&'a str]) -> Self {
+		self.names = names;
+		self
+	}
+
+	/// Sets names of signals columns
+	///
+	/// Extra names are ignored.
+	#[must_use]
+	pub const fn signal_names(mut self, names: &'a [&'a str]) -> Self {
+		self.signal_names = names;
+		self
+	}
+
+	/// Writes all the columns on a single line
+	#[must_use]
+	pub const fn compact(mut self) -> Self {
+		self.compact = true;
+		self
+	}
+
+	fn write_column(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+		first: bool,
+		name: fmt::Arguments<'_>,
+		value: fmt::Arguments<'_>,
+	) -> fmt::Result {
+		match (first, self.compact) {
+			(true, true) => write!(f, "{name}={value}"),
+			(true, false) => write!(f, "{name}: {value}"),
+			(false, true) => write!(f, " {name}={value}"),
+			(false, false) => write!(f, "\n{name}: {value}"),
+		}
+	}
+}
+
+impl fmt::Display for ResultDisplay<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let precision = self.precision;
+
+		for (index, value) in self.result.values().iter().enumerate() {
+			let first = index == 0;
+
+			match self.names.get(index) {
+				Some(name) => {
+					self.write_column(
+						f,
+						first,
+						format_args!("{name}"),
+						format_args!("{value:.precision$}"),
+					)?;
+				}
+				None => {
+					self.write_column(
+						f,
+						first,
+						format_args!("value{index}"),
+						format_args!("{value:.precision$}"),
+					)?;
+				}
+			}
+		}
+
+		let offset = self.result.values().len();
+
+		for (index, signal) in self.result.signals().iter().enumerate() {
+			let first = offset + index == 0;
+
+			match self.signal_names.get(index) {
+				Some(name) => {
+					self.write_column(f, first, format_args!("{name}"), format_args!("{signal}"))?;
+				}
+				None => {
+					self.write_column(
+						f,
+						first,
+						format_args!("signal{index}"),
+						format_args!("{signal}"),
+					)?;
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Display for IndicatorResult {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let display = self
+			.display()
+			.precision(f.precision().unwrap_or(ResultDisplay::DEFAULT_PRECISION));
+
+		if f.alternate() {
+			fmt::Display::fmt(&display, f)
+		} else {
+			fmt::Display::fmt(&display.compact(), f)
+		}
+	}
 }
 
 impl fmt::Debug for IndicatorResult {
@@ -123,3 +428,109 @@ impl fmt::Debug for IndicatorResult {
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorResult;
+	use crate::core::{Action, ValueType};
+
+	#[test]
+	fn test_indicator_result_full_size() {
+		let values: Vec<ValueType> = (0..IndicatorResult::VALUES_SIZE)
+			.map(|x| x as ValueType)
+			.collect();
+		let signals: Vec<Action> = (0..IndicatorResult::SIGNALS_SIZE)
+			.map(|x| Action::from(x as ValueType / IndicatorResult::SIGNALS_SIZE as ValueType))
+			.collect();
+
+		let result = IndicatorResult::new(&values, &signals);
+
+		assert_eq!(result.values(), values.as_slice());
+		assert_eq!(result.signals(), signals.as_slice());
+		assert_eq!(result.size().0 as usize, IndicatorResult::VALUES_SIZE);
+		assert_eq!(result.size().1 as usize, IndicatorResult::SIGNALS_SIZE);
+	}
+
+	#[test]
+	#[should_panic(expected = "IndicatorResult cannot hold")]
+	fn test_indicator_result_overflow() {
+		let signals = [Action::None; IndicatorResult::SIGNALS_SIZE + 1];
+		let _ = IndicatorResult::new(&[], &signals);
+	}
+
+	#[test]
+	fn test_indicator_result_helpers() {
+		let a = IndicatorResult::new(&[1.0, 2.0], &[Action::BUY_ALL, Action::from(-0.5)]);
+		let b = IndicatorResult::new(&[3.0], &[Action::None]);
+
+		let merged = a.merge(&b);
+		assert_eq!(merged.size(), (3, 3));
+		assert_eq!(merged.values(), &[1.0, 2.0, 3.0]);
+		assert_eq!(
+			merged.signals(),
+			&[Action::BUY_ALL, Action::from(-0.5), Action::None]
+		);
+
+		let mapped = merged.map_values(|x| -x);
+		assert_eq!(mapped.values(), &[-1.0, -2.0, -3.0]);
+		assert_eq!(mapped.signals(), merged.signals());
+
+		// weighted signals are quantized again, so they may differ by a half of the signal step
+		let sell_half = merged.signals()[1].ratio().unwrap();
+		let assert_weighted = |weights: &[ValueType], expected: ValueType| {
+			let ratio = merged.weighted_signal(weights).ratio().unwrap();
+			assert!(
+				(ratio - expected).abs() <= 0.5 / 127.0 + ValueType::EPSILON,
+				"weights={:?}, expected={}, ratio={}",
+				weights,
+				expected,
+				ratio
+			);
+		};
+
+		assert_weighted(&[1.0, 1.0], sell_half.mul_add(0.5, 0.5));
+		assert_weighted(&[1.0, 2.0, 1.0], sell_half.mul_add(0.5, 0.25));
+		assert_weighted(&[0.0, -1.0], -sell_half);
+		assert_weighted(&[0.0, 0.0, 5.0], 0.0);
+		assert_eq!(merged.weighted_signal(&[0.0]), Action::None);
+	}
+
+	#[test]
+	fn test_indicator_result_display() {
+		let result = IndicatorResult::new(&[1.0, -2.5, 0.126], &[Action::BUY_ALL, Action::None]);
+
+		assert_eq!(
+			result.to_string(),
+			"value0=1.0000 value1=-2.5000 value2=0.1260 signal0=+1.00 signal1=N"
+		);
+		assert_eq!(
+			format!("{result:.1}"),
+			"value0=1.0 value1=-2.5 value2=0.1 signal0=+1.00 signal1=N"
+		);
+		assert_eq!(
+			format!("{result:#.0}"),
+			"value0: 1\nvalue1: -2\nvalue2: 0\nsignal0: +1.00\nsignal1: N"
+		);
+
+		let named = result
+			.display()
+			.precision(2)
+			.names(&["a", "b", "c", "d"])
+			.signal_names(&["x"])
+			.compact()
+			.to_string();
+		assert_eq!(named, "a=1.00 b=-2.50 c=0.13 x=+1.00 signal1=N");
+
+		let line = result.display().precision(3).compact().to_string();
+		let parsed: Vec<(&str, &str)> = line
+			.split(' ')
+			.map(|column| column.split_once('=').unwrap())
+			.collect();
+		assert_eq!(parsed.len(), 5);
+		assert_eq!(parsed[1], ("value1", "-2.500"));
+
+		let empty = IndicatorResult::new(&[], &[Action::SELL_ALL]);
+		assert_eq!(empty.to_string(), "signal0=-1.00");
+		assert_eq!(format!("{empty:#}"), "signal0: -1.00");
+	}
+}
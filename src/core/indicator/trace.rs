@@ -0,0 +1,214 @@
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::{Action, ValueType, OHLCV};
+use std::fmt::{self, Write};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Single record of the [`SignalTrace`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalRecord {
+	/// Index of the candle (starting from `0`) the signal was produced on
+	pub index: usize,
+
+	/// Index of the signal in the indicator's [`IndicatorResult`]
+	pub output: usize,
+
+	/// The signal itself
+	pub signal: Action,
+
+	/// Raw indicator values at the moment of the signal
+	pub values: Vec<ValueType>,
+}
+
+impl fmt::Display for SignalRecord {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let values: Vec<String> = self.values.iter().map(ToString::to_string).collect();
+
+		write!(
+			f,
+			"#{} signal {}: {} [{}]",
+			self.index,
+			self.output,
+			self.signal,
+			values.join(", ")
+		)
+	}
+}
+
+/// Recorder of every produced signal of any [`IndicatorInstance`]
+///
+/// Every non-`None` signal is stored as a [`SignalRecord`] along with its candle index and the indicator values.
+/// Records may be serialized by `serde` (e.g. into JSON) or exported as CSV by [`to_csv`](SignalTrace::to_csv).
+///
+/// Usually created by [`IndicatorInstance::with_trace`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let mut candles = RandomCandles::new();
+/// let mut state = MACD::default().init(&candles.first()).unwrap().with_trace();
+///
+/// for candle in candles.take(100) {
+///     state.next(&candle);
+/// }
+///
+/// for record in state.records() {
+///     assert!(record.signal.is_some());
+///     assert!(record.index < 100);
+///     println!("{}", record);
+/// }
+///
+/// let csv = state.to_csv();
+/// assert_eq!(csv.lines().count(), state.records().len() + 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SignalTrace<I: IndicatorInstance> {
+	instance: I,
+	index: usize,
+	records: Vec<SignalRecord>,
+}
+
+impl<I: IndicatorInstance> SignalTrace<I> {
+	/// Creates a new `SignalTrace` over the `instance`
+	pub const fn new(instance: I) -> Self {
+		Self {
+			instance,
+			index: 0,
+			records: Vec::new(),
+		}
+	}
+
+	/// Evaluates the `candle` by the inner instance and records all of its non-`None` signals
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+		let index = self.index;
+
+		self.records.extend(
+			result
+				.signals()
+				.iter()
+				.enumerate()
+				.filter(|(_, signal)| signal.is_some())
+				.map(|(output, &signal)| SignalRecord {
+					index,
+					output,
+					signal,
+					values: result.values().to_vec(),
+				}),
+		);
+
+		self.index += 1;
+
+		result
+	}
+
+	/// Returns all the records
+	#[must_use]
+	pub fn records(&self) -> &[SignalRecord] {
+		&self.records
+	}
+
+	/// Takes all the records out of the trace, so the trace keeps recording from scratch
+	pub fn take_records(&mut self) -> Vec<SignalRecord> {
+		std::mem::take(&mut self.records)
+	}
+
+	/// Returns count of evaluated candles
+	#[must_use]
+	pub const fn candles_count(&self) -> usize {
+		self.index
+	}
+
+	/// Returns all the records as CSV text with a header line
+	///
+	/// Columns are `index`, `output`, `signal` (as [`ratio`](Action::ratio)) and `value0`, `value1`, ... for every indicator value.
+	#[must_use]
+	pub fn to_csv(&self) -> String {
+		let mut csv = String::from("index,output,signal");
+
+		for i in 0..self.instance.size().0 {
+			let _ = write!(csv, ",value{i}");
+		}
+
+		for record in &self.records {
+			let _ = write!(
+				csv,
+				"\n{},{},{}",
+				record.index,
+				record.output,
+				record.signal.ratio().unwrap_or(0.)
+			);
+
+			for value in &record.values {
+				let _ = write!(csv, ",{value}");
+			}
+		}
+
+		csv
+	}
+
+	/// Returns a reference to the inner indicator instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Returns the inner indicator instance
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SignalTrace;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_signal_trace() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+
+		let mut reference = MACD::default().init(&candles[0]).unwrap();
+		let mut trace = SignalTrace::new(MACD::default().init(&candles[0]).unwrap());
+		let mut expected = Vec::new();
+
+		for (i, candle) in candles.iter().enumerate() {
+			let result = reference.next(candle);
+			assert_eq!(trace.next(candle).signals(), result.signals());
+
+			for (output, &signal) in result.signals().iter().enumerate() {
+				if signal.is_some() {
+					expected.push((i, output, signal, result.values().to_vec()));
+				}
+			}
+		}
+
+		assert!(!expected.is_empty());
+		assert_eq!(trace.candles_count(), candles.len());
+		assert_eq!(trace.records().len(), expected.len());
+
+		for (record, (index, output, signal, values)) in trace.records().iter().zip(&expected) {
+			assert_eq!(record.index, *index);
+			assert_eq!(record.output, *output);
+			assert_eq!(record.signal, *signal);
+			assert_eq!(&record.values, values);
+		}
+
+		let csv = trace.to_csv();
+		let mut lines = csv.lines();
+		assert_eq!(lines.next(), Some("index,output,signal,value0,value1"));
+		assert_eq!(lines.count(), expected.len());
+
+		let records = trace.take_records();
+		assert_eq!(records.len(), expected.len());
+		assert!(trace.records().is_empty());
+	}
+}
@@ -0,0 +1,351 @@
+use super::{IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, ValueType, OHLCV};
+use std::io::{self, Write};
+
+/// Text format of the [`ResultWriter`] output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResultFormat {
+	/// Comma separated values with a header line
+	///
+	/// Columns are `index`, `timestamp` (only when enabled), `value0`, `value1`, ... and `signal0`, `signal1`, ...
+	/// Signals are written as [`ratio`](Action::ratio) or as an empty field for [`Action::None`].
+	/// Non-finite values are written as empty fields.
+	Csv,
+
+	/// [Newline delimited JSON](http://ndjson.org/): one JSON object per line
+	///
+	/// Every object has `index`, `timestamp` (only when enabled), `values` and `signals` fields.
+	/// Signals are written as [`ratio`](Action::ratio) or as `null` for [`Action::None`].
+	/// Non-finite values are written as `null`.
+	NdJson,
+}
+
+/// Streaming writer of [`IndicatorResult`]s into CSV or NDJSON
+///
+/// Every result is written as soon as it is pushed, so the results of a long-running job never have to be held in memory.
+/// Results are numbered by their index (starting from `0`). Optionally every result may also have a `timestamp`
+/// (see [`with_timestamp`](ResultWriter::with_timestamp) and [`push_at`](ResultWriter::push_at)).
+///
+/// Every result is written straight into the inner writer by a few small writes without any intermediate allocations.
+/// The output is not buffered by the writer itself, so wrap files and sockets into [`BufWriter`](std::io::BufWriter).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{ResultFormat, ResultWriter};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+/// let mut state = MACD::default().init(&candles[0]).unwrap();
+///
+/// let mut writer = ResultWriter::new(Vec::new(), ResultFormat::Csv, state.size()).with_timestamp();
+///
+/// for (i, candle) in candles.iter().enumerate() {
+///     let timestamp = 1_600_000_000 + 60 * i as i64;
+///     writer.push_at(timestamp, &state.next(candle)).unwrap();
+/// }
+///
+/// let csv = String::from_utf8(writer.into_inner()).unwrap();
+/// let mut lines = csv.lines();
+///
+/// assert_eq!(lines.next(), Some("index,timestamp,value0,value1,signal0,signal1"));
+/// assert!(lines.next().unwrap().starts_with("0,1600000000,"));
+/// assert_eq!(lines.count(), 9);
+/// ```
+#[derive(Debug)]
+pub struct ResultWriter<W: Write> {
+	writer: W,
+	format: ResultFormat,
+	size: (u8, u8),
+	timestamp: bool,
+	index: usize,
+}
+
+impl<W: Write> ResultWriter<W> {
+	/// Creates a new writer of the results of specified `size` (count of raw values, count of signals)
+	///
+	/// Usually `size` is taken from [`IndicatorConfig::size`](crate::core::IndicatorConfig::size)
+	/// or [`IndicatorInstance::size`](crate::core::IndicatorInstance::size).
+	pub const fn new(writer: W, format: ResultFormat, size: (u8, u8)) -> Self {
+		Self {
+			writer,
+			format,
+			size,
+			timestamp: false,
+			index: 0,
+		}
+	}
+
+	/// Enables `timestamp` field for every written result
+	///
+	/// Results, pushed by [`push`](ResultWriter::push), get an empty `timestamp` (`null` in NDJSON).
+	#[must_use]
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn with_timestamp(mut self) -> Self {
+		self.timestamp = true;
+		self
+	}
+
+	/// Writes the `result` without a timestamp
+	pub fn push(&mut self, result: &IndicatorResult) -> Result<(), Error> {
+		self.write_record(None, result)
+	}
+
+	/// Writes the `result` with the `timestamp`
+	///
+	/// Timestamp is ignored, unless it is enabled by [`with_timestamp`](ResultWriter::with_timestamp).
+	pub fn push_at(&mut self, timestamp: i64, result: &IndicatorResult) -> Result<(), Error> {
+		self.write_record(Some(timestamp), result)
+	}
+
+	/// Evaluates the `instance` over `inputs` and writes every result without a timestamp
+	pub fn extend_over<I, T, S>(&mut self, instance: &mut I, inputs: S) -> Result<(), Error>
+	where
+		I: IndicatorInstance,
+		T: OHLCV,
+		S: AsRef<[T]>,
+	{
+		inputs
+			.as_ref()
+			.iter()
+			.try_for_each(|candle| self.push(&instance.next(candle)))
+	}
+
+	/// Flushes the inner writer
+	pub fn flush(&mut self) -> Result<(), Error> {
+		self.writer.flush().map_err(Self::io_error)
+	}
+
+	/// Returns count of written results
+	#[must_use]
+	pub const fn count(&self) -> usize {
+		self.index
+	}
+
+	/// Returns the inner writer
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn into_inner(self) -> W {
+		self.writer
+	}
+
+	fn io_error(error: io::Error) -> Error {
+		Error::wrap("Unable to write indicator results", error)
+	}
+
+	fn write_record(
+		&mut self,
+		timestamp: Option<i64>,
+		result: &IndicatorResult,
+	) -> Result<(), Error> {
+		if self.index == 0 && self.format == ResultFormat::Csv {
+			self.write_csv_header().map_err(Self::io_error)?;
+		}
+
+		let values = (0..self.size.0 as usize).map(|i| result.values().get(i).copied());
+		let signals = (0..self.size.1 as usize)
+			.map(|i| result.signals().get(i).copied().and_then(Action::ratio));
+
+		match self.format {
+			ResultFormat::Csv => self.write_csv(timestamp, values, signals),
+			ResultFormat::NdJson => self.write_ndjson(timestamp, values, signals),
+		}
+		.map_err(Self::io_error)?;
+
+		self.index += 1;
+
+		Ok(())
+	}
+
+	fn write_csv_header(&mut self) -> io::Result<()> {
+		self.writer.write_all(b"index")?;
+
+		if self.timestamp {
+			self.writer.write_all(b",timestamp")?;
+		}
+
+		for i in 0..self.size.0 {
+			write!(self.writer, ",value{i}")?;
+		}
+
+		for i in 0..self.size.1 {
+			write!(self.writer, ",signal{i}")?;
+		}
+
+		self.writer.write_all(b"\n")
+	}
+
+	fn write_csv<V, S>(&mut self, timestamp: Option<i64>, values: V, signals: S) -> io::Result<()>
+	where
+		V: Iterator<Item = Option<ValueType>>,
+		S: Iterator<Item = Option<ValueType>>,
+	{
+		let writer = &mut self.writer;
+		write!(writer, "{}", self.index)?;
+
+		if self.timestamp {
+			writer.write_all(b",")?;
+
+			if let Some(timestamp) = timestamp {
+				write!(writer, "{timestamp}")?;
+			}
+		}
+
+		for value in values.chain(signals) {
+			writer.write_all(b",")?;
+
+			if let Some(value) = value.filter(|value| value.is_finite()) {
+				write!(writer, "{value}")?;
+			}
+		}
+
+		writer.write_all(b"\n")
+	}
+
+	fn write_ndjson<V, S>(
+		&mut self,
+		timestamp: Option<i64>,
+		values: V,
+		signals: S,
+	) -> io::Result<()>
+	where
+		V: Iterator<Item = Option<ValueType>>,
+		S: Iterator<Item = Option<ValueType>>,
+	{
+		let writer = &mut self.writer;
+		write!(writer, "{{\"index\":{}", self.index)?;
+
+		if self.timestamp {
+			match timestamp {
+				Some(timestamp) => write!(writer, ",\"timestamp\":{timestamp}")?,
+				None => writer.write_all(b",\"timestamp\":null")?,
+			}
+		}
+
+		writer.write_all(b",\"values\":[")?;
+		write_json_array(writer, values)?;
+		writer.write_all(b"],\"signals\":[")?;
+		write_json_array(writer, signals)?;
+
+		writer.write_all(b"]}\n")
+	}
+}
+
+fn write_json_array<W, I>(writer: &mut W, items: I) -> io::Result<()>
+where
+	W: Write,
+	I: Iterator<Item = Option<ValueType>>,
+{
+	for (i, item) in items.enumerate() {
+		if i > 0 {
+			writer.write_all(b",")?;
+		}
+
+		match item {
+			Some(value) if value.is_finite() => write!(writer, "{value}")?,
+			_ => writer.write_all(b"null")?,
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ResultFormat, ResultWriter};
+	use crate::core::{
+		Action, Candle, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType,
+	};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_result_writer_csv() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let results = MACD::default().over(&candles).unwrap();
+
+		let mut state = MACD::default().init(&candles[0]).unwrap();
+		let mut writer = ResultWriter::new(Vec::new(), ResultFormat::Csv, state.size());
+		writer.extend_over(&mut state, &candles).unwrap();
+		assert_eq!(writer.count(), candles.len());
+
+		let csv = String::from_utf8(writer.into_inner()).unwrap();
+		let mut lines = csv.lines();
+		assert_eq!(lines.next(), Some("index,value0,value1,signal0,signal1"));
+
+		for (i, (line, result)) in lines.zip(&results).enumerate() {
+			let fields: Vec<&str> = line.split(',').collect();
+			assert_eq!(fields.len(), 5);
+			assert_eq!(fields[0], i.to_string());
+			assert_eq!(fields[1], result.value(0).to_string());
+			assert_eq!(fields[2], result.value(1).to_string());
+
+			for (field, signal) in fields[3..].iter().zip(result.signals()) {
+				match signal.ratio() {
+					Some(ratio) => assert_eq!(*field, ratio.to_string()),
+					None => assert!(field.is_empty()),
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_result_writer_ndjson() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let results = MACD::default().over(&candles).unwrap();
+
+		let mut writer =
+			ResultWriter::new(Vec::new(), ResultFormat::NdJson, (2, 2)).with_timestamp();
+
+		for ((i, result), timestamp) in results.iter().enumerate().zip((0_i64..).step_by(60)) {
+			if i % 2 == 0 {
+				writer.push_at(timestamp, result).unwrap();
+			} else {
+				writer.push(result).unwrap();
+			}
+		}
+
+		let json = String::from_utf8(writer.into_inner()).unwrap();
+		assert_eq!(json.lines().count(), results.len());
+
+		for (i, (line, result)) in json.lines().zip(&results).enumerate() {
+			let timestamp = if i % 2 == 0 {
+				(i * 60).to_string()
+			} else {
+				"null".to_string()
+			};
+
+			let prefix = format!(
+				"{{\"index\":{},\"timestamp\":{},\"values\":[{},{}],\"signals\":[",
+				i,
+				timestamp,
+				result.value(0),
+				result.value(1)
+			);
+
+			assert!(line.starts_with(&prefix), "{} {}", line, prefix);
+			assert!(line.ends_with("]}"));
+		}
+	}
+
+	#[test]
+	fn test_result_writer_non_finite() {
+		let result = IndicatorResult::new(&[ValueType::NAN, 1.5], &[Action::None, Action::BUY_ALL]);
+
+		let mut csv = ResultWriter::new(Vec::new(), ResultFormat::Csv, (3, 2));
+		csv.push(&result).unwrap();
+		assert_eq!(
+			String::from_utf8(csv.into_inner()).unwrap(),
+			"index,value0,value1,value2,signal0,signal1\n0,,1.5,,,1\n"
+		);
+
+		let mut json = ResultWriter::new(Vec::new(), ResultFormat::NdJson, (3, 2));
+		json.push(&result).unwrap();
+		assert_eq!(
+			String::from_utf8(json.into_inner()).unwrap(),
+			"{\"index\":0,\"values\":[null,1.5,null],\"signals\":[null,1]}\n"
+		);
+	}
+}
@@ -0,0 +1,372 @@
+use super::{Candle, ValueType, OHLCV};
+use serde::de::{self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Candle with an optional open time, deserialized from a common exchange kline payload
+///
+/// Exchanges send klines (candles) in a few common shapes. `Kline` accepts any of them:
+///
+/// * Array of arrays (f.e. Binance REST API): `[open_time, open, high, low, close, volume, ...]`.
+///   Volume and all the fields after it are optional. Extra fields are ignored.
+///   If there is no volume, the candle is marked [`without_volume`](Candle::without_volume).
+/// * Object with short or full field names: `o` / `open`, `h` / `high`, `l` / `low`, `c` / `close`, `v` / `volume` / `vol`
+///   and `t` / `time` / `timestamp` / `ts` / `open_time` / `openTime` / `start` for the open time.
+///   Unknown fields are ignored.
+/// * Object with the kline nested into `k` field (f.e. Binance websocket kline stream): `{"e": "kline", "k": {"t": ..., "o": ...}}`.
+///
+/// Every number may be either a JSON number or a string (`"42.5"`), because most exchanges send prices as strings
+/// to keep their precision.
+///
+/// Open time is taken as is (usually milliseconds or seconds since Unix epoch, depending on the exchange).
+///
+/// Requires `klines` feature.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Candle, Kline, OHLCV};
+///
+/// let rest = r#"[1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100", "148976.11427815", 1499644799999]"#;
+/// let kline: Kline = serde_json::from_str(rest).unwrap();
+///
+/// assert_eq!(kline.timestamp, Some(1_499_040_000_000));
+/// assert_eq!(kline.high(), 0.8);
+///
+/// let stream = r#"{"e": "kline", "E": 1672515782136, "k": {"t": 1672515780000, "o": "0.0010", "h": "0.0025", "l": "0.0015", "c": "0.0020", "v": "1000"}}"#;
+/// let candle: Candle = serde_json::from_str::<Kline>(stream).unwrap().into();
+///
+/// assert_eq!(candle.close, 0.002);
+/// ```
+///
+/// Use [`deserialize_candle`] and [`deserialize_candles`] to put [`Candle`]s right into your own types:
+///
+/// ```
+/// use serde::Deserialize;
+/// use yata::core::{deserialize_candles, Candle};
+///
+/// #[derive(Deserialize)]
+/// struct Response {
+///     symbol: String,
+///     #[serde(deserialize_with = "deserialize_candles")]
+///     klines: Vec<Candle>,
+/// }
+///
+/// let json = r#"{"symbol": "BTCUSDT", "klines": [[0, 1, 2, 0.5, 1.5, 10], {"open": "1.5", "high": 3, "low": 1, "close": "2"}]}"#;
+/// let response: Response = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(response.klines.len(), 2);
+/// assert!(!response.klines[1].volume.is_finite());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd)]
+pub struct Kline {
+	/// Open time of the kline, if the payload has it
+	pub timestamp: Option<i64>,
+
+	/// Prices and volume of the kline
+	pub candle: Candle,
+}
+
+impl OHLCV for Kline {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.candle.open
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.candle.high
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.candle.low
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.candle.close
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.candle.volume
+	}
+}
+
+impl From<Kline> for Candle {
+	fn from(kline: Kline) -> Self {
+		kline.candle
+	}
+}
+
+impl<'de> Deserialize<'de> for Kline {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(KlineVisitor)
+	}
+}
+
+/// Deserializes a single [`Candle`] from any kline payload shape, supported by [`Kline`]
+///
+/// Use it with `#[serde(deserialize_with = "deserialize_candle")]`.
+///
+/// Requires `klines` feature.
+pub fn deserialize_candle<'de, D>(deserializer: D) -> Result<Candle, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Kline::deserialize(deserializer).map(|kline| kline.candle)
+}
+
+/// Deserializes a sequence of [`Candle`]s from any kline payload shapes, supported by [`Kline`]
+///
+/// Use it with `#[serde(deserialize_with = "deserialize_candles")]`.
+///
+/// Requires `klines` feature.
+pub fn deserialize_candles<'de, D>(deserializer: D) -> Result<Vec<Candle>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let klines = Vec::<Kline>::deserialize(deserializer)?;
+	Ok(klines.into_iter().map(|kline| kline.candle).collect())
+}
+
+struct KlineVisitor;
+
+impl<'de> Visitor<'de> for KlineVisitor {
+	type Value = Kline;
+
+	fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("kline as an array or as an object")
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		let timestamp = seq
+			.next_element::<Timestamp>()?
+			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+		let mut prices = [0.0; 4];
+		for (index, price) in prices.iter_mut().enumerate() {
+			*price = seq
+				.next_element::<Number>()?
+				.ok_or_else(|| de::Error::invalid_length(index + 1, &self))?
+				.0;
+		}
+
+		let volume = seq.next_element::<Number>()?;
+
+		while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+		let [open, high, low, close] = prices;
+
+		Ok(Kline {
+			timestamp: Some(timestamp.0),
+			candle: Candle {
+				open,
+				high,
+				low,
+				close,
+				volume: volume.map_or(ValueType::NAN, |volume| volume.0),
+			},
+		})
+	}
+
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		let mut timestamp = None;
+		let mut open = None;
+		let mut high = None;
+		let mut low = None;
+		let mut close = None;
+		let mut volume = None;
+
+		while let Some(key) = map.next_key::<String>()? {
+			match key.as_str() {
+				"k" => {
+					let kline = map.next_value::<Self::Value>()?;
+					while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+
+					return Ok(kline);
+				}
+				"t" | "time" | "timestamp" | "ts" | "open_time" | "openTime" | "start" => {
+					timestamp = Some(map.next_value::<Timestamp>()?.0);
+				}
+				"o" | "open" => open = Some(map.next_value::<Number>()?.0),
+				"h" | "high" => high = Some(map.next_value::<Number>()?.0),
+				"l" | "low" => low = Some(map.next_value::<Number>()?.0),
+				"c" | "close" => close = Some(map.next_value::<Number>()?.0),
+				"v" | "volume" | "vol" => volume = Some(map.next_value::<Number>()?.0),
+				_ => {
+					map.next_value::<IgnoredAny>()?;
+				}
+			}
+		}
+
+		Ok(Kline {
+			timestamp,
+			candle: Candle {
+				open: open.ok_or_else(|| de::Error::missing_field("open"))?,
+				high: high.ok_or_else(|| de::Error::missing_field("high"))?,
+				low: low.ok_or_else(|| de::Error::missing_field("low"))?,
+				close: close.ok_or_else(|| de::Error::missing_field("close"))?,
+				volume: volume.unwrap_or(ValueType::NAN),
+			},
+		})
+	}
+}
+
+/// Number, which may be sent either as a number or as a string
+struct Number(ValueType);
+
+impl<'de> Deserialize<'de> for Number {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(NumberVisitor)
+	}
+}
+
+struct NumberVisitor;
+
+impl Visitor<'_> for NumberVisitor {
+	type Value = Number;
+
+	fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("a number or a string with a number")
+	}
+
+	#[allow(trivial_numeric_casts, clippy::cast_possible_truncation)]
+	fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+		Ok(Number(value as ValueType))
+	}
+
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+		Ok(Number(value as ValueType))
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		Ok(Number(value as ValueType))
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		value
+			.trim()
+			.parse()
+			.map(Number)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+	}
+}
+
+/// Integer timestamp, which may be sent either as a number or as a string
+struct Timestamp(i64);
+
+impl<'de> Deserialize<'de> for Timestamp {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_any(TimestampVisitor)
+	}
+}
+
+struct TimestampVisitor;
+
+impl Visitor<'_> for TimestampVisitor {
+	type Value = Timestamp;
+
+	fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("an integer timestamp or a string with it")
+	}
+
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+		Ok(Timestamp(value))
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		i64::try_from(value)
+			.map(Timestamp)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(value), &self))
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		value
+			.trim()
+			.parse()
+			.map(Timestamp)
+			.map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{deserialize_candle, Kline};
+	use crate::core::Candle;
+	use serde::Deserialize;
+
+	#[test]
+	fn test_kline_array() {
+		let kline: Kline = serde_json::from_str(
+			r#"[1600000000, "1.5", 2, "1.0", 1.25, "100.5", 1600000059, "7"]"#,
+		)
+		.unwrap();
+
+		assert_eq!(kline.timestamp, Some(1_600_000_000));
+		assert_eq!(kline.candle, (1.5, 2.0, 1.0, 1.25, 100.5).into());
+
+		let kline: Kline = serde_json::from_str(r#"["1600000000", 1, 2, 0.5, 1.5]"#).unwrap();
+		assert_eq!(kline.timestamp, Some(1_600_000_000));
+		assert!(kline.candle.volume.is_nan());
+
+		assert!(serde_json::from_str::<Kline>("[1600000000, 1, 2, 0.5]").is_err());
+		assert!(serde_json::from_str::<Kline>(r#"[1600000000, "x", 2, 0.5, 1.5]"#).is_err());
+		assert!(serde_json::from_str::<Kline>("[1.5, 1, 2, 0.5, 1.5]").is_err());
+	}
+
+	#[test]
+	fn test_kline_object() {
+		let kline: Kline = serde_json::from_str(
+			r#"{"time": 60, "open": "1.5", "high": 2, "low": "1.0", "close": 1.25, "volume": "100.5", "symbol": "BTCUSDT"}"#,
+		)
+		.unwrap();
+
+		assert_eq!(kline.timestamp, Some(60));
+		assert_eq!(kline.candle, (1.5, 2.0, 1.0, 1.25, 100.5).into());
+
+		let kline: Kline = serde_json::from_str(
+			r#"{"e": "kline", "s": "BNBBTC", "k": {"t": 120, "o": "1", "h": "2", "l": "0.5", "c": "1.5", "v": "10", "x": false}, "E": 1}"#,
+		)
+		.unwrap();
+
+		assert_eq!(kline.timestamp, Some(120));
+		assert_eq!(kline.candle, (1.0, 2.0, 0.5, 1.5, 10.0).into());
+
+		let kline: Kline = serde_json::from_str(r#"{"o": 1, "h": 2, "l": 0.5, "c": 1.5}"#).unwrap();
+		assert_eq!(kline.timestamp, None);
+		assert!(kline.candle.volume.is_nan());
+
+		assert!(serde_json::from_str::<Kline>(r#"{"o": 1, "h": 2, "c": 1.5}"#).is_err());
+	}
+
+	#[test]
+	fn test_deserialize_candle() {
+		#[derive(Deserialize)]
+		struct Message {
+			#[serde(deserialize_with = "deserialize_candle")]
+			data: Candle,
+		}
+
+		let message: Message =
+			serde_json::from_str(r#"{"data": [0, "1", "2", "0.5", "1.5", "10"]}"#).unwrap();
+		assert_eq!(message.data, (1.0, 2.0, 0.5, 1.5, 10.0).into());
+	}
+}
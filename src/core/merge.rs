@@ -0,0 +1,263 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Candle, ValueType, OHLCV};
+
+/// Policy of choosing *open* value of the merged candle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum OpenPolicy {
+	/// *open* of the first merged candle
+	#[default]
+	First,
+
+	/// *close* of the candle right before the first merged candle, so there are no gaps between merged candles
+	///
+	/// When there is no previous candle (or its *close* is `NaN`), *open* of the first merged candle is used.
+	PreviousClose,
+}
+
+/// Policy of merging *volume* values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum VolumePolicy {
+	/// Sum of volumes
+	#[default]
+	Sum,
+
+	/// Maximum of volumes
+	Max,
+
+	/// Volume of the last merged candle (f.e. for cumulative volume data)
+	Last,
+}
+
+/// Policy of merging `NaN` *high*, *low* and *volume* values
+///
+/// *open* of the first candle and *close* of the last candle are always taken as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum NanMergePolicy {
+	/// Values are merged by plain floating point operations, as [`CollapseTimeframe`](crate::methods::CollapseTimeframe) always did:
+	/// `NaN` *high* and *low* values are ignored by `max` and `min`, while `NaN` *volume* is propagated by the sum
+	#[default]
+	Native,
+
+	/// Any `NaN` value makes the corresponding merged value `NaN`
+	Propagate,
+
+	/// `NaN` values are ignored. Merged value is `NaN` only when all the merged values are `NaN`
+	Ignore,
+}
+
+/// Configuration of merging candles by [`Merge`]
+///
+/// Default policy is the classic timeframe collapsing: *open* of the first candle, maximum of *high*, minimum of *low*,
+/// *close* of the last candle and sum of *volume* values. `NaN` values are handled by [`NanMergePolicy::Native`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Candle, Merge, MergePolicy, VolumePolicy};
+///
+/// let mut candle = Candle::from(&(10.0, 15.0, 5.0, 12.0, 1000.0));
+/// let next = Candle::from(&(12.1, 17.0, 6.0, 13.0, 2000.0));
+///
+/// let policy = MergePolicy {
+///     volume: VolumePolicy::Max,
+///     ..MergePolicy::default()
+/// };
+///
+/// candle.merge(&next, &policy);
+///
+/// assert_eq!(candle, Candle::from(&(10.0, 17.0, 5.0, 13.0, 2000.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MergePolicy {
+	/// *open* value policy. Default is [`OpenPolicy::First`].
+	pub open: OpenPolicy,
+
+	/// *volume* value policy. Default is [`VolumePolicy::Sum`].
+	pub volume: VolumePolicy,
+
+	/// `NaN` *high*, *low* and *volume* values policy. Default is [`NanMergePolicy::Native`].
+	pub nan: NanMergePolicy,
+}
+
+impl MergePolicy {
+	/// Creates the first merged candle from the `first` candle
+	///
+	/// `previous_close` is a *close* value of the candle right before the `first` one, if any.
+	/// It is used by [`OpenPolicy::PreviousClose`].
+	pub fn start<T: OHLCV + ?Sized>(&self, first: &T, previous_close: Option<ValueType>) -> Candle {
+		let candle = Candle::from(first);
+
+		match (self.open, previous_close) {
+			(OpenPolicy::PreviousClose, Some(open)) if !open.is_nan() => Candle {
+				open,
+				high: self.value(candle.high, open, ValueType::max),
+				low: self.value(candle.low, open, ValueType::min),
+				..candle
+			},
+			_ => candle,
+		}
+	}
+
+	/// Merges all the `candles` into a single one
+	///
+	/// Returns `None` if `candles` is empty.
+	pub fn merge_all<T: OHLCV>(
+		&self,
+		candles: &[T],
+		previous_close: Option<ValueType>,
+	) -> Option<Candle> {
+		let (first, rest) = candles.split_first()?;
+
+		Some(
+			rest.iter()
+				.fold(self.start(first, previous_close), |mut merged, next| {
+					merged.merge(next, self);
+					merged
+				}),
+		)
+	}
+
+	fn value<F>(self, a: ValueType, b: ValueType, f: F) -> ValueType
+	where
+		F: Fn(ValueType, ValueType) -> ValueType,
+	{
+		match self.nan {
+			NanMergePolicy::Propagate if a.is_nan() || b.is_nan() => ValueType::NAN,
+			NanMergePolicy::Ignore if a.is_nan() => b,
+			NanMergePolicy::Ignore if b.is_nan() => a,
+			_ => f(a, b),
+		}
+	}
+}
+
+/// Merging of candles
+///
+/// It is used for converting timeframes by [`CollapseTimeframe`](crate::methods::CollapseTimeframe)
+/// and [`Sequence::collapse_timeframe_with`](crate::core::Sequence::collapse_timeframe_with).
+pub trait Merge<T: ?Sized = Self> {
+	/// Merges the `next` candle into `self` according to the `policy`
+	///
+	/// [`MergePolicy::open`] is not applied here, because *open* value is chosen once by [`MergePolicy::start`].
+	fn merge(&mut self, next: &T, policy: &MergePolicy);
+}
+
+impl<T: OHLCV + ?Sized> Merge<T> for Candle {
+	fn merge(&mut self, next: &T, policy: &MergePolicy) {
+		let volume: fn(ValueType, ValueType) -> ValueType = match policy.volume {
+			VolumePolicy::Sum => |a: ValueType, b: ValueType| a + b,
+			VolumePolicy::Max => ValueType::max,
+			VolumePolicy::Last => |_, b| b,
+		};
+
+		*self = Self {
+			open: self.open,
+			high: policy.value(self.high, next.high(), ValueType::max),
+			low: policy.value(self.low, next.low(), ValueType::min),
+			close: next.close(),
+			volume: policy.value(self.volume, next.volume(), volume),
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Merge, MergePolicy, NanMergePolicy, OpenPolicy, VolumePolicy};
+	use crate::core::{Candle, ValueType};
+
+	const NAN: ValueType = ValueType::NAN;
+
+	#[test]
+	fn test_merge_volume() {
+		let first = Candle::from(&(10.0, 15.0, 5.0, 12.0, 1000.0));
+		let next = Candle::from(&(12.0, 17.0, 6.0, 13.0, 2000.0));
+
+		for &(volume, expected) in &[
+			(VolumePolicy::Sum, 3000.0),
+			(VolumePolicy::Max, 2000.0),
+			(VolumePolicy::Last, 2000.0),
+		] {
+			let mut merged = first;
+			merged.merge(
+				&next,
+				&MergePolicy {
+					volume,
+					..MergePolicy::default()
+				},
+			);
+
+			assert_eq!(merged, Candle::from(&(10.0, 17.0, 5.0, 13.0, expected)));
+		}
+	}
+
+	#[test]
+	fn test_merge_nan() {
+		let candles = [
+			Candle::from(&(10.0, 15.0, 5.0, 12.0, NAN)),
+			Candle::from(&(12.0, NAN, 6.0, 13.0, 2000.0)),
+			Candle::from(&(13.0, 14.0, 4.0, NAN, 100.0)),
+		];
+
+		let native = MergePolicy::default().merge_all(&candles, None).unwrap();
+		assert_eq!((native.open, native.high, native.low), (10.0, 15.0, 4.0));
+		assert!(native.close.is_nan());
+		assert!(native.volume.is_nan());
+
+		let propagated = MergePolicy {
+			nan: NanMergePolicy::Propagate,
+			..MergePolicy::default()
+		}
+		.merge_all(&candles, None)
+		.unwrap();
+		assert!(propagated.high.is_nan());
+		assert_eq!((propagated.open, propagated.low), (10.0, 4.0));
+		assert!(propagated.close.is_nan());
+		assert!(propagated.volume.is_nan());
+
+		let ignored = MergePolicy {
+			nan: NanMergePolicy::Ignore,
+			..MergePolicy::default()
+		}
+		.merge_all(&candles, None)
+		.unwrap();
+		assert_eq!(
+			(ignored.open, ignored.high, ignored.low, ignored.volume),
+			(10.0, 15.0, 4.0, 2100.0)
+		);
+		assert!(ignored.close.is_nan());
+	}
+
+	#[test]
+	fn test_merge_open() {
+		let candles = [
+			Candle::from(&(10.0, 15.0, 5.0, 12.0, 1000.0)),
+			Candle::from(&(12.0, 17.0, 6.0, 13.0, 2000.0)),
+		];
+
+		let policy = MergePolicy {
+			open: OpenPolicy::PreviousClose,
+			..MergePolicy::default()
+		};
+
+		assert_eq!(
+			policy.merge_all(&candles, None),
+			MergePolicy::default().merge_all(&candles, None)
+		);
+		assert_eq!(
+			policy.merge_all(&candles, Some(20.0)),
+			Some(Candle::from(&(20.0, 20.0, 5.0, 13.0, 3000.0)))
+		);
+		assert_eq!(policy.merge_all::<Candle>(&[], Some(20.0)), None);
+	}
+}
@@ -1,6 +1,7 @@
-use super::{Error, Sequence};
+use super::{Error, PeriodType, Sequence};
 
 use std::fmt;
+use std::mem::size_of_val;
 
 type BoxedFnMethod<'a, M> = Box<dyn FnMut(<M as Method<'a>>::Input) -> <M as Method<'a>>::Output>;
 
@@ -75,6 +76,35 @@ pub trait Method<'a>: fmt::Debug {
 	where
 		Self: Sized;
 
+	/// Creates an instance of the method, which has a single [`PeriodType`] parameter, from `usize` `period`
+	///
+	/// `period` is validated against the active [`PeriodType`], so there is no need for feature dependent casts.
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::WrongMethodParameters`] when `period` does not fit into [`PeriodType`]
+	/// and any error of the method's [`new`](Method::new).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let periods: Vec<usize> = vec![2, 5, 10];
+	///
+	/// for &period in &periods {
+	///     let mut ma = SMA::new_with_period(period, 1.0).unwrap();
+	///     assert_eq!(ma.next(1.0), 1.0);
+	/// }
+	/// ```
+	fn new_with_period(period: usize, initial_value: Self::Input) -> Result<Self, Error>
+	where
+		Self: Method<'a, Params = PeriodType> + Sized,
+	{
+		Self::new(super::period(period)?, initial_value)
+	}
+
 	/// Generates next output value based on the given input `value`
 	fn next(&mut self, value: Self::Input) -> Self::Output;
 
@@ -84,6 +114,35 @@ pub trait Method<'a>: fmt::Debug {
 		parts.last().unwrap_or_default()
 	}
 
+	/// Returns count of bytes, allocated by the method on the heap (f.e. buffers of the inner [`Window`](crate::core::Window)s)
+	///
+	/// Methods, which own heap allocated buffers, override it.
+	/// Default implementation returns `0`.
+	fn heap_size(&self) -> usize {
+		0
+	}
+
+	/// Returns estimated total memory size of the method in bytes, including heap allocated buffers
+	///
+	/// It is useful for capacity planning, when a lot of methods are kept in memory.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::ValueType;
+	/// use yata::methods::{EMA, SMA};
+	/// use yata::prelude::*;
+	///
+	/// let ema = EMA::new(20, 1.0).unwrap();
+	/// let sma = SMA::new(20, 1.0).unwrap();
+	///
+	/// assert_eq!(ema.memory_size(), std::mem::size_of::<EMA>());
+	/// assert_eq!(sma.memory_size(), std::mem::size_of::<SMA>() + 20 * std::mem::size_of::<ValueType>());
+	/// ```
+	fn memory_size(&self) -> usize {
+		size_of_val(self) + self.heap_size()
+	}
+
 	/// Returns memory size of the method `(size, align)`
 	fn memsize(&self) -> (usize, usize)
 	where
@@ -127,6 +186,37 @@ pub trait Method<'a>: fmt::Debug {
 		inputs.call(self)
 	}
 
+	/// Iterates the `Method` over the given `inputs` slice and writes output values into the `outputs` slice.
+	///
+	/// Unlike [`over`](Method::over), it does not allocate anything, so it may be used in hot loops with preallocated buffers.
+	///
+	/// # Guarantees
+	///
+	/// Only `min(inputs.len(), outputs.len())` values are evaluated. The rest of the `outputs` stay untouched.
+	///
+	/// ```
+	/// use yata::methods::EMA;
+	/// use yata::prelude::*;
+	///
+	/// let s = [1., 2., 3., 4., 5.];
+	/// let mut outputs = [0.; 5];
+	///
+	/// let mut ema = EMA::new_const(3, s[0]);
+	/// ema.over_slice(&s, &mut outputs);
+	///
+	/// assert_eq!(outputs.to_vec(), EMA::new(3, s[0]).unwrap().over(&s));
+	/// ```
+	#[inline]
+	fn over_slice(&mut self, inputs: &[Self::Input], outputs: &mut [Self::Output])
+	where
+		Self::Input: Copy,
+	{
+		outputs
+			.iter_mut()
+			.zip(inputs)
+			.for_each(|(output, &input)| *output = self.next(input));
+	}
+
 	/// Applies method to the sequence in-place.
 	fn apply<'b: 'a, T, S>(&'a mut self, sequence: &'b mut S)
 	where
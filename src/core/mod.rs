@@ -4,21 +4,39 @@
 
 mod action;
 mod candles;
+mod checkpoint;
 mod errors;
 mod indicator;
+#[cfg(feature = "klines")]
+mod klines;
+mod merge;
 mod method;
+mod nan_policy;
 mod ohlcv;
+mod peekable;
+mod period;
+#[cfg(feature = "persist")]
+mod persist;
 mod sequence;
 mod window;
 
 pub use action::Action;
 pub use candles::*;
+pub use checkpoint::Checkpoint;
 pub use errors::Error;
 pub use indicator::*;
+#[cfg(feature = "klines")]
+pub use klines::{deserialize_candle, deserialize_candles, Kline};
+pub use merge::{Merge, MergePolicy, NanMergePolicy, OpenPolicy, VolumePolicy};
 pub use method::Method;
+pub use nan_policy::NanPolicy;
 pub use ohlcv::OHLCV;
+pub use peekable::Peekable;
+pub use period::Period;
+#[cfg(feature = "persist")]
+pub use persist::{Persist, STATE_VERSION};
 pub use sequence::*;
-pub use window::Window;
+pub use window::{Window, WindowCheckpoint};
 
 /// Main value type for calculations
 ///
@@ -83,3 +101,31 @@ pub type PeriodType = u32;
 #[cfg(feature = "period_type_u64")]
 #[allow(missing_docs)]
 pub type PeriodType = u64;
+
+/// Converts `usize` `value` into [`PeriodType`], checking if it fits into the active [`PeriodType`]
+///
+/// Lets code be written without casts that depend on `period_type_*` features.
+///
+/// # Errors
+///
+/// Returns [`Error::WrongMethodParameters`] when `value` is greater than [`PeriodType::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{period, PeriodType};
+///
+/// assert_eq!(period(10).unwrap(), 10 as PeriodType);
+///
+/// if let Some(too_long) = (PeriodType::MAX as usize).checked_add(1) {
+///     assert!(period(too_long).is_err());
+/// }
+/// ```
+///
+/// See also [`Method::new_with_period`].
+pub fn period(value: usize) -> Result<PeriodType, Error> {
+	use std::convert::TryFrom;
+
+	PeriodType::try_from(value)
+		.map_err(|_| Error::wrong_method_parameter("period", "<= PeriodType::MAX", value))
+}
@@ -0,0 +1,65 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::core::Error;
+
+/// Policy of handling non-finite (`NaN` or infinite) input values
+///
+/// Used by [`NanGuard`](crate::methods::NanGuard) method and [`NanGuarded`](crate::indicators::NanGuarded) indicator wrappers.
+///
+/// Without any guard, every method and indicator handles such values on its own way:
+/// some of them panic, others silently propagate `NaN` into their internal state forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum NanPolicy {
+	/// Skip non-finite value: internal state is not changed, last output is returned
+	#[default]
+	Skip,
+
+	/// Replace non-finite value by the last finite one
+	#[cfg_attr(feature = "serde", serde(rename = "carry_last"))]
+	CarryLast,
+
+	/// Return [`Error::NonFinite`](crate::core::Error::NonFinite) and do not change internal state
+	Error,
+}
+
+impl FromStr for NanPolicy {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"skip" => Ok(Self::Skip),
+			"carry_last" | "carry" => Ok(Self::CarryLast),
+			"error" => Ok(Self::Error),
+
+			value => Err(Error::ParameterParse(
+				"NanPolicy".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+impl TryFrom<&str> for NanPolicy {
+	type Error = Error;
+
+	fn try_from(s: &str) -> Result<Self, Error> {
+		Self::from_str(s)
+	}
+}
+
+impl From<NanPolicy> for &'static str {
+	fn from(value: NanPolicy) -> Self {
+		match value {
+			NanPolicy::Skip => "skip",
+			NanPolicy::CarryLast => "carry_last",
+			NanPolicy::Error => "error",
+		}
+	}
+}
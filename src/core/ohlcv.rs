@@ -30,8 +30,50 @@ pub trait OHLCV {
 	fn close(&self) -> ValueType;
 
 	/// Should return *volume* value for the period
+	///
+	/// Candles without volume data should return `NaN`. See [`OHLCV::has_volume`].
 	fn volume(&self) -> ValueType;
 
+	/// Checks if the candle has volume data
+	///
+	/// Candles without volume data are marked by `NaN` volume value (see [`Candle::without_volume`](crate::core::Candle::without_volume)).
+	/// Volume-based indicators and methods return [`Error::NoVolume`](crate::core::Error::NoVolume) when they are initialized by such candle.
+	/// Later volume-less candles are treated by volume-based indicators as candles with zero volume (see [`OHLCV::volume_or_zero`]).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::Candle;
+	///
+	/// let candle = Candle {
+	///     close: 10.0,
+	///     volume: 120.0,
+	///     ..Candle::default()
+	/// };
+	///
+	/// assert!(candle.has_volume());
+	/// assert!(!candle.without_volume().has_volume());
+	/// ```
+	#[inline]
+	fn has_volume(&self) -> bool {
+		!self.volume().is_nan()
+	}
+
+	/// Returns *volume* value or `0.0` if the candle [has no volume](OHLCV::has_volume)
+	///
+	/// Volume-based indicators use it for every candle after initialization, so a volume-less candle in the middle of the series
+	/// is considered as a period without any trades instead of turning every further value into `NaN`.
+	/// Use [`NanGuarded`](crate::indicators::NanGuarded) to skip or reject such candles instead.
+	#[inline]
+	fn volume_or_zero(&self) -> ValueType {
+		if self.has_volume() {
+			self.volume()
+		} else {
+			0.0
+		}
+	}
+
 	/// Calculates [Typical price](https://en.wikipedia.org/wiki/Typical_price).
 	/// It's just a simple \(`High` + `Low` + `Close`\) / `3`
 	///
@@ -0,0 +1,129 @@
+use super::{IndicatorInstance, IndicatorResult, OHLCV};
+
+/// Trait for peeking the would-be output over the provisional candle without changing current state
+///
+/// It is useful for live charts, which display values of the still-open bar.
+///
+/// Implemented for every [`IndicatorInstance`], which implements [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Peekable;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::MACD;
+///
+/// let mut candles = RandomCandles::new();
+/// let mut state = MACD::default().init(&candles.first()).unwrap();
+///
+/// let open_bar = candles.next().unwrap();
+/// let peeked = state.peek(&open_bar);
+///
+/// // bar is closed now
+/// let closed = state.next(&open_bar);
+///
+/// assert_eq!(closed.values(), peeked.values());
+/// assert_eq!(closed.signals(), peeked.signals());
+/// ```
+pub trait Peekable<V> {
+	/// Returns the value, which would be returned over the provisional `candle`, without changing current state
+	fn peek<T: OHLCV>(&self, candle: &T) -> V;
+}
+
+impl<I> Peekable<IndicatorResult> for I
+where
+	I: IndicatorInstance + Clone,
+{
+	#[inline]
+	fn peek<T: OHLCV>(&self, candle: &T) -> IndicatorResult {
+		self.clone().next(candle)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Peekable;
+	use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::*;
+
+	fn same_values(a: &[ValueType], b: &[ValueType]) -> bool {
+		a.len() == b.len()
+			&& a.iter()
+				.zip(b.iter())
+				.all(|(x, y)| x.to_bits() == y.to_bits())
+	}
+
+	fn test_peek<C>()
+	where
+		C: IndicatorConfig + Default,
+		C::Instance: Peekable<IndicatorResult>,
+	{
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+		let mut state = C::default().init(&candles[0]).unwrap();
+
+		for candle in &candles {
+			// peeking some other provisional candle must not change the state
+			state.peek(&candles[0]);
+
+			let peeked = state.peek(candle);
+			let result = state.next(candle);
+
+			assert!(same_values(peeked.values(), result.values()));
+			assert_eq!(peeked.signals(), result.signals());
+		}
+	}
+
+	#[test]
+	fn test_peekable_indicators() {
+		test_peek::<Aroon>();
+		test_peek::<AverageDirectionalIndex>();
+		test_peek::<AwesomeOscillator>();
+		test_peek::<BalanceOfPower>();
+		test_peek::<BollingerBands>();
+		test_peek::<ChaikinMoneyFlow>();
+		test_peek::<ChaikinOscillator>();
+		test_peek::<ChandeKrollStop>();
+		test_peek::<ChandeMomentumOscillator>();
+		test_peek::<CommodityChannelIndex>();
+		test_peek::<CoppockCurve>();
+		test_peek::<Debounce<MACD>>();
+		test_peek::<DetrendedPriceOscillator>();
+		test_peek::<DonchianChannel>();
+		test_peek::<EaseOfMovement>();
+		test_peek::<ElderRay>();
+		test_peek::<EldersForceIndex>();
+		test_peek::<Envelopes>();
+		test_peek::<example::Example>();
+		test_peek::<FisherTransform>();
+		test_peek::<HullMovingAverage>();
+		test_peek::<IchimokuCloud>();
+		test_peek::<Imbalance>();
+		test_peek::<Kaufman>();
+		test_peek::<KeltnerChannel>();
+		test_peek::<KlingerVolumeOscillator>();
+		test_peek::<KnowSureThing>();
+		test_peek::<MACD>();
+		test_peek::<MomentumIndex>();
+		test_peek::<MoneyFlowIndex>();
+		test_peek::<NanGuarded<RSI>>();
+		test_peek::<ParabolicSAR>();
+		test_peek::<PivotReversalStrategy>();
+		test_peek::<PriceChannelStrategy>();
+		test_peek::<QuantitativeQualitativeEstimation>();
+		test_peek::<RelativeStrengthIndex>();
+		test_peek::<RelativeVigorIndex>();
+		test_peek::<SchaffTrendCycle>();
+		test_peek::<SMIErgodicIndicator>();
+		test_peek::<SqueezeMomentum>();
+		test_peek::<StochasticOscillator>();
+		test_peek::<TripleScreen>();
+		test_peek::<Trix>();
+		test_peek::<TrendStrengthIndex>();
+		test_peek::<TrueStrengthIndex>();
+		test_peek::<UlcerIndex>();
+		test_peek::<VolumeProfile>();
+		test_peek::<WoodiesCCI>();
+	}
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+/// Integer type, which may be used for lengths and indexes of [`Window`](crate::core::Window)
+///
+/// Implemented for `u8`, `u16`, `u32` and `u64`. Default period type for everything in the crate is [`PeriodType`](crate::core::PeriodType),
+/// but windows and window-based methods (f.e. [`SMA`](crate::methods::SMA)) of other period types may be used at the same time,
+/// f.e. small `u8` windows for hot paths and `u32` windows for long lookbacks.
+pub trait Period: Copy + Ord + fmt::Debug + fmt::Display + Send + Sync + 'static {
+	/// Zero value
+	const ZERO: Self;
+
+	/// Maximum value
+	const MAX: Self;
+
+	/// Converts the value into `usize`
+	fn to_usize(self) -> usize;
+
+	/// Converts `usize` `value` into the period type
+	///
+	/// `value` must fit into the period type, otherwise it is truncated.
+	fn from_usize(value: usize) -> Self;
+}
+
+macro_rules! impl_period {
+	($($t:ty),*) => {
+		$(
+			impl Period for $t {
+				const ZERO: Self = 0;
+				const MAX: Self = <$t>::MAX;
+
+				#[inline]
+				#[allow(clippy::cast_possible_truncation)]
+				fn to_usize(self) -> usize {
+					self as usize
+				}
+
+				#[inline]
+				#[allow(clippy::cast_possible_truncation)]
+				fn from_usize(value: usize) -> Self {
+					value as Self
+				}
+			}
+		)*
+	};
+}
+
+impl_period!(u8, u16, u32, u64);
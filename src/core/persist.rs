@@ -0,0 +1,179 @@
+use super::Error;
+use bincode::Options;
+use serde::{de::DeserializeOwned, Serialize};
+
+const MAGIC: [u8; 4] = *b"YATA";
+const HEADER_SIZE: usize = MAGIC.len() + 2;
+
+/// Current binary state format version
+///
+/// Every binary state starts with a header, which holds this version.
+/// Loading a state with any other version fails with [`Error::WrongStateVersion`](crate::core::Error::WrongStateVersion).
+pub const STATE_VERSION: u16 = 1;
+
+fn options() -> impl Options {
+	bincode::DefaultOptions::new()
+}
+
+/// Compact versioned binary serialization of methods, indicator configs and any other serializable state
+///
+/// Binary state is much smaller and faster than any text (e.g. JSON) representation,
+/// so it suits periodical checkpointing of lots of methods.
+///
+/// Implemented for every type, which implements `serde`'s `Serialize` and `Deserialize`.
+///
+/// Requires `persist` feature.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Persist;
+/// use yata::methods::SMA;
+///
+/// let mut sma = SMA::new(5, 1.0).unwrap();
+/// sma.next(2.0);
+///
+/// let bytes = sma.to_bytes().unwrap();
+/// let mut restored: SMA = SMA::from_bytes(&bytes).unwrap();
+///
+/// assert_eq!(sma.next(3.0), restored.next(3.0));
+/// ```
+pub trait Persist: Sized {
+	/// Serializes current state into bytes
+	fn to_bytes(&self) -> Result<Vec<u8>, Error>;
+
+	/// Deserializes state from `bytes`, produced by [`to_bytes`](Persist::to_bytes)
+	///
+	/// Returns [`Error::WrongStateVersion`](crate::core::Error::WrongStateVersion) when `bytes` were saved with another [`STATE_VERSION`]
+	/// and [`Error::InvalidState`](crate::core::Error::InvalidState) when `bytes` are not a state at all.
+	fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+impl<T> Persist for T
+where
+	T: Serialize + DeserializeOwned,
+{
+	fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+		let mut bytes = MAGIC.to_vec();
+		bytes.extend_from_slice(&STATE_VERSION.to_le_bytes());
+
+		options()
+			.serialize_into(&mut bytes, self)
+			.map_err(|e| Error::wrap("Unable to serialize state", e))?;
+
+		Ok(bytes)
+	}
+
+	fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+		if bytes.len() < HEADER_SIZE || bytes[..MAGIC.len()] != MAGIC {
+			return Err(Error::InvalidState);
+		}
+
+		let found = u16::from_le_bytes([bytes[MAGIC.len()], bytes[MAGIC.len() + 1]]);
+
+		if found != STATE_VERSION {
+			return Err(Error::WrongStateVersion {
+				expected: STATE_VERSION,
+				found,
+			});
+		}
+
+		options()
+			.reject_trailing_bytes()
+			.deserialize(&bytes[HEADER_SIZE..])
+			.map_err(|e| Error::wrap("Unable to deserialize state", e))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Persist, HEADER_SIZE, STATE_VERSION};
+	use crate::core::{Error, IndicatorConfig, Method, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+	use crate::methods::{StDev, SMA};
+
+	#[test]
+	fn test_persist_methods() {
+		let values: Vec<ValueType> = RandomCandles::new().take(300).map(|c| c.close).collect();
+
+		let mut sma = SMA::new(20, values[0]).unwrap();
+		let mut st_dev = StDev::new(20, values[0]).unwrap();
+
+		for (i, &value) in values.iter().enumerate() {
+			sma.next(value);
+			st_dev.next(value);
+
+			if i % 10 == 0 {
+				sma = SMA::from_bytes(&sma.to_bytes().unwrap()).unwrap();
+				st_dev = StDev::from_bytes(&st_dev.to_bytes().unwrap()).unwrap();
+			}
+		}
+
+		let mut reference_sma = SMA::new(20, values[0]).unwrap();
+		let mut reference_st_dev = StDev::new(20, values[0]).unwrap();
+
+		for &value in &values {
+			reference_sma.next(value);
+			reference_st_dev.next(value);
+		}
+
+		let value = values[0];
+		assert_eq!(
+			sma.next(value).to_bits(),
+			reference_sma.next(value).to_bits()
+		);
+		assert_eq!(
+			st_dev.next(value).to_bits(),
+			reference_st_dev.next(value).to_bits()
+		);
+	}
+
+	#[test]
+	fn test_persist_config() {
+		let config = MACD {
+			period3: 4,
+			..MACD::default()
+		};
+
+		let restored = MACD::from_bytes(&config.to_bytes().unwrap()).unwrap();
+		let candle = RandomCandles::new().first();
+
+		assert_eq!(restored.period3, 4);
+		assert!(restored.init(&candle).is_ok());
+	}
+
+	#[test]
+	fn test_persist_version() {
+		let mut bytes = SMA::new(3, 1.0).unwrap().to_bytes().unwrap();
+		bytes[HEADER_SIZE - 2..HEADER_SIZE].copy_from_slice(&(STATE_VERSION + 1).to_le_bytes());
+
+		assert!(matches!(
+			<SMA>::from_bytes(&bytes),
+			Err(Error::WrongStateVersion { expected, found }) if expected == STATE_VERSION && found == STATE_VERSION + 1
+		));
+	}
+
+	#[test]
+	fn test_persist_invalid() {
+		let bytes = SMA::new(3, 1.0).unwrap().to_bytes().unwrap();
+
+		assert!(matches!(
+			<SMA>::from_bytes(&bytes[..2]),
+			Err(Error::InvalidState)
+		));
+		assert!(matches!(
+			<SMA>::from_bytes(&bytes[1..]),
+			Err(Error::InvalidState)
+		));
+		assert!(matches!(
+			<SMA>::from_bytes(&bytes[..bytes.len() - 1]),
+			Err(Error::Wrapped { .. })
+		));
+		assert!(matches!(
+			<SMA>::from_bytes(&[bytes.as_slice(), &[0]].concat()),
+			Err(Error::Wrapped { .. })
+		));
+	}
+}
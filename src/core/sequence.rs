@@ -1,7 +1,11 @@
 use crate::core::Method;
-use crate::core::{ValueType, OHLCV};
+use crate::core::{Error, IndicatorConfigDyn, IndicatorResult};
+use crate::core::{MergePolicy, ValueType, OHLCV};
 use crate::prelude::Candle;
 
+/// Count of candles, evaluated by every indicator at once in [`Sequence::apply_indicators`]
+const BATCH_SIZE: usize = 256;
+
 /// Implements some methods for sequence manipulations.
 pub trait Sequence<T>: AsRef<[T]> {
 	/// Validates the sequence.
@@ -40,40 +44,123 @@ pub trait Sequence<T>: AsRef<[T]> {
 		self.as_mut().first_mut()
 	}
 
+	/// Evaluates every indicator config over the sequence and returns results of each indicator in the same order as `configs`
+	///
+	/// Every config is initialized on the first candle. Then the sequence is passed just once:
+	/// it is split into small batches and every indicator evaluates the whole batch before moving on to the next one,
+	/// so the batch stays in the CPU cache while all the indicators process it.
+	///
+	/// Returns empty results for the empty sequence.
+	/// Returns an error if any of the configs fails to initialize.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::prelude::dd::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::{Trix, MACD, RSI};
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	/// let (trix, macd, rsi) = (Trix::default(), MACD::default(), RSI::default());
+	///
+	/// let results = candles.apply_indicators(&[&trix, &macd, &rsi]).unwrap();
+	///
+	/// assert_eq!(results.len(), 3);
+	///
+	/// let expected = macd.over(&candles).unwrap();
+	/// assert_eq!(results[1].len(), expected.len());
+	/// assert_eq!(results[1][999].values(), expected[999].values());
+	/// ```
+	fn apply_indicators(
+		&self,
+		configs: &[&dyn IndicatorConfigDyn<T>],
+	) -> Result<Vec<Vec<IndicatorResult>>, Error>
+	where
+		T: OHLCV,
+	{
+		let candles = self.as_ref();
+
+		if candles.is_empty() {
+			return Ok(vec![Vec::new(); configs.len()]);
+		}
+
+		let mut instances = configs
+			.iter()
+			.map(|config| config.init(&candles[0]))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut results: Vec<Vec<IndicatorResult>> = configs
+			.iter()
+			.map(|_| Vec::with_capacity(candles.len()))
+			.collect();
+
+		for batch in candles.chunks(BATCH_SIZE) {
+			for (instance, results) in instances.iter_mut().zip(results.iter_mut()) {
+				instance.over_into(&batch, results);
+			}
+		}
+
+		Ok(results)
+	}
+
 	/// Converts timeframe of the series
 	///
+	/// Candles are merged by the default [`MergePolicy`].
+	///
 	/// See also [`CollapseTimeframe`](crate::methods::CollapseTimeframe) method.
 	fn collapse_timeframe(&self, size: usize, continuous: bool) -> Vec<Candle>
 	where
 		T: OHLCV,
 	{
-		fn fold<T: OHLCV>(folded: Candle, next: &T) -> Candle {
-			Candle {
-				high: folded.high.max(next.high()),
-				low: folded.low.min(next.low()),
-				close: next.close(),
-				volume: folded.volume + next.volume(),
-				..folded
-			}
-		}
+		self.collapse_timeframe_with(size, continuous, MergePolicy::default())
+	}
 
-		fn window<T: OHLCV>(window: &[T]) -> Candle {
-			let first = window.first().unwrap();
-			let initial = Candle {
-				open: first.open(),
-				high: first.high(),
-				low: first.low(),
-				close: first.close(),
-				volume: first.volume(),
-			};
-
-			window.iter().skip(1).fold(initial, fold)
-		}
+	/// Converts timeframe of the series, merging candles according to the `policy`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::{Candle, MergePolicy, OpenPolicy};
+	///
+	/// let candles: Vec<Candle> = vec![
+	///     (10.0, 15.0, 5.0, 12.0, 1000.0).into(),
+	///     (12.1, 17.0, 6.0, 13.0, 2000.0).into(),
+	///     (13.5, 14.0, 7.0, 8.0, 1000.0).into(),
+	///     (8.0, 9.0, 6.0, 7.0, 500.0).into(),
+	/// ];
+	///
+	/// let policy = MergePolicy {
+	///     open: OpenPolicy::PreviousClose,
+	///     ..MergePolicy::default()
+	/// };
+	///
+	/// let collapsed = candles.collapse_timeframe_with(2, false, policy);
+	///
+	/// assert_eq!(collapsed[0].open(), 10.0);
+	/// // open of the second candle is the close of the first one
+	/// assert_eq!(collapsed[1].open(), 13.0);
+	/// ```
+	fn collapse_timeframe_with(
+		&self,
+		size: usize,
+		continuous: bool,
+		policy: MergePolicy,
+	) -> Vec<Candle>
+	where
+		T: OHLCV,
+	{
+		let candles = self.as_ref();
 
-		self.as_ref()
+		candles
 			.windows(size)
+			.enumerate()
 			.step_by(if continuous { 1 } else { size })
-			.map(window)
+			.filter_map(|(index, window)| {
+				let previous_close = index.checked_sub(1).map(|i| candles[i].close());
+				policy.merge_all(window, previous_close)
+			})
 			.collect()
 	}
 }
@@ -111,3 +198,67 @@ impl<T: OHLCV + Clone, Q: AsRef<[T]>> Sequence<T> for Q {
 			.collect()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{Sequence, BATCH_SIZE};
+	use crate::core::{Candle, IndicatorConfigDyn, ValueType};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+	use crate::indicators::{ChaikinMoneyFlow, CommodityChannelIndex, RelativeStrengthIndex};
+
+	#[test]
+	fn test_apply_indicators() {
+		// steadily rising candles, which always close at the high
+		let candles: Vec<Candle> = (0..BATCH_SIZE * 2 + 3)
+			.map(|i| {
+				let close = 100.0 + i as ValueType;
+				Candle {
+					high: close,
+					low: close - 1.0,
+					close,
+					volume: 1.0,
+					..Candle::default()
+				}
+			})
+			.collect();
+
+		let rsi = RelativeStrengthIndex {
+			period: 3,
+			method: RegularMethods::SMA,
+			..RelativeStrengthIndex::default()
+		};
+		let cci = CommodityChannelIndex {
+			period: 2,
+			..CommodityChannelIndex::default()
+		};
+		let cmf = ChaikinMoneyFlow::default();
+		let configs: [&dyn IndicatorConfigDyn<Candle>; 3] = [&rsi, &cci, &cmf];
+
+		let results = candles.apply_indicators(&configs).unwrap();
+		assert_eq!(results.len(), 3);
+
+		// the first candle is the initial one, so there is no change yet
+		let first = [0.5, 0.0, 1.0];
+		let rest = [1.0, 1.0 / 1.5, 1.0];
+
+		for (i, results) in results.iter().enumerate() {
+			assert_eq!(results.len(), candles.len());
+			assert_eq_float(first[i], results[0].value(0));
+
+			for result in &results[1..] {
+				assert_eq_float(rest[i], result.value(0));
+			}
+		}
+
+		let empty: &[Candle] = &[];
+		let results = empty.apply_indicators(&configs).unwrap();
+		assert_eq!(results.len(), 3);
+		assert!(results.iter().all(Vec::is_empty));
+
+		let wrong = CommodityChannelIndex {
+			period: 1,
+			..CommodityChannelIndex::default()
+		};
+		assert!(candles.apply_indicators(&[&rsi, &wrong]).is_err());
+	}
+}
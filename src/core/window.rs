@@ -1,6 +1,6 @@
 #![allow(unsafe_code)]
-use super::PeriodType;
-use std::mem;
+use super::{Checkpoint, Period, PeriodType};
+use std::mem::{self, size_of_val};
 use std::vec;
 
 #[cfg(feature = "serde")]
@@ -48,20 +48,35 @@ use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializ
 /// assert_eq!(w[2], 2);
 /// ```
 ///
+/// Window's length and indexes are of type `P`, which is [`PeriodType`] by default.
+/// Any other [`Period`] type may be used by [`with_size`](Window::with_size):
+///
+/// ```
+/// use yata::core::Window;
+///
+/// let mut w: Window<f64, u32> = Window::with_size(1000, 0.0);
+///
+/// assert_eq!(w.push(1.0), 0.0);
+/// assert_eq!(w.len(), 1000u32);
+/// ```
+///
 /// # See also
 ///
 /// [`Past`](crate::methods::Past)
 ///
 /// [`Windows`](std::slice::Windows)
 #[derive(Debug, Clone)]
-pub struct Window<T>
+pub struct Window<T, P = PeriodType>
 where
 	T: Copy,
+	P: Period,
 {
 	buf: Box<[T]>,
-	index: PeriodType,
-	size: PeriodType,
-	s_1: PeriodType,
+	index: P,
+	size: P,
+	s_1: P,
+	// count of pushes, used for validating checkpoints
+	steps: usize,
 }
 
 impl<T> Window<T>
@@ -76,23 +91,34 @@ where
 	///
 	/// [`PeriodType::MAX`]: crate::core::PeriodType
 	pub fn new(size: PeriodType, value: T) -> Self {
-		debug_assert!(size <= (PeriodType::MAX - 1), "PeriodType overflow");
-		Self {
-			buf: vec![value; size as usize].into(),
-			index: 0,
-			size,
-			s_1: size.saturating_sub(1),
-		}
+		Self::with_size(size, value)
 	}
 
 	/// Creates an empty `Window` instance (no buffer allocated)
 	#[must_use]
 	pub fn empty() -> Self {
+		Self::default()
+	}
+}
+
+impl<T, P> Window<T, P>
+where
+	T: Copy,
+	P: Period,
+{
+	/// Creates new Window object of size `size` of any [`Period`] type with filled values `value`
+	///
+	/// # Panics
+	///
+	/// When in development mode, this method may panic if `size` is equal to `P::MAX`
+	pub fn with_size(size: P, value: T) -> Self {
+		debug_assert!(size < P::MAX, "Period type overflow");
 		Self {
-			buf: Vec::new().into(),
-			index: 0,
-			size: 0,
-			s_1: 0,
+			buf: vec![value; size.to_usize()].into(),
+			index: P::ZERO,
+			size,
+			s_1: P::from_usize(size.to_usize().saturating_sub(1)),
+			steps: 0,
 		}
 	}
 
@@ -107,10 +133,11 @@ where
 	pub fn push(&mut self, value: T) -> T {
 		debug_assert!(!self.is_empty(), "Trying to use an empty window");
 
+		let index = self.index.to_usize();
 		let refer = if cfg!(feature = "unsafe_performance") {
-			unsafe { self.buf.get_unchecked_mut(self.index as usize) }
+			unsafe { self.buf.get_unchecked_mut(index) }
 		} else {
-			&mut self.buf[self.index as usize]
+			&mut self.buf[index]
 		};
 
 		let old_value = mem::replace(refer, value);
@@ -121,7 +148,8 @@ where
 		// } else {
 		//	self.index += 1;
 		// }
-		self.index = (self.index != self.s_1) as PeriodType * (self.index + 1);
+		self.index = P::from_usize((self.index != self.s_1) as usize * (index + 1));
+		self.steps = self.steps.wrapping_add(1);
 
 		old_value
 	}
@@ -145,7 +173,7 @@ where
 	/// ```
 	#[inline]
 	#[must_use]
-	pub fn iter(&self) -> WindowIterator<T> {
+	pub fn iter(&self) -> WindowIterator<T, P> {
 		WindowIterator::new(self)
 	}
 
@@ -168,7 +196,7 @@ where
 	/// ```
 	#[inline]
 	#[must_use]
-	pub fn iter_rev(&self) -> ReversedWindowIterator<T> {
+	pub fn iter_rev(&self) -> ReversedWindowIterator<T, P> {
 		ReversedWindowIterator::new(self)
 	}
 
@@ -195,23 +223,60 @@ where
 	#[inline]
 	#[must_use]
 	pub fn newest(&self) -> T {
-		let index = self.index.checked_sub(1).unwrap_or(self.s_1);
+		let index = self
+			.index
+			.to_usize()
+			.checked_sub(1)
+			.unwrap_or_else(|| self.s_1.to_usize());
 
 		if cfg!(feature = "unsafe_performance") {
-			*unsafe { self.buf.get_unchecked(index as usize) }
+			*unsafe { self.buf.get_unchecked(index) }
 		} else {
-			self.buf[index as usize]
+			self.buf[index]
+		}
+	}
+
+	/// Returns a value, pushed `index` steps before the newest one (so `0` means the newest value).
+	///
+	/// Returns `None` if `index` is not less than the `Window`'s length.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	/// w.push(2);
+	/// w.push(3);
+	///
+	/// assert_eq!(w.get_back(0), Some(3));
+	/// assert_eq!(w.get_back(1), Some(2));
+	/// assert_eq!(w.get_back(2), Some(1));
+	/// assert_eq!(w.get_back(3), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn get_back(&self, index: P) -> Option<T> {
+		if index >= self.size {
+			return None;
 		}
+
+		let size = self.size.to_usize();
+		let position = (self.index.to_usize() + size - 1 - index.to_usize()) % size;
+
+		Some(self.buf[position])
 	}
 
 	/// Returns an oldest value
 	#[inline]
 	#[must_use]
 	pub fn oldest(&self) -> T {
+		let index = self.index.to_usize();
+
 		if cfg!(feature = "unsafe_performance") {
-			*unsafe { self.buf.get_unchecked(self.index as usize) }
+			*unsafe { self.buf.get_unchecked(index) }
 		} else {
-			self.buf[self.index as usize]
+			self.buf[index]
 		}
 	}
 
@@ -233,43 +298,118 @@ where
 		&self.buf
 	}
 
+	/// Returns count of bytes, allocated by the `Window` on the heap
+	#[must_use]
+	#[inline]
+	pub fn heap_size(&self) -> usize {
+		size_of_val(&*self.buf)
+	}
+
 	/// Returns the length (elements count) of the `Window`
 	#[must_use]
 	#[inline]
-	pub fn len(&self) -> PeriodType {
+	pub fn len(&self) -> P {
 		self.size
 	}
 }
 
-impl<T: Copy> AsRef<[T]> for Window<T> {
+/// [`Window`]'s checkpoint, created by [`Checkpoint::save`]
+///
+/// Holds only the window position and the single value, which is going to be overwritten by the next push.
+/// So it may be restored only while at most one value was pushed into the window since the checkpoint was saved.
+#[derive(Debug, Clone, Copy)]
+pub struct WindowCheckpoint<T: Copy, P: Period = PeriodType> {
+	index: P,
+	value: Option<T>,
+	steps: usize,
+}
+
+impl<T: Copy, P: Period> Window<T, P> {
+	/// Returns a pair of the value, pushed since the `state` checkpoint was saved, and the value, which it has overwritten.
+	/// Returns `None` if nothing was pushed.
+	pub(crate) fn pushed_since(&self, state: &WindowCheckpoint<T, P>) -> Option<(T, T)> {
+		if self.steps == state.steps {
+			return None;
+		}
+
+		state
+			.value
+			.map(|overwritten| (self.buf[state.index.to_usize()], overwritten))
+	}
+}
+
+impl<T: Copy, P: Period> Checkpoint for Window<T, P> {
+	type State = WindowCheckpoint<T, P>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		WindowCheckpoint {
+			index: self.index,
+			value: self.buf.get(self.index.to_usize()).copied(),
+			steps: self.steps,
+		}
+	}
+
+	/// # Panics
+	///
+	/// Panics if more than one value was pushed into the `Window` since the `state` was saved
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		assert!(
+			self.steps.wrapping_sub(state.steps) <= 1,
+			"Window checkpoint cannot be restored after more than one push"
+		);
+
+		self.steps = state.steps;
+
+		if let Some(value) = state.value {
+			self.buf[state.index.to_usize()] = value;
+		}
+
+		self.index = state.index;
+	}
+}
+
+impl<T: Copy, P: Period> AsRef<[T]> for Window<T, P> {
 	fn as_ref(&self) -> &[T] {
 		&self.buf
 	}
 }
 
-impl<T> Default for Window<T>
+impl<T, P> Default for Window<T, P>
 where
 	T: Copy,
+	P: Period,
 {
 	fn default() -> Self {
-		Self::empty()
+		Self {
+			buf: Vec::new().into(),
+			index: P::ZERO,
+			size: P::ZERO,
+			s_1: P::ZERO,
+			steps: 0,
+		}
 	}
 }
 
-impl<T> std::ops::Index<PeriodType> for Window<T>
+impl<T, P> std::ops::Index<P> for Window<T, P>
 where
 	T: Copy,
+	P: Period,
 {
 	type Output = T;
 
-	fn index(&self, index: PeriodType) -> &Self::Output {
+	fn index(&self, index: P) -> &Self::Output {
 		debug_assert!(index < self.size, "Window index {:} is out of range", index);
 
-		let index = self.s_1 - index;
-		let saturated = self.index.saturating_add(index);
-		let overflow = (saturated >= self.size) as PeriodType;
-		let s = self.size - self.index;
-		let buf_index = (overflow * index.saturating_sub(s) + (1 - overflow) * saturated) as usize;
+		let size = self.size.to_usize();
+		let current = self.index.to_usize();
+
+		let index = self.s_1.to_usize() - index.to_usize();
+		let saturated = current + index;
+		let overflow = (saturated >= size) as usize;
+		let s = size - current;
+		let buf_index = overflow * index.saturating_sub(s) + (1 - overflow) * saturated;
 
 		if cfg!(feature = "unsafe_performance") {
 			unsafe { self.buf.get_unchecked(buf_index) }
@@ -279,12 +419,13 @@ where
 	}
 }
 
-impl<'a, T> IntoIterator for &'a Window<T>
+impl<'a, T, P> IntoIterator for &'a Window<T, P>
 where
 	T: Copy,
+	P: Period,
 {
 	type Item = T;
-	type IntoIter = WindowIterator<'a, T>;
+	type IntoIter = WindowIterator<'a, T, P>;
 
 	fn into_iter(self) -> Self::IntoIter {
 		self.iter()
@@ -302,31 +443,34 @@ where
 // }
 
 #[derive(Debug)]
-pub struct WindowIterator<'a, T>
+pub struct WindowIterator<'a, T, P = PeriodType>
 where
 	T: Copy,
+	P: Period,
 {
-	window: &'a Window<T>,
-	index: PeriodType,
-	size: PeriodType,
+	window: &'a Window<T, P>,
+	index: usize,
+	size: usize,
 }
 
-impl<'a, T> WindowIterator<'a, T>
+impl<'a, T, P> WindowIterator<'a, T, P>
 where
 	T: Copy,
+	P: Period,
 {
-	pub fn new(window: &'a Window<T>) -> Self {
+	pub fn new(window: &'a Window<T, P>) -> Self {
 		Self {
 			window,
-			index: window.index,
-			size: window.size,
+			index: window.index.to_usize(),
+			size: window.size.to_usize(),
 		}
 	}
 }
 
-impl<'a, T> Iterator for WindowIterator<'a, T>
+impl<'a, T, P> Iterator for WindowIterator<'a, T, P>
 where
 	T: Copy,
+	P: Period,
 {
 	type Item = T;
 
@@ -338,25 +482,24 @@ where
 
 		self.size -= 1;
 
-		let at_start = (self.index == 0) as PeriodType;
-		self.index = self.index.saturating_sub(1) + at_start * self.window.s_1;
+		let at_start = (self.index == 0) as usize;
+		self.index = self.index.saturating_sub(1) + at_start * self.window.s_1.to_usize();
 
 		let value = if cfg!(feature = "unsafe_performance") {
-			*unsafe { self.window.buf.get_unchecked(self.index as usize) }
+			*unsafe { self.window.buf.get_unchecked(self.index) }
 		} else {
-			self.window.buf[self.index as usize]
+			self.window.buf[self.index]
 		};
 
 		Some(value)
 	}
 
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let size = self.size as usize;
-		(size, Some(size))
+		(self.size, Some(self.size))
 	}
 
 	fn count(self) -> usize {
-		self.size as usize
+		self.size
 	}
 
 	fn last(self) -> Option<Self::Item> {
@@ -364,35 +507,48 @@ where
 	}
 }
 
-impl<'a, T> ExactSizeIterator for WindowIterator<'a, T> where T: Copy {}
-impl<'a, T> std::iter::FusedIterator for WindowIterator<'a, T> where T: Copy {}
+impl<'a, T, P> ExactSizeIterator for WindowIterator<'a, T, P>
+where
+	T: Copy,
+	P: Period,
+{
+}
+impl<'a, T, P> std::iter::FusedIterator for WindowIterator<'a, T, P>
+where
+	T: Copy,
+	P: Period,
+{
+}
 
 #[derive(Debug)]
-pub struct ReversedWindowIterator<'a, T>
+pub struct ReversedWindowIterator<'a, T, P = PeriodType>
 where
 	T: Copy,
+	P: Period,
 {
-	window: &'a Window<T>,
-	index: PeriodType,
-	size: PeriodType,
+	window: &'a Window<T, P>,
+	index: usize,
+	size: usize,
 }
 
-impl<'a, T> ReversedWindowIterator<'a, T>
+impl<'a, T, P> ReversedWindowIterator<'a, T, P>
 where
 	T: Copy,
+	P: Period,
 {
-	pub fn new(window: &'a Window<T>) -> Self {
+	pub fn new(window: &'a Window<T, P>) -> Self {
 		Self {
 			window,
-			index: window.index,
-			size: window.size,
+			index: window.index.to_usize(),
+			size: window.size.to_usize(),
 		}
 	}
 }
 
-impl<'a, T> Iterator for ReversedWindowIterator<'a, T>
+impl<'a, T, P> Iterator for ReversedWindowIterator<'a, T, P>
 where
 	T: Copy,
+	P: Period,
 {
 	type Item = T;
 
@@ -403,26 +559,25 @@ where
 		}
 
 		let value = if cfg!(feature = "unsafe_performance") {
-			*unsafe { self.window.buf.get_unchecked(self.index as usize) }
+			*unsafe { self.window.buf.get_unchecked(self.index) }
 		} else {
-			self.window.buf[self.index as usize]
+			self.window.buf[self.index]
 		};
 
 		self.size -= 1;
 
-		let not_at_the_end = (self.index != self.window.s_1) as PeriodType;
+		let not_at_the_end = (self.index != self.window.s_1.to_usize()) as usize;
 		self.index = (self.index + 1) * not_at_the_end;
 
 		Some(value)
 	}
 
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let size = self.size as usize;
-		(size, Some(size))
+		(self.size, Some(self.size))
 	}
 
 	fn count(self) -> usize {
-		self.size as usize
+		self.size
 	}
 
 	fn last(self) -> Option<Self::Item> {
@@ -430,20 +585,31 @@ where
 	}
 }
 
-impl<'a, T> ExactSizeIterator for ReversedWindowIterator<'a, T> where T: Copy {}
-impl<'a, T> std::iter::FusedIterator for ReversedWindowIterator<'a, T> where T: Copy {}
+impl<'a, T, P> ExactSizeIterator for ReversedWindowIterator<'a, T, P>
+where
+	T: Copy,
+	P: Period,
+{
+}
+impl<'a, T, P> std::iter::FusedIterator for ReversedWindowIterator<'a, T, P>
+where
+	T: Copy,
+	P: Period,
+{
+}
 
 #[derive(Deserialize)]
 #[cfg(feature = "serde")]
-struct SerializableWindow<T: Copy> {
+struct SerializableWindow<T: Copy, P> {
 	buf: Box<[T]>,
-	index: PeriodType,
+	index: P,
 }
 
 #[cfg(feature = "serde")]
-impl<T> Serialize for Window<T>
+impl<T, P> Serialize for Window<T, P>
 where
 	T: Copy + Serialize,
+	P: Period + Serialize,
 {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
@@ -460,21 +626,22 @@ where
 use serde::de::Error as SerdeError;
 
 #[cfg(feature = "serde")]
-impl<'de, T> Deserialize<'de> for Window<T>
+impl<'de, T, P> Deserialize<'de> for Window<T, P>
 where
 	T: Copy + Deserialize<'de>,
+	P: Period + Deserialize<'de>,
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
-		let w = SerializableWindow::deserialize(deserializer)?;
+		let w = SerializableWindow::<T, P>::deserialize(deserializer)?;
 
 		let buf = w.buf;
 		let index = w.index;
 
-		if buf.len() > PeriodType::MAX as usize - 1 {
-			let max_length = PeriodType::MAX as usize - 1;
+		let max_length = P::MAX.to_usize().saturating_sub(1);
+		if buf.len() > max_length {
 			let error = SerdeError::custom(format!(
 				"Length of window's buffer cannot be more than {}.",
 				max_length
@@ -482,20 +649,26 @@ where
 			return Err(error);
 		}
 
-		if (buf.len() as PeriodType) <= index {
+		// empty window always has zero index
+		if buf.is_empty() && index.to_usize() == 0 {
+			return Ok(Self::default());
+		}
+
+		if buf.len() <= index.to_usize() {
 			let error =
 				SerdeError::custom(format!("Index {} is out of window's buffer bounds.", index));
 			return Err(error);
 		}
 
-		let size = buf.len() as PeriodType;
-		let s_1 = size - 1;
+		let size = P::from_usize(buf.len());
+		let s_1 = P::from_usize(buf.len() - 1);
 
 		let result = Self {
 			buf,
 			index,
 			size,
 			s_1,
+			steps: 0,
 		};
 
 		Ok(result)
@@ -507,6 +680,61 @@ mod tests {
 	use super::*;
 	use crate::helpers::RandomCandles;
 
+	#[test]
+	fn test_checkpoint() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+
+			for &c in &data {
+				let checkpoint = w.save();
+				let before: Vec<_> = w.iter().collect();
+
+				w.push(data[0]);
+				w.restore(&checkpoint);
+
+				assert!(w.iter().eq(before.iter().copied()));
+
+				w.push(c);
+				assert_eq!(c, w.newest());
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "more than one push")]
+	fn test_checkpoint_two_steps() {
+		let mut w = Window::new(3, 1);
+		let checkpoint = w.save();
+
+		w.push(2);
+		w.push(3);
+		w.restore(&checkpoint);
+	}
+
+	#[test]
+	fn test_period_types() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+			let mut w_u32: Window<_, u32> = Window::with_size(length as u32, data[0]);
+
+			for &c in &data {
+				assert_eq!(w.push(c), w_u32.push(c));
+				assert!(w.iter().eq(w_u32.iter()));
+				assert_eq!(w[length - 1], w_u32[length as u32 - 1]);
+			}
+		}
+
+		let mut w: Window<_, u32> = Window::with_size(1000, 0);
+		for i in 0..2000_u32 {
+			assert_eq!(w.push(i), i.saturating_sub(1000));
+		}
+		assert_eq!(w.len(), 1000);
+	}
+
 	#[test]
 	fn test_push() {
 		let data: Vec<_> = RandomCandles::new().take(300).collect();
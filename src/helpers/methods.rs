@@ -1,29 +1,180 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, ValueType};
+use crate::core::{Checkpoint, Error, Method, PeriodType, ValueType};
 use crate::methods::{
-	Derivative, Highest, HighestLowestDelta, Integral, LinReg, Lowest, MeanAbsDev, MedianAbsDev,
-	Momentum, Past, RateOfChange, StDev, Vidya, CCI, DEMA, DMA, EMA, HMA, RMA, SMA, SMM, SWMA,
-	TEMA, TMA, TRIMA, WMA, WSMA,
+	Constant, Derivative, Highest, HighestLowestDelta, Identity, Integral, LinReg, Lowest,
+	MeanAbsDev, MedianAbsDev, Momentum, Past, RateOfChange, StDev, Vidya, CCI, DEMA, DMA, EMA, HMA,
+	RMA, SMA, SMM, SWMA, TEMA, TMA, TRIMA, WMA, WSMA,
 };
 
 use std::convert::TryFrom;
 use std::str::FromStr;
-/// A shortcut for dynamically (runtime) generated regular methods
+macro_rules! regular_method {
+	($($(#[$attr:meta])* $variant:ident: $method:ty),* $(,)?) => {
+		/// A shortcut for dynamically (runtime) generated regular methods
+		///
+		/// Regular method is a method which has parameters of single [`PeriodType`], input is single [`ValueType`] and output is single [`ValueType`].
+		///
+		/// `RegularMethod` holds any of the [`RegularMethods`] inline, so it may be cloned, serialized and checkpointed
+		/// just like any other method. Usually it is created by [`method`] or by [`MA::init`].
+		///
+		/// # See also
+		///
+		/// [Default regular methods list](RegularMethods)
+		///
+		/// [`ValueType`]: crate::core::ValueType
+		/// [`PeriodType`]: crate::core::PeriodType
+		#[derive(Debug, Clone)]
+		#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+		#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+		#[allow(missing_docs)]
+		pub enum RegularMethod {
+			$($(#[$attr])* $variant($method),)*
+		}
+
+		/// [`RegularMethod`]'s checkpoint, created by [`Checkpoint::save`]
+		#[derive(Debug, Clone, Copy)]
+		#[allow(missing_docs)]
+		pub enum RegularMethodCheckpoint {
+			$($variant(<$method as Checkpoint>::State),)*
+		}
+
+		impl Method<'_> for RegularMethod {
+			type Params = MA;
+			type Input = ValueType;
+			type Output = ValueType;
+
+			fn new(parameters: Self::Params, initial_value: Self::Input) -> Result<Self, Error> {
+				parameters.init(initial_value)
+			}
+
+			#[inline]
+			fn next(&mut self, value: Self::Input) -> Self::Output {
+				match self {
+					$(Self::$variant(method) => method.next(value),)*
+				}
+			}
+
+			fn name(&self) -> &str {
+				match self {
+					$(Self::$variant(method) => method.name(),)*
+				}
+			}
+
+			fn heap_size(&self) -> usize {
+				match self {
+					$(Self::$variant(method) => method.heap_size(),)*
+				}
+			}
+		}
+
+		impl Checkpoint for RegularMethod {
+			type State = RegularMethodCheckpoint;
+
+			#[inline]
+			fn save(&self) -> Self::State {
+				match self {
+					$(Self::$variant(method) => RegularMethodCheckpoint::$variant(method.save()),)*
+				}
+			}
+
+			/// # Panics
+			///
+			/// Panics if the `state` was saved from another regular method
+			#[inline]
+			fn restore(&mut self, state: &Self::State) {
+				match (self, state) {
+					$((Self::$variant(method), RegularMethodCheckpoint::$variant(state)) => method.restore(state),)*
+					#[allow(unreachable_patterns)]
+					_ => panic!("Checkpoint of another regular method"),
+				}
+			}
+		}
+
+		$(
+			impl From<$method> for RegularMethod {
+				fn from(method: $method) -> Self {
+					Self::$variant(method)
+				}
+			}
+		)*
+	};
+}
+
+regular_method!(
+	SMA: SMA,
+	WMA: WMA,
+	HMA: HMA,
+	RMA: RMA,
+	EMA: EMA,
+	DMA: DMA,
+	DEMA: DEMA,
+	TMA: TMA,
+	TEMA: TEMA,
+	WSMA: WSMA,
+	SMM: SMM,
+	SWMA: SWMA,
+	#[cfg_attr(feature = "serde", serde(rename = "lin_reg"))]
+	LinReg: LinReg,
+	TRIMA: TRIMA,
+	Vidya: Vidya,
+	Past: Past<ValueType>,
+	Derivative: Derivative,
+	Integral: Integral,
+	#[cfg_attr(feature = "serde", serde(rename = "mean_abs_dev"))]
+	MeanAbsDev: MeanAbsDev,
+	#[cfg_attr(feature = "serde", serde(rename = "median_abs_dev"))]
+	MedianAbsDev: MedianAbsDev,
+	#[cfg_attr(feature = "serde", serde(rename = "st_dev"))]
+	StDev: StDev,
+	CCI: CCI,
+	Momentum: Momentum,
+	#[cfg_attr(feature = "serde", serde(rename = "rate_of_change"))]
+	RateOfChange: RateOfChange,
+	Highest: Highest,
+	Lowest: Lowest,
+	#[cfg_attr(feature = "serde", serde(rename = "highest_lowest_delta"))]
+	HighestLowestDelta: HighestLowestDelta,
+	Identity: Identity,
+	Constant: Constant,
+);
+
+/// Heap-allocated regular method trait object
 ///
-/// Regular method is a method which has parameters of single [`PeriodType`], input is single [`ValueType`] and output is single [`ValueType`].
+/// It is the former definition of [`RegularMethod`], which is an enum with [`MA`] parameters now.
+/// Any method with [`PeriodType`](crate::core::PeriodType) parameter, [`ValueType`](crate::core::ValueType) input and output may still be boxed into it.
 ///
-/// # See also
+/// # Examples
 ///
-/// [Default regular methods list](RegularMethods)
+/// ```
+/// # #![allow(deprecated)]
+/// use yata::prelude::*;
+/// use yata::helpers::BoxedRegularMethod;
+/// use yata::methods::SMA;
 ///
-/// [`ValueType`]: crate::core::ValueType
-/// [`PeriodType`]: crate::core::PeriodType
-pub type RegularMethod =
+/// let mut boxed: BoxedRegularMethod = Box::new(SMA::new(2, 1.0).unwrap());
+///
+/// assert_eq!(boxed.next(3.0), 2.0);
+/// ```
+#[deprecated(note = "use `RegularMethod` instead")]
+pub type BoxedRegularMethod =
 	Box<dyn Method<'static, Params = PeriodType, Input = ValueType, Output = ValueType>>;
 
 /// Regular methods dictionary
+///
+/// Parsed from method names, listed at [`method`]. Strings in [`MA`] form (f.e. `ema-20`) are accepted too, but only the method part is taken.
+/// So every `RegularMethods` parameter of indicators accepts the same strings in [`IndicatorConfig::set`](crate::core::IndicatorConfig::set) as `MA` parameters do.
+/// Periods are separate parameters there, except for `MA` parameters (f.e. `ma1` of [`MACD`](crate::indicators::MACD)), which set both.
+///
+/// ```
+/// use yata::helpers::{RegularMethods, MA};
+///
+/// assert_eq!("ema".parse(), Ok(RegularMethods::EMA));
+/// assert_eq!("EMA(20)".parse(), Ok(RegularMethods::EMA));
+/// assert_eq!(RegularMethods::from(MA::new(RegularMethods::HMA, 9)), RegularMethods::HMA);
+/// assert_eq!(MA::from(RegularMethods::WMA), MA::new(RegularMethods::WMA, MA::DEFAULT_PERIOD));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
@@ -126,44 +277,58 @@ pub enum RegularMethods {
 	/// [HighestLowestDelta](crate::methods::HighestLowestDelta)
 	#[cfg_attr(feature = "serde", serde(rename = "highest_lowest_delta"))]
 	HighestLowestDelta,
+
+	/// [Identity](crate::methods::Identity) passes values through without any changes
+	Identity,
+
+	/// [Constant](crate::methods::Constant) always returns the initial value
+	Constant,
+}
+
+impl RegularMethods {
+	fn from_name(name: &str) -> Option<Self> {
+		match name.to_ascii_lowercase().trim() {
+			"sma" => Some(Self::SMA),
+			"wma" => Some(Self::WMA),
+			"hma" => Some(Self::HMA),
+			"rma" => Some(Self::RMA),
+			"ema" => Some(Self::EMA),
+			"dma" => Some(Self::DMA),
+			"dema" => Some(Self::DEMA),
+			"tma" => Some(Self::TMA),
+			"tema" => Some(Self::TEMA),
+			"wsma" => Some(Self::WSMA),
+			"smm" => Some(Self::SMM),
+			"swma" => Some(Self::SWMA),
+			"trima" => Some(Self::TRIMA),
+			"lin_reg" | "linreg" => Some(Self::LinReg),
+			"vidya" => Some(Self::Vidya),
+
+			"past" | "move" => Some(Self::Past),
+			"derivative" => Some(Self::Derivative),
+			"integral" => Some(Self::Integral),
+			"mean_abs_dev" => Some(Self::MeanAbsDev),
+			"median_abs_dev" => Some(Self::MedianAbsDev),
+			"st_dev" | "stdev" => Some(Self::StDev),
+			"cci" => Some(Self::CCI),
+			"momentum" | "change" => Some(Self::Momentum),
+			"rate_of_change" | "rateofchange" | "roc" => Some(Self::RateOfChange),
+			"highest" => Some(Self::Highest),
+			"lowest" => Some(Self::Lowest),
+			"highest_lowest_delta" => Some(Self::HighestLowestDelta),
+			"identity" | "none" => Some(Self::Identity),
+			"constant" | "const" => Some(Self::Constant),
+
+			_ => None,
+		}
+	}
 }
 
 impl FromStr for RegularMethods {
 	type Err = String;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match s.to_ascii_lowercase().trim() {
-			"sma" => Ok(Self::SMA),
-			"wma" => Ok(Self::WMA),
-			"hma" => Ok(Self::HMA),
-			"rma" => Ok(Self::RMA),
-			"ema" => Ok(Self::EMA),
-			"dma" => Ok(Self::DMA),
-			"dema" => Ok(Self::DEMA),
-			"tma" => Ok(Self::TMA),
-			"tema" => Ok(Self::TEMA),
-			"wsma" => Ok(Self::WSMA),
-			"smm" => Ok(Self::SMM),
-			"swma" => Ok(Self::SWMA),
-			"trima" => Ok(Self::TRIMA),
-			"lin_reg" | "linreg" => Ok(Self::LinReg),
-			"vidya" => Ok(Self::Vidya),
-
-			"past" | "move" => Ok(Self::Past),
-			"derivative" => Ok(Self::Derivative),
-			"integral" => Ok(Self::Integral),
-			"mean_abs_dev" => Ok(Self::MeanAbsDev),
-			"median_abs_dev" => Ok(Self::MedianAbsDev),
-			"st_dev" | "stdev" => Ok(Self::StDev),
-			"cci" => Ok(Self::CCI),
-			"momentum" | "change" => Ok(Self::Momentum),
-			"rate_of_change" | "rateofchange" | "roc" => Ok(Self::RateOfChange),
-			"highest" => Ok(Self::Highest),
-			"lowest" => Ok(Self::Lowest),
-			"highest_lowest_delta" => Ok(Self::HighestLowestDelta),
-
-			_ => Err(format!("Unknown regular method name {}", s)),
-		}
+		Self::from_name(s).map_or_else(|| s.parse::<MA>().map(Self::from), Ok)
 	}
 }
 
@@ -183,7 +348,163 @@ impl TryFrom<String> for RegularMethods {
 	}
 }
 
-/// Returns a heap-allocated [`RegularMethod`] for timeseries by given `name` and window `length`.
+/// Regular method together with its period
+///
+/// Parsed from strings like `ema-20`, `EMA(20)`, `ema:20` or `ema 20`. Method names are the same as for [`RegularMethods`]
+/// and are case-insensitive. A bare method name (f.e. `ema`) gets [`MA::DEFAULT_PERIOD`]. Parentheses must be balanced.
+///
+/// Indicators with pairs of `method` and `period` parameters accept `MA` strings in [`IndicatorConfig::set`](crate::core::IndicatorConfig::set)
+/// (f.e. `ma1` of [`MACD`](crate::indicators::MACD)). Any other [`RegularMethods`] parameter accepts `MA` strings too, but takes only the method part.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{RegularMethods, MA};
+///
+/// let ma: MA = "EMA(20)".parse().unwrap();
+/// assert_eq!(ma, MA::new(RegularMethods::EMA, 20));
+///
+/// let ma: MA = "wma".parse().unwrap();
+/// assert_eq!(ma, MA::new(RegularMethods::WMA, MA::DEFAULT_PERIOD));
+///
+/// assert!("ema-x".parse::<MA>().is_err());
+/// assert!("ema(20".parse::<MA>().is_err());
+///
+/// let mut sma = "sma-3".parse::<MA>().unwrap().init(1.0).unwrap();
+/// sma.next(2.0);
+/// assert_eq!(sma.next(3.0), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MA {
+	/// Regular method
+	pub method: RegularMethods,
+
+	/// Period of the method
+	pub period: PeriodType,
+}
+
+impl MA {
+	/// Period, which is used when only the method name is provided
+	pub const DEFAULT_PERIOD: PeriodType = 14;
+
+	/// Creates a new `MA` of `method` with `period`
+	#[must_use]
+	pub const fn new(method: RegularMethods, period: PeriodType) -> Self {
+		Self { method, period }
+	}
+
+	/// Creates a new [`RegularMethod`] with `initial_value`
+	///
+	/// See also [`method`].
+	pub fn init(self, initial_value: ValueType) -> Result<RegularMethod, Error> {
+		method(self.method, self.period, initial_value)
+	}
+
+	/// Parses `MA` from JSON
+	///
+	/// Accepts either a string in any format, supported by [`FromStr`], or an object with `method` and optional `period` fields.
+	///
+	/// Requires `json` feature.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::helpers::{RegularMethods, MA};
+	///
+	/// let ma = MA::from_json(r#"{"method": "Ema", "period": 20}"#).unwrap();
+	/// assert_eq!(ma, MA::new(RegularMethods::EMA, 20));
+	///
+	/// let ma = MA::from_json(r#"{"method": "ema"}"#).unwrap();
+	/// assert_eq!(ma.period, MA::DEFAULT_PERIOD);
+	///
+	/// let ma = MA::from_json(r#""hma-9""#).unwrap();
+	/// assert_eq!(ma, MA::new(RegularMethods::HMA, 9));
+	///
+	/// assert!(MA::from_json(r#"{"period": 20}"#).is_err());
+	/// ```
+	#[cfg(feature = "json")]
+	pub fn from_json(json: &str) -> Result<Self, Error> {
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum Json {
+			Str(String),
+			Object {
+				method: String,
+				period: Option<PeriodType>,
+			},
+		}
+
+		let parsed = serde_json::from_str(json)
+			.map_err(|error| Error::wrap("Unable to parse MA from JSON", error))?;
+
+		match parsed {
+			Json::Str(value) => value
+				.parse()
+				.map_err(|error| Error::parameter_parse("MA", &value, error)),
+			Json::Object { method, period } => Ok(Self {
+				method: method
+					.parse()
+					.map_err(|error| Error::parameter_parse("method", &method, error))?,
+				period: period.unwrap_or(Self::DEFAULT_PERIOD),
+			}),
+		}
+	}
+}
+
+impl FromStr for MA {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+
+		let (name, period) = match s.split_once(&['-', '(', ':', ' '][..]) {
+			Some((name, period)) if s[name.len()..].starts_with('(') => {
+				let period = period
+					.strip_suffix(')')
+					.ok_or_else(|| format!("Unbalanced parentheses in {s}"))?;
+				(name, Some(period.trim()))
+			}
+			Some((name, period)) => (name, Some(period.trim())),
+			None => (s, None),
+		};
+
+		let period = match period {
+			Some(period) => period
+				.parse()
+				.map_err(|_| format!("Wrong period {period} in {s}"))?,
+			None => Self::DEFAULT_PERIOD,
+		};
+
+		let method = RegularMethods::from_name(name)
+			.ok_or_else(|| format!("Unknown regular method name {name}"))?;
+
+		Ok(Self { method, period })
+	}
+}
+
+impl From<MA> for RegularMethods {
+	fn from(ma: MA) -> Self {
+		ma.method
+	}
+}
+
+impl From<RegularMethods> for MA {
+	fn from(method: RegularMethods) -> Self {
+		Self::new(method, Self::DEFAULT_PERIOD)
+	}
+}
+
+impl TryFrom<&str> for MA {
+	type Error = String;
+
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		Self::from_str(s)
+	}
+}
+
+/// Returns a [`RegularMethod`] for timeseries by given `name` and window `length`.
 /// These methods are always gets an input value of type f64 and the same output value type
 ///
 /// Available methods:
@@ -211,10 +532,13 @@ impl TryFrom<String> for RegularMethods {
 /// * `momentum`, `change` - [absolute change of values](Momentum)
 /// * `rate_of_change` - [relative change of values](RateOfChange)
 /// * [`highest`](Highest), [`lowest`](Lowest), [`highest_lowest_delta`](HighestLowestDelta)
+/// * `identity`, `none` - [passes values through](Identity)
+/// * `constant`, `const` - [always returns the initial value](Constant)
 ///
 /// # Examples
 ///
 /// ```
+/// use yata::prelude::*;
 /// use yata::helpers::{method, RegularMethods};
 ///
 /// let mut m = method(RegularMethods::SMA, 3, 1.0).unwrap();
@@ -236,41 +560,114 @@ pub fn method(
 	initial_value: ValueType,
 ) -> Result<RegularMethod, Error> {
 	match method {
-		RegularMethods::SMA => Ok(Box::new(SMA::new(length, initial_value)?)),
-		RegularMethods::WMA => Ok(Box::new(WMA::new(length, initial_value)?)),
-		RegularMethods::HMA => Ok(Box::new(HMA::new(length, initial_value)?)),
-		RegularMethods::RMA => Ok(Box::new(RMA::new(length, initial_value)?)),
-		RegularMethods::EMA => Ok(Box::new(EMA::new(length, initial_value)?)),
-		RegularMethods::DMA => Ok(Box::new(DMA::new(length, initial_value)?)),
-		RegularMethods::DEMA => Ok(Box::new(DEMA::new(length, initial_value)?)),
-		RegularMethods::TMA => Ok(Box::new(TMA::new(length, initial_value)?)),
-		RegularMethods::TEMA => Ok(Box::new(TEMA::new(length, initial_value)?)),
-		RegularMethods::WSMA => Ok(Box::new(WSMA::new(length, initial_value)?)),
-		RegularMethods::SMM => Ok(Box::new(SMM::new(length, initial_value)?)),
-		RegularMethods::SWMA => Ok(Box::new(SWMA::new(length, initial_value)?)),
-		RegularMethods::LinReg => Ok(Box::new(LinReg::new(length, initial_value)?)),
-		RegularMethods::TRIMA => Ok(Box::new(TRIMA::new(length, initial_value)?)),
-		RegularMethods::Vidya => Ok(Box::new(Vidya::new(length, initial_value)?)),
+		RegularMethods::SMA => Ok(RegularMethod::from(SMA::new(length, initial_value)?)),
+		RegularMethods::WMA => Ok(RegularMethod::from(WMA::new(length, initial_value)?)),
+		RegularMethods::HMA => Ok(RegularMethod::from(HMA::new(length, initial_value)?)),
+		RegularMethods::RMA => Ok(RegularMethod::from(RMA::new(length, initial_value)?)),
+		RegularMethods::EMA => Ok(RegularMethod::from(EMA::new(length, initial_value)?)),
+		RegularMethods::DMA => Ok(RegularMethod::from(DMA::new(length, initial_value)?)),
+		RegularMethods::DEMA => Ok(RegularMethod::from(DEMA::new(length, initial_value)?)),
+		RegularMethods::TMA => Ok(RegularMethod::from(TMA::new(length, initial_value)?)),
+		RegularMethods::TEMA => Ok(RegularMethod::from(TEMA::new(length, initial_value)?)),
+		RegularMethods::WSMA => Ok(RegularMethod::from(WSMA::new(length, initial_value)?)),
+		RegularMethods::SMM => Ok(RegularMethod::from(SMM::new(length, initial_value)?)),
+		RegularMethods::SWMA => Ok(RegularMethod::from(SWMA::new(length, initial_value)?)),
+		RegularMethods::LinReg => Ok(RegularMethod::from(LinReg::new(length, initial_value)?)),
+		RegularMethods::TRIMA => Ok(RegularMethod::from(TRIMA::new(length, initial_value)?)),
+		RegularMethods::Vidya => Ok(RegularMethod::from(Vidya::new(length, initial_value)?)),
 
 		RegularMethods::Past | RegularMethods::Move => {
-			Ok(Box::new(Past::new(length, initial_value)?))
+			Ok(RegularMethod::from(Past::new(length, initial_value)?))
+		}
+		RegularMethods::Derivative => {
+			Ok(RegularMethod::from(Derivative::new(length, initial_value)?))
 		}
-		RegularMethods::Derivative => Ok(Box::new(Derivative::new(length, initial_value)?)),
-		RegularMethods::Integral => Ok(Box::new(Integral::new(length, initial_value)?)),
-		RegularMethods::MeanAbsDev => Ok(Box::new(MeanAbsDev::new(length, initial_value)?)),
-		RegularMethods::MedianAbsDev => Ok(Box::new(MedianAbsDev::new(length, initial_value)?)),
-		RegularMethods::StDev => Ok(Box::new(StDev::new(length, initial_value)?)),
-		RegularMethods::CCI => Ok(Box::new(CCI::new(length, initial_value)?)),
+		RegularMethods::Integral => Ok(RegularMethod::from(Integral::new(length, initial_value)?)),
+		RegularMethods::MeanAbsDev => {
+			Ok(RegularMethod::from(MeanAbsDev::new(length, initial_value)?))
+		}
+		RegularMethods::MedianAbsDev => Ok(RegularMethod::from(MedianAbsDev::new(
+			length,
+			initial_value,
+		)?)),
+		RegularMethods::StDev => Ok(RegularMethod::from(StDev::new(length, initial_value)?)),
+		RegularMethods::CCI => Ok(RegularMethod::from(CCI::new(length, initial_value)?)),
 		RegularMethods::Momentum | RegularMethods::Change => {
-			Ok(Box::new(Momentum::new(length, initial_value)?))
+			Ok(RegularMethod::from(Momentum::new(length, initial_value)?))
 		}
-		RegularMethods::RateOfChange | RegularMethods::ROC => {
-			Ok(Box::new(RateOfChange::new(length, initial_value)?))
+		RegularMethods::RateOfChange | RegularMethods::ROC => Ok(RegularMethod::from(
+			RateOfChange::new(length, initial_value)?,
+		)),
+		RegularMethods::Highest => Ok(RegularMethod::from(Highest::new(length, initial_value)?)),
+		RegularMethods::Lowest => Ok(RegularMethod::from(Lowest::new(length, initial_value)?)),
+		RegularMethods::HighestLowestDelta => Ok(RegularMethod::from(HighestLowestDelta::new(
+			length,
+			initial_value,
+		)?)),
+		RegularMethods::Identity => Ok(RegularMethod::from(Identity::new(length, initial_value)?)),
+		RegularMethods::Constant => Ok(RegularMethod::from(Constant::new(length, initial_value)?)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{RegularMethods, MA};
+
+	#[test]
+	fn test_ma_from_str() {
+		let values = [
+			("ema-20", RegularMethods::EMA, 20),
+			("EMA(20)", RegularMethods::EMA, 20),
+			("Ema( 20 )", RegularMethods::EMA, 20),
+			("ema:20", RegularMethods::EMA, 20),
+			("ema 20", RegularMethods::EMA, 20),
+			(" wma-3 ", RegularMethods::WMA, 3),
+			("lin_reg(9)", RegularMethods::LinReg, 9),
+			("hma", RegularMethods::HMA, MA::DEFAULT_PERIOD),
+		];
+
+		for &(s, method, period) in &values {
+			assert_eq!(s.parse::<MA>(), Ok(MA::new(method, period)), "{s}");
 		}
-		RegularMethods::Highest => Ok(Box::new(Highest::new(length, initial_value)?)),
-		RegularMethods::Lowest => Ok(Box::new(Lowest::new(length, initial_value)?)),
-		RegularMethods::HighestLowestDelta => {
-			Ok(Box::new(HighestLowestDelta::new(length, initial_value)?))
+	}
+
+	#[test]
+	fn test_ma_from_str_errors() {
+		let values = [
+			"",
+			"ema-",
+			"ema-x",
+			"ema--20",
+			"ema(20",
+			"ema 20)",
+			"ema-20)",
+			"ema(20))",
+			"ema)",
+			"unknown-20",
+			"ema-1.5",
+		];
+
+		for s in &values {
+			assert!(s.parse::<MA>().is_err(), "{}", s);
+		}
+	}
+
+	#[test]
+	fn test_regular_methods_from_str() {
+		let values = [
+			("ema", RegularMethods::EMA),
+			(" Lin_Reg ", RegularMethods::LinReg),
+			("ema-20", RegularMethods::EMA),
+			("HMA(9)", RegularMethods::HMA),
+			("wma 3", RegularMethods::WMA),
+		];
+
+		for &(s, method) in &values {
+			assert_eq!(s.parse::<RegularMethods>(), Ok(method), "{s}");
+		}
+
+		for s in &["", "unknown", "unknown-20", "ema(20", "ema-x"] {
+			assert!(s.parse::<RegularMethods>().is_err(), "{}", s);
 		}
 	}
 }
@@ -3,6 +3,10 @@
 //!
 
 mod methods;
+#[cfg(any(test, feature = "testing"))]
+pub mod naive;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 use crate::core::{Candle, ValueType};
 pub use methods::*;
 
@@ -0,0 +1,244 @@
+//! Naive reference implementations of the [`methods`](crate::methods)
+//!
+//! Available with `testing` feature. Every function fully recomputes the method's output for every item of `values`
+//! without any rolling state, so it costs O(n·k), but it is simple enough to be obviously correct.
+//! It is used for differential testing of the streaming implementations:
+//!
+//! ```
+//! use yata::helpers::naive;
+//! use yata::methods::SMA;
+//! use yata::prelude::*;
+//!
+//! let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+//! let mut sma = SMA::new(3, values[0]).unwrap();
+//!
+//! for (output, expected) in sma.over(&values).into_iter().zip(naive::sma(&values, 3)) {
+//!     assert!((output - expected).abs() < 1e-6);
+//! }
+//! ```
+//!
+//! Every streaming method is initialized by the first value, so every window here is padded by `values[0]` at the start.
+
+use crate::core::{PeriodType, ValueType};
+
+/// Returns the last `length` values up to the `index` (from the oldest to the newest) padded by the first value
+fn window(values: &[ValueType], index: usize, length: PeriodType) -> Vec<ValueType> {
+	(0..length as usize)
+		.rev()
+		.map(|back| values[index.saturating_sub(back)])
+		.collect()
+}
+
+/// Returns the value `length` items back from the `index` padded by the first value
+fn past(values: &[ValueType], index: usize, length: PeriodType) -> ValueType {
+	values[index.saturating_sub(length as usize)]
+}
+
+fn each<F>(values: &[ValueType], length: PeriodType, f: F) -> Vec<ValueType>
+where
+	F: Fn(&[ValueType]) -> ValueType,
+{
+	(0..values.len())
+		.map(|index| f(&window(values, index, length)))
+		.collect()
+}
+
+fn mean(window: &[ValueType]) -> ValueType {
+	window.iter().sum::<ValueType>() / window.len() as ValueType
+}
+
+fn median(window: &[ValueType]) -> ValueType {
+	let mut sorted = window.to_vec();
+	sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+	let half = sorted.len() / 2;
+
+	if half * 2 == sorted.len() {
+		sorted[half - 1..=half].iter().sum::<ValueType>() / 2.
+	} else {
+		sorted[half]
+	}
+}
+
+fn weighted(window: &[ValueType], weights: &[ValueType]) -> ValueType {
+	let sum: ValueType = weights.iter().sum();
+
+	window
+		.iter()
+		.zip(weights)
+		.map(|(x, w)| x * w)
+		.sum::<ValueType>()
+		/ sum
+}
+
+/// Exponentially smoothed value over the whole history with smoothing factor `alpha`
+fn exponential(values: &[ValueType], alpha: ValueType) -> Vec<ValueType> {
+	let decay = 1. - alpha;
+
+	(0..values.len())
+		.map(|index| {
+			let (sum, k) = values[..=index]
+				.iter()
+				.rev()
+				.fold((0., 1.), |(sum, k), &x| {
+					((alpha * k).mul_add(x, sum), k * decay)
+				});
+
+			// the initial value of the method is the first value
+			k.mul_add(values[0], sum)
+		})
+		.collect()
+}
+
+/// Naive [`SMA`](crate::methods::SMA)
+#[must_use]
+pub fn sma(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, mean)
+}
+
+/// Naive [`WMA`](crate::methods::WMA)
+#[must_use]
+pub fn wma(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	let weights: Vec<ValueType> = (1..=length).map(|w| w as ValueType).collect();
+
+	each(values, length, |window| weighted(window, &weights))
+}
+
+/// Naive [`SWMA`](crate::methods::SWMA)
+#[must_use]
+pub fn swma(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	let weights: Vec<ValueType> = (1..=length - length / 2)
+		.chain((1..=length / 2).rev())
+		.map(|w| w as ValueType)
+		.collect();
+
+	each(values, length, |window| weighted(window, &weights))
+}
+
+/// Naive [`TRIMA`](crate::methods::TRIMA)
+#[must_use]
+pub fn trima(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	sma(&sma(values, length), length)
+}
+
+/// Naive [`EMA`](crate::methods::EMA)
+#[must_use]
+pub fn ema(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	exponential(values, 2. / (length as ValueType + 1.))
+}
+
+/// Naive [`RMA`](crate::methods::RMA)
+#[must_use]
+pub fn rma(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	exponential(values, (length as ValueType).recip())
+}
+
+/// Naive [`SMM`](crate::methods::SMM)
+#[must_use]
+pub fn smm(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, median)
+}
+
+/// Naive [`LinReg`](crate::methods::LinReg)
+#[must_use]
+pub fn lin_reg(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, |window| {
+		let n = window.len() as ValueType;
+		let x_mean = (n - 1.) / 2.;
+		let y_mean = mean(window);
+
+		let (cov, var) = window
+			.iter()
+			.enumerate()
+			.fold((0., 0.), |(cov, var), (x, y)| {
+				let dx = x as ValueType - x_mean;
+				(dx.mul_add(y - y_mean, cov), dx.mul_add(dx, var))
+			});
+
+		(cov / var).mul_add(n - 1. - x_mean, y_mean)
+	})
+}
+
+/// Naive [`StDev`](crate::methods::StDev)
+#[must_use]
+pub fn st_dev(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, |window| {
+		let mean = mean(window);
+		let sum: ValueType = window.iter().map(|x| (x - mean).powi(2)).sum();
+
+		(sum / (window.len() - 1) as ValueType).sqrt()
+	})
+}
+
+/// Naive [`MeanAbsDev`](crate::methods::MeanAbsDev)
+#[must_use]
+pub fn mean_abs_dev(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, |window| {
+		let mean = mean(window);
+
+		window.iter().map(|x| (x - mean).abs()).sum::<ValueType>() / window.len() as ValueType
+	})
+}
+
+/// Naive [`Highest`](crate::methods::Highest)
+#[must_use]
+pub fn highest(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, |window| {
+		window
+			.iter()
+			.copied()
+			.fold(ValueType::NEG_INFINITY, ValueType::max)
+	})
+}
+
+/// Naive [`Lowest`](crate::methods::Lowest)
+#[must_use]
+pub fn lowest(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	each(values, length, |window| {
+		window
+			.iter()
+			.copied()
+			.fold(ValueType::INFINITY, ValueType::min)
+	})
+}
+
+/// Naive [`Integral`](crate::methods::Integral)
+///
+/// When `length` is `0`, returns cumulative sum of all the values.
+#[must_use]
+pub fn integral(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	if length == 0 {
+		return (0..values.len())
+			.map(|index| values[..=index].iter().sum())
+			.collect();
+	}
+
+	each(values, length, |window| window.iter().sum())
+}
+
+/// Naive [`Momentum`](crate::methods::Momentum)
+#[must_use]
+pub fn momentum(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	(0..values.len())
+		.map(|index| values[index] - past(values, index, length))
+		.collect()
+}
+
+/// Naive [`RateOfChange`](crate::methods::RateOfChange)
+#[must_use]
+pub fn rate_of_change(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	(0..values.len())
+		.map(|index| {
+			let past = past(values, index, length);
+			(values[index] - past) / past
+		})
+		.collect()
+}
+
+/// Naive [`Derivative`](crate::methods::Derivative)
+#[must_use]
+pub fn derivative(values: &[ValueType], length: PeriodType) -> Vec<ValueType> {
+	(0..values.len())
+		.map(|index| (values[index] - past(values, index, length)) / length as ValueType)
+		.collect()
+}
@@ -0,0 +1,158 @@
+//! Property based testing harness for [`Method`]s
+//!
+//! Available with `testing` feature. It is built on top of [`proptest`], so every check returns
+//! [`TestCaseError`] and may be used right inside `proptest!` blocks. It is also useful for testing your own custom methods.
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use yata::helpers::testing::{check_constant, check_finite, check_serde_round_trip, finite_values};
+//! use yata::methods::SMA;
+//! use yata::prelude::*;
+//!
+//! proptest! {
+//!     fn sma_invariants(length in 1..50u8, values in finite_values(1..300)) {
+//!         check_finite(SMA::new(length.into(), values[0]).unwrap(), &values)?;
+//!         check_constant::<SMA>(length.into(), values[0], 100)?;
+//!         check_serde_round_trip(SMA::new(length.into(), values[0]).unwrap(), &values)?;
+//!     }
+//! }
+//! # sma_invariants();
+//! ```
+
+use crate::core::{Method, ValueType};
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+	4e-3
+} else {
+	1e-10
+};
+
+/// Returns a strategy which generates vectors of finite positive values in range \[`0.1`; `1000.0`\)
+///
+/// Values are positive, so the methods which divide by the input values (f.e. relative momentum) stay finite.
+pub fn finite_values(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<ValueType>> {
+	let min: ValueType = 0.1;
+
+	vec(min..1000.0, size)
+}
+
+/// Checks that `method` produces only finite output values for finite `inputs`
+pub fn check_finite<'a, M>(mut method: M, inputs: &[M::Input]) -> Result<(), TestCaseError>
+where
+	M: Method<'a, Output = ValueType>,
+	M::Input: Copy,
+{
+	for (i, &input) in inputs.iter().enumerate() {
+		let output = method.next(input);
+
+		prop_assert!(
+			output.is_finite(),
+			"{}: output #{} is not finite: {}",
+			method.name(),
+			i,
+			output
+		);
+	}
+
+	Ok(())
+}
+
+/// Checks that the method, created with `parameters` over the constant `value`, produces constant output for `steps` steps
+pub fn check_constant<'a, M>(
+	parameters: M::Params,
+	value: M::Input,
+	steps: usize,
+) -> Result<(), TestCaseError>
+where
+	M: Method<'a, Output = ValueType>,
+	M::Input: Copy,
+{
+	let mut method = M::new(parameters, value).map_err(|e| TestCaseError::fail(e.to_string()))?;
+	let expected = method.next(value);
+
+	for i in 0..steps {
+		let output = method.next(value);
+		let diff = (expected - output).abs();
+
+		prop_assert!(
+			diff <= SIGMA || diff <= SIGMA * expected.abs().max(output.abs()),
+			"{}: output #{} differs for constant input: expected={}, output={}",
+			method.name(),
+			i,
+			expected,
+			output
+		);
+	}
+
+	Ok(())
+}
+
+/// Checks that `method` produces the same output values over `inputs` as the `expected` ones
+///
+/// Usually `expected` values are calculated by [`naive`](super::naive) reference implementations.
+/// Streaming implementations accumulate rounding errors, so values are compared with `sigma` tolerance,
+/// relative to the magnitude of the values (but not less than `sigma` itself).
+pub fn check_reference<'a, M>(
+	mut method: M,
+	inputs: &[M::Input],
+	expected: &[ValueType],
+	sigma: ValueType,
+) -> Result<(), TestCaseError>
+where
+	M: Method<'a, Output = ValueType>,
+	M::Input: Copy,
+{
+	prop_assert_eq!(inputs.len(), expected.len());
+
+	for (i, (&input, &expected)) in inputs.iter().zip(expected).enumerate() {
+		let output = method.next(input);
+		let diff = (expected - output).abs();
+
+		prop_assert!(
+			diff <= sigma * expected.abs().max(output.abs()).max(1.),
+			"{}: output #{} differs from the reference: expected={}, output={}",
+			method.name(),
+			i,
+			expected,
+			output
+		);
+	}
+
+	Ok(())
+}
+
+/// Checks that serialized and then deserialized state of the `method` behaves exactly the same as the in-memory state
+///
+/// The first half of `inputs` is fed into the `method` before serialization, the second half is fed into both states.
+#[cfg(feature = "serde")]
+pub fn check_serde_round_trip<'a, M>(
+	mut method: M,
+	inputs: &[M::Input],
+) -> Result<(), TestCaseError>
+where
+	M: Method<'a> + Serialize + DeserializeOwned,
+	M::Input: Copy,
+	M::Output: PartialEq + std::fmt::Debug,
+{
+	let (head, tail) = inputs.split_at(inputs.len() / 2);
+
+	for &input in head {
+		method.next(input);
+	}
+
+	let bytes = bincode::serialize(&method).map_err(|e| TestCaseError::fail(e.to_string()))?;
+	let mut restored: M =
+		bincode::deserialize(&bytes).map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+	for &input in tail {
+		prop_assert_eq!(method.next(input), restored.next(input));
+	}
+
+	Ok(())
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::methods::{Cross, HighestIndex, LowestIndex};
 
 // https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/aroon-indicator
@@ -58,6 +59,11 @@ impl IndicatorConfig for Aroon {
 
 	const NAME: &'static str = "Aroon";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Bounded(0.0, 1.0), ValueRange::Bounded(0.0, 1.0)],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -87,15 +93,15 @@ impl IndicatorConfig for Aroon {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"signal_zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.signal_zone = value,
 			},
 			"over_zone_period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.over_zone_period = value,
 			},
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 
@@ -124,6 +130,7 @@ impl Default for Aroon {
 
 /// Aroon state structure
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AroonInstance {
 	cfg: Aroon,
 	lowest_index: LowestIndex,
@@ -142,6 +149,10 @@ impl IndicatorInstance for AroonInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.lowest_index.heap_size() + self.highest_index.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let highest_index = self.highest_index.next(candle.high());
 		let lowest_index = self.lowest_index.next(candle.low());
@@ -172,3 +183,33 @@ impl IndicatorInstance for AroonInstance {
 		)
 	}
 }
+
+impl Checkpoint for AroonInstance {
+	type State = (
+		<LowestIndex as Checkpoint>::State,
+		<HighestIndex as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		isize,
+		isize,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.lowest_index.save(),
+			self.highest_index.save(),
+			self.cross.save(),
+			self.uptrend,
+			self.downtrend,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.lowest_index.restore(&state.0);
+		self.highest_index.restore(&state.1);
+		self.cross.restore(&state.2);
+		self.uptrend = state.3;
+		self.downtrend = state.4;
+	}
+}
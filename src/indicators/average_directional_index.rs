@@ -2,7 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
-use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 
@@ -65,6 +67,15 @@ impl IndicatorConfig for AverageDirectionalIndex {
 
 	const NAME: &'static str = "AverageDirectionalIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Bounded(0.0, 1.0),
+			ValueRange::Bounded(0.0, 1.0),
+			ValueRange::Bounded(0.0, 1.0),
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -99,29 +110,29 @@ impl IndicatorConfig for AverageDirectionalIndex {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"method1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method1 = value,
 			},
 			"di_length" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.di_length = value,
 			},
 
 			"method2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method2 = value,
 			},
 			"adx_smoothing" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.adx_smoothing = value,
 			},
 
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 
@@ -151,7 +162,8 @@ impl Default for AverageDirectionalIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AverageDirectionalIndexInstance {
 	cfg: AverageDirectionalIndex,
 
@@ -202,6 +214,14 @@ impl IndicatorInstance for AverageDirectionalIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+			+ self.tr_ma.heap_size()
+			+ self.plus_di.heap_size()
+			+ self.minus_di.heap_size()
+			+ self.ma2.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (plus, minus) = self.dir_mov(HLC::from(candle));
 		let adx = self.adx(plus, minus);
@@ -214,3 +234,36 @@ impl IndicatorInstance for AverageDirectionalIndexInstance {
 		IndicatorResult::new(&values, &[signal1.into(), signal2.into()])
 	}
 }
+
+impl Checkpoint for AverageDirectionalIndexInstance {
+	type State = (
+		WindowCheckpoint<HLC>,
+		ValueType,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.window.save(),
+			self.prev_close,
+			self.tr_ma.save(),
+			self.plus_di.save(),
+			self.minus_di.save(),
+			self.ma2.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(&state.0);
+		self.prev_close = state.1;
+		self.tr_ma.restore(&state.2);
+		self.plus_di.restore(&state.3);
+		self.minus_di.restore(&state.4);
+		self.ma2.restore(&state.5);
+	}
+}
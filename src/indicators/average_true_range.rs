@@ -0,0 +1,153 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::TR;
+
+/// Average True Range
+///
+/// Moving average of [`TR`].
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Average_true_range>
+///
+/// # 2 values
+///
+/// * `ATR` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `percent ATR` value
+///
+/// `ATR` relative to the *close* price: `ATR` / `close`. It is not multiplied by `100`, so `0.01` means `1%`.
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # Has no signals
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AverageTrueRange {
+	/// Moving average period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Moving average type. Default is [`RMA`](crate::methods::RMA) (Wilder's smoothing).
+	pub method: RegularMethods,
+}
+
+/// Just an alias for [`AverageTrueRange`]
+pub type ATR = AverageTrueRange;
+
+impl IndicatorConfig for AverageTrueRange {
+	type Instance = AverageTrueRangeInstance;
+
+	const NAME: &'static str = "AverageTrueRange";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volatility,
+		&[
+			ValueRange::Bounded(0.0, ValueType::INFINITY),
+			ValueRange::Unbounded,
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			tr: TR::new(candle)?,
+			ma: method(cfg.method, cfg.period, candle.high() - candle.low())?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 0)
+	}
+}
+
+impl Default for AverageTrueRange {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			method: RegularMethods::RMA,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AverageTrueRangeInstance {
+	cfg: AverageTrueRange,
+
+	tr: TR,
+	ma: RegularMethod,
+}
+
+impl IndicatorInstance for AverageTrueRangeInstance {
+	type Config = AverageTrueRange;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size()
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let atr = self.ma.next(self.tr.next(candle));
+
+		IndicatorResult::new(&[atr, atr / candle.close()], &[])
+	}
+}
+
+impl Checkpoint for AverageTrueRangeInstance {
+	type State = (
+		<TR as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.tr.save(), self.ma.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.tr.restore(&state.0);
+		self.ma.restore(&state.1);
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, ReversalSignal};
 
@@ -58,6 +59,9 @@ impl IndicatorConfig for AwesomeOscillator {
 
 	const NAME: &'static str = "AwesomeOscillator";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Momentum, &[ValueRange::Unbounded]);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -91,27 +95,27 @@ impl IndicatorConfig for AwesomeOscillator {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			"left" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.left = value,
 			},
 			"right" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.right = value,
 			},
 
@@ -142,7 +146,8 @@ impl Default for AwesomeOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AwesomeOscillatorInstance {
 	cfg: AwesomeOscillator,
 
@@ -161,6 +166,10 @@ impl IndicatorInstance for AwesomeOscillatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma1.heap_size() + self.ma2.heap_size() + self.reverse.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -188,3 +197,36 @@ impl IndicatorInstance for AwesomeOscillatorInstance {
 		IndicatorResult::new(&values, &signals)
 	}
 }
+
+impl Checkpoint for AwesomeOscillatorInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<ReversalSignal as Checkpoint>::State,
+		u8,
+		u8,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma1.save(),
+			self.ma2.save(),
+			self.cross_over.save(),
+			self.reverse.save(),
+			self.low_peaks,
+			self.high_peaks,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma1.restore(&state.0);
+		self.ma2.restore(&state.1);
+		self.cross_over.restore(&state.2);
+		self.reverse.restore(&state.3);
+		self.low_peaks = state.4;
+		self.high_peaks = state.5;
+	}
+}
@@ -0,0 +1,162 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+
+/// Balance Of Power
+///
+/// Measures the strength of buyers against sellers by the position of the candle's body inside its range:
+/// (`close` - `open`) / (`high` - `low`), smoothed by moving average.
+///
+/// When `high` equals to `low`, the raw value is `0.0`.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:balance_of_power>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// Some smoothing methods (e.g. [`HMA`](crate::methods::HMA)) may slightly overshoot the range.
+///
+/// # 1 signal
+///
+/// * When `main` value crosses zero line upwards, returns full buy signal.
+///   When `main` value crosses zero line downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BalanceOfPower {
+	/// MA period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// MA method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+}
+
+impl IndicatorConfig for BalanceOfPower {
+	type Instance = BalanceOfPowerInstance;
+
+	const NAME: &'static str = "BalanceOfPower";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Bounded(-1.0, 1.0)],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, balance_of_power(candle))?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for BalanceOfPower {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			method: RegularMethods::SMA,
+		}
+	}
+}
+
+#[inline]
+fn balance_of_power<T: OHLCV>(candle: &T) -> ValueType {
+	let range = candle.high() - candle.low();
+
+	if range > 0. {
+		(candle.close() - candle.open()) / range
+	} else {
+		0.
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BalanceOfPowerInstance {
+	cfg: BalanceOfPower,
+
+	ma: RegularMethod,
+	cross: Cross,
+}
+
+impl IndicatorInstance for BalanceOfPowerInstance {
+	type Config = BalanceOfPower;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = self.ma.next(balance_of_power(candle));
+		let signal = self.cross.next((value, 0.));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
+
+impl Checkpoint for BalanceOfPowerInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.ma.save(), self.cross.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma.restore(&state.0);
+		self.cross.restore(&state.1);
+	}
+}
@@ -1,9 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{StDev, SMA};
+use crate::core::{Action, Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Channel, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, Preset, Presets, ValueRange};
+use crate::methods::StDev;
 
 /// Bollinger Bands
 ///
@@ -17,7 +18,10 @@ use crate::methods::{StDev, SMA};
 ///
 /// Range of values is the same as the range of the `source` values.
 ///
-/// * `source` value
+/// * `middle` value (moving average)
+///
+/// Range of values is the same as the range of the `source` values.
+///
 /// * `lower bound`
 ///
 /// Range of values is the same as the range of the `source` values.
@@ -47,6 +51,11 @@ impl IndicatorConfig for BollingerBands {
 
 	const NAME: &'static str = "BollingerBands";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volatility,
+		&[ValueRange::Source, ValueRange::Source, ValueRange::Source],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -55,7 +64,6 @@ impl IndicatorConfig for BollingerBands {
 		let cfg = self;
 		let src = T::source(candle, cfg.source);
 		Ok(Self::Instance {
-			ma: SMA::new(cfg.avg_size, src)?,
 			st_dev: StDev::new(cfg.avg_size, src)?,
 			cfg,
 		})
@@ -68,15 +76,15 @@ impl IndicatorConfig for BollingerBands {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"avg_size" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.avg_size = value,
 			},
 			"sigma" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sigma = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -93,6 +101,36 @@ impl IndicatorConfig for BollingerBands {
 	}
 }
 
+impl Channel for BollingerBands {
+	fn upper(result: &IndicatorResult) -> ValueType {
+		result.value(0)
+	}
+
+	fn lower(result: &IndicatorResult) -> ValueType {
+		result.value(2)
+	}
+
+	fn middle(result: &IndicatorResult) -> ValueType {
+		result.value(1)
+	}
+}
+
+impl Presets for BollingerBands {
+	const PRESETS: &'static [Preset<Self>] = &[
+		Preset::new("classic", "20 periods, 2.0 sigma", Self::default),
+		Preset::new("short_term", "10 periods, 1.5 sigma", || Self {
+			avg_size: 10,
+			sigma: 1.5,
+			..Self::default()
+		}),
+		Preset::new("long_term", "50 periods, 2.5 sigma", || Self {
+			avg_size: 50,
+			sigma: 2.5,
+			..Self::default()
+		}),
+	];
+}
+
 impl Default for BollingerBands {
 	fn default() -> Self {
 		Self {
@@ -104,10 +142,10 @@ impl Default for BollingerBands {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BollingerBandsInstance {
 	cfg: BollingerBands,
 
-	ma: SMA,
 	st_dev: StDev,
 }
 
@@ -119,10 +157,14 @@ impl IndicatorInstance for BollingerBandsInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.st_dev.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
-		let middle = self.ma.next(source);
 		let sq_error = self.st_dev.next(source);
+		let middle = self.st_dev.get_mean();
 
 		let upper = sq_error.mul_add(self.cfg.sigma, middle);
 		let lower = middle - sq_error * self.cfg.sigma;
@@ -140,3 +182,17 @@ impl IndicatorInstance for BollingerBandsInstance {
 		IndicatorResult::new(&values, &signals)
 	}
 }
+
+impl Checkpoint for BollingerBandsInstance {
+	type State = <StDev as Checkpoint>::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.st_dev.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.st_dev.restore(state);
+	}
+}
@@ -1,7 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{Cross, ADI};
 
@@ -17,11 +19,17 @@ use crate::methods::{Cross, ADI};
 ///
 /// Range in \[`-1.0`; `1.0`\]
 ///
-/// # 1 signal
+/// # 2 signals
 ///
-/// When `main` value goes above zero, then returns full buy signal.
-/// When `main` value goes below zero, then returns full sell signal.
-/// Otherwise no signal
+/// * Signal 1 appears when `main` value crosses zero line.
+///   When `main` value goes above zero, then returns full buy signal.
+///   When `main` value goes below zero, then returns full sell signal.
+///   Otherwise no signal
+///
+/// * Signal 2 appears when `main` value holds on the same side of zero line for `hold` candles in a row.
+///   When `main` value stays above zero for `hold` candles, then returns full buy signal.
+///   When `main` value stays below zero for `hold` candles, then returns full sell signal.
+///   Otherwise no signal. The signal appears only once per each such series of candles.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChaikinMoneyFlow {
@@ -29,6 +37,11 @@ pub struct ChaikinMoneyFlow {
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub size: PeriodType,
+
+	/// Count of candles `main` value should hold above or below zero line for signal 2. Default is `3`
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub hold: PeriodType,
 }
 
 impl IndicatorConfig for ChaikinMoneyFlow {
@@ -36,31 +49,43 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 
 	const NAME: &'static str = "ChaikinMoneyFlow";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Volume, &[ValueRange::Bounded(-1.0, 1.0)])
+			.with_volume();
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
+		Self::METADATA.check(candle)?;
+
 		let cfg = self;
 		Ok(Self::Instance {
 			adi: ADI::new(cfg.size, candle)?,
 			vol_sum: candle.volume() * cfg.size as ValueType,
 			window: Window::new(cfg.size, candle.volume()),
 			cross_over: Cross::default(),
+			above: 0,
+			below: 0,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.size > 1 && self.size < PeriodType::MAX
+		self.size > 1 && self.size < PeriodType::MAX && self.hold > 0 && self.hold < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"size" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.size = value,
 			},
+			"hold" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.hold = value,
+			},
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
 			}
@@ -70,17 +95,18 @@ impl IndicatorConfig for ChaikinMoneyFlow {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		(1, 2)
 	}
 }
 
 impl Default for ChaikinMoneyFlow {
 	fn default() -> Self {
-		Self { size: 20 }
+		Self { size: 20, hold: 3 }
 	}
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChaikinMoneyFlowInstance {
 	cfg: ChaikinMoneyFlow,
 
@@ -88,6 +114,8 @@ pub struct ChaikinMoneyFlowInstance {
 	vol_sum: ValueType,
 	window: Window<ValueType>,
 	cross_over: Cross,
+	above: PeriodType,
+	below: PeriodType,
 }
 
 impl IndicatorInstance for ChaikinMoneyFlowInstance {
@@ -97,12 +125,107 @@ impl IndicatorInstance for ChaikinMoneyFlowInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.adi.heap_size() + self.window.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let adi = self.adi.next(candle);
-		self.vol_sum += candle.volume() - self.window.push(candle.volume());
+		let volume = candle.volume_or_zero();
+		self.vol_sum += volume - self.window.push(volume);
 		let value = adi / self.vol_sum;
 		let signal = self.cross_over.next((value, 0.));
 
-		IndicatorResult::new(&[value], &[signal])
+		if value > 0. {
+			self.above = self.above.saturating_add(1);
+			self.below = 0;
+		} else if value < 0. {
+			self.above = 0;
+			self.below = self.below.saturating_add(1);
+		} else {
+			self.above = 0;
+			self.below = 0;
+		}
+
+		let signal_hold = if self.above == self.cfg.hold {
+			Action::BUY_ALL
+		} else if self.below == self.cfg.hold {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[value], &[signal, signal_hold])
+	}
+}
+
+impl Checkpoint for ChaikinMoneyFlowInstance {
+	type State = (
+		<ADI as Checkpoint>::State,
+		ValueType,
+		WindowCheckpoint<ValueType>,
+		<Cross as Checkpoint>::State,
+		PeriodType,
+		PeriodType,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.adi.save(),
+			self.vol_sum,
+			self.window.save(),
+			self.cross_over.save(),
+			self.above,
+			self.below,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.adi.restore(&state.0);
+		self.vol_sum = state.1;
+		self.window.restore(&state.2);
+		self.cross_over.restore(&state.3);
+		self.above = state.4;
+		self.below = state.5;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ChaikinMoneyFlow;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_chaikin_money_flow_hold() {
+		let cfg = ChaikinMoneyFlow { size: 2, hold: 2 };
+
+		let candle = |close: ValueType| Candle {
+			high: 11.0,
+			low: 9.0,
+			close,
+			volume: 100.0,
+			..Candle::default()
+		};
+
+		let mut instance = cfg.init(&candle(10.0)).unwrap();
+
+		let expected = [
+			(11.0, 0.5, Action::None),
+			(11.0, 1.0, Action::BUY_ALL),
+			(11.0, 1.0, Action::None),
+			(9.5, 0.25, Action::None),
+			(9.0, -0.75, Action::None),
+			(9.0, -1.0, Action::SELL_ALL),
+			(10.0, -0.5, Action::None),
+		];
+
+		for &(close, value, signal) in &expected {
+			let result = instance.next(&candle(close));
+			assert_eq_float(value, result.value(0));
+			assert_eq!(signal, result.signal(1));
+		}
 	}
 }
@@ -1,10 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Cross, ADI};
+use crate::methods::{Cross, Divergence, ADI};
 
 /// Chaikin Oscillator
 ///
@@ -16,15 +17,33 @@ use crate::methods::{Cross, ADI};
 ///
 /// * `oscillator` value
 ///
-/// Range in \[`-1.0`; `1.0`\]
+/// Range in \(`-inf`; `+inf`\)
 ///
-/// # 1 signal
+/// # 2 signals
 ///
-/// When `oscillator` value goes above zero, then returns full buy signal.
-/// When `oscillator` value goes below zero, then returns full sell signal.
-/// Otherwise no signal
+/// * When `oscillator` value goes above zero, then returns full buy signal.
+///   When `oscillator` value goes below zero, then returns full sell signal.
+///   Otherwise no signal
+///
+/// * When `source` value makes a new low over `period3` candles, but `oscillator` value does not, then returns full buy signal.
+///   When `source` value makes a new high over `period3` candles, but `oscillator` value does not, then returns full sell signal.
+///   Otherwise no signal
+///
+/// See also [`Divergence`](crate::methods::Divergence).
+///
+/// # Compatibility
+///
+/// Configs, serialized before `method1`, `method2`, `period3` and `source` were introduced, are still deserializable:
+/// single `method` field sets both `method1` and `method2`, missing fields get their default values.
+/// [`set`](IndicatorConfig::set) also accepts `method` name, which sets both `method1` and `method2`.
+///
+/// Rust code, which uses `method` field directly, has to be updated to `method1` and `method2` (this is a breaking change).
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(from = "ChaikinOscillatorRepr", into = "ChaikinOscillatorRepr")
+)]
 pub struct ChaikinOscillator {
 	/// Short period for smoothing [AD index](https://en.wikipedia.org/wiki/Accumulation/distribution_index). Default is 3.
 	///
@@ -34,12 +53,20 @@ pub struct ChaikinOscillator {
 	///
 	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period2: PeriodType,
-	/// Method for smoothing [AD index](https://en.wikipedia.org/wiki/Accumulation/distribution_index). Default is [`EMA`](crate::methods::EMA).
-	pub method: RegularMethods,
+	/// Short method for smoothing [AD index](https://en.wikipedia.org/wiki/Accumulation/distribution_index). Default is [`EMA`](crate::methods::EMA).
+	pub method1: RegularMethods,
+	/// Long method for smoothing [AD index](https://en.wikipedia.org/wiki/Accumulation/distribution_index). Default is [`EMA`](crate::methods::EMA).
+	pub method2: RegularMethods,
 	/// [AD index](https://en.wikipedia.org/wiki/Accumulation/distribution_index) size. Default is 0 (windowless)
 	///
 	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\]
 	pub window: PeriodType, // from 0 to ...
+	/// Divergence search period. Default is 14.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+	/// Price source type for divergence search. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
 }
 
 impl IndicatorConfig for ChaikinOscillator {
@@ -47,40 +74,73 @@ impl IndicatorConfig for ChaikinOscillator {
 
 	const NAME: &'static str = "ChaikinOscillator";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Volume, &[ValueRange::Unbounded]).with_volume();
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
+		Self::METADATA.check(candle)?;
+
 		let cfg = self;
 		let adi = ADI::new(cfg.window, &candle)?;
 
 		Ok(Self::Instance {
-			ma1: method(cfg.method, cfg.period1, adi.get_value())?,
-			ma2: method(cfg.method, cfg.period2, adi.get_value())?,
+			ma1: method(cfg.method1, cfg.period1, adi.get_value())?,
+			ma2: method(cfg.method2, cfg.period2, adi.get_value())?,
 			adi,
 			cross_over: Cross::default(),
+			divergence: Divergence::new(cfg.period3, (candle.source(cfg.source), 0.))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 0 && self.period1 < self.period2 && self.period2 < PeriodType::MAX
+		self.period1 > 0
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
-				Ok(value) => self.method = value,
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => {
+					self.method1 = value;
+					self.method2 = value;
+				}
+			},
+			"method1" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method1 = value,
+			},
+			"method2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method2 = value,
+			},
+			"window" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.window = value,
+			},
+			"period3" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period3 = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
 			},
 
 			_ => {
@@ -92,7 +152,7 @@ impl IndicatorConfig for ChaikinOscillator {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		(1, 2)
 	}
 }
 
@@ -101,13 +161,85 @@ impl Default for ChaikinOscillator {
 		Self {
 			period1: 3,
 			period2: 10,
-			method: RegularMethods::EMA,
+			method1: RegularMethods::EMA,
+			method2: RegularMethods::EMA,
 			window: 0,
+			period3: 14,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Serializable representation of [`ChaikinOscillator`], which also accepts the legacy `method` field
+///
+/// The same representation is used for serialization, so non self-describing formats (e.g. [`Persist`](crate::core::Persist)) can read it back.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct ChaikinOscillatorRepr {
+	period1: PeriodType,
+	period2: PeriodType,
+	method: Option<RegularMethods>,
+	method1: Option<RegularMethods>,
+	method2: Option<RegularMethods>,
+	window: PeriodType,
+	period3: PeriodType,
+	source: Source,
+}
+
+#[cfg(feature = "serde")]
+impl Default for ChaikinOscillatorRepr {
+	fn default() -> Self {
+		let cfg = ChaikinOscillator::default();
+
+		Self {
+			period1: cfg.period1,
+			period2: cfg.period2,
+			method: None,
+			method1: None,
+			method2: None,
+			window: cfg.window,
+			period3: cfg.period3,
+			source: cfg.source,
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<ChaikinOscillator> for ChaikinOscillatorRepr {
+	fn from(cfg: ChaikinOscillator) -> Self {
+		Self {
+			period1: cfg.period1,
+			period2: cfg.period2,
+			method: None,
+			method1: Some(cfg.method1),
+			method2: Some(cfg.method2),
+			window: cfg.window,
+			period3: cfg.period3,
+			source: cfg.source,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[cfg(feature = "serde")]
+impl From<ChaikinOscillatorRepr> for ChaikinOscillator {
+	fn from(repr: ChaikinOscillatorRepr) -> Self {
+		let default = Self::default();
+
+		Self {
+			period1: repr.period1,
+			period2: repr.period2,
+			method1: repr.method1.or(repr.method).unwrap_or(default.method1),
+			method2: repr.method2.or(repr.method).unwrap_or(default.method2),
+			window: repr.window,
+			period3: repr.period3,
+			source: repr.source,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChaikinOscillatorInstance {
 	cfg: ChaikinOscillator,
 
@@ -115,6 +247,7 @@ pub struct ChaikinOscillatorInstance {
 	ma1: RegularMethod,
 	ma2: RegularMethod,
 	cross_over: Cross,
+	divergence: Divergence,
 }
 
 impl IndicatorInstance for ChaikinOscillatorInstance {
@@ -126,6 +259,13 @@ impl IndicatorInstance for ChaikinOscillatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.adi.heap_size()
+			+ self.ma1.heap_size()
+			+ self.ma2.heap_size()
+			+ self.divergence.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let adi = self.adi.next(candle);
 
@@ -135,7 +275,98 @@ impl IndicatorInstance for ChaikinOscillatorInstance {
 		let value = data1 - data2;
 
 		let signal = self.cross_over.next((value, 0.));
+		let divergence = self
+			.divergence
+			.next((candle.source(self.cfg.source), value));
+
+		IndicatorResult::new(&[value], &[signal, divergence])
+	}
+}
+
+impl Checkpoint for ChaikinOscillatorInstance {
+	type State = (
+		<ADI as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Divergence as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.adi.save(),
+			self.ma1.save(),
+			self.ma2.save(),
+			self.cross_over.save(),
+			self.divergence.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.adi.restore(&state.0);
+		self.ma1.restore(&state.1);
+		self.ma2.restore(&state.2);
+		self.cross_over.restore(&state.3);
+		self.divergence.restore(&state.4);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ChaikinOscillator;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+
+	#[test]
+	fn test_chaikin_oscillator_method() {
+		let mut cfg = ChaikinOscillator::default();
+		cfg.set("method", "sma".to_string()).unwrap();
+
+		assert_eq!(cfg.method1, RegularMethods::SMA);
+		assert_eq!(cfg.method2, RegularMethods::SMA);
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_chaikin_oscillator_legacy_format() {
+		let cfg: ChaikinOscillator =
+			serde_json::from_str(r#"{"period1":5,"period2":12,"method":"wma","window":0}"#)
+				.unwrap();
+
+		assert_eq!((cfg.period1, cfg.period2), (5, 12));
+		assert_eq!(cfg.method1, RegularMethods::WMA);
+		assert_eq!(cfg.method2, RegularMethods::WMA);
+		assert_eq!(cfg.period3, ChaikinOscillator::default().period3);
+
+		let json = serde_json::to_string(&cfg).unwrap();
+		let restored: ChaikinOscillator = serde_json::from_str(&json).unwrap();
+		assert_eq!(restored.method1, cfg.method1);
+		assert_eq!(restored.method2, cfg.method2);
+	}
+
+	#[test]
+	fn test_chaikin_oscillator_unbounded() {
+		// every candle closes at its high, so AD index grows by the whole volume at every step
+		let candle = |i: ValueType| Candle {
+			open: i,
+			high: i + 1.0,
+			low: i,
+			close: i + 1.0,
+			volume: 1000.0,
+		};
+
+		let cfg = ChaikinOscillator {
+			method1: RegularMethods::SMA,
+			method2: RegularMethods::SMA,
+			..ChaikinOscillator::default()
+		};
+
+		let mut instance = cfg.init(&candle(0.0)).unwrap();
+		let value = (1..50).fold(0.0, |_, i| instance.next(&candle(i as ValueType)).value(0));
 
-		IndicatorResult::new(&[value], &[signal])
+		// SMA of a linear series lags by (`length` - 1) / 2 steps, so the difference is (10 - 3) / 2 steps of 1000.0
+		assert_eq_float(3500.0, value);
 	}
 }
@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 // use std::str::FromStr;
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, signi, RegularMethod, RegularMethods};
 use crate::methods::{CrossAbove, Highest, Lowest};
 
@@ -62,6 +63,11 @@ impl IndicatorConfig for ChandeKrollStop {
 
 	const NAME: &'static str = "ChandeKrollStop";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Source, ValueRange::Source, ValueRange::Source],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -97,19 +103,19 @@ impl IndicatorConfig for ChandeKrollStop {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"p" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.p = value,
 			},
 			"x" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.x = value,
 			},
 			"q" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.q = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -139,7 +145,8 @@ impl Default for ChandeKrollStop {
 }
 
 /// Chande Kroll Stop state structure
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChandeKrollStopInstance {
 	cfg: ChandeKrollStop,
 
@@ -161,6 +168,14 @@ impl IndicatorInstance for ChandeKrollStopInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size()
+			+ self.highest1.heap_size()
+			+ self.lowest1.heap_size()
+			+ self.highest2.heap_size()
+			+ self.lowest2.heap_size()
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let tr = candle.tr_close(self.prev_close);
@@ -196,3 +211,45 @@ impl IndicatorInstance for ChandeKrollStopInstance {
 		)
 	}
 }
+
+impl Checkpoint for ChandeKrollStopInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		ValueType,
+		ValueType,
+		ValueType,
+		<CrossAbove as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma.save(),
+			self.highest1.save(),
+			self.lowest1.save(),
+			self.highest2.save(),
+			self.lowest2.save(),
+			self.prev_close,
+			self.prev_stop_short,
+			self.prev_stop_long,
+			self.cross_above.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma.restore(&state.0);
+		self.highest1.restore(&state.1);
+		self.lowest1.restore(&state.2);
+		self.highest2.restore(&state.3);
+		self.lowest2.restore(&state.4);
+		self.prev_close = state.5;
+		self.prev_stop_short = state.6;
+		self.prev_stop_long = state.7;
+		self.cross_above.restore(&state.8);
+	}
+}
@@ -1,9 +1,13 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Change, CrossAbove, CrossUnder};
+use crate::core::{
+	Checkpoint, Error, Method, PeriodType, Source, ValueType, Window, WindowCheckpoint, OHLCV,
+};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Change, Cross, CrossAbove, CrossUnder, Divergence};
 
 /// Chande Momentum Oscillator
 ///
@@ -11,17 +15,33 @@ use crate::methods::{Change, CrossAbove, CrossUnder};
 ///
 /// * <https://www.investopedia.com/terms/c/chandemomentumoscillator.asp>
 ///
-/// # 1 value
+/// # 2 values
 ///
-/// * `oscillator` value
+/// * `oscillator` value, smoothed by `method2` over `period2` values
 ///
 /// Range in \[`-1.0`; `1.0`\]
 ///
-/// # 1 signal
+/// * `signal line` value
 ///
-/// When `oscillator` value goes above `zone`, then returns full sell signal.
-/// When `oscillator` value goes below `-zone`, then returns full buy signal.
-/// Otherwise no signal
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// Some smoothing methods (e.g. [`HMA`](crate::methods::HMA)) may slightly overshoot the range.
+///
+/// # 3 signals
+///
+/// * When `oscillator` value goes above `zone`, then returns full sell signal.
+///   When `oscillator` value goes below `-zone`, then returns full buy signal.
+///   Otherwise no signal
+///
+/// * When `oscillator` value crosses `signal line` value upwards, returns full buy signal.
+///   When `oscillator` value crosses `signal line` value downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * When `source` value makes a new low over `period4` candles, but `oscillator` value does not, then returns full buy signal.
+///   When `source` value makes a new high over `period4` candles, but `oscillator` value does not, then returns full sell signal.
+///   Otherwise no signal
+///
+/// See also [`Divergence`](crate::methods::Divergence).
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChandeMomentumOscillator {
@@ -33,6 +53,22 @@ pub struct ChandeMomentumOscillator {
 	///
 	/// Range in \[`0.0`; `1.0`\]
 	pub zone: ValueType,
+	/// Oscillator smoothing period. Default is `1` (no smoothing).
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+	/// Oscillator smoothing method. Default is [`EMA`](crate::methods::EMA).
+	pub method2: RegularMethods,
+	/// Signal line period. Default is `9`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+	/// Signal line moving average method. Default is [`EMA`](crate::methods::EMA).
+	pub method3: RegularMethods,
+	/// Divergence search period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period4: PeriodType,
 	/// Source type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
 }
@@ -42,40 +78,81 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 
 	const NAME: &'static str = "ChandeMomentumOscillator";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Bounded(-1.0, 1.0),
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
 		let cfg = self;
+		let src = candle.source(cfg.source);
 
 		Ok(Self::Instance {
 			pos_sum: 0.,
 			neg_sum: 0.,
-			change: Change::new(1, candle.source(cfg.source))?,
+			change: Change::new(1, src)?,
 			window: Window::new(cfg.period, 0.),
+			smooth: method(cfg.method2, cfg.period2, 0.)?,
+			signal_line: method(cfg.method3, cfg.period3, 0.)?,
 			cross_under: CrossUnder::default(),
 			cross_above: CrossAbove::default(),
+			cross: Cross::default(),
+			divergence: Divergence::new(cfg.period4, (src, 0.))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0. && self.zone <= 1.0 && self.period > 1
+		self.zone >= 0.
+			&& self.zone <= 1.0
+			&& self.period > 1
+			&& self.period2 > 0
+			&& self.period2 < PeriodType::MAX
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
+			&& self.period4 > 0
+			&& self.period4 < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
+			"period2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period2 = value,
+			},
+			"method2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method2 = value,
+			},
+			"period3" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period3 = value,
+			},
+			"method3" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method3 = value,
+			},
+			"period4" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period4 = value,
+			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -88,7 +165,20 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		(2, 3)
+	}
+}
+
+impl Oscillator for ChandeMomentumOscillator {
+	const MAIN: usize = 0;
+	const SIGNAL_LINE: Option<usize> = Some(1);
+
+	fn overbought(&self) -> ValueType {
+		self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		-self.zone
 	}
 }
 
@@ -97,12 +187,18 @@ impl Default for ChandeMomentumOscillator {
 		Self {
 			period: 9,
 			zone: 0.5,
+			period2: 1,
+			method2: RegularMethods::EMA,
+			period3: 9,
+			method3: RegularMethods::EMA,
+			period4: 14,
 			source: Source::Close,
 		}
 	}
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChandeMomentumOscillatorInstance {
 	cfg: ChandeMomentumOscillator,
 
@@ -110,8 +206,12 @@ pub struct ChandeMomentumOscillatorInstance {
 	neg_sum: ValueType,
 	change: Change,
 	window: Window<ValueType>,
+	smooth: RegularMethod,
+	signal_line: RegularMethod,
 	cross_under: CrossUnder,
 	cross_above: CrossAbove,
+	cross: Cross,
+	divergence: Divergence,
 }
 
 #[inline]
@@ -131,8 +231,17 @@ impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.change.heap_size()
+			+ self.window.heap_size()
+			+ self.smooth.heap_size()
+			+ self.signal_line.heap_size()
+			+ self.divergence.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let ch = self.change.next(candle.source(self.cfg.source));
+		let src = candle.source(self.cfg.source);
+		let ch = self.change.next(src);
 
 		let left_value = self.window.push(ch);
 
@@ -147,9 +256,59 @@ impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 		} else {
 			0.
 		};
-		let signal = self.cross_under.next((value, -self.cfg.zone))
+		let value = self.smooth.next(value);
+		let signal_line = self.signal_line.next(value);
+
+		let signal1 = self.cross_under.next((value, -self.cfg.zone))
 			- self.cross_above.next((value, self.cfg.zone));
+		let signal2 = self.cross.next((value, signal_line));
+		let signal3 = self.divergence.next((src, value));
+
+		IndicatorResult::new(&[value, signal_line], &[signal1, signal2, signal3])
+	}
+}
+
+impl Checkpoint for ChandeMomentumOscillatorInstance {
+	type State = (
+		ValueType,
+		ValueType,
+		<Change as Checkpoint>::State,
+		WindowCheckpoint<ValueType>,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Divergence as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.pos_sum,
+			self.neg_sum,
+			self.change.save(),
+			self.window.save(),
+			self.smooth.save(),
+			self.signal_line.save(),
+			self.cross_under.save(),
+			self.cross_above.save(),
+			self.cross.save(),
+			self.divergence.save(),
+		)
+	}
 
-		IndicatorResult::new(&[value], &[signal])
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.pos_sum = state.0;
+		self.neg_sum = state.1;
+		self.change.restore(&state.2);
+		self.window.restore(&state.3);
+		self.smooth.restore(&state.4);
+		self.signal_line.restore(&state.5);
+		self.cross_under.restore(&state.6);
+		self.cross_above.restore(&state.7);
+		self.cross.restore(&state.8);
+		self.divergence.restore(&state.9);
 	}
 }
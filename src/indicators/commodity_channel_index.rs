@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
 use crate::methods::CCI;
 
 const SCALE: ValueType = 1.0 / 1.5;
@@ -18,11 +19,20 @@ const SCALE: ValueType = 1.0 / 1.5;
 ///
 /// Range in \(`-inf`; `+inf`\)
 ///
+/// When `long_period` is set, there is a second value:
+///
+/// * `long oscillator` value of the same scale, calculated over `long_period`
+///
+/// Range in \(`-inf`; `+inf`\)
+///
 /// # 1 signal
 ///
 /// When `oscillator` value goes above `zone`, then returns full sell signal.
 /// When `oscillator` value goes below `-zone`, then returns full buy signal.
 /// Otherwise no signal
+///
+/// When `long_period` is set, signals are confirmed by the `long oscillator` (the classic dual CCI setup):
+/// buy signal appears only when `long oscillator` is above zero and sell signal appears only when it is below zero.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CommodityChannelIndex {
@@ -36,6 +46,11 @@ pub struct CommodityChannelIndex {
 	/// Range in \[`0.0`; `+inf`\).
 	pub zone: ValueType,
 
+	/// Period of the long confirmation CCI. Default is `0`, which means no confirmation.
+	///
+	/// Range is `0` or \(`period`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub long_period: PeriodType,
+
 	/// Source type. Default is [`Close`](crate::core::Source::Close).
 	pub source: Source,
 }
@@ -45,6 +60,9 @@ impl IndicatorConfig for CommodityChannelIndex {
 
 	const NAME: &'static str = "CommodityChannelIndex";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Momentum, &[ValueRange::Unbounded]);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -57,27 +75,40 @@ impl IndicatorConfig for CommodityChannelIndex {
 			last_cci: 0.,
 			last_signal: 0,
 			cci: CCI::new(cfg.period, value)?,
+			long_cci: if cfg.long_period > 0 {
+				Some(CCI::new(cfg.long_period, value)?)
+			} else {
+				None
+			},
 
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.zone >= 0.0 && self.period > 1 && self.period < PeriodType::MAX
+		self.zone >= 0.0
+			&& self.period > 1
+			&& self.period < PeriodType::MAX
+			&& (self.long_period == 0
+				|| (self.long_period > self.period && self.long_period < PeriodType::MAX))
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
+			"long_period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.long_period = value,
+			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -90,7 +121,23 @@ impl IndicatorConfig for CommodityChannelIndex {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		if self.long_period > 0 {
+			(2, 1)
+		} else {
+			(1, 1)
+		}
+	}
+}
+
+impl Oscillator for CommodityChannelIndex {
+	const MAIN: usize = 0;
+
+	fn overbought(&self) -> ValueType {
+		self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		-self.zone
 	}
 }
 
@@ -99,16 +146,19 @@ impl Default for CommodityChannelIndex {
 		Self {
 			period: 18,
 			zone: 1.0,
+			long_period: 0,
 			source: Source::Close,
 		}
 	}
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CommodityChannelIndexInstance {
 	cfg: CommodityChannelIndex,
 
 	cci: CCI,
+	long_cci: Option<CCI>,
 	last_cci: ValueType,
 	last_signal: i8,
 }
@@ -120,6 +170,10 @@ impl IndicatorInstance for CommodityChannelIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.cci.heap_size() + self.long_cci.as_ref().map_or(0, CCI::heap_size)
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let value = candle.source(self.cfg.source);
 
@@ -148,6 +202,89 @@ impl IndicatorInstance for CommodityChannelIndexInstance {
 		self.last_cci = cci;
 		self.last_signal = signal;
 
+		if let Some(long_cci) = self.long_cci.as_mut() {
+			let long = long_cci.next(value) * SCALE;
+			let confirmed = (signal > 0 && long > 0.0) || (signal < 0 && long < 0.0);
+
+			return IndicatorResult::new(&[cci, long], &[Action::from(confirmed as i8 * signal)]);
+		}
+
 		IndicatorResult::new(&[cci], &[Action::from(signal)])
 	}
 }
+
+impl Checkpoint for CommodityChannelIndexInstance {
+	type State = (
+		<CCI as Checkpoint>::State,
+		<Option<CCI> as Checkpoint>::State,
+		ValueType,
+		i8,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.cci.save(),
+			self.long_cci.save(),
+			self.last_cci,
+			self.last_signal,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.cci.restore(&state.0);
+		self.long_cci.restore(&state.1);
+		self.last_cci = state.2;
+		self.last_signal = state.3;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CommodityChannelIndex;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_commodity_channel_index_long_period() {
+		let candle = |close: ValueType| Candle {
+			close,
+			..Candle::default()
+		};
+
+		let single = CommodityChannelIndex {
+			period: 2,
+			zone: 0.5,
+			long_period: 0,
+			..CommodityChannelIndex::default()
+		};
+		let dual = CommodityChannelIndex {
+			long_period: 3,
+			..single
+		};
+
+		let mut single = single.init(&candle(10.0)).unwrap();
+		let mut dual = dual.init(&candle(10.0)).unwrap();
+
+		// CCI of period 2 is always +-1.0 / 1.5, so the short one signals on every turn
+		let expected = [
+			(9.0, -1.0, Action::BUY_ALL, Action::None),
+			(8.0, -1.0, Action::None, Action::None),
+			(8.2, -1.0 / 3.0, Action::SELL_ALL, Action::SELL_ALL),
+			(10.0, 1.0, Action::None, Action::None),
+			(11.0, 19.0 / 23.0, Action::None, Action::None),
+			(10.8, 1.0 / 3.0, Action::BUY_ALL, Action::BUY_ALL),
+		];
+
+		for &(close, long, single_signal, dual_signal) in &expected {
+			let single = single.next(&candle(close));
+			let dual = dual.next(&candle(close));
+
+			assert_eq_float(single.value(0), dual.value(0));
+			assert_eq_float(long, dual.value(1));
+			assert_eq!(single_signal, single.signal(0));
+			assert_eq!(dual_signal, dual.signal(0));
+		}
+	}
+}
@@ -0,0 +1,300 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
+use crate::methods::{Cross, PercentRank, Streak, RMA, ROC};
+use std::mem::replace;
+
+/// Connors RSI
+///
+/// Average of three components:
+///
+/// 1. [`RSI`](crate::indicators::RSI) of the `source` values of period `rsi_period`;
+/// 2. [`RSI`](crate::indicators::RSI) of the up/down [`Streak`] length of period `streak_period`;
+/// 3. [`PercentRank`] of 1-bar [`ROC`] of period `rank_period`.
+///
+/// Both RSIs are calculated using Wilder's smoothing ([`RMA`]).
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000502017-connors-rsi-crsi/>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 2 signals
+///
+/// * Signal #1 on enters over-zone.
+///
+/// When main value crosses upper zone upwards, returns full sell signal.
+/// When main value crosses lower zone downwards, returns full buy signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2 on leaves over-zone.
+///
+/// When main value crosses upper zone downwards, returns full sell signal.
+/// When main value crosses lower zone upwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConnorsRSI {
+	/// Period of the price RSI. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub rsi_period: PeriodType,
+
+	/// Period of the streak RSI. Default is `2`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub streak_period: PeriodType,
+
+	/// Period of the percent rank of rate of change. Default is `100`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub rank_period: PeriodType,
+
+	/// Overbought/oversell relative zone. Default is `0.1`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for ConnorsRSI {
+	type Instance = ConnorsRSIInstance;
+
+	const NAME: &'static str = "ConnorsRSI";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Bounded(0.0, 1.0)],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			rsi: RsiOf::new(cfg.rsi_period, src)?,
+			streak: Streak::new((), src)?,
+			streak_rsi: RsiOf::new(cfg.streak_period, 0.0)?,
+			roc: ROC::new(1, src)?,
+			rank: PercentRank::new(cfg.rank_period, 0.0)?,
+			cross_upper: Cross::new((), (0.5, 1.0 - cfg.zone))?,
+			cross_lower: Cross::new((), (0.5, cfg.zone))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.rsi_period > 0
+			&& self.rsi_period < PeriodType::MAX
+			&& self.streak_period > 0
+			&& self.streak_period < PeriodType::MAX
+			&& self.rank_period > 0
+			&& self.rank_period < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"rsi_period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.rsi_period = value,
+			},
+			"streak_period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.streak_period = value,
+			},
+			"rank_period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.rank_period = value,
+			},
+			"zone" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 2)
+	}
+}
+
+impl Oscillator for ConnorsRSI {
+	const MAIN: usize = 0;
+
+	fn overbought(&self) -> ValueType {
+		1.0 - self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		self.zone
+	}
+}
+
+impl Default for ConnorsRSI {
+	fn default() -> Self {
+		Self {
+			rsi_period: 3,
+			streak_period: 2,
+			rank_period: 100,
+			zone: 0.1,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`ConnorsRSI`]
+pub type CRSI = ConnorsRSI;
+
+/// Relative strength index of arbitrary timeseries, smoothed by [`RMA`]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct RsiOf {
+	previous_input: ValueType,
+	posma: RMA,
+	negma: RMA,
+}
+
+impl RsiOf {
+	fn new(period: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			previous_input: value,
+			posma: RMA::new(period, 0.)?,
+			negma: RMA::new(period, 0.)?,
+		})
+	}
+
+	fn next(&mut self, value: ValueType) -> ValueType {
+		let change = value - replace(&mut self.previous_input, value);
+
+		let pos = self.posma.next(change.max(0.));
+		let neg = -self.negma.next(change.min(0.));
+
+		if pos != 0. || neg != 0. {
+			pos / (pos + neg)
+		} else {
+			0.5
+		}
+	}
+}
+
+impl Checkpoint for RsiOf {
+	type State = (ValueType, RMA, RMA);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.previous_input, self.posma, self.negma)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.previous_input = state.0;
+		self.posma = state.1;
+		self.negma = state.2;
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConnorsRSIInstance {
+	cfg: ConnorsRSI,
+
+	rsi: RsiOf,
+	streak: Streak,
+	streak_rsi: RsiOf,
+	roc: ROC,
+	rank: PercentRank,
+	cross_upper: Cross,
+	cross_lower: Cross,
+}
+
+impl IndicatorInstance for ConnorsRSIInstance {
+	type Config = ConnorsRSI;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.roc.heap_size() + self.rank.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let rsi = self.rsi.next(src);
+		let streak_rsi = self.streak_rsi.next(self.streak.next(src) as ValueType);
+		let rank = self.rank.next(self.roc.next(src));
+
+		let value = (rsi + streak_rsi + rank) / 3.;
+
+		let oversold = self.cross_lower.next((value, self.cfg.zone)).analog();
+		let overbought = self.cross_upper.next((value, 1. - self.cfg.zone)).analog();
+
+		let signal1 = (oversold < 0) as i8 - (overbought > 0) as i8;
+		let signal2 = (oversold > 0) as i8 - (overbought < 0) as i8;
+
+		IndicatorResult::new(&[value], &[signal1.into(), signal2.into()])
+	}
+}
+
+impl Checkpoint for ConnorsRSIInstance {
+	type State = (
+		(ValueType, RMA, RMA),
+		<Streak as Checkpoint>::State,
+		(ValueType, RMA, RMA),
+		<ROC as Checkpoint>::State,
+		<PercentRank as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.rsi.save(),
+			self.streak.save(),
+			self.streak_rsi.save(),
+			self.roc.save(),
+			self.rank.save(),
+			self.cross_upper.save(),
+			self.cross_lower.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.rsi.restore(&state.0);
+		self.streak.restore(&state.1);
+		self.streak_rsi.restore(&state.2);
+		self.roc.restore(&state.3);
+		self.rank.restore(&state.4);
+		self.cross_upper.restore(&state.5);
+		self.cross_lower.restore(&state.6);
+	}
+}
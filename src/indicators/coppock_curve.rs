@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, RateOfChange, ReversalSignal};
 
@@ -75,6 +76,11 @@ impl IndicatorConfig for CoppockCurve {
 
 	const NAME: &'static str = "CoppockCurve";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Source, ValueRange::Source],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -109,39 +115,39 @@ impl IndicatorConfig for CoppockCurve {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"s2_left" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.s2_left = value,
 			},
 			"s2_right" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.s2_right = value,
 			},
 			"s3_period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.s3_period = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			"method1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method1 = value,
 			},
 			"method2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method2 = value,
 			},
 			// "zone"		=> self.zone = value.parse().unwrap(),
@@ -175,7 +181,8 @@ impl Default for CoppockCurve {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoppockCurveInstance {
 	cfg: CoppockCurve,
 
@@ -195,6 +202,14 @@ impl IndicatorInstance for CoppockCurveInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.roc1.heap_size()
+			+ self.roc2.heap_size()
+			+ self.ma1.heap_size()
+			+ self.ma2.heap_size()
+			+ self.pivot.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let roc1 = self.roc1.next(src);
@@ -209,3 +224,39 @@ impl IndicatorInstance for CoppockCurveInstance {
 		IndicatorResult::new(&[value1, value2], &[signal1, signal2, signal3])
 	}
 }
+
+impl Checkpoint for CoppockCurveInstance {
+	type State = (
+		<RateOfChange as Checkpoint>::State,
+		<RateOfChange as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<ReversalSignal as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.roc1.save(),
+			self.roc2.save(),
+			self.ma1.save(),
+			self.ma2.save(),
+			self.cross_over1.save(),
+			self.pivot.save(),
+			self.cross_over2.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.roc1.restore(&state.0);
+		self.roc2.restore(&state.1);
+		self.ma1.restore(&state.2);
+		self.ma2.restore(&state.3);
+		self.cross_over1.restore(&state.4);
+		self.pivot.restore(&state.5);
+		self.cross_over2.restore(&state.6);
+	}
+}
@@ -0,0 +1,247 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Checkpoint, Error, PeriodType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorMetadata, IndicatorResult};
+use std::fmt;
+
+/// Debounce signals of any other indicator
+///
+/// Suppresses every signal of the inner `indicator` until it persists (has the same direction) for `period` candles in a row.
+/// When it happens, returns inner signal once. Then signal is suppressed again until direction changes or signal disappears.
+///
+/// It may be useful for reducing whipsaw of "state"-like signals.
+///
+/// Debouncing depends only on the persistence of the inner signal itself. Confirmation of the signal by any other condition
+/// is not supported: such a logic belongs to the strategy code, which has access to both the debounced result and the condition.
+///
+/// # Values
+///
+/// Returns the same values as the inner `indicator` does.
+///
+/// # Signals
+///
+/// Returns the same count of signals as the inner `indicator` does, but every signal is debounced.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{Debounce, MomentumIndex};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(20).collect();
+/// let debounced = Debounce {
+///     indicator: MomentumIndex::default(),
+///     period: 3,
+/// };
+///
+/// let results = debounced.over(&candles).unwrap();
+/// assert_eq!(results[0].size(), MomentumIndex::default().size());
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Debounce<C: IndicatorConfig> {
+	/// Inner indicator configuration.
+	pub indicator: C,
+
+	/// How many candles in a row signal should persist. Default is `2`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for Debounce<C> {
+	type Instance = DebounceInstance<C>;
+
+	const NAME: &'static str = "Debounce";
+
+	const METADATA: IndicatorMetadata = C::METADATA;
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			streaks: [(0, 0); IndicatorResult::SIGNALS_SIZE],
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.indicator.validate()
+	}
+
+	/// Sets `period` of the wrapper. Any other parameter is passed to the inner `indicator`.
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.indicator.size()
+	}
+}
+
+impl<C: IndicatorConfig + Default> Default for Debounce<C> {
+	fn default() -> Self {
+		Self {
+			indicator: C::default(),
+			period: 2,
+		}
+	}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DebounceInstance<C: IndicatorConfig> {
+	cfg: Debounce<C>,
+
+	instance: C::Instance,
+	streaks: [(i8, PeriodType); IndicatorResult::SIGNALS_SIZE],
+}
+
+impl<C> Clone for DebounceInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			instance: self.instance.clone(),
+			streaks: self.streaks,
+		}
+	}
+}
+
+impl<C> fmt::Debug for DebounceInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("DebounceInstance")
+			.field("cfg", &self.cfg)
+			.field("instance", &self.instance)
+			.field("streaks", &self.streaks)
+			.finish()
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for DebounceInstance<C> {
+	type Config = Debounce<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.instance.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+
+		let mut signals = [Action::None; IndicatorResult::SIGNALS_SIZE];
+		let period = self.cfg.period;
+
+		result
+			.signals()
+			.iter()
+			.zip(self.streaks.iter_mut())
+			.zip(signals.iter_mut())
+			.for_each(|((&signal, (direction, count)), output)| {
+				let sign = signal.analog();
+
+				if sign == 0 {
+					*count = 0;
+				} else if sign == *direction {
+					*count = count.saturating_add(1);
+				} else {
+					*count = 1;
+				}
+				*direction = sign;
+
+				if *count == period {
+					*output = signal;
+				}
+			});
+
+		let signals_length = result.signals_length() as usize;
+		IndicatorResult::new(result.values(), &signals[..signals_length])
+	}
+}
+
+impl<C> Checkpoint for DebounceInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Checkpoint,
+{
+	type State = (
+		<C::Instance as Checkpoint>::State,
+		[(i8, PeriodType); IndicatorResult::SIGNALS_SIZE],
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.instance.save(), self.streaks)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.instance.restore(&state.0);
+		self.streaks = state.1;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Debounce;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, PeriodType};
+	use crate::indicators::MomentumIndex;
+
+	fn debounce(period: PeriodType) -> Vec<Action> {
+		let cfg = Debounce {
+			indicator: MomentumIndex {
+				period1: 2,
+				period2: 1,
+				..MomentumIndex::default()
+			},
+			period,
+		};
+
+		let candle = |close| Candle {
+			close,
+			..Candle::default()
+		};
+
+		let mut instance = cfg.init(&candle(1.0)).unwrap();
+
+		// inner signals are: buy, buy, buy, buy, none, sell, sell, sell
+		[2.0, 3.0, 4.0, 5.0, 4.0, 3.0, 2.0, 1.0]
+			.iter()
+			.map(|&close| instance.next(&candle(close)).signal(0))
+			.collect()
+	}
+
+	#[test]
+	fn test_debounce() {
+		let (b, s, n) = (Action::BUY_ALL, Action::SELL_ALL, Action::None);
+
+		assert_eq!(debounce(1), vec![b, n, n, n, n, s, n, n]);
+		assert_eq!(debounce(2), vec![n, b, n, n, n, n, s, n]);
+		assert_eq!(debounce(3), vec![n, n, b, n, n, n, n, s]);
+		assert_eq!(debounce(5), vec![n; 8]);
+	}
+}
@@ -1,7 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 
@@ -24,6 +26,11 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 ///
 /// Range in \(`-inf`; `+inf`\).
 ///
+/// When `centered` is `true`, the value is calculated as `source` value from `period`/2+1 candles ago minus current moving average value.
+/// That is the textbook formula, which must be displaced back by `period`/2+1 candles on the chart.
+///
+/// When `centered` is `false`, the value is calculated as current `source` value minus moving average value from `period`/2+1 candles ago.
+///
 /// # Has no signals
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -38,6 +45,12 @@ pub struct DetrendedPriceOscillator {
 
 	/// Source type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Use centered (textbook) variant of the oscillator. Default is `true`.
+	///
+	/// Configs, serialized before this field was introduced, get `false` (the previous behavior).
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub centered: bool,
 }
 
 impl IndicatorConfig for DetrendedPriceOscillator {
@@ -45,6 +58,9 @@ impl IndicatorConfig for DetrendedPriceOscillator {
 
 	const NAME: &'static str = "DetrendedPriceOscillator";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Momentum, &[ValueRange::Unbounded]);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -66,17 +82,21 @@ impl IndicatorConfig for DetrendedPriceOscillator {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
+			"centered" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.centered = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -97,11 +117,13 @@ impl Default for DetrendedPriceOscillator {
 			period: 21,
 			method: RegularMethods::SMA,
 			source: Source::Close,
+			centered: true,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DetrendedPriceOscillatorInstance {
 	cfg: DetrendedPriceOscillator,
 
@@ -116,14 +138,58 @@ impl IndicatorInstance for DetrendedPriceOscillatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.sma.heap_size() + self.window.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
 		let sma = self.sma.next(src);
-		let left_src = self.window.push(src);
 
-		let dpo = left_src - sma;
+		let dpo = if self.cfg.centered {
+			self.window.push(src) - sma
+		} else {
+			src - self.window.push(sma)
+		};
 
 		IndicatorResult::new(&[dpo], &[])
 	}
 }
+
+impl Checkpoint for DetrendedPriceOscillatorInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		WindowCheckpoint<ValueType>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.sma.save(), self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sma.restore(&state.0);
+		self.window.restore(&state.1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_detrended_price_oscillator_legacy_format() {
+		use super::DetrendedPriceOscillator;
+		use crate::core::Source;
+		use crate::helpers::RegularMethods;
+
+		let cfg: DetrendedPriceOscillator =
+			serde_json::from_str(r#"{"period":14,"method":"wma","source":"high"}"#).unwrap();
+
+		assert_eq!(cfg.period, 14);
+		assert_eq!(cfg.method, RegularMethods::WMA);
+		assert_eq!(cfg.source, Source::High);
+		assert!(!cfg.centered);
+	}
+}
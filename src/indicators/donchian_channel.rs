@@ -3,8 +3,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Candle, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Candle, Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Channel, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, Highest, Lowest};
 
@@ -53,6 +54,11 @@ impl IndicatorConfig for DonchianChannel {
 
 	const NAME: &'static str = "DonchianChannel";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volatility,
+		&[ValueRange::Source, ValueRange::Source, ValueRange::Source],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -74,7 +80,7 @@ impl IndicatorConfig for DonchianChannel {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			_ => {
@@ -90,13 +96,28 @@ impl IndicatorConfig for DonchianChannel {
 	}
 }
 
+impl Channel for DonchianChannel {
+	fn upper(result: &IndicatorResult) -> ValueType {
+		result.value(2)
+	}
+
+	fn lower(result: &IndicatorResult) -> ValueType {
+		result.value(0)
+	}
+
+	fn middle(result: &IndicatorResult) -> ValueType {
+		result.value(1)
+	}
+}
+
 impl Default for DonchianChannel {
 	fn default() -> Self {
 		Self { period: 20 }
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DonchianChannelInstance {
 	cfg: DonchianChannel,
 
@@ -111,6 +132,10 @@ impl IndicatorInstance for DonchianChannelInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.highest.heap_size() + self.lowest.heap_size()
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
@@ -125,3 +150,21 @@ impl IndicatorInstance for DonchianChannelInstance {
 		IndicatorResult::new(&[lowest, middle, highest], &[signal1.into()])
 	}
 }
+
+impl Checkpoint for DonchianChannelInstance {
+	type State = (
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.highest.save(), self.lowest.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest.restore(&state.0);
+		self.lowest.restore(&state.1);
+	}
+}
@@ -2,13 +2,24 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
-use crate::core::{Error, Method, PeriodType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
 /// Ease Of Movement
 ///
+/// Distance moved (the change of the middle point of `high` and `low` over `period2` candles) is divided by the box ratio,
+/// which is `volume` / `volume_divisor` / (`high` - `low`), and then smoothed by `method` over `period1` candles.
+///
+/// Raw volumes differ a lot between assets, so the magnitude of the indicator does too. It may be leveled either by
+/// setting `volume_divisor` (f.e. to `10000.0` or `100000000.0`) or by enabling `relative_volume`,
+/// which divides `volume` by its own moving average before the box ratio is calculated.
+///
+/// Candles without any volume are considered as not moving at all.
+///
 /// ## Links
 ///
 /// * <https://en.wikipedia.org/wiki/Ease_of_movement>
@@ -40,6 +51,14 @@ pub struct EaseOfMovement {
 
 	/// MA type \(using `period1`\). Default is [`SMA`](crate::methods::SMA).
 	pub method: RegularMethods,
+
+	/// Volume scale divisor of the box ratio. Default is `1.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub volume_divisor: ValueType,
+
+	/// If `true`, `volume` is divided by its moving average \(using `period1` and `method`\) in the box ratio. Default is `false`.
+	pub relative_volume: bool,
 }
 
 impl IndicatorConfig for EaseOfMovement {
@@ -47,14 +66,20 @@ impl IndicatorConfig for EaseOfMovement {
 
 	const NAME: &'static str = "EaseOfMovement";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Volume, &[ValueRange::Unbounded]).with_volume();
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
+		Self::METADATA.check(candle)?;
+
 		let cfg = self;
 		Ok(Self::Instance {
 			m1: method(cfg.method, cfg.period1, 0.)?,
+			m_volume: method(cfg.method, cfg.period1, candle.volume())?,
 			w: Window::new(cfg.period2, HLC::from(candle)),
 			cross: Cross::new((), (0.0, 0.0))?,
 
@@ -63,23 +88,35 @@ impl IndicatorConfig for EaseOfMovement {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.period1 < PeriodType::MAX && self.period2 >= 1
+		self.period1 > 1
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 >= 1
+			&& self.volume_divisor > 0.0
+			&& self.volume_divisor.is_finite()
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
+			"volume_divisor" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.volume_divisor = value,
+			},
+			"relative_volume" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.relative_volume = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -100,15 +137,19 @@ impl Default for EaseOfMovement {
 			period1: 13,
 			period2: 1,
 			method: RegularMethods::SMA,
+			volume_divisor: 1.0,
+			relative_volume: false,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EaseOfMovementInstance {
 	cfg: EaseOfMovement,
 
 	m1: RegularMethod,
+	m_volume: RegularMethod,
 	w: Window<HLC>,
 	cross: Cross,
 }
@@ -120,6 +161,10 @@ impl IndicatorInstance for EaseOfMovementInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.m1.heap_size() + self.m_volume.heap_size() + self.w.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let prev_candle = self.w.push(HLC::from(candle));
 
@@ -128,7 +173,19 @@ impl IndicatorInstance for EaseOfMovementInstance {
 
 		let d = (d_high + d_low) * 0.5;
 
-		let v = d * (candle.high() - candle.low()) / candle.volume();
+		let volume = candle.volume_or_zero();
+		let volume_avg = self.m_volume.next(volume);
+		let volume = if self.cfg.relative_volume {
+			volume / volume_avg
+		} else {
+			volume
+		};
+
+		let v = if volume > 0.0 {
+			d * (candle.high() - candle.low()) * self.cfg.volume_divisor / volume
+		} else {
+			0.0
+		};
 		debug_assert!(v.is_finite() && !v.is_nan());
 
 		let value = self.m1.next(v);
@@ -145,3 +202,85 @@ impl IndicatorInstance for EaseOfMovementInstance {
 		IndicatorResult::new(&[value], &[signal])
 	}
 }
+
+impl Checkpoint for EaseOfMovementInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		WindowCheckpoint<HLC>,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.m1.save(),
+			self.m_volume.save(),
+			self.w.save(),
+			self.cross.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.m1.restore(&state.0);
+		self.m_volume.restore(&state.1);
+		self.w.restore(&state.2);
+		self.cross.restore(&state.3);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::EaseOfMovement;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+
+	fn candle(high: ValueType, low: ValueType, volume: ValueType) -> Candle {
+		Candle {
+			high,
+			low,
+			volume,
+			..Candle::default()
+		}
+	}
+
+	fn test_values(cfg: EaseOfMovement, expected: &[ValueType]) {
+		let mut instance = cfg.init(&candle(11.0, 9.0, 100.0)).unwrap();
+
+		for (candle, &value) in [candle(13.0, 11.0, 200.0), candle(12.0, 8.0, 400.0)]
+			.iter()
+			.zip(expected)
+		{
+			assert_eq_float(value, instance.next(candle).value(0));
+		}
+	}
+
+	#[test]
+	fn test_ease_of_movement_volume_divisor() {
+		let cfg = EaseOfMovement {
+			period1: 2,
+			period2: 1,
+			method: RegularMethods::SMA,
+			volume_divisor: 100.0,
+			relative_volume: false,
+		};
+
+		// box ratios are 200 / 100 / 2 and 400 / 100 / 4, so raw values are 2 and -2
+		test_values(cfg, &[1.0, 0.0]);
+	}
+
+	#[test]
+	fn test_ease_of_movement_relative_volume() {
+		let cfg = EaseOfMovement {
+			period1: 2,
+			period2: 1,
+			method: RegularMethods::SMA,
+			volume_divisor: 1.0,
+			relative_volume: true,
+		};
+
+		// relative volumes are 200 / 150 and 400 / 300, so raw values are 3 and -6
+		test_values(cfg, &[1.5, -1.5]);
+	}
+}
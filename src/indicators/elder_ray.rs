@@ -0,0 +1,200 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::Cross;
+
+/// Elder Ray (Bull Power and Bear Power)
+///
+/// `Bull power` is the distance from the moving average to `high` value and
+/// `bear power` is the distance from the moving average to `low` value.
+///
+/// The direction of the moving average is used as a trend filter:
+/// the MA slope is rising when current MA value is greater than the previous one and falling when it is lower.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/e/elderray.asp>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:elder_ray_index>
+///
+/// # 2 values
+///
+/// * `bull power` value (`high` - `MA`)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// * `bear power` value (`low` - `MA`)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * Signal 1 appears when `bull power` crosses zero line.
+///   When `bull power` crosses zero line upwards and MA slope is rising, returns full buy signal.
+///   When `bull power` crosses zero line downwards and MA slope is falling, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * Signal 2 appears when `bear power` crosses zero line.
+///   When `bear power` crosses zero line upwards and MA slope is rising, returns full buy signal.
+///   When `bear power` crosses zero line downwards and MA slope is falling, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderRay {
+	/// MA period. Default is `13`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub period: PeriodType,
+
+	/// MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// MA source type of values. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for ElderRay {
+	type Instance = ElderRayInstance;
+
+	const NAME: &'static str = "ElderRay";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma: method(cfg.method, cfg.period, src)?,
+			last_ma: src,
+			cross_bull: Cross::default(),
+			cross_bear: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for ElderRay {
+	fn default() -> Self {
+		Self {
+			period: 13,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderRayInstance {
+	cfg: ElderRay,
+
+	ma: RegularMethod,
+	last_ma: ValueType,
+	cross_bull: Cross,
+	cross_bear: Cross,
+}
+
+/// Keeps the signal only when its direction agrees with the MA slope
+#[inline]
+fn filter_by_slope(signal: Action, slope: ValueType) -> Action {
+	match signal.analog() {
+		s if s > 0 && slope > 0.0 => Action::BUY_ALL,
+		s if s < 0 && slope < 0.0 => Action::SELL_ALL,
+		_ => Action::None,
+	}
+}
+
+impl IndicatorInstance for ElderRayInstance {
+	type Config = ElderRay;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let ma = self.ma.next(candle.source(self.cfg.source));
+		let slope = ma - self.last_ma;
+		self.last_ma = ma;
+
+		let bull = candle.high() - ma;
+		let bear = candle.low() - ma;
+
+		let signal_bull = filter_by_slope(self.cross_bull.next((bull, 0.0)), slope);
+		let signal_bear = filter_by_slope(self.cross_bear.next((bear, 0.0)), slope);
+
+		IndicatorResult::new(&[bull, bear], &[signal_bull, signal_bear])
+	}
+}
+
+impl Checkpoint for ElderRayInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		ValueType,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma.save(),
+			self.last_ma,
+			self.cross_bull.save(),
+			self.cross_bear.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma.restore(&state.0);
+		self.last_ma = state.1;
+		self.cross_bull.restore(&state.2);
+		self.cross_bear.restore(&state.3);
+	}
+}
@@ -2,7 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::Candle;
+use crate::core::{Checkpoint, WindowCheckpoint};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Cross;
@@ -14,17 +16,28 @@ use crate::methods::Cross;
 /// * <https://en.wikipedia.org/wiki/Force_index>
 /// * <https://www.investopedia.com/terms/f/force-index.asp>
 ///
-/// # 1 value
+/// # 2 values
 ///
-/// * Main value
+/// * Main value (force index smoothed by MA of period `period1`)
 ///
 /// Range in \(`-inf`; `+inf`\)
 ///
-/// # 1 signal
+/// * Short value (force index smoothed by MA of period `period3`)
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// Elder uses `13`-period force index to confirm the trend and `2`-period force index to find entry points,
+/// so both of them are calculated over the same price changes and volumes.
+///
+/// # 2 signals
 ///
 /// * Signal 1 appears when `main value` crosses zero line.
-/// When `main value` crosses zero line upwards, returns full buy signal.
-/// When `main value` crosses zero line downwards, returns full sell signal.
+///   When `main value` crosses zero line upwards, returns full buy signal.
+///   When `main value` crosses zero line downwards, returns full sell signal.
+///
+/// * Signal 2 appears when `short value` crosses zero line.
+///   When `short value` crosses zero line upwards, returns full buy signal.
+///   When `short value` crosses zero line downwards, returns full sell signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EldersForceIndex {
@@ -36,6 +49,10 @@ pub struct EldersForceIndex {
 	///
 	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub period2: PeriodType,
+	/// Short MA period. Default is `2`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub period3: PeriodType,
 	/// MA method. Default is [`EMA`](crate::methods::EMA).
 	pub method: RegularMethods,
 	/// Price source type of values. Default is [`Close`](crate::core::Source::Close).
@@ -47,41 +64,55 @@ impl IndicatorConfig for EldersForceIndex {
 
 	const NAME: &'static str = "EldersForceIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volume,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	)
+	.with_volume();
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
+		Self::METADATA.check(candle)?;
+
 		let cfg = self;
 		Ok(Self::Instance {
 			ma: method(cfg.method, cfg.period1, 0.)?,
+			ma_short: method(cfg.method, cfg.period3, 0.)?,
 			window: Window::new(cfg.period2, Candle::from(candle)),
 			vol_sum: candle.volume() * cfg.period2 as ValueType,
 			cross_over: Cross::default(),
+			cross_short: Cross::default(),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 > 1 && self.period2 >= 1
+		self.period1 > 1 && self.period2 >= 1 && self.period3 >= 1
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
+			"period3" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period3 = value,
+			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -94,7 +125,7 @@ impl IndicatorConfig for EldersForceIndex {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		(2, 2)
 	}
 }
 
@@ -103,20 +134,24 @@ impl Default for EldersForceIndex {
 		Self {
 			period1: 13,
 			period2: 1,
+			period3: 2,
 			method: RegularMethods::EMA,
 			source: Source::Close,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EldersForceIndexInstance {
 	cfg: EldersForceIndex,
 
 	ma: RegularMethod,
+	ma_short: RegularMethod,
 	window: Window<Candle>,
 	vol_sum: ValueType,
 	cross_over: Cross,
+	cross_short: Cross,
 }
 
 impl IndicatorInstance for EldersForceIndexInstance {
@@ -126,17 +161,57 @@ impl IndicatorInstance for EldersForceIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size() + self.ma_short.heap_size() + self.window.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let left_candle = self.window.push(Candle::from(candle));
 
-		self.vol_sum += candle.volume() - left_candle.volume();
+		self.vol_sum += candle.volume_or_zero() - left_candle.volume_or_zero();
 		let r = (OHLCV::source(&candle, self.cfg.source)
 			- OHLCV::source(&left_candle, self.cfg.source))
 			* self.vol_sum;
 
 		let value = self.ma.next(r);
+		let short = self.ma_short.next(r);
+
 		let signal = self.cross_over.next((value, 0.));
+		let signal_short = self.cross_short.next((short, 0.));
+
+		IndicatorResult::new(&[value, short], &[signal, signal_short])
+	}
+}
+
+impl Checkpoint for EldersForceIndexInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		WindowCheckpoint<Candle>,
+		ValueType,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma.save(),
+			self.ma_short.save(),
+			self.window.save(),
+			self.vol_sum,
+			self.cross_over.save(),
+			self.cross_short.save(),
+		)
+	}
 
-		IndicatorResult::new(&[value], &[signal])
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma.restore(&state.0);
+		self.ma_short.restore(&state.1);
+		self.window.restore(&state.2);
+		self.vol_sum = state.3;
+		self.cross_over.restore(&state.4);
+		self.cross_short.restore(&state.5);
 	}
 }
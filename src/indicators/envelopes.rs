@@ -1,9 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::core::{Action, Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Channel, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{RegularMethod, RegularMethods, MA};
+use crate::methods::{Cross, EnvelopeOf, SMA};
 
 /// Envelopes
 ///
@@ -11,7 +13,7 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 ///
 /// * <https://www.investopedia.com/terms/e/envelope.asp>
 ///
-/// # 3 values
+/// # 4 values
 ///
 /// * `Upper bound`
 ///
@@ -19,15 +21,24 @@ use crate::helpers::{method, RegularMethod, RegularMethods};
 ///
 /// * `Lower bound`
 ///
-/// Range of values is the same as the range of the `source` values.Action
+/// Range of values is the same as the range of the `source` values.
 ///
 /// *  Raw `Source2` value
 ///
-/// # 1 signal
+/// * `Middle line` (moving average) value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 2 signals
 ///
 /// * Signal 1 appears when `Source2` value crosses bounds.
-/// When `Source2` value crosses `upper bound` upwards, returns full sell signal.
-/// When `Source2` value crosses `lower bound` downwards, returns full buy signal.
+///   When `Source2` value crosses `upper bound` upwards, returns full sell signal.
+///   When `Source2` value crosses `lower bound` downwards, returns full buy signal.
+///
+/// * Signal 2 appears when `Source2` value crosses `middle line`.
+///   When `Source2` value crosses `middle line` upwards, returns full buy signal.
+///   When `Source2` value crosses `middle line` downwards, returns full sell signal.
+///   Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Envelopes {
@@ -35,10 +46,22 @@ pub struct Envelopes {
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub period: PeriodType,
-	/// Bound relative size. Default is `0.1`.
+	/// Upper bound relative size. Default is `0.1`.
+	///
+	/// When `atr` is `true`, it is a multiplier of the average true range instead.
 	///
 	/// Range in (`0.0`; `+inf`).
 	pub k: ValueType,
+	/// Lower bound relative size. Default is `0.1`.
+	///
+	/// When `atr` is `true`, it is a multiplier of the average true range instead.
+	///
+	/// Range in (`0.0`; `1.0`) or in (`0.0`; `+inf`) when `atr` is `true`.
+	pub k2: ValueType,
+	/// Use multiples of average true range for bounds instead of relative size. Default is `false`.
+	///
+	/// Average true range is calculated as [`SMA`](crate::methods::SMA) of [`TR`](crate::methods::TR) over `period`.
+	pub atr: bool,
 	/// MA method. Default is [`SMA`](crate::methods::SMA).
 	pub method: RegularMethods,
 	/// Source value type for bounds. Default is [`Close`](crate::core::Source::Close).
@@ -52,6 +75,16 @@ impl IndicatorConfig for Envelopes {
 
 	const NAME: &'static str = "Envelopes";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -59,39 +92,57 @@ impl IndicatorConfig for Envelopes {
 
 		let cfg = self;
 		let src = candle.source(cfg.source);
+		let src2 = candle.source(cfg.source2);
+
+		let ma = MA::new(cfg.method, cfg.period);
 
 		Ok(Self::Instance {
-			ma: method(cfg.method, cfg.period, src)?,
-			k_high: 1.0 + cfg.k,
-			k_low: 1.0 - cfg.k,
+			bounds: if cfg.atr {
+				Bounds::Atr(
+					ma.init(src)?,
+					SMA::new(cfg.period, candle.high() - candle.low())?,
+				)
+			} else {
+				Bounds::Relative(EnvelopeOf::new((ma, cfg.k2), src)?.with_upper(cfg.k)?)
+			},
+			prev_close: candle.close(),
+			cross: Cross::new((), (src2, src))?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.k > 0.0 && self.period > 1
+		self.k > 0.0 && self.k2 > 0.0 && (self.atr || self.k2 < 1.0) && self.period > 1
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"k" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.k = value,
 			},
+			"k2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.k2 = value,
+			},
+			"atr" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.atr = value,
+			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			"source2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source2 = value,
 			},
 
@@ -104,7 +155,21 @@ impl IndicatorConfig for Envelopes {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(3, 1)
+		(4, 2)
+	}
+}
+
+impl Channel for Envelopes {
+	fn upper(result: &IndicatorResult) -> ValueType {
+		result.value(0)
+	}
+
+	fn lower(result: &IndicatorResult) -> ValueType {
+		result.value(1)
+	}
+
+	fn middle(result: &IndicatorResult) -> ValueType {
+		result.value(3)
 	}
 }
 
@@ -113,6 +178,8 @@ impl Default for Envelopes {
 		Self {
 			period: 20,
 			k: 0.1,
+			k2: 0.1,
+			atr: false,
 			method: RegularMethods::SMA,
 			source: Source::Close,
 			source2: Source::Close,
@@ -120,13 +187,21 @@ impl Default for Envelopes {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EnvelopesInstance {
 	cfg: Envelopes,
 
-	ma: RegularMethod,
-	k_high: ValueType,
-	k_low: ValueType,
+	bounds: Bounds,
+	prev_close: ValueType,
+	cross: Cross,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Bounds {
+	Relative(EnvelopeOf<RegularMethod>),
+	Atr(RegularMethod, SMA),
 }
 
 impl IndicatorInstance for EnvelopesInstance {
@@ -136,11 +211,27 @@ impl IndicatorInstance for EnvelopesInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		match &self.bounds {
+			Bounds::Relative(envelope) => envelope.heap_size(),
+			Bounds::Atr(ma, atr) => ma.heap_size() + atr.heap_size(),
+		}
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
-		let v = self.ma.next(src);
 
-		let (value1, value2) = (v * self.k_high, v * self.k_low);
+		let (value1, v, value2) = match &mut self.bounds {
+			Bounds::Relative(envelope) => envelope.next(src),
+			Bounds::Atr(ma, atr) => {
+				let v = ma.next(src);
+				let tr = candle.tr_close(self.prev_close);
+				self.prev_close = candle.close();
+				let atr = atr.next(tr);
+
+				(atr.mul_add(self.cfg.k, v), v, atr.mul_add(-self.cfg.k2, v))
+			}
+		};
 
 		let src2 = candle.source(self.cfg.source2);
 		// let signal = if src2 < value2 {
@@ -152,7 +243,94 @@ impl IndicatorInstance for EnvelopesInstance {
 		// };
 
 		let signal = (src2 < value2) as i8 - (src2 > value1) as i8;
+		let middle_signal = self.cross.next((src2, v));
+
+		IndicatorResult::new(
+			&[value1, value2, src2, v],
+			&[Action::from(signal), middle_signal],
+		)
+	}
+}
+
+impl Checkpoint for EnvelopesInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		Option<<SMA as Checkpoint>::State>,
+		ValueType,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		let (ma, atr) = match &self.bounds {
+			Bounds::Relative(envelope) => (envelope.save(), None),
+			Bounds::Atr(ma, atr) => (ma.save(), Some(atr.save())),
+		};
+
+		(ma, atr, self.prev_close, self.cross.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		match (&mut self.bounds, &state.1) {
+			(Bounds::Relative(envelope), _) => envelope.restore(&state.0),
+			(Bounds::Atr(ma, atr), atr_state) => {
+				ma.restore(&state.0);
+				if let Some(atr_state) = atr_state {
+					atr.restore(atr_state);
+				}
+			}
+		}
+
+		self.prev_close = state.2;
+		self.cross.restore(&state.3);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Envelopes;
+	use crate::core::IndicatorConfig;
+
+	#[test]
+	fn test_envelopes_validate() {
+		let relative = |k, k2| Envelopes {
+			k,
+			k2,
+			..Envelopes::default()
+		};
+
+		assert!(relative(0.5, 0.99).validate());
+		assert!(relative(1.0, 0.1).validate());
+		assert!(relative(2.5, 0.1).validate());
+		assert!(!relative(0.0, 0.1).validate());
+		assert!(!relative(0.1, 1.0).validate());
+
+		let atr = |k, k2| Envelopes {
+			atr: true,
+			..relative(k, k2)
+		};
+
+		assert!(atr(2.0, 3.0).validate());
+		assert!(!atr(0.0, 3.0).validate());
+	}
+
+	#[test]
+	fn test_envelopes_set_error() {
+		use std::error::Error as _;
+
+		let mut cfg = Envelopes::default();
+
+		let error = cfg.set("k", "0.1.2".to_string()).unwrap_err();
+		assert!(error.source().is_some());
+		assert!(error
+			.to_string()
+			.starts_with("Unable to parse into k: \"0.1.2\": "));
+
+		let error = cfg.set("method", "unknown".to_string()).unwrap_err();
+		assert!(error.source().is_some());
 
-		IndicatorResult::new(&[value1, value2, src2], &[Action::from(signal)])
+		let error = cfg.set("unknown", "1".to_string()).unwrap_err();
+		assert!(error.source().is_none());
 	}
 }
@@ -5,7 +5,10 @@
 //! The idea is to find signals where price of timeseries crosses this config's `price` for the last `period` frames.
 
 // Some core structures and traits
-use crate::core::{Action, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{
+	Action, Checkpoint, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV,
+};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::prelude::*;
 
 // Cross method for searching crossover between price and our value
@@ -37,6 +40,10 @@ impl IndicatorConfig for Example {
 
 	const NAME: &'static str = "Example";
 
+	// Metadata is optional. It helps front-ends to present the indicator and engines to validate the data
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Other, &[ValueRange::Source]);
+
 	fn init<T: OHLCV>(self, _candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -60,7 +67,7 @@ impl IndicatorConfig for Example {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"price" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.price = value,
 			},
 
@@ -95,6 +102,7 @@ impl Default for Example {
 ///
 /// See source code for the full example
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExampleInstance {
 	cfg: Example,
 
@@ -139,3 +147,17 @@ impl IndicatorInstance for ExampleInstance {
 		IndicatorResult::new(&[candle.close()], &[signal, some_other_signal])
 	}
 }
+
+impl Checkpoint for ExampleInstance {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
@@ -0,0 +1,271 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{LowerReversalSignal, UpperReversalSignal};
+
+use super::HLC;
+
+const RETRACEMENTS: [ValueType; 5] = [0.236, 0.382, 0.5, 0.618, 0.786];
+const EXTENSIONS: [ValueType; 2] = [1.272, 1.618];
+
+/// Fibonacci Retracement
+///
+/// Builds standard Fibonacci retracement and extension levels over the latest pair of confirmed swing points.
+///
+/// Swing high is an upper reversal point of *high* prices and swing low is a lower reversal point of *low* prices
+/// (see [`UpperReversalSignal`] and [`LowerReversalSignal`]). Every swing point is confirmed `right` candles after it happens.
+///
+/// When the latest swing point is a swing high, the swing is upward: from the last swing low (`start`) to the swing high (`end`).
+/// When the latest swing point is a swing low, the swing is downward: from the last swing high (`start`) to the swing low (`end`).
+///
+/// Retracement level `k` is `end` + `k` * (`start` - `end`). Extension level `k` is `start` + `k` * (`end` - `start`).
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/f/fibonacciretracement.asp>
+/// * <https://www.investopedia.com/terms/f/fibonacciextensions.asp>
+///
+/// # 7 values
+///
+/// * `23.6%`, `38.2%`, `50%`, `61.8%` and `78.6%` retracement levels
+/// * `127.2%` and `161.8%` extension levels
+///
+/// Until the first pair of swing points appears, every level is equal to the first candle's *close* price.
+///
+/// Range of values is the same as the range of the source values.
+///
+/// # 2 signals
+///
+/// * When candle touches (its *high*-*low* range contains) any retracement level for the first time since the latest swing point,
+///   returns full buy signal for upward swing and full sell signal for downward swing.
+/// * When candle touches any extension level for the first time since the latest swing point,
+///   returns full sell signal for upward swing and full buy signal for downward swing (the swing reached its target).
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FibRetracement {
+	/// How many periods should left before swing point. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`right`\).
+	pub left: PeriodType,
+
+	/// How many periods should appear after swing point. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`left`\).
+	pub right: PeriodType,
+}
+
+impl IndicatorConfig for FibRetracement {
+	type Instance = FibRetracementInstance;
+
+	const NAME: &'static str = "FibRetracement";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			ph: UpperReversalSignal::new(cfg.left, cfg.right, candle.high())?,
+			pl: LowerReversalSignal::new(cfg.left, cfg.right, candle.low())?,
+			window: Window::new(cfg.right, HLC::from(candle)),
+			swing_high: None,
+			swing_low: None,
+			direction: 0,
+			levels: [candle.close(); 7],
+			touched: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"left" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.right = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(7, 2)
+	}
+}
+
+impl Default for FibRetracement {
+	fn default() -> Self {
+		Self { left: 5, right: 5 }
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FibRetracementInstance {
+	cfg: FibRetracement,
+
+	ph: UpperReversalSignal,
+	pl: LowerReversalSignal,
+	window: Window<HLC>,
+	swing_high: Option<ValueType>,
+	swing_low: Option<ValueType>,
+	direction: i8,
+	levels: [ValueType; 7],
+	// bit mask of the levels, already touched since the latest swing point
+	touched: u8,
+}
+
+impl FibRetracementInstance {
+	fn update_levels(&mut self) {
+		let (start, end) = match (self.swing_high, self.swing_low, self.direction) {
+			(Some(high), Some(low), 1) => (low, high),
+			(Some(high), Some(low), -1) => (high, low),
+			_ => return,
+		};
+
+		let retracements = RETRACEMENTS.iter().map(|k| k.mul_add(start - end, end));
+		let extensions = EXTENSIONS.iter().map(|k| k.mul_add(end - start, start));
+
+		self.levels
+			.iter_mut()
+			.zip(retracements.chain(extensions))
+			.for_each(|(level, value)| *level = value);
+
+		self.touched = 0;
+	}
+}
+
+impl IndicatorInstance for FibRetracementInstance {
+	type Config = FibRetracement;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ph.heap_size() + self.pl.heap_size() + self.window.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let past_candle = self.window.push(HLC::from(candle));
+
+		let is_swing_high = self.ph.next(candle.high()).analog() > 0;
+		let is_swing_low = self.pl.next(candle.low()).analog() > 0;
+
+		if is_swing_high {
+			self.swing_high = Some(past_candle.high());
+			self.direction = 1;
+		}
+
+		if is_swing_low {
+			self.swing_low = Some(past_candle.low());
+			self.direction = -1;
+		}
+
+		if is_swing_high || is_swing_low {
+			self.update_levels();
+		}
+
+		let mut retracement_touch = false;
+		let mut extension_touch = false;
+
+		if self.swing_high.is_some() && self.swing_low.is_some() {
+			for (i, &level) in self.levels.iter().enumerate() {
+				let mask = 1 << i;
+
+				if self.touched & mask == 0 && candle.low() <= level && level <= candle.high() {
+					self.touched |= mask;
+
+					if i < RETRACEMENTS.len() {
+						retracement_touch = true;
+					} else {
+						extension_touch = true;
+					}
+				}
+			}
+		}
+
+		let retracement_signal = if retracement_touch {
+			Action::from(self.direction)
+		} else {
+			Action::None
+		};
+
+		let extension_signal = if extension_touch {
+			Action::from(-self.direction)
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&self.levels, &[retracement_signal, extension_signal])
+	}
+}
+
+impl Checkpoint for FibRetracementInstance {
+	type State = (
+		<UpperReversalSignal as Checkpoint>::State,
+		<LowerReversalSignal as Checkpoint>::State,
+		WindowCheckpoint<HLC>,
+		Option<ValueType>,
+		Option<ValueType>,
+		i8,
+		[ValueType; 7],
+		u8,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ph.save(),
+			self.pl.save(),
+			self.window.save(),
+			self.swing_high,
+			self.swing_low,
+			self.direction,
+			self.levels,
+			self.touched,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ph.restore(&state.0);
+		self.pl.restore(&state.1);
+		self.window.restore(&state.2);
+		self.swing_high = state.3;
+		self.swing_low = state.4;
+		self.direction = state.5;
+		self.levels = state.6;
+		self.touched = state.7;
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, Highest, Lowest};
 
@@ -57,6 +58,11 @@ impl IndicatorConfig for FisherTransform {
 
 	const NAME: &'static str = "FisherTransform";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -84,23 +90,23 @@ impl IndicatorConfig for FisherTransform {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -129,7 +135,8 @@ impl Default for FisherTransform {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FisherTransformInstance {
 	cfg: FisherTransform,
 
@@ -156,6 +163,10 @@ impl IndicatorInstance for FisherTransformInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma1.heap_size() + self.highest.heap_size() + self.lowest.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -203,10 +214,47 @@ impl IndicatorInstance for FisherTransformInstance {
 
 		let s2 = signal_line / self.cfg.zone
 			* ((signal_line < 0.0 && self.last_reverse > 0 && crossed_ma > 0)
-				|| (signal_line > 0.0 && self.last_reverse < 0 && crossed_ma < 0)) as i8 as ValueType;
+				|| (signal_line > 0.0 && self.last_reverse < 0 && crossed_ma < 0)) as i8
+				as ValueType;
 
 		self.prev_value = cumulative;
 
 		IndicatorResult::new(&[cumulative, signal_line], &[s1.into(), s2.into()])
 	}
 }
+
+impl Checkpoint for FisherTransformInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		ValueType,
+		i8,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma1.save(),
+			self.highest.save(),
+			self.lowest.save(),
+			self.cross.save(),
+			self.cross_ma.save(),
+			self.prev_value,
+			self.last_reverse,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma1.restore(&state.0);
+		self.highest.restore(&state.1);
+		self.lowest.restore(&state.2);
+		self.cross.restore(&state.3);
+		self.cross_ma.restore(&state.4);
+		self.prev_value = state.5;
+		self.last_reverse = state.6;
+	}
+}
@@ -0,0 +1,252 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::methods::EMA;
+
+const FAST: [PeriodType; 6] = [3, 5, 8, 10, 12, 15];
+const SLOW: [PeriodType; 6] = [30, 35, 40, 45, 50, 60];
+
+/// Guppy Multiple Moving Averages
+///
+/// Two groups of [`EMA`]s: fast group of periods `3`, `5`, `8`, `10`, `12`, `15` (traders)
+/// and slow group of periods `30`, `35`, `40`, `45`, `50`, `60` (investors).
+///
+/// Spread of a group is the distance between its highest and lowest EMAs.
+/// Group is *expanding* when its spread grows and *compressing* when its spread shrinks.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/guppy-multiple-moving-average.asp>
+///
+/// # 3 values
+///
+/// * `fast spread` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `slow spread` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `separation` value: average of the fast group minus average of the slow group
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// When `lines` is `true`, values of all the `12` EMAs (first fast group, then slow group) are returned after these `3` values,
+/// so there are `15` values total. Range of these values is the same as the range of the `source` values.
+///
+/// # 2 signals
+///
+/// * When both groups start expanding and the fast group is above the slow group, returns full buy signal.
+///   When both groups start expanding and the fast group is below the slow group, returns full sell signal.
+///   Otherwise returns no signal. Signal may appear again only after any of the groups compresses.
+///
+/// * When every EMA of the fast group becomes higher than every EMA of the slow group, returns full buy signal.
+///   When every EMA of the fast group becomes lower than every EMA of the slow group, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GMMA {
+	/// Return values of all the EMAs after the spreads. Default is `false`.
+	pub lines: bool,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for GMMA {
+	type Instance = GMMAInstance;
+
+	const NAME: &'static str = "GMMA";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Bounded(0.0, ValueType::INFINITY),
+			ValueRange::Bounded(0.0, ValueType::INFINITY),
+			ValueRange::Unbounded,
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		let mut fast = [EMA::new(FAST[0], src)?; 6];
+		let mut slow = [EMA::new(SLOW[0], src)?; 6];
+
+		for (ema, &period) in fast.iter_mut().zip(FAST.iter()) {
+			*ema = EMA::new(period, src)?;
+		}
+
+		for (ema, &period) in slow.iter_mut().zip(SLOW.iter()) {
+			*ema = EMA::new(period, src)?;
+		}
+
+		Ok(Self::Instance {
+			fast,
+			slow,
+			fast_spread: 0.0,
+			slow_spread: 0.0,
+			expansion: 0,
+			side: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		true
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"lines" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.lines = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		if self.lines {
+			(15, 2)
+		} else {
+			(3, 2)
+		}
+	}
+}
+
+impl Default for GMMA {
+	fn default() -> Self {
+		Self {
+			lines: false,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`GMMA`]
+pub type GuppyMultipleMovingAverages = GMMA;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GMMAInstance {
+	cfg: GMMA,
+
+	fast: [EMA; 6],
+	slow: [EMA; 6],
+	fast_spread: ValueType,
+	slow_spread: ValueType,
+	expansion: i8,
+	side: i8,
+}
+
+/// Pushes `value` into every EMA of the group, writes outputs into `lines` and returns (`min`, `max`, `sum`) of the outputs
+fn next_group(
+	group: &mut [EMA; 6],
+	lines: &mut [ValueType],
+	value: ValueType,
+) -> (ValueType, ValueType, ValueType) {
+	group.iter_mut().zip(lines.iter_mut()).fold(
+		(ValueType::INFINITY, ValueType::NEG_INFINITY, 0.0),
+		|(min, max, sum), (ema, line)| {
+			*line = ema.next(value);
+			(min.min(*line), max.max(*line), sum + *line)
+		},
+	)
+}
+
+impl IndicatorInstance for GMMAInstance {
+	type Config = GMMA;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let mut values = [0.0; 15];
+		let (lines_fast, lines_slow) = values[3..].split_at_mut(6);
+
+		let (fast_min, fast_max, fast_sum) = next_group(&mut self.fast, lines_fast, src);
+		let (slow_min, slow_max, slow_sum) = next_group(&mut self.slow, lines_slow, src);
+
+		let fast_spread = fast_max - fast_min;
+		let slow_spread = slow_max - slow_min;
+		let separation = (fast_sum - slow_sum) / 6.0;
+
+		let is_expanding = fast_spread > self.fast_spread && slow_spread > self.slow_spread;
+		self.fast_spread = fast_spread;
+		self.slow_spread = slow_spread;
+
+		let expansion = if !is_expanding || separation == 0.0 {
+			0
+		} else if separation > 0.0 {
+			1
+		} else {
+			-1
+		};
+
+		let expansion_signal = if expansion != 0 && expansion != self.expansion {
+			Action::from(expansion)
+		} else {
+			Action::None
+		};
+		self.expansion = expansion;
+
+		let side = if fast_min > slow_max {
+			1
+		} else if fast_max < slow_min {
+			-1
+		} else {
+			self.side
+		};
+
+		let side_signal = if side == self.side {
+			Action::None
+		} else {
+			Action::from(side)
+		};
+		self.side = side;
+
+		values[0] = fast_spread;
+		values[1] = slow_spread;
+		values[2] = separation;
+
+		let length = if self.cfg.lines { 15 } else { 3 };
+
+		IndicatorResult::new(&values[..length], &[expansion_signal, side_signal])
+	}
+}
+
+impl Checkpoint for GMMAInstance {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
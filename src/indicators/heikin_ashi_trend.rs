@@ -0,0 +1,175 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::methods::HeikinAshi;
+
+/// Heikin Ashi Trend
+///
+/// Converts candles by [`HeikinAshi`] method and tracks color of the converted candles.
+/// Candle is bullish (green) when its *close* is greater than *open* and bearish (red) when its *close* is less than *open*.
+/// Candles with equal *open* and *close* do not change the color.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Candlestick_chart#Heikin-Ashi_candlesticks>
+/// * <https://www.investopedia.com/trading/heikin-ashi-better-candlestick/>
+///
+/// # 2 values
+///
+/// * `color` value
+///
+/// `1.0` for bullish, `-1.0` for bearish candles and `0.0` until the first colored candle appears.
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `count` of consecutive candles of the same color, including the current one
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// # 2 signals
+///
+/// * When candles color changes to bullish, gives full positive signal.
+///   When candles color changes to bearish, gives full negative signal.
+///
+/// * When `count` of consecutive candles of the same color reaches `period`, gives full signal of the color's direction.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeikinAshiTrend {
+	/// Count of consecutive candles of the same color, which confirms the trend. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for HeikinAshiTrend {
+	type Instance = HeikinAshiTrendInstance;
+
+	const NAME: &'static str = "HeikinAshiTrend";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Bounded(0.0, ValueType::INFINITY),
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			heikin_ashi: HeikinAshi::new((), candle)?,
+			color: 0,
+			count: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for HeikinAshiTrend {
+	fn default() -> Self {
+		Self { period: 3 }
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeikinAshiTrendInstance {
+	cfg: HeikinAshiTrend,
+
+	heikin_ashi: HeikinAshi,
+	color: i8,
+	count: PeriodType,
+}
+
+impl IndicatorInstance for HeikinAshiTrendInstance {
+	type Config = HeikinAshiTrend;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let ha = self.heikin_ashi.next(candle);
+
+		let color = if ha.close() > ha.open() {
+			1
+		} else if ha.close() < ha.open() {
+			-1
+		} else {
+			self.color
+		};
+
+		let color_signal = if color == self.color {
+			self.count = self.count.saturating_add(PeriodType::from(color != 0));
+			Action::None
+		} else {
+			// the very first colored candle is not a color change
+			let signal = if self.color == 0 {
+				Action::None
+			} else {
+				color.into()
+			};
+
+			self.count = 1;
+			signal
+		};
+
+		self.color = color;
+
+		let trend_signal = if self.count == self.cfg.period {
+			color.into()
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(
+			&[color.into(), self.count as ValueType],
+			&[color_signal, trend_signal],
+		)
+	}
+}
+
+impl Checkpoint for HeikinAshiTrendInstance {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::methods::{ReversalSignal, HMA};
 
 /// Hull Moving Average indicator
@@ -49,6 +50,9 @@ impl IndicatorConfig for HullMovingAverage {
 
 	const NAME: &'static str = "HullMovingAverage";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Trend, &[ValueRange::Source]);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -74,19 +78,19 @@ impl IndicatorConfig for HullMovingAverage {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"left" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.left = value,
 			},
 			"right" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.right = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -115,6 +119,7 @@ impl Default for HullMovingAverage {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HullMovingAverageInstance {
 	cfg: HullMovingAverage,
 
@@ -129,6 +134,10 @@ impl IndicatorInstance for HullMovingAverageInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.hma.heap_size() + self.pivot.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let value = self.hma.next(candle.source(self.cfg.source));
 		let signal = self.pivot.next(value);
@@ -136,3 +145,21 @@ impl IndicatorInstance for HullMovingAverageInstance {
 		IndicatorResult::new(&[value], &[signal])
 	}
 }
+
+impl Checkpoint for HullMovingAverageInstance {
+	type State = (
+		<HMA as Checkpoint>::State,
+		<ReversalSignal as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.hma.save(), self.pivot.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.hma.restore(&state.0);
+		self.pivot.restore(&state.1);
+	}
+}
@@ -2,6 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{Cross, Highest, Lowest};
 
@@ -63,6 +65,16 @@ impl IndicatorConfig for IchimokuCloud {
 
 	const NAME: &'static str = "IchimokuCloud";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -91,23 +103,23 @@ impl IndicatorConfig for IchimokuCloud {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"l1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.l1 = value,
 			},
 			"l2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.l2 = value,
 			},
 			"l3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.l3 = value,
 			},
 			"m" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.m = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -137,6 +149,7 @@ impl Default for IchimokuCloud {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IchimokuCloudInstance {
 	cfg: IchimokuCloud,
 
@@ -159,6 +172,17 @@ impl IndicatorInstance for IchimokuCloudInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.highest1.heap_size()
+			+ self.highest2.heap_size()
+			+ self.highest3.heap_size()
+			+ self.lowest1.heap_size()
+			+ self.lowest2.heap_size()
+			+ self.lowest3.heap_size()
+			+ self.window1.heap_size()
+			+ self.window2.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let (high, low) = (candle.high(), candle.low());
@@ -209,3 +233,48 @@ impl IndicatorInstance for IchimokuCloudInstance {
 		)
 	}
 }
+
+impl Checkpoint for IchimokuCloudInstance {
+	type State = (
+		<Highest as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		WindowCheckpoint<ValueType>,
+		WindowCheckpoint<ValueType>,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.highest1.save(),
+			self.highest2.save(),
+			self.highest3.save(),
+			self.lowest1.save(),
+			self.lowest2.save(),
+			self.lowest3.save(),
+			self.window1.save(),
+			self.window2.save(),
+			self.cross1.save(),
+			self.cross2.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest1.restore(&state.0);
+		self.highest2.restore(&state.1);
+		self.highest3.restore(&state.2);
+		self.lowest1.restore(&state.3);
+		self.lowest2.restore(&state.4);
+		self.lowest3.restore(&state.5);
+		self.window1.restore(&state.6);
+		self.window2.restore(&state.7);
+		self.cross1.restore(&state.8);
+		self.cross2.restore(&state.9);
+	}
+}
@@ -0,0 +1,188 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{Cross, ADI};
+
+/// Order-flow Imbalance proxy
+///
+/// Approximates buying and selling pressure from candles only, so it may be used when there is no tick data.
+/// Every candle's volume is split into buying and selling parts by the close position in the candle's range:
+/// the delta of the candle is [`clv`](crate::core::OHLCV::clv) × `volume`.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Accumulation/distribution_index>
+///
+/// # 2 values
+///
+/// * `imbalance` value
+///
+/// Smoothed delta divided by smoothed volume. Positive values mean buying pressure, negative values mean selling pressure.
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `cumulative` value
+///
+/// Cumulative delta over the last `window` candles or over all the candles when `window` is `0`.
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// * When `imbalance` value goes above zero, then returns full buy signal.
+///   When `imbalance` value goes below zero, then returns full sell signal.
+///   Otherwise no signal
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Imbalance {
+	/// Smoothing period for delta and volume. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Smoothing method for delta and volume. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+	/// Cumulative delta window size. Default is `0` (windowless).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub window: PeriodType,
+}
+
+impl IndicatorConfig for Imbalance {
+	type Instance = ImbalanceInstance;
+
+	const NAME: &'static str = "Imbalance";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volume,
+		&[ValueRange::Bounded(-1.0, 1.0), ValueRange::Unbounded],
+	)
+	.with_volume();
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		Self::METADATA.check(candle)?;
+
+		let cfg = self;
+		let volume = candle.volume();
+
+		Ok(Self::Instance {
+			delta_ma: method(cfg.method, cfg.period, candle.clv() * volume)?,
+			volume_ma: method(cfg.method, cfg.period, volume)?,
+			adi: ADI::new(cfg.window, &candle)?,
+			cross_over: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.window < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+			"window" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.window = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for Imbalance {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			method: RegularMethods::EMA,
+			window: 0,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ImbalanceInstance {
+	cfg: Imbalance,
+
+	delta_ma: RegularMethod,
+	volume_ma: RegularMethod,
+	adi: ADI,
+	cross_over: Cross,
+}
+
+impl IndicatorInstance for ImbalanceInstance {
+	type Config = Imbalance;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.delta_ma.heap_size() + self.volume_ma.heap_size() + self.adi.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let volume = candle.volume_or_zero();
+
+		let delta = self.delta_ma.next(candle.clv() * volume);
+		let volume = self.volume_ma.next(volume);
+
+		let imbalance = if volume > 0.0 { delta / volume } else { 0.0 };
+		let cumulative = self.adi.next(candle);
+
+		let signal = self.cross_over.next((imbalance, 0.));
+
+		IndicatorResult::new(&[imbalance, cumulative], &[signal])
+	}
+}
+
+impl Checkpoint for ImbalanceInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<ADI as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.delta_ma.save(),
+			self.volume_ma.save(),
+			self.adi.save(),
+			self.cross_over.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.delta_ma.restore(&state.0);
+		self.volume_ma.restore(&state.1);
+		self.adi.restore(&state.2);
+		self.cross_over.restore(&state.3);
+	}
+}
@@ -2,7 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::methods::{Change, Cross, LinearVolatility, StDev};
 
 /// Kaufman Adaptive Moving Average (KAMA)
@@ -68,6 +69,9 @@ impl IndicatorConfig for Kaufman {
 
 	const NAME: &'static str = "Kaufman";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Trend, &[ValueRange::Source]);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -100,31 +104,31 @@ impl IndicatorConfig for Kaufman {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"filter_period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.filter_period = value,
 			},
 			"square_smooth" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.square_smooth = value,
 			},
 			"k" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.k = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -155,6 +159,7 @@ impl Default for Kaufman {
 	}
 }
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KaufmanInstance {
 	cfg: Kaufman,
 
@@ -176,6 +181,10 @@ impl IndicatorInstance for KaufmanInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.volatility.heap_size() + self.change.heap_size() + self.st_dev.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -221,3 +230,39 @@ impl IndicatorInstance for KaufmanInstance {
 		IndicatorResult::new(&[value], &[signal])
 	}
 }
+
+impl Checkpoint for KaufmanInstance {
+	type State = (
+		<LinearVolatility as Checkpoint>::State,
+		<Change as Checkpoint>::State,
+		<StDev as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		Action,
+		ValueType,
+		ValueType,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.volatility.save(),
+			self.change.save(),
+			self.st_dev.save(),
+			self.cross.save(),
+			self.last_signal,
+			self.last_signal_value,
+			self.prev_value,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.volatility.restore(&state.0);
+		self.change.restore(&state.1);
+		self.st_dev.restore(&state.2);
+		self.cross.restore(&state.3);
+		self.last_signal = state.4;
+		self.last_signal_value = state.5;
+		self.prev_value = state.6;
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Channel, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{CrossAbove, CrossUnder, SMA};
 
@@ -14,11 +15,14 @@ use crate::methods::{CrossAbove, CrossUnder, SMA};
 ///
 /// # 3 values
 ///
+/// * `source` value
+///
+/// Raw `source` value of the candle. Middle line of the channel is exactly between `upper bound` and `lower bound`.
+///
 /// * `upper bound`
 ///
 /// Range of values is the same as the range of the `source` values.
 ///
-/// * `source` value
 /// * `lower bound`
 ///
 /// Range of values is the same as the range of the `source` values.
@@ -53,6 +57,11 @@ impl IndicatorConfig for KeltnerChannel {
 
 	const NAME: &'static str = "KeltnerChannel";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volatility,
+		&[ValueRange::Source, ValueRange::Source, ValueRange::Source],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -77,19 +86,19 @@ impl IndicatorConfig for KeltnerChannel {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"sigma" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sigma = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -106,6 +115,16 @@ impl IndicatorConfig for KeltnerChannel {
 	}
 }
 
+impl Channel for KeltnerChannel {
+	fn upper(result: &IndicatorResult) -> ValueType {
+		result.value(1)
+	}
+
+	fn lower(result: &IndicatorResult) -> ValueType {
+		result.value(2)
+	}
+}
+
 impl Default for KeltnerChannel {
 	fn default() -> Self {
 		Self {
@@ -117,7 +136,8 @@ impl Default for KeltnerChannel {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KeltnerChannelInstance {
 	cfg: KeltnerChannel,
 
@@ -135,6 +155,10 @@ impl IndicatorInstance for KeltnerChannelInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size() + self.sma.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
 		let tr = candle.tr_close(self.prev_close);
@@ -152,3 +176,33 @@ impl IndicatorInstance for KeltnerChannelInstance {
 		IndicatorResult::new(&[source, upper, lower], &[signal])
 	}
 }
+
+impl Checkpoint for KeltnerChannelInstance {
+	type State = (
+		ValueType,
+		<RegularMethod as Checkpoint>::State,
+		<SMA as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.prev_close,
+			self.ma.save(),
+			self.sma.save(),
+			self.cross_above.save(),
+			self.cross_under.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.prev_close = state.0;
+		self.ma.restore(&state.1);
+		self.sma.restore(&state.2);
+		self.cross_above.restore(&state.3);
+		self.cross_under.restore(&state.4);
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, sign, RegularMethod, RegularMethods};
 use crate::methods::Cross;
 
@@ -56,11 +57,19 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 
 	const NAME: &'static str = "KlingerVolumeOscillator";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volume,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	)
+	.with_volume();
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
+		Self::METADATA.check(candle)?;
+
 		let cfg = self;
 		Ok(Self::Instance {
 			ma1: method(cfg.method1, cfg.period1, 0.)?,
@@ -80,23 +89,23 @@ impl IndicatorConfig for KlingerVolumeOscillator {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"method1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method1 = value,
 			},
 			"method2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method2 = value,
 			},
 
@@ -125,7 +134,8 @@ impl Default for KlingerVolumeOscillator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KlingerVolumeOscillatorInstance {
 	cfg: KlingerVolumeOscillator,
 
@@ -144,6 +154,10 @@ impl IndicatorInstance for KlingerVolumeOscillatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma1.heap_size() + self.ma2.heap_size() + self.ma3.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let tp = candle.tp();
 
@@ -158,7 +172,7 @@ impl IndicatorInstance for KlingerVolumeOscillatorInstance {
 		// 	0.
 		// };
 
-		let vol = sign(d) * candle.volume();
+		let vol = sign(d) * candle.volume_or_zero();
 
 		let ma1: ValueType = self.ma1.next(vol);
 		let ma2: ValueType = self.ma2.next(vol);
@@ -172,3 +186,36 @@ impl IndicatorInstance for KlingerVolumeOscillatorInstance {
 		IndicatorResult::new(&[ko, ma3], &[s1, s2])
 	}
 }
+
+impl Checkpoint for KlingerVolumeOscillatorInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		ValueType,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma1.save(),
+			self.ma2.save(),
+			self.ma3.save(),
+			self.cross1.save(),
+			self.cross2.save(),
+			self.last_tp,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma1.restore(&state.0);
+		self.ma2.restore(&state.1);
+		self.ma3.restore(&state.2);
+		self.cross1.restore(&state.3);
+		self.cross2.restore(&state.4);
+		self.last_tp = state.5;
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, RateOfChange};
 
@@ -12,6 +13,11 @@ use crate::methods::{Cross, RateOfChange};
 ///
 /// * <https://en.wikipedia.org/wiki/KST_oscillator>
 ///
+/// `KST` is the weighted sum of four smoothed ROCs: `weight1` * `MA(ROC1)` + `weight2` * `MA(ROC2)` + `weight3` * `MA(ROC3)` + `weight4` * `MA(ROC4)`.
+///
+/// ROC periods may be in any order and weights may be any finite numbers, so published variants of `KST`
+/// (f.e. with uniform weights or with different short-term/long-term ROC sets) may be configured.
+///
 /// # 2 values
 ///
 /// * `KST` value
@@ -31,29 +37,57 @@ use crate::methods::{Cross, RateOfChange};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KnowSureThing {
 	/// ROC1 period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period1: PeriodType,
 
 	/// ROC2 period. Default is `15`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period2: PeriodType,
 
 	/// ROC3 period. Default is `20`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period3: PeriodType,
 
 	/// ROC4 period. Default is `30`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period4: PeriodType,
 
 	/// ROC1 moving average period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub sma1: PeriodType,
 
 	/// ROC2 moving average period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub sma2: PeriodType,
 
 	/// ROC3 moving average period. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub sma3: PeriodType,
 
 	/// ROC4 moving average period. Default is `15`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub sma4: PeriodType,
 
+	/// ROC1 line weight. Default is `1.0`.
+	pub weight1: ValueType,
+
+	/// ROC2 line weight. Default is `2.0`.
+	pub weight2: ValueType,
+
+	/// ROC3 line weight. Default is `3.0`.
+	pub weight3: ValueType,
+
+	/// ROC4 line weight. Default is `4.0`.
+	pub weight4: ValueType,
+
 	/// ROCs lines moving average type. Defual is [`SMA`](crate::methods::SMA).
 	pub method1: RegularMethods,
 
@@ -69,6 +103,11 @@ impl IndicatorConfig for KnowSureThing {
 
 	const NAME: &'static str = "KnowSureThing";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -93,53 +132,85 @@ impl IndicatorConfig for KnowSureThing {
 	}
 
 	fn validate(&self) -> bool {
-		self.period1 < self.period2 && self.period2 < self.period3 && self.period3 < self.period4
+		let periods = [
+			self.period1,
+			self.period2,
+			self.period3,
+			self.period4,
+			self.sma1,
+			self.sma2,
+			self.sma3,
+			self.sma4,
+			self.sma5,
+		];
+		let weights = [self.weight1, self.weight2, self.weight3, self.weight4];
+
+		periods
+			.iter()
+			.all(|&period| period > 0 && period < PeriodType::MAX)
+			&& weights.iter().all(|weight| weight.is_finite())
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"period4" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period4 = value,
 			},
 			"sma1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sma1 = value,
 			},
 			"sma2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sma2 = value,
 			},
 			"sma3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sma3 = value,
 			},
 			"sma4" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sma4 = value,
 			},
 			"sma5" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sma5 = value,
 			},
+			"weight1" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.weight1 = value,
+			},
+			"weight2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.weight2 = value,
+			},
+			"weight3" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.weight3 = value,
+			},
+			"weight4" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.weight4 = value,
+			},
 			"method1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method1 = value,
 			},
 			"method2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method2 = value,
 			},
 
@@ -168,13 +239,18 @@ impl Default for KnowSureThing {
 			sma3: 10,
 			sma4: 15,
 			sma5: 9,
+			weight1: 1.,
+			weight2: 2.,
+			weight3: 3.,
+			weight4: 4.,
 			method1: RegularMethods::SMA,
 			method2: RegularMethods::SMA,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KnowSureThingInstance {
 	cfg: KnowSureThing,
 
@@ -197,6 +273,18 @@ impl IndicatorInstance for KnowSureThingInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.roc1v.heap_size()
+			+ self.roc2v.heap_size()
+			+ self.roc3v.heap_size()
+			+ self.roc4v.heap_size()
+			+ self.ma1.heap_size()
+			+ self.ma2.heap_size()
+			+ self.ma3.heap_size()
+			+ self.ma4.heap_size()
+			+ self.ma5.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let close = candle.close();
 
@@ -210,7 +298,13 @@ impl IndicatorInstance for KnowSureThingInstance {
 		let rcma3: ValueType = self.ma3.next(roc3);
 		let rcma4: ValueType = self.ma4.next(roc4);
 
-		let kst = rcma2.mul_add(2., rcma1) + rcma3.mul_add(3., rcma4 * 4.);
+		let kst = rcma1.mul_add(
+			self.cfg.weight1,
+			rcma2.mul_add(
+				self.cfg.weight2,
+				rcma3.mul_add(self.cfg.weight3, rcma4 * self.cfg.weight4),
+			),
+		);
 		let sl: ValueType = self.ma5.next(kst);
 
 		let signal = self.cross.next((kst, sl));
@@ -218,3 +312,89 @@ impl IndicatorInstance for KnowSureThingInstance {
 		IndicatorResult::new(&[kst, sl], &[signal])
 	}
 }
+
+impl Checkpoint for KnowSureThingInstance {
+	type State = (
+		<RateOfChange as Checkpoint>::State,
+		<RateOfChange as Checkpoint>::State,
+		<RateOfChange as Checkpoint>::State,
+		<RateOfChange as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.roc1v.save(),
+			self.roc2v.save(),
+			self.roc3v.save(),
+			self.roc4v.save(),
+			self.ma1.save(),
+			self.ma2.save(),
+			self.ma3.save(),
+			self.ma4.save(),
+			self.ma5.save(),
+			self.cross.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.roc1v.restore(&state.0);
+		self.roc2v.restore(&state.1);
+		self.roc3v.restore(&state.2);
+		self.roc4v.restore(&state.3);
+		self.ma1.restore(&state.4);
+		self.ma2.restore(&state.5);
+		self.ma3.restore(&state.6);
+		self.ma4.restore(&state.7);
+		self.ma5.restore(&state.8);
+		self.cross.restore(&state.9);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::KnowSureThing;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_know_sure_thing_weights() {
+		let cfg = KnowSureThing {
+			period1: 3,
+			period2: 1,
+			period3: 2,
+			period4: 1,
+			sma1: 1,
+			sma2: 1,
+			sma3: 1,
+			sma4: 1,
+			sma5: 1,
+			weight1: 0.5,
+			weight2: -1.0,
+			weight3: 2.0,
+			weight4: 0.25,
+			..KnowSureThing::default()
+		};
+
+		let candle = |close: ValueType| Candle {
+			close,
+			..Candle::default()
+		};
+
+		let mut instance = cfg.init(&candle(1.0)).unwrap();
+
+		// ROCs of periods 3, 1, 2 and 1 are (1, 1, 1, 1), (3, 1, 3, 1) and (7, 1, 3, 1)
+		for &(close, kst) in &[(2.0, 1.75), (4.0, 6.75), (8.0, 8.75)] {
+			let result = instance.next(&candle(close));
+			assert_eq_float(kst, result.value(0));
+			assert_eq_float(kst, result.value(1));
+		}
+	}
+}
@@ -0,0 +1,223 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods, MA};
+use crate::methods::Cross;
+
+/// Moving Average Cross
+///
+/// Classic strategy based on crosses of the fast and the slow moving averages.
+/// Fast MA crossing slow MA upwards is a *golden cross*, fast MA crossing slow MA downwards is a *death cross*.
+///
+/// Any [regular method](crate::helpers::RegularMethods) may be used for both of the moving averages,
+/// and any price may be used as a source (f.e. [`TP`](crate::core::Source::TP) or [`HL2`](crate::core::Source::HL2)).
+/// Method and period of each MA may be set at once by [`set`](IndicatorConfig::set) with `ma1` or `ma2` name
+/// and an [`MA`](crate::helpers::MA) string (f.e. `ema-20`).
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/goldencross.asp>
+/// * <https://www.investopedia.com/terms/d/deathcross.asp>
+///
+/// # 2 values
+///
+/// * `fast MA` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `slow MA` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When golden cross happens and `fast MA` stays above `slow MA` for `confirmation` candles after the cross, returns full buy signal.
+///   When death cross happens and `fast MA` stays below `slow MA` for `confirmation` candles after the cross, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MACross {
+	/// Fast MA period. Default is `50`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period1: PeriodType,
+
+	/// Fast MA type. Default is [`SMA`](crate::methods::SMA).
+	pub method1: RegularMethods,
+
+	/// Slow MA period. Default is `200`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// Slow MA type. Default is [`SMA`](crate::methods::SMA).
+	pub method2: RegularMethods,
+
+	/// Count of candles, for which `fast MA` must stay on the same side of `slow MA` after the cross. Default is `0`.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub confirmation: PeriodType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for MACross {
+	type Instance = MACrossInstance;
+
+	const NAME: &'static str = "MACross";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Source, ValueRange::Source],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma1: method(cfg.method1, cfg.period1, src)?,
+			ma2: method(cfg.method2, cfg.period2, src)?,
+			cross: Cross::new((), (src, src))?.with_confirmation(cfg.confirmation),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period1 > 0
+			&& self.period1 < PeriodType::MAX
+			&& self.period2 > 0
+			&& self.period2 < PeriodType::MAX
+			&& self.confirmation < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period1 = value,
+			},
+			"method1" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period2 = value,
+			},
+			"method2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method2 = value,
+			},
+			"confirmation" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.confirmation = value,
+			},
+			"ma1" => match value.parse::<MA>() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => {
+					self.method1 = value.method;
+					self.period1 = value.period;
+				}
+			},
+			"ma2" => match value.parse::<MA>() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => {
+					self.method2 = value.method;
+					self.period2 = value.period;
+				}
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for MACross {
+	fn default() -> Self {
+		Self {
+			period1: 50,
+			method1: RegularMethods::SMA,
+			period2: 200,
+			method2: RegularMethods::SMA,
+			confirmation: 0,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`MACross`]
+pub type MovingAverageCross = MACross;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MACrossInstance {
+	cfg: MACross,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	cross: Cross,
+}
+
+impl IndicatorInstance for MACrossInstance {
+	type Config = MACross;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma1.heap_size() + self.ma2.heap_size()
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let fast = self.ma1.next(src);
+		let slow = self.ma2.next(src);
+		let signal = self.cross.next((fast, slow));
+
+		IndicatorResult::new(&[fast, slow], &[signal])
+	}
+}
+
+impl Checkpoint for MACrossInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.ma1.save(), self.ma2.save(), self.cross.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma1.restore(&state.0);
+		self.ma2.restore(&state.1);
+		self.cross.restore(&state.2);
+	}
+}
@@ -0,0 +1,243 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use std::mem::size_of;
+
+/// Maximum count of lines of [`MARibbon`], limited by the size of [`IndicatorResult`]
+const MAX_LINES: usize = 15;
+
+/// Moving Average Ribbon
+///
+/// A set of moving averages (lines) of different types and periods, ordered from the fastest to the slowest one.
+///
+/// Alignment score shows how well the lines are ordered.
+/// For every pair of adjacent lines it counts `+1` when the faster line is above the slower one and `-1` when it is below.
+/// The sum is divided by the count of pairs, so `1.0` means every line is above the next slower line (fully bullish ribbon)
+/// and `-1.0` means every line is below the next slower line (fully bearish ribbon).
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/m/movingaverageribbon.asp>
+///
+/// # 1 + `lines.len()` values
+///
+/// * `alignment` score
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * values of the lines in the same order as `lines`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When ribbon flips to fully bullish (`alignment` becomes `1.0`), returns full buy signal.
+///   When ribbon flips to fully bearish (`alignment` becomes `-1.0`), returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MARibbon {
+	/// Moving averages types and periods, from the fastest to the slowest one.
+	/// Default is [`EMA`](crate::methods::EMA)s of periods `10`, `20` and `50`.
+	///
+	/// Count of lines should be in range \[`2`; `15`\].
+	/// Every period should be in range \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	///
+	/// When set by [`set`](IndicatorConfig::set), lines are comma separated pairs of `method:period`, f.e. `"ema:10,ema:20,sma:50"`.
+	pub lines: Vec<(RegularMethods, PeriodType)>,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl MARibbon {
+	fn parse_lines(value: &str) -> Option<Vec<(RegularMethods, PeriodType)>> {
+		value
+			.split(',')
+			.map(|line| {
+				let mut parts = line.split(':');
+				let method = parts.next()?.parse().ok()?;
+				let period = parts.next()?.trim().parse().ok()?;
+
+				if parts.next().is_some() {
+					None
+				} else {
+					Some((method, period))
+				}
+			})
+			.collect()
+	}
+}
+
+impl IndicatorConfig for MARibbon {
+	type Instance = MARibbonInstance;
+
+	const NAME: &'static str = "MARibbon";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			mas: cfg
+				.lines
+				.iter()
+				.map(|&(m, period)| method(m, period, src))
+				.collect::<Result<_, _>>()?,
+			values: vec![0.0; cfg.lines.len() + 1],
+			last_alignment: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.lines.len() >= 2
+			&& self.lines.len() <= MAX_LINES
+			&& self
+				.lines
+				.iter()
+				.all(|&(_, period)| period > 0 && period < PeriodType::MAX)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"lines" => match Self::parse_lines(&value) {
+				None => return Err(Error::ParameterParse(name.to_string(), value)),
+				Some(value) => self.lines = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	// count of lines is limited by `MAX_LINES` in `validate`
+	#[allow(clippy::cast_possible_truncation)]
+	fn size(&self) -> (u8, u8) {
+		(self.lines.len() as u8 + 1, 1)
+	}
+}
+
+impl Default for MARibbon {
+	fn default() -> Self {
+		Self {
+			lines: vec![
+				(RegularMethods::EMA, 10),
+				(RegularMethods::EMA, 20),
+				(RegularMethods::EMA, 50),
+			],
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`MARibbon`]
+pub type MovingAverageRibbon = MARibbon;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MARibbonInstance {
+	cfg: MARibbon,
+
+	mas: Vec<RegularMethod>,
+	values: Vec<ValueType>,
+	last_alignment: i8,
+}
+
+impl IndicatorInstance for MARibbonInstance {
+	type Config = MARibbon;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.cfg.lines.len() * size_of::<(RegularMethods, PeriodType)>()
+			+ self.values.len() * size_of::<ValueType>()
+			+ self.mas.len() * size_of::<RegularMethod>()
+			+ self.mas.iter().map(RegularMethod::heap_size).sum::<usize>()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		for (value, ma) in self.values[1..].iter_mut().zip(self.mas.iter_mut()) {
+			*value = ma.next(src);
+		}
+
+		let pairs = self.values[1..].windows(2);
+		let count = pairs.len() as ValueType;
+		let score: ValueType = pairs
+			.map(|pair| {
+				if pair[0] > pair[1] {
+					1.0
+				} else if pair[0] < pair[1] {
+					-1.0
+				} else {
+					0.0
+				}
+			})
+			.sum();
+
+		let alignment = score / count;
+		self.values[0] = alignment;
+
+		let full_alignment = if alignment >= 1.0 {
+			1
+		} else if alignment <= -1.0 {
+			-1
+		} else {
+			0
+		};
+
+		let signal = if full_alignment != 0 && full_alignment != self.last_alignment {
+			self.last_alignment = full_alignment;
+			Action::from(full_alignment)
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&self.values, &[signal])
+	}
+}
+
+impl Checkpoint for MARibbonInstance {
+	// `values` is just an output buffer, which is fully rewritten on every step
+	type State = (<Vec<RegularMethod> as Checkpoint>::State, i8);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.mas.save(), self.last_alignment)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.mas.restore(&state.0);
+		self.last_alignment = state.1;
+	}
+}
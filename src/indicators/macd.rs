@@ -3,13 +3,17 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Candle, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::core::{Action, Candle, Checkpoint, Error, Method, PeriodType, Source, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Preset, Presets};
+use crate::helpers::{method, RegularMethod, RegularMethods, MA};
 use crate::methods::Cross;
 
 /// Moving average convergence/divergence (MACD)
 ///
+/// Method and period of each MA may be set at once by [`set`](IndicatorConfig::set) with `ma1`, `ma2` or `ma3` name
+/// and an [`MA`](crate::helpers::MA) string (f.e. `ema-20`).
+///
 /// ## Links
 ///
 /// * <https://en.wikipedia.org/wiki/MACD>
@@ -34,6 +38,12 @@ use crate::methods::Cross;
 /// When `MACD` crosses zero line downwards, returns full sell signal.
 /// Otherwise returns no signal.
 ///
+/// # Output selection
+///
+/// When `signal_line` is `false`, the `Signal line` MA is not calculated at all,
+/// so the indicator returns only `MACD` value. The first signal is always [`Action::None`](crate::core::Action::None),
+/// so the zero line cross signal keeps its index.
+/// It is useful when a lot of instances are evaluated and the `Signal line` is not needed.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MACD {
@@ -61,6 +71,10 @@ pub struct MACD {
 	/// Signal line MA type. Default is [`EMA`](crate::methods::EMA).
 	pub method3: RegularMethods,
 
+	/// Calculate `Signal line` value and its cross signal. Default is `true`.
+	#[cfg_attr(feature = "serde", serde(default = "default_signal_line"))]
+	pub signal_line: bool,
+
 	/// Source value type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
 }
@@ -70,6 +84,11 @@ impl IndicatorConfig for MACD {
 
 	const NAME: &'static str = "MACD";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if self.validate() {
 			let cfg = self;
@@ -77,7 +96,11 @@ impl IndicatorConfig for MACD {
 			Ok(Self::Instance {
 				ma1: method(cfg.method1, cfg.period1, src)?,
 				ma2: method(cfg.method2, cfg.period2, src)?,
-				ma3: method(cfg.method3, cfg.period3, src)?,
+				ma3: if cfg.signal_line {
+					Some(method(cfg.method3, cfg.period3, src)?)
+				} else {
+					None
+				},
 				cross1: Cross::default(),
 				cross2: Cross::default(),
 				cfg,
@@ -94,31 +117,56 @@ impl IndicatorConfig for MACD {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"method1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method1 = value,
 			},
 			"method2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method2 = value,
 			},
 			"method3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method3 = value,
 			},
+			"signal_line" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.signal_line = value,
+			},
+			"ma1" => match value.parse::<MA>() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => {
+					self.method1 = value.method;
+					self.period1 = value.period;
+				}
+			},
+			"ma2" => match value.parse::<MA>() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => {
+					self.method2 = value.method;
+					self.period2 = value.period;
+				}
+			},
+			"ma3" => match value.parse::<MA>() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => {
+					self.method3 = value.method;
+					self.period3 = value.period;
+				}
+			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			_ => {
@@ -130,10 +178,31 @@ impl IndicatorConfig for MACD {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(2, 2)
+		if self.signal_line {
+			(2, 2)
+		} else {
+			(1, 2)
+		}
 	}
 }
 
+impl Presets for MACD {
+	const PRESETS: &'static [Preset<Self>] = &[
+		Preset::new("classic", "12/26 EMA with 9 EMA signal line", Self::default),
+		Preset::new("fast", "5/35 EMA with 5 EMA signal line", || Self {
+			period1: 5,
+			period2: 35,
+			period3: 5,
+			..Self::default()
+		}),
+		Preset::new("long_term", "19/39 EMA with 9 EMA signal line", || Self {
+			period1: 19,
+			period2: 39,
+			..Self::default()
+		}),
+	];
+}
+
 impl Default for MACD {
 	fn default() -> Self {
 		Self {
@@ -143,22 +212,29 @@ impl Default for MACD {
 			method1: RegularMethods::EMA,
 			method2: RegularMethods::EMA,
 			method3: RegularMethods::EMA,
+			signal_line: true,
 			source: Source::Close,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MACDInstance {
 	cfg: MACD,
 
 	ma1: RegularMethod,
 	ma2: RegularMethod,
-	ma3: RegularMethod,
+	ma3: Option<RegularMethod>,
 	cross1: Cross,
 	cross2: Cross,
 }
 
+#[cfg(feature = "serde")]
+const fn default_signal_line() -> bool {
+	true
+}
+
 /// Just an alias for MACD
 pub type MovingAverageConvergenceDivergence = MACD;
 
@@ -169,6 +245,12 @@ impl IndicatorInstance for MACDInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma1.heap_size()
+			+ self.ma2.heap_size()
+			+ self.ma3.as_ref().map_or(0, RegularMethod::heap_size)
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
@@ -177,11 +259,93 @@ impl IndicatorInstance for MACDInstance {
 		let ema2 = self.ma2.next(src);
 
 		let macd = ema1 - ema2;
-		let sigline = self.ma3.next(macd);
-
-		let signal1 = self.cross1.next((macd, sigline));
 		let signal2 = self.cross2.next((macd, 0.0));
 
-		IndicatorResult::new(&[macd, sigline], &[signal1, signal2])
+		match &mut self.ma3 {
+			Some(ma3) => {
+				let sigline = ma3.next(macd);
+				let signal1 = self.cross1.next((macd, sigline));
+
+				IndicatorResult::new(&[macd, sigline], &[signal1, signal2])
+			}
+			None => IndicatorResult::new(&[macd], &[Action::None, signal2]),
+		}
+	}
+}
+
+impl Checkpoint for MACDInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Option<RegularMethod> as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma1.save(),
+			self.ma2.save(),
+			self.ma3.save(),
+			self.cross1.save(),
+			self.cross2.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma1.restore(&state.0);
+		self.ma2.restore(&state.1);
+		self.ma3.restore(&state.2);
+		self.cross1.restore(&state.3);
+		self.cross2.restore(&state.4);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MACD;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::{RandomCandles, RegularMethods};
+
+	#[test]
+	fn test_macd_without_signal_line() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let cfg = MACD {
+			signal_line: false,
+			..MACD::default()
+		};
+		assert_eq!(cfg.size(), (1, 2));
+
+		let mut full = MACD::default().init(&candles[0]).unwrap();
+		let mut instance = cfg.init(&candles[0]).unwrap();
+
+		for candle in &candles {
+			let expected = full.next(candle);
+			let result = instance.next(candle);
+
+			assert_eq!(result.values(), &expected.values()[..1]);
+			assert_eq!(result.signals(), &[Action::None, expected.signals()[1]]);
+		}
+	}
+
+	#[test]
+	fn test_macd_set_ma() {
+		let mut cfg = MACD::default();
+
+		cfg.set("ma1", "wma-10".to_string()).unwrap();
+		cfg.set("ma2", "HMA(30)".to_string()).unwrap();
+		cfg.set("ma3", "sma".to_string()).unwrap();
+
+		assert_eq!((cfg.method1, cfg.period1), (RegularMethods::WMA, 10));
+		assert_eq!((cfg.method2, cfg.period2), (RegularMethods::HMA, 30));
+		assert_eq!((cfg.method3, cfg.period3), (RegularMethods::SMA, 14));
+
+		assert!(cfg.set("ma1", "wma-10)".to_string()).is_err());
+		assert_eq!((cfg.method1, cfg.period1), (RegularMethods::WMA, 10));
+
+		cfg.set("method2", "tema-5".to_string()).unwrap();
+		assert_eq!((cfg.method2, cfg.period2), (RegularMethods::TEMA, 30));
 	}
 }
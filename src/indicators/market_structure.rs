@@ -0,0 +1,392 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Candle, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, ResultHistory, WindowCheckpoint};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{LowerReversalSignal, UpperReversalSignal};
+
+/// Market Structure
+///
+/// Tracks swing points of the price and classifies them as
+/// *higher high* (HH), *lower high* (LH), *higher low* (HL) and *lower low* (LL).
+///
+/// Swing high is an upper reversal point of *high* prices and swing low is a lower reversal point of *low* prices
+/// (see [`UpperReversalSignal`] and [`LowerReversalSignal`]). Every swing point is confirmed `right` candles after it happens.
+///
+/// When `source` value crosses the last swing high upwards (or the last swing low downwards), the structure breaks.
+/// Break in the direction of the current trend is a *break of structure* (`BoS`).
+/// Break against the current trend (or when there is no trend yet) is a *change of character* (`CHoCH`), which also flips the trend.
+/// Every swing point may be broken only once.
+///
+/// Use [`with_history`](crate::core::IndicatorInstance::with_history) to check rules like *"`CHoCH` happened within the last `K` bars"*.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/swinghigh.asp>
+///
+/// # 4 values
+///
+/// * `swing high` value
+///
+/// Price of the last confirmed swing high. Until the first swing high appears, the first candle's *high* price is used.
+///
+/// Range of values is the same as the range of the source values.
+///
+/// * `swing low` value
+///
+/// Price of the last confirmed swing low. Until the first swing low appears, the first candle's *low* price is used.
+///
+/// Range of values is the same as the range of the source values.
+///
+/// * `trend` value
+///
+/// `1.0` for uptrend, `-1.0` for downtrend and `0.0` until the first structure break.
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `structure` of the last swing point
+///
+/// `2.0` for HH, `1.0` for HL, `-1.0` for LH, `-2.0` for LL and `0.0` until the second swing point of the same side appears.
+///
+/// Range in \[`-2.0`; `2.0`\]
+///
+/// # 3 signals
+///
+/// * When swing point is confirmed as HH or HL, returns full buy signal.
+///   When swing point is confirmed as LH or LL, returns full sell signal.
+///
+/// * When bullish `BoS` happens, returns full buy signal.
+///   When bearish `BoS` happens, returns full sell signal.
+///
+/// * When bullish `CHoCH` happens, returns full buy signal.
+///   When bearish `CHoCH` happens, returns full sell signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarketStructure {
+	/// How many periods should left before swing point. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`right`\).
+	pub left: PeriodType,
+
+	/// How many periods should appear after swing point. Default is `3`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`left`\).
+	pub right: PeriodType,
+
+	/// Source type of values for structure breaks. Default is [`Close`](crate::core::Source::Close).
+	pub source: Source,
+}
+
+impl IndicatorConfig for MarketStructure {
+	type Instance = MarketStructureInstance;
+
+	const NAME: &'static str = "MarketStructure";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Bounded(-2.0, 2.0),
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let swing_points = SwingPoints {
+			left: cfg.left,
+			right: cfg.right,
+		};
+
+		Ok(Self::Instance {
+			// swing point candle is `right` bars ago, so the history holds one more bar
+			swings: swing_points.init(candle)?.with_history(cfg.right + 1)?,
+			swing_high: None,
+			swing_low: None,
+			high_broken: true,
+			low_broken: true,
+			first_high: candle.high(),
+			first_low: candle.low(),
+			trend: 0,
+			structure: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"left" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.right = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 3)
+	}
+}
+
+impl Default for MarketStructure {
+	fn default() -> Self {
+		Self {
+			left: 3,
+			right: 3,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarketStructureInstance {
+	cfg: MarketStructure,
+
+	swings: ResultHistory<SwingPointsInstance>,
+	swing_high: Option<ValueType>,
+	swing_low: Option<ValueType>,
+	high_broken: bool,
+	low_broken: bool,
+	first_high: ValueType,
+	first_low: ValueType,
+	trend: i8,
+	structure: i8,
+}
+
+impl MarketStructureInstance {
+	/// Marks break of the swing point in the `direction` and returns `(BoS, CHoCH)` signals
+	fn structure_break(&mut self, direction: i8) -> (Action, Action) {
+		let is_bos = self.trend == direction;
+		self.trend = direction;
+
+		if is_bos {
+			(direction.into(), Action::None)
+		} else {
+			(Action::None, direction.into())
+		}
+	}
+}
+
+impl IndicatorInstance for MarketStructureInstance {
+	type Config = MarketStructure;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.swings.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let swings = self.swings.next(candle);
+		let is_swing_high = swings.signal(0).analog() > 0;
+		let is_swing_low = swings.signal(1).analog() > 0;
+
+		// until the history is full, reversal signals look back at the first candle
+		let past_candle = self.swings.candle(self.cfg.right);
+
+		let mut swing_signal = 0;
+
+		if is_swing_high {
+			let price = past_candle.map_or(self.first_high, |past_candle| past_candle.high());
+
+			if let Some(prev) = self.swing_high {
+				self.structure = if price > prev { 2 } else { -1 };
+				swing_signal = self.structure.signum();
+			}
+
+			self.swing_high = Some(price);
+			self.high_broken = false;
+		}
+
+		if is_swing_low {
+			let price = past_candle.map_or(self.first_low, |past_candle| past_candle.low());
+
+			if let Some(prev) = self.swing_low {
+				self.structure = if price < prev { -2 } else { 1 };
+				swing_signal = self.structure.signum();
+			}
+
+			self.swing_low = Some(price);
+			self.low_broken = false;
+		}
+
+		let src = candle.source(self.cfg.source);
+		let mut breaks = (Action::None, Action::None);
+
+		match (self.swing_high, self.swing_low) {
+			(Some(high), _) if !self.high_broken && src > high => {
+				self.high_broken = true;
+				breaks = self.structure_break(1);
+			}
+			(_, Some(low)) if !self.low_broken && src < low => {
+				self.low_broken = true;
+				breaks = self.structure_break(-1);
+			}
+			_ => {}
+		}
+
+		let values = [
+			self.swing_high.unwrap_or(self.first_high),
+			self.swing_low.unwrap_or(self.first_low),
+			self.trend.into(),
+			self.structure.into(),
+		];
+
+		IndicatorResult::new(&values, &[swing_signal.into(), breaks.0, breaks.1])
+	}
+}
+
+impl Checkpoint for MarketStructureInstance {
+	// swing points detector is private, so the state of its history is spelled out
+	type State = (
+		(
+			(
+				<UpperReversalSignal as Checkpoint>::State,
+				<LowerReversalSignal as Checkpoint>::State,
+			),
+			WindowCheckpoint<IndicatorResult>,
+			WindowCheckpoint<Candle>,
+			PeriodType,
+		),
+		Option<ValueType>,
+		Option<ValueType>,
+		bool,
+		bool,
+		i8,
+		i8,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.swings.save(),
+			self.swing_high,
+			self.swing_low,
+			self.high_broken,
+			self.low_broken,
+			self.trend,
+			self.structure,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.swings.restore(&state.0);
+		self.swing_high = state.1;
+		self.swing_low = state.2;
+		self.high_broken = state.3;
+		self.low_broken = state.4;
+		self.trend = state.5;
+		self.structure = state.6;
+	}
+}
+
+/// Swing points detector, which history [`MarketStructure`] is built over
+///
+/// Returns no values and 2 signals: the first one is for swing highs and the second one is for swing lows.
+/// Every swing point is confirmed `right` candles after it happens.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct SwingPoints {
+	left: PeriodType,
+	right: PeriodType,
+}
+
+impl IndicatorConfig for SwingPoints {
+	type Instance = SwingPointsInstance;
+
+	const NAME: &'static str = "SwingPoints";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		let cfg = self;
+		Ok(Self::Instance {
+			ph: UpperReversalSignal::new(cfg.left, cfg.right, candle.high())?,
+			pl: LowerReversalSignal::new(cfg.left, cfg.right, candle.low())?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		Err(Error::ParameterParse(name.to_string(), value))
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(0, 2)
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct SwingPointsInstance {
+	cfg: SwingPoints,
+
+	ph: UpperReversalSignal,
+	pl: LowerReversalSignal,
+}
+
+impl IndicatorInstance for SwingPointsInstance {
+	type Config = SwingPoints;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ph.heap_size() + self.pl.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let signals = [self.ph.next(candle.high()), self.pl.next(candle.low())];
+
+		IndicatorResult::new(&[], &signals)
+	}
+}
+
+impl Checkpoint for SwingPointsInstance {
+	type State = (
+		<UpperReversalSignal as Checkpoint>::State,
+		<LowerReversalSignal as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.ph.save(), self.pl.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ph.restore(&state.0);
+		self.pl.restore(&state.1);
+	}
+}
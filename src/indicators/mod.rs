@@ -1,11 +1,16 @@
 #![allow(missing_docs)]
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::core::{ValueType, OHLCV};
 
 pub mod example;
 
+/// High, low and close values of the candle, which indicators keep in their windows and checkpoints
 #[derive(Clone, Copy, Debug)]
-struct HLC {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HLC {
 	high: ValueType,
 	low: ValueType,
 	close: ValueType,
@@ -52,9 +57,15 @@ pub use aroon::Aroon;
 mod average_directional_index;
 pub use average_directional_index::AverageDirectionalIndex;
 
+mod average_true_range;
+pub use average_true_range::{AverageTrueRange, ATR};
+
 mod awesome_oscillator;
 pub use awesome_oscillator::AwesomeOscillator;
 
+mod balance_of_power;
+pub use balance_of_power::BalanceOfPower;
+
 mod bollinger_bands;
 pub use bollinger_bands::BollingerBands;
 
@@ -73,9 +84,15 @@ pub use chande_momentum_oscillator::ChandeMomentumOscillator;
 mod commodity_channel_index;
 pub use commodity_channel_index::CommodityChannelIndex;
 
+mod connors_rsi;
+pub use connors_rsi::{ConnorsRSI, CRSI};
+
 mod coppock_curve;
 pub use coppock_curve::CoppockCurve;
 
+mod debounce;
+pub use debounce::Debounce;
+
 mod detrended_price_oscillator;
 pub use detrended_price_oscillator::DetrendedPriceOscillator;
 
@@ -85,21 +102,36 @@ pub use donchian_channel::DonchianChannel;
 mod ease_of_movement;
 pub use ease_of_movement::EaseOfMovement;
 
+mod elder_ray;
+pub use elder_ray::ElderRay;
+
 mod elders_force_index;
 pub use elders_force_index::EldersForceIndex;
 
 mod envelopes;
 pub use envelopes::Envelopes;
 
+mod fib_retracement;
+pub use fib_retracement::FibRetracement;
+
 mod fisher_transform;
 pub use fisher_transform::FisherTransform;
 
+mod gmma;
+pub use gmma::{GuppyMultipleMovingAverages, GMMA};
+
+mod heikin_ashi_trend;
+pub use heikin_ashi_trend::HeikinAshiTrend;
+
 mod hull_moving_average;
 pub use hull_moving_average::HullMovingAverage;
 
 mod ichimoku_cloud;
 pub use ichimoku_cloud::IchimokuCloud;
 
+mod imbalance;
+pub use imbalance::Imbalance;
+
 mod kaufman;
 pub use kaufman::{Kaufman, KAMA};
 
@@ -112,15 +144,27 @@ pub use klinger_volume_oscillator::KlingerVolumeOscillator;
 mod know_sure_thing;
 pub use know_sure_thing::KnowSureThing;
 
+mod ma_cross;
+pub use ma_cross::{MACross, MovingAverageCross};
+
+mod ma_ribbon;
+pub use ma_ribbon::{MARibbon, MovingAverageRibbon};
+
 mod macd;
 pub use macd::{MovingAverageConvergenceDivergence, MACD};
 
+mod market_structure;
+pub use market_structure::MarketStructure;
+
 mod momentum_index;
 pub use momentum_index::MomentumIndex;
 
 mod money_flow_index;
 pub use money_flow_index::MoneyFlowIndex;
 
+mod nan_guarded;
+pub use nan_guarded::{NanGuarded, NanGuardedInstance};
+
 mod parabolic_sar;
 pub use parabolic_sar::{ParabolicSAR, ParabolicStopAndReverse};
 
@@ -130,17 +174,29 @@ pub use pivot_reversal_strategy::PivotReversalStrategy;
 mod price_channel_strategy;
 pub use price_channel_strategy::PriceChannelStrategy;
 
+mod quantitative_qualitative_estimation;
+pub use quantitative_qualitative_estimation::{QuantitativeQualitativeEstimation, QQE};
+
 mod relative_strength_index;
 pub use relative_strength_index::{RelativeStrengthIndex, RSI};
 
 mod relative_vigor_index;
 pub use relative_vigor_index::RelativeVigorIndex;
 
+mod schaff_trend_cycle;
+pub use schaff_trend_cycle::{SchaffTrendCycle, STC};
+
 mod smi_ergodic_indicator;
 pub use smi_ergodic_indicator::SMIErgodicIndicator;
 
+mod squeeze_momentum;
+pub use squeeze_momentum::{SqueezeMomentum, TTMSqueeze};
+
 mod stochastic_oscillator;
-pub use stochastic_oscillator::StochasticOscillator;
+pub use stochastic_oscillator::{StochasticMode, StochasticOscillator};
+
+mod triple_screen;
+pub use triple_screen::TripleScreen;
 
 mod trix;
 pub use trix::Trix;
@@ -151,5 +207,481 @@ pub use trend_strength_index::TrendStrengthIndex;
 mod true_strength_index;
 pub use true_strength_index::TrueStrengthIndex;
 
+mod ulcer_index;
+pub use ulcer_index::UlcerIndex;
+
+mod volume_profile;
+pub use volume_profile::VolumeProfile;
+
+mod vortex_indicator;
+pub use vortex_indicator::VortexIndicator;
+
 mod woodies_cci;
 pub use woodies_cci::WoodiesCCI;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::Oscillator;
+	use crate::core::{Candle, Channel, Checkpoint, Error, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+
+	fn assert_send_sync<C>()
+	where
+		C: IndicatorConfig + Send + Sync,
+		C::Instance: Send + Sync,
+	{
+	}
+
+	fn test_metadata<C: IndicatorConfig + Default>() {
+		let config = C::default();
+		let metadata = C::METADATA;
+		// `RandomCandles` may produce negative volume, which is not valid for volume-based indicators
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect();
+
+		assert_eq!(
+			metadata.ranges.len(),
+			config.size().0 as usize,
+			"{}",
+			C::NAME
+		);
+
+		for result in config.over(&candles).unwrap() {
+			for (range, &value) in metadata.ranges.iter().zip(result.values()) {
+				assert!(
+					range.contains(value),
+					"{}: {} is out of {:?}",
+					C::NAME,
+					value,
+					range
+				);
+			}
+		}
+
+		let no_volume = C::default().init(&candles[0].without_volume());
+
+		if metadata.requires_volume {
+			assert!(
+				matches!(no_volume, Err(Error::NoVolume)),
+				"{}: initialized without volume",
+				C::NAME
+			);
+		} else {
+			assert!(no_volume.is_ok(), "{}: requires volume", C::NAME);
+		}
+
+		// volume-less candles in the middle of the series must not produce chains of `NaN`s
+		let mut instance = C::default().init(&candles[0]).unwrap();
+		for (i, candle) in candles.iter().enumerate() {
+			let candle = if i % 10 == 5 {
+				candle.without_volume()
+			} else {
+				*candle
+			};
+
+			let result = instance.next(&candle);
+			assert!(
+				result.values().iter().all(|value| !value.is_nan()),
+				"{}: NaN after a volume-less candle",
+				C::NAME
+			);
+		}
+	}
+
+	fn test_checkpoint<C>()
+	where
+		C: IndicatorConfig + Default,
+		C::Instance: Checkpoint + Clone,
+	{
+		// `RandomCandles` may produce negative volume, which is not valid for volume-based indicators
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect();
+
+		let mut instance = C::default().init(&candles[0]).unwrap();
+		let mut reference = instance.clone();
+		let last = candles.len() - 1;
+
+		for (i, candle) in candles.iter().enumerate() {
+			let checkpoint = instance.save();
+
+			// speculative steps must not change the state after restoring
+			instance.next(&candles[last - i]);
+			instance.restore(&checkpoint);
+			instance.next(&candles[i / 2]);
+			instance.restore(&checkpoint);
+
+			assert_eq!(
+				format!("{:?}", instance.next(candle)),
+				format!("{:?}", reference.next(candle)),
+				"{}",
+				C::NAME
+			);
+		}
+	}
+
+	#[cfg(feature = "persist")]
+	fn test_persist<C>()
+	where
+		C: IndicatorConfig + Default,
+		C::Instance: crate::core::Persist,
+	{
+		use crate::core::Persist;
+
+		// `RandomCandles` may produce negative volume, which is not valid for volume-based indicators
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect();
+
+		let (first, second) = candles.split_at(150);
+
+		let mut instance = C::default().init(&first[0]).unwrap();
+		first.iter().for_each(|candle| {
+			instance.next(candle);
+		});
+
+		let bytes = instance.to_bytes().unwrap();
+		let mut restored = C::Instance::from_bytes(&bytes)
+			.unwrap_or_else(|error| panic!("{}: {:?}", C::NAME, error));
+
+		for candle in second {
+			assert_eq!(
+				format!("{:?}", restored.next(candle)),
+				format!("{:?}", instance.next(candle)),
+				"{}",
+				C::NAME
+			);
+		}
+	}
+
+	fn test_channel<C: Channel + Default>() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+
+		for result in C::default().over(&candles).unwrap() {
+			let (upper, middle, lower) = (C::upper(&result), C::middle(&result), C::lower(&result));
+
+			assert!(upper >= middle, "{}: {} < {}", C::NAME, upper, middle);
+			assert!(middle >= lower, "{}: {} < {}", C::NAME, middle, lower);
+			assert!(C::width(&result) >= 0.0, "{}", C::NAME);
+			assert!(C::contains(&result, middle), "{}", C::NAME);
+
+			let position = C::position(&result, middle);
+			assert!((0.0..=1.0).contains(&position), "{}: {}", C::NAME, position);
+		}
+	}
+
+	#[test]
+	fn test_channels() {
+		test_channel::<BollingerBands>();
+		test_channel::<DonchianChannel>();
+		test_channel::<Envelopes>();
+		test_channel::<KeltnerChannel>();
+	}
+
+	fn test_oscillator<O: Oscillator + Default>() {
+		let config = O::default();
+		// `RandomCandles` may produce negative volume, which is not valid for volume-based indicators
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect();
+
+		assert!(config.overbought() >= config.oversold(), "{}", O::NAME);
+
+		for result in O::default().over(&candles).unwrap() {
+			let main = O::main(&result);
+
+			assert!(O::range().contains(main), "{}: {}", O::NAME, main);
+			assert_eq!(
+				O::signal_line(&result).is_some(),
+				O::SIGNAL_LINE.is_some(),
+				"{}",
+				O::NAME
+			);
+
+			if let Some(normalized) = O::normalized(&result) {
+				assert!(
+					(0.0..=1.0).contains(&normalized),
+					"{}: {}",
+					O::NAME,
+					normalized
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_oscillators() {
+		test_oscillator::<ChandeMomentumOscillator>();
+		test_oscillator::<CommodityChannelIndex>();
+		test_oscillator::<ConnorsRSI>();
+		test_oscillator::<MoneyFlowIndex>();
+		test_oscillator::<RelativeStrengthIndex>();
+		test_oscillator::<SchaffTrendCycle>();
+		test_oscillator::<SMIErgodicIndicator>();
+		test_oscillator::<StochasticOscillator>();
+		test_oscillator::<TrueStrengthIndex>();
+	}
+
+	#[test]
+	fn test_indicators_metadata() {
+		test_metadata::<Aroon>();
+		test_metadata::<AverageDirectionalIndex>();
+		test_metadata::<AverageTrueRange>();
+		test_metadata::<AwesomeOscillator>();
+		test_metadata::<BalanceOfPower>();
+		test_metadata::<BollingerBands>();
+		test_metadata::<ChaikinMoneyFlow>();
+		test_metadata::<ChaikinOscillator>();
+		test_metadata::<ChandeKrollStop>();
+		test_metadata::<ChandeMomentumOscillator>();
+		test_metadata::<CommodityChannelIndex>();
+		test_metadata::<ConnorsRSI>();
+		test_metadata::<CoppockCurve>();
+		test_metadata::<Debounce<MACD>>();
+		test_metadata::<DetrendedPriceOscillator>();
+		test_metadata::<DonchianChannel>();
+		test_metadata::<EaseOfMovement>();
+		test_metadata::<ElderRay>();
+		test_metadata::<EldersForceIndex>();
+		test_metadata::<Envelopes>();
+		test_metadata::<example::Example>();
+		test_metadata::<FibRetracement>();
+		test_metadata::<FisherTransform>();
+		test_metadata::<GMMA>();
+		test_metadata::<HeikinAshiTrend>();
+		test_metadata::<HullMovingAverage>();
+		test_metadata::<IchimokuCloud>();
+		test_metadata::<Imbalance>();
+		test_metadata::<Kaufman>();
+		test_metadata::<KeltnerChannel>();
+		test_metadata::<KlingerVolumeOscillator>();
+		test_metadata::<KnowSureThing>();
+		test_metadata::<MACross>();
+		test_metadata::<MARibbon>();
+		test_metadata::<MACD>();
+		test_metadata::<MarketStructure>();
+		test_metadata::<MomentumIndex>();
+		test_metadata::<MoneyFlowIndex>();
+		test_metadata::<NanGuarded<MACD>>();
+		test_metadata::<ParabolicSAR>();
+		test_metadata::<PivotReversalStrategy>();
+		test_metadata::<PriceChannelStrategy>();
+		test_metadata::<QuantitativeQualitativeEstimation>();
+		test_metadata::<RelativeStrengthIndex>();
+		test_metadata::<RelativeVigorIndex>();
+		test_metadata::<SchaffTrendCycle>();
+		test_metadata::<SMIErgodicIndicator>();
+		test_metadata::<SqueezeMomentum>();
+		test_metadata::<StochasticOscillator>();
+		test_metadata::<TrendStrengthIndex>();
+		test_metadata::<TripleScreen>();
+		test_metadata::<Trix>();
+		test_metadata::<TrueStrengthIndex>();
+		test_metadata::<UlcerIndex>();
+		test_metadata::<VolumeProfile>();
+		test_metadata::<VortexIndicator>();
+		test_metadata::<WoodiesCCI>();
+	}
+
+	#[test]
+	fn test_indicators_checkpoint() {
+		test_checkpoint::<Aroon>();
+		test_checkpoint::<AverageDirectionalIndex>();
+		test_checkpoint::<AverageTrueRange>();
+		test_checkpoint::<AwesomeOscillator>();
+		test_checkpoint::<BalanceOfPower>();
+		test_checkpoint::<BollingerBands>();
+		test_checkpoint::<ChaikinMoneyFlow>();
+		test_checkpoint::<ChaikinOscillator>();
+		test_checkpoint::<ChandeKrollStop>();
+		test_checkpoint::<ChandeMomentumOscillator>();
+		test_checkpoint::<CommodityChannelIndex>();
+		test_checkpoint::<ConnorsRSI>();
+		test_checkpoint::<CoppockCurve>();
+		test_checkpoint::<Debounce<MACD>>();
+		test_checkpoint::<DetrendedPriceOscillator>();
+		test_checkpoint::<DonchianChannel>();
+		test_checkpoint::<EaseOfMovement>();
+		test_checkpoint::<ElderRay>();
+		test_checkpoint::<EldersForceIndex>();
+		test_checkpoint::<Envelopes>();
+		test_checkpoint::<example::Example>();
+		test_checkpoint::<FibRetracement>();
+		test_checkpoint::<FisherTransform>();
+		test_checkpoint::<GMMA>();
+		test_checkpoint::<HeikinAshiTrend>();
+		test_checkpoint::<HullMovingAverage>();
+		test_checkpoint::<IchimokuCloud>();
+		test_checkpoint::<Imbalance>();
+		test_checkpoint::<Kaufman>();
+		test_checkpoint::<KeltnerChannel>();
+		test_checkpoint::<KlingerVolumeOscillator>();
+		test_checkpoint::<KnowSureThing>();
+		test_checkpoint::<MACross>();
+		test_checkpoint::<MARibbon>();
+		test_checkpoint::<MACD>();
+		test_checkpoint::<MarketStructure>();
+		test_checkpoint::<MomentumIndex>();
+		test_checkpoint::<MoneyFlowIndex>();
+		test_checkpoint::<NanGuarded<MACD>>();
+		test_checkpoint::<ParabolicSAR>();
+		test_checkpoint::<PivotReversalStrategy>();
+		test_checkpoint::<PriceChannelStrategy>();
+		test_checkpoint::<QuantitativeQualitativeEstimation>();
+		test_checkpoint::<RelativeStrengthIndex>();
+		test_checkpoint::<RelativeVigorIndex>();
+		test_checkpoint::<SchaffTrendCycle>();
+		test_checkpoint::<SMIErgodicIndicator>();
+		test_checkpoint::<SqueezeMomentum>();
+		test_checkpoint::<StochasticOscillator>();
+		test_checkpoint::<TrendStrengthIndex>();
+		test_checkpoint::<TripleScreen>();
+		test_checkpoint::<Trix>();
+		test_checkpoint::<TrueStrengthIndex>();
+		test_checkpoint::<UlcerIndex>();
+		test_checkpoint::<VolumeProfile>();
+		test_checkpoint::<VortexIndicator>();
+		test_checkpoint::<WoodiesCCI>();
+	}
+
+	#[test]
+	#[cfg(feature = "persist")]
+	fn test_indicators_persist() {
+		test_persist::<Aroon>();
+		test_persist::<AverageDirectionalIndex>();
+		test_persist::<AverageTrueRange>();
+		test_persist::<AwesomeOscillator>();
+		test_persist::<BalanceOfPower>();
+		test_persist::<BollingerBands>();
+		test_persist::<ChaikinMoneyFlow>();
+		test_persist::<ChaikinOscillator>();
+		test_persist::<ChandeKrollStop>();
+		test_persist::<ChandeMomentumOscillator>();
+		test_persist::<CommodityChannelIndex>();
+		test_persist::<ConnorsRSI>();
+		test_persist::<CoppockCurve>();
+		test_persist::<Debounce<MACD>>();
+		test_persist::<DetrendedPriceOscillator>();
+		test_persist::<DonchianChannel>();
+		test_persist::<EaseOfMovement>();
+		test_persist::<ElderRay>();
+		test_persist::<EldersForceIndex>();
+		test_persist::<Envelopes>();
+		test_persist::<example::Example>();
+		test_persist::<FibRetracement>();
+		test_persist::<FisherTransform>();
+		test_persist::<GMMA>();
+		test_persist::<HeikinAshiTrend>();
+		test_persist::<HullMovingAverage>();
+		test_persist::<IchimokuCloud>();
+		test_persist::<Imbalance>();
+		test_persist::<Kaufman>();
+		test_persist::<KeltnerChannel>();
+		test_persist::<KlingerVolumeOscillator>();
+		test_persist::<KnowSureThing>();
+		test_persist::<MACross>();
+		test_persist::<MARibbon>();
+		test_persist::<MACD>();
+		test_persist::<MarketStructure>();
+		test_persist::<MomentumIndex>();
+		test_persist::<MoneyFlowIndex>();
+		test_persist::<NanGuarded<MACD>>();
+		test_persist::<ParabolicSAR>();
+		test_persist::<PivotReversalStrategy>();
+		test_persist::<PriceChannelStrategy>();
+		test_persist::<QuantitativeQualitativeEstimation>();
+		test_persist::<RelativeStrengthIndex>();
+		test_persist::<RelativeVigorIndex>();
+		test_persist::<SchaffTrendCycle>();
+		test_persist::<SMIErgodicIndicator>();
+		test_persist::<SqueezeMomentum>();
+		test_persist::<StochasticOscillator>();
+		test_persist::<TrendStrengthIndex>();
+		test_persist::<TripleScreen>();
+		test_persist::<Trix>();
+		test_persist::<TrueStrengthIndex>();
+		test_persist::<UlcerIndex>();
+		test_persist::<VolumeProfile>();
+		test_persist::<VortexIndicator>();
+		test_persist::<WoodiesCCI>();
+	}
+
+	#[test]
+	fn test_send_sync() {
+		assert_send_sync::<Aroon>();
+		assert_send_sync::<AverageDirectionalIndex>();
+		assert_send_sync::<AverageTrueRange>();
+		assert_send_sync::<AwesomeOscillator>();
+		assert_send_sync::<BalanceOfPower>();
+		assert_send_sync::<BollingerBands>();
+		assert_send_sync::<ChaikinMoneyFlow>();
+		assert_send_sync::<ChaikinOscillator>();
+		assert_send_sync::<ChandeKrollStop>();
+		assert_send_sync::<ChandeMomentumOscillator>();
+		assert_send_sync::<CommodityChannelIndex>();
+		assert_send_sync::<ConnorsRSI>();
+		assert_send_sync::<CoppockCurve>();
+		assert_send_sync::<Debounce<MACD>>();
+		assert_send_sync::<DetrendedPriceOscillator>();
+		assert_send_sync::<DonchianChannel>();
+		assert_send_sync::<EaseOfMovement>();
+		assert_send_sync::<ElderRay>();
+		assert_send_sync::<EldersForceIndex>();
+		assert_send_sync::<Envelopes>();
+		assert_send_sync::<example::Example>();
+		assert_send_sync::<FibRetracement>();
+		assert_send_sync::<FisherTransform>();
+		assert_send_sync::<GMMA>();
+		assert_send_sync::<HeikinAshiTrend>();
+		assert_send_sync::<HullMovingAverage>();
+		assert_send_sync::<IchimokuCloud>();
+		assert_send_sync::<Imbalance>();
+		assert_send_sync::<Kaufman>();
+		assert_send_sync::<KeltnerChannel>();
+		assert_send_sync::<KlingerVolumeOscillator>();
+		assert_send_sync::<KnowSureThing>();
+		assert_send_sync::<MACross>();
+		assert_send_sync::<MARibbon>();
+		assert_send_sync::<MACD>();
+		assert_send_sync::<MarketStructure>();
+		assert_send_sync::<MomentumIndex>();
+		assert_send_sync::<MoneyFlowIndex>();
+		assert_send_sync::<NanGuarded<RSI>>();
+		assert_send_sync::<ParabolicSAR>();
+		assert_send_sync::<PivotReversalStrategy>();
+		assert_send_sync::<PriceChannelStrategy>();
+		assert_send_sync::<QuantitativeQualitativeEstimation>();
+		assert_send_sync::<RelativeStrengthIndex>();
+		assert_send_sync::<RelativeVigorIndex>();
+		assert_send_sync::<SchaffTrendCycle>();
+		assert_send_sync::<SMIErgodicIndicator>();
+		assert_send_sync::<SqueezeMomentum>();
+		assert_send_sync::<StochasticOscillator>();
+		assert_send_sync::<TripleScreen>();
+		assert_send_sync::<Trix>();
+		assert_send_sync::<TrendStrengthIndex>();
+		assert_send_sync::<TrueStrengthIndex>();
+		assert_send_sync::<UlcerIndex>();
+		assert_send_sync::<VolumeProfile>();
+		assert_send_sync::<VortexIndicator>();
+		assert_send_sync::<WoodiesCCI>();
+	}
+}
@@ -1,8 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, PeriodType, Source, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::Momentum;
 
 /// Momentum Index
@@ -17,6 +19,11 @@ use crate::methods::Momentum;
 ///
 /// Range in \(`-inf`; `+inf`\)
 ///
+/// When `percent` is `true`, both momentums are calculated as a percentage of the reference (`n`-th back) `source` value,
+/// like in [`RateOfChange`](crate::methods::RateOfChange). When the reference value is zero, momentum is `0`.
+///
+/// Both momentums are smoothed by `method` of `period3` length.
+///
 /// # 1 signal
 ///
 /// * When both momentums are positive, returns full buy signal.
@@ -24,6 +31,7 @@ use crate::methods::Momentum;
 /// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct MomentumIndex {
 	/// Slow momentum period. Default is `10`.
 	///
@@ -35,6 +43,17 @@ pub struct MomentumIndex {
 	/// Range in \[`1`; `period1`\)
 	pub period2: PeriodType,
 
+	/// Smoothing period. Default is `1` (no smoothing).
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period3: PeriodType,
+
+	/// Smoothing method. Default is [`SMA`](crate::methods::SMA).
+	pub method: RegularMethods,
+
+	/// Calculate momentums as a percentage of the reference value. Default is `false`.
+	pub percent: bool,
+
 	/// Source value type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
 }
@@ -44,6 +63,11 @@ impl IndicatorConfig for MomentumIndex {
 
 	const NAME: &'static str = "MomentumIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -55,26 +79,43 @@ impl IndicatorConfig for MomentumIndex {
 		Ok(Self::Instance {
 			momentum1: Momentum::new(cfg.period1, src)?,
 			momentum2: Momentum::new(cfg.period2, src)?,
+			ma1: method(cfg.method, cfg.period3, 0.)?,
+			ma2: method(cfg.method, cfg.period3, 0.)?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period2 > 0 && self.period1 > self.period2
+		self.period2 > 0
+			&& self.period1 > self.period2
+			&& self.period3 > 0
+			&& self.period3 < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
+			"period3" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period3 = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+			"percent" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.percent = value,
+			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -96,17 +137,36 @@ impl Default for MomentumIndex {
 		Self {
 			period1: 10,
 			period2: 1,
+			period3: 1,
+			method: RegularMethods::SMA,
+			percent: false,
 			source: Source::Close,
 		}
 	}
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MomentumIndexInstance {
 	cfg: MomentumIndex,
 
 	momentum1: Momentum,
 	momentum2: Momentum,
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+}
+
+/// Returns `momentum` as a percentage of the reference value or `0` when the reference value is zero
+#[inline]
+fn percent(src: ValueType, momentum: ValueType) -> ValueType {
+	// reference value is `src` - `momentum`
+	let reference = src - momentum;
+
+	if reference == 0. {
+		0.
+	} else {
+		momentum / reference * 100.
+	}
 }
 
 impl IndicatorInstance for MomentumIndexInstance {
@@ -116,11 +176,26 @@ impl IndicatorInstance for MomentumIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.momentum1.heap_size()
+			+ self.momentum2.heap_size()
+			+ self.ma1.heap_size()
+			+ self.ma2.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
-		let v = self.momentum1.next(src);
-		let s = self.momentum2.next(src);
+		let mut v = self.momentum1.next(src);
+		let mut s = self.momentum2.next(src);
+
+		if self.cfg.percent {
+			v = percent(src, v);
+			s = percent(src, s);
+		}
+
+		let v = self.ma1.next(v);
+		let s = self.ma2.next(s);
 
 		// let signal;
 		// if v > 0. && s > 0. {
@@ -136,3 +211,75 @@ impl IndicatorInstance for MomentumIndexInstance {
 		IndicatorResult::new(&[v, s], &[Action::from(signal)])
 	}
 }
+
+impl Checkpoint for MomentumIndexInstance {
+	type State = (
+		<Momentum as Checkpoint>::State,
+		<Momentum as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.momentum1.save(),
+			self.momentum2.save(),
+			self.ma1.save(),
+			self.ma2.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.momentum1.restore(&state.0);
+		self.momentum2.restore(&state.1);
+		self.ma1.restore(&state.2);
+		self.ma2.restore(&state.3);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MomentumIndex;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+
+	#[test]
+	fn test_momentum_index_percent_zero_reference() {
+		let cfg = MomentumIndex {
+			period1: 2,
+			period2: 1,
+			percent: true,
+			..MomentumIndex::default()
+		};
+
+		let candle = |close| Candle {
+			close,
+			..Candle::default()
+		};
+
+		let mut instance = cfg.init(&candle(0.0)).unwrap();
+		let result = instance.next(&candle(2.0));
+		assert_eq!(result.values(), &[0.0, 0.0]);
+
+		let result = instance.next(&candle(3.0));
+		assert_eq!(result.values(), &[0.0, 50.0]);
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_momentum_index_legacy_format() {
+		use crate::core::Source;
+
+		let cfg: MomentumIndex =
+			serde_json::from_str(r#"{"period1":12,"period2":3,"source":"open"}"#).unwrap();
+
+		assert_eq!(
+			(cfg.period1, cfg.period2, cfg.source),
+			(12, 3, Source::Open)
+		);
+		assert_eq!(cfg.period3, MomentumIndex::default().period3);
+		assert_eq!(cfg.method, MomentumIndex::default().method);
+		assert_eq!(cfg.percent, MomentumIndex::default().percent);
+	}
+}
@@ -2,8 +2,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::Candle;
-use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	Checkpoint, Error, Method, PeriodType, ValueType, Window, WindowCheckpoint, OHLCV,
+};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
 use crate::methods::Cross;
 
 /// Money Flow Index
@@ -54,11 +57,23 @@ impl IndicatorConfig for MoneyFlowIndex {
 
 	const NAME: &'static str = "MoneyFlowIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volume,
+		&[
+			ValueRange::Bounded(0.5, 1.0),
+			ValueRange::Bounded(0.0, 1.0),
+			ValueRange::Bounded(0.0, 0.5),
+		],
+	)
+	.with_volume();
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
 		}
 
+		Self::METADATA.check(candle)?;
+
 		let static_candle = Candle::from(candle);
 		let cfg = self;
 		Ok(Self::Instance {
@@ -80,11 +95,11 @@ impl IndicatorConfig for MoneyFlowIndex {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 
@@ -101,6 +116,18 @@ impl IndicatorConfig for MoneyFlowIndex {
 	}
 }
 
+impl Oscillator for MoneyFlowIndex {
+	const MAIN: usize = 1;
+
+	fn overbought(&self) -> ValueType {
+		1.0 - self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		self.zone
+	}
+}
+
 impl Default for MoneyFlowIndex {
 	fn default() -> Self {
 		Self {
@@ -111,6 +138,7 @@ impl Default for MoneyFlowIndex {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MoneyFlowIndexInstance {
 	cfg: MoneyFlowIndex,
 
@@ -129,8 +157,8 @@ fn tfunc(candle: &Candle, last_candle: &Candle) -> (ValueType, ValueType) {
 	let tp2 = last_candle.tp();
 
 	(
-		(tp1 > tp2) as i8 as ValueType * candle.volume(),
-		(tp1 < tp2) as i8 as ValueType * candle.volume(),
+		(tp1 > tp2) as i8 as ValueType * candle.volume_or_zero(),
+		(tp1 < tp2) as i8 as ValueType * candle.volume_or_zero(),
 	)
 }
 
@@ -141,6 +169,10 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let static_candle = Candle::from(candle);
 		let (pos, neg) = tfunc(&static_candle, &self.prev_candle);
@@ -176,3 +208,39 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		)
 	}
 }
+
+impl Checkpoint for MoneyFlowIndexInstance {
+	type State = (
+		WindowCheckpoint<Candle>,
+		Candle,
+		Candle,
+		ValueType,
+		ValueType,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.window.save(),
+			self.prev_candle,
+			self.last_prev_candle,
+			self.pmf,
+			self.nmf,
+			self.cross_lower.save(),
+			self.cross_upper.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(&state.0);
+		self.prev_candle = state.1;
+		self.last_prev_candle = state.2;
+		self.pmf = state.3;
+		self.nmf = state.4;
+		self.cross_lower.restore(&state.5);
+		self.cross_upper.restore(&state.6);
+	}
+}
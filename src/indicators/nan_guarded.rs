@@ -0,0 +1,290 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Candle, Checkpoint, Error, NanPolicy, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorMetadata, IndicatorResult};
+use std::fmt;
+
+/// Guards any other indicator from candles with non-finite (`NaN` or infinite) values
+///
+/// Candle is considered non-finite when any of its *open*, *high*, *low* or *close* values is not finite or when *volume* is infinite
+/// (*volume* may be `NaN` for candles without volume data).
+///
+/// When the inner `indicator` [requires volume](crate::core::IndicatorMetadata::requires_volume), candles without volume data
+/// (see [`OHLCV::has_volume`]) are handled the same way as non-finite ones, but [`Error::NoVolume`] is returned instead.
+/// So volume-based indicators may be explicitly degraded over data with gaps in volume instead of considering such candles
+/// as candles with zero volume (see [`OHLCV::volume_or_zero`]).
+///
+/// When `validate_candles` is `true`, every candle is also checked by [`OHLCV::validate`] (e.g. *high* must not be less than *low*),
+/// so a single bad tick does not poison internal state of the inner `indicator` forever.
+/// Invalid candles are handled the same way as non-finite ones, but [`Error::InvalidCandles`] is returned instead of [`Error::NonFinite`].
+///
+/// Every finite (and valid) candle is passed to the inner `indicator` as is.
+/// Every non-finite (or invalid) candle is handled according to `policy`:
+///
+/// * [`NanPolicy::Skip`] — candle is not passed to the inner `indicator`, last result is returned.
+///   If there were no results yet, the last finite candle is passed instead;
+/// * [`NanPolicy::CarryLast`] — the last finite candle is passed to the inner `indicator` instead;
+/// * [`NanPolicy::Error`] — candle is not passed to the inner `indicator`.
+///   [`NanGuardedInstance::try_next`] returns an error for such candles,
+///   while [`IndicatorInstance::next`] acts like [`NanPolicy::Skip`], because it can not return an error.
+///
+/// # Values
+///
+/// Returns the same values as the inner `indicator` does.
+///
+/// # Signals
+///
+/// Returns the same signals as the inner `indicator` does.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, NanPolicy, ValueType};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::{NanGuarded, RelativeStrengthIndex};
+///
+/// let mut candles: Vec<Candle> = RandomCandles::new().take(20).collect();
+/// candles[10].close = ValueType::NAN;
+///
+/// let guarded = NanGuarded {
+///     indicator: RelativeStrengthIndex::default(),
+///     policy: NanPolicy::Error,
+///     validate_candles: false,
+/// };
+///
+/// let mut state = guarded.init(&candles[0]).unwrap();
+///
+/// assert!(state.try_next(&candles[9]).is_ok());
+/// assert!(state.try_next(&candles[10]).is_err());
+/// assert!(state.try_next(&candles[11]).unwrap().value(0).is_finite());
+///
+/// // validating candles
+/// let guarded = NanGuarded {
+///     indicator: RelativeStrengthIndex::default(),
+///     policy: NanPolicy::Error,
+///     validate_candles: true,
+/// };
+///
+/// let mut state = guarded.init(&candles[0]).unwrap();
+/// let mut bad_candle = candles[12];
+/// bad_candle.high = bad_candle.low - 1.0;
+///
+/// assert!(state.try_next(&bad_candle).is_err());
+/// assert!(state.try_next(&candles[12]).is_ok());
+/// ```
+///
+/// [`NanPolicy::Skip`]: crate::core::NanPolicy::Skip
+/// [`NanPolicy::CarryLast`]: crate::core::NanPolicy::CarryLast
+/// [`NanPolicy::Error`]: crate::core::NanPolicy::Error
+/// [`Error::NonFinite`]: crate::core::Error::NonFinite
+/// [`Error::InvalidCandles`]: crate::core::Error::InvalidCandles
+/// [`Error::NoVolume`]: crate::core::Error::NoVolume
+/// [`OHLCV::has_volume`]: crate::core::OHLCV::has_volume
+/// [`OHLCV::volume_or_zero`]: crate::core::OHLCV::volume_or_zero
+/// [`OHLCV::validate`]: crate::core::OHLCV::validate
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NanGuarded<C: IndicatorConfig> {
+	/// Inner indicator configuration.
+	pub indicator: C,
+
+	/// Non-finite (or invalid) candles handling policy. Default is [`Skip`](crate::core::NanPolicy::Skip).
+	pub policy: NanPolicy,
+
+	/// Also validate every candle by [`OHLCV::validate`](crate::core::OHLCV::validate). Default is `false`.
+	pub validate_candles: bool,
+}
+
+impl<C: IndicatorConfig> NanGuarded<C> {
+	fn check<T: OHLCV>(&self, candle: &T) -> Result<(), Error> {
+		let is_finite = candle.open().is_finite()
+			&& candle.high().is_finite()
+			&& candle.low().is_finite()
+			&& candle.close().is_finite()
+			&& !candle.volume().is_infinite();
+
+		if !is_finite {
+			Err(Error::NonFinite)
+		} else if self.validate_candles && !candle.validate() {
+			Err(Error::InvalidCandles)
+		} else {
+			C::METADATA.check(candle)
+		}
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorConfig for NanGuarded<C> {
+	type Instance = NanGuardedInstance<C>;
+
+	const NAME: &'static str = "NanGuarded";
+
+	const METADATA: IndicatorMetadata = C::METADATA;
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		self.check(candle)?;
+
+		let cfg = self;
+		Ok(Self::Instance {
+			instance: cfg.indicator.clone().init(candle)?,
+			last_candle: Candle::from(candle),
+			last_result: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.indicator.validate()
+	}
+
+	/// Sets `policy` or `validate_candles` of the wrapper. Any other parameter is passed to the inner `indicator`.
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"policy" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.policy = value,
+			},
+			"validate_candles" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.validate_candles = value,
+			},
+
+			_ => return self.indicator.set(name, value),
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		self.indicator.size()
+	}
+}
+
+impl<C: IndicatorConfig + Default> Default for NanGuarded<C> {
+	fn default() -> Self {
+		Self {
+			indicator: C::default(),
+			policy: NanPolicy::default(),
+			validate_candles: false,
+		}
+	}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NanGuardedInstance<C: IndicatorConfig> {
+	cfg: NanGuarded<C>,
+
+	instance: C::Instance,
+	last_candle: Candle,
+	last_result: Option<IndicatorResult>,
+}
+
+impl<C: IndicatorConfig> NanGuardedInstance<C> {
+	/// Evaluates the next candle like [`IndicatorInstance::next`] does,
+	/// but returns [`Error::NonFinite`](crate::core::Error::NonFinite) for non-finite candles
+	/// (or [`Error::InvalidCandles`](crate::core::Error::InvalidCandles) for invalid candles when `validate_candles` is `true`)
+	/// when `policy` is [`NanPolicy::Error`](crate::core::NanPolicy::Error).
+	pub fn try_next<T: OHLCV>(&mut self, candle: &T) -> Result<IndicatorResult, Error> {
+		let result = if let Err(error) = self.cfg.check(candle) {
+			match (self.cfg.policy, self.last_result) {
+				(NanPolicy::Error, _) => return Err(error),
+				(NanPolicy::Skip, Some(result)) => return Ok(result),
+				_ => self.instance.next(&self.last_candle),
+			}
+		} else {
+			self.last_candle = Candle::from(candle);
+			self.instance.next(candle)
+		};
+
+		self.last_result = Some(result);
+
+		Ok(result)
+	}
+}
+
+impl<C> Clone for NanGuardedInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			instance: self.instance.clone(),
+			last_candle: self.last_candle,
+			last_result: self.last_result,
+		}
+	}
+}
+
+impl<C> fmt::Debug for NanGuardedInstance<C>
+where
+	C: IndicatorConfig + fmt::Debug,
+	C::Instance: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("NanGuardedInstance")
+			.field("cfg", &self.cfg)
+			.field("instance", &self.instance)
+			.field("last_candle", &self.last_candle)
+			.field("last_result", &self.last_result)
+			.finish()
+	}
+}
+
+impl<C: IndicatorConfig> IndicatorInstance for NanGuardedInstance<C> {
+	type Config = NanGuarded<C>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.instance.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		match self.try_next(candle) {
+			Ok(result) => result,
+			// policy is `Error`, so act like `Skip`
+			Err(_) => {
+				if let Some(result) = self.last_result {
+					result
+				} else {
+					let result = self.instance.next(&self.last_candle);
+					self.last_result = Some(result);
+					result
+				}
+			}
+		}
+	}
+}
+
+impl<C> Checkpoint for NanGuardedInstance<C>
+where
+	C: IndicatorConfig,
+	C::Instance: Checkpoint,
+{
+	type State = (
+		<C::Instance as Checkpoint>::State,
+		Candle,
+		Option<IndicatorResult>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.instance.save(), self.last_candle, self.last_result)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.instance.restore(&state.0);
+		self.last_candle = state.1;
+		self.last_result = state.2;
+	}
+}
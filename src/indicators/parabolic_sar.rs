@@ -2,7 +2,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use std::cmp::Ordering;
 
 use super::HLC;
@@ -39,6 +40,11 @@ impl IndicatorConfig for ParabolicSAR {
 
 	const NAME: &'static str = "ParabolicSAR";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Source, ValueRange::Bounded(-1.0, 1.0)],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -64,11 +70,11 @@ impl IndicatorConfig for ParabolicSAR {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"af_step" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.af_step = value,
 			},
 			"af_max" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.af_max = value,
 			},
 
@@ -95,6 +101,7 @@ impl Default for ParabolicSAR {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParabolicSARInstance {
 	cfg: ParabolicSAR,
 
@@ -176,3 +183,17 @@ impl IndicatorInstance for ParabolicSARInstance {
 		IndicatorResult::new(&[sar, trend as ValueType], &[Action::from(signal)])
 	}
 }
+
+impl Checkpoint for ParabolicSARInstance {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
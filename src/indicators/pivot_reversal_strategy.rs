@@ -1,9 +1,12 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, WindowCheckpoint};
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{LowerReversalSignal, UpperReversalSignal};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{LowerReversalSignal, UpperReversalSignal, TR};
 
 use super::HLC;
 
@@ -15,7 +18,21 @@ use super::HLC;
 ///
 /// * <https://www.incrediblecharts.com/technical/pivot_point_reversal.php>
 ///
-/// # No values
+/// # 2 values
+///
+/// * `long stop` value
+///
+/// Suggested stop level for long position: last low pivot price minus `k` average true ranges.
+/// Until the first low pivot appears, the first candle's `low` price is used.
+///
+/// Range of values is the same as the range of the source values.
+///
+/// * `short stop` value
+///
+/// Suggested stop level for short position: last high pivot price plus `k` average true ranges.
+/// Until the first high pivot appears, the first candle's `high` price is used.
+///
+/// Range of values is the same as the range of the source values.
 ///
 /// # 1 signal
 ///
@@ -27,6 +44,7 @@ use super::HLC;
 ///
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PivotReversalStrategy {
 	/// How many periods should left before pivot point.
 	///
@@ -37,6 +55,19 @@ pub struct PivotReversalStrategy {
 	///
 	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`left`\).
 	pub right: PeriodType,
+
+	/// Average true range period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub period: PeriodType,
+
+	/// Average true range method. Default is [`RMA`](crate::methods::RMA).
+	pub method: RegularMethods,
+
+	/// Average true range multiplier for stop levels. Default is `1.0`.
+	///
+	/// Range in \[`0.0`; `+inf`\).
+	pub k: ValueType,
 }
 
 impl IndicatorConfig for PivotReversalStrategy {
@@ -44,6 +75,11 @@ impl IndicatorConfig for PivotReversalStrategy {
 
 	const NAME: &'static str = "PivotReversalStrategy";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Source, ValueRange::Source],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -56,24 +92,45 @@ impl IndicatorConfig for PivotReversalStrategy {
 			window: Window::new(cfg.right, HLC::from(candle)),
 			hprice: 0.,
 			lprice: 0.,
+			high_pivot: candle.high(),
+			low_pivot: candle.low(),
+			tr: TR::new(&candle)?,
+			atr: method(cfg.method, cfg.period, candle.high() - candle.low())?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+		self.left >= 1
+			&& self.right >= 1
+			&& self.left.saturating_add(self.right) < PeriodType::MAX
+			&& self.period >= 1
+			&& self.period < PeriodType::MAX
+			&& self.k >= 0.0
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"left" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.left = value,
 			},
 			"right" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.right = value,
 			},
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+			"k" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.k = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -84,17 +141,24 @@ impl IndicatorConfig for PivotReversalStrategy {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(0, 1)
+		(2, 1)
 	}
 }
 
 impl Default for PivotReversalStrategy {
 	fn default() -> Self {
-		Self { left: 4, right: 2 }
+		Self {
+			left: 4,
+			right: 2,
+			period: 14,
+			method: RegularMethods::RMA,
+			k: 1.0,
+		}
 	}
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PivotReversalStrategyInstance {
 	cfg: PivotReversalStrategy,
 
@@ -103,6 +167,10 @@ pub struct PivotReversalStrategyInstance {
 	window: Window<HLC>,
 	hprice: ValueType,
 	lprice: ValueType,
+	high_pivot: ValueType,
+	low_pivot: ValueType,
+	tr: TR,
+	atr: RegularMethod,
 }
 
 impl IndicatorInstance for PivotReversalStrategyInstance {
@@ -112,6 +180,10 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ph.heap_size() + self.pl.heap_size() + self.window.heap_size() + self.atr.heap_size()
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
@@ -120,11 +192,14 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 		let swh = self.ph.next(high);
 		let swl = self.pl.next(low);
 
+		let atr = self.atr.next(self.tr.next(candle));
+
 		let mut le = 0;
 		let mut se = 0;
 
 		if swh.analog() > 0 {
 			self.hprice = past_candle.high();
+			self.high_pivot = self.hprice;
 		}
 
 		if swh.analog() > 0 || candle.high() <= self.hprice {
@@ -133,6 +208,7 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 
 		if swl.analog() > 0 {
 			self.lprice = past_candle.low();
+			self.low_pivot = self.lprice;
 		}
 
 		if swl.analog() > 0 || low >= self.lprice {
@@ -141,6 +217,68 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 
 		let r = se - le;
 
-		IndicatorResult::new(&[], &[r.into()])
+		let long_stop = atr.mul_add(-self.cfg.k, self.low_pivot);
+		let short_stop = atr.mul_add(self.cfg.k, self.high_pivot);
+
+		IndicatorResult::new(&[long_stop, short_stop], &[r.into()])
+	}
+}
+
+impl Checkpoint for PivotReversalStrategyInstance {
+	type State = (
+		<UpperReversalSignal as Checkpoint>::State,
+		<LowerReversalSignal as Checkpoint>::State,
+		WindowCheckpoint<HLC>,
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+		<TR as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ph.save(),
+			self.pl.save(),
+			self.window.save(),
+			self.hprice,
+			self.lprice,
+			self.high_pivot,
+			self.low_pivot,
+			self.tr.save(),
+			self.atr.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ph.restore(&state.0);
+		self.pl.restore(&state.1);
+		self.window.restore(&state.2);
+		self.hprice = state.3;
+		self.lprice = state.4;
+		self.high_pivot = state.5;
+		self.low_pivot = state.6;
+		self.tr.restore(&state.7);
+		self.atr.restore(&state.8);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_pivot_reversal_strategy_legacy_format() {
+		use super::PivotReversalStrategy;
+		use crate::helpers::assert_eq_float;
+
+		let cfg: PivotReversalStrategy = serde_json::from_str(r#"{"left":3,"right":5}"#).unwrap();
+
+		assert_eq!((cfg.left, cfg.right), (3, 5));
+		let default = PivotReversalStrategy::default();
+		assert_eq!((cfg.period, cfg.method), (default.period, default.method));
+		assert_eq_float(default.k, cfg.k);
 	}
 }
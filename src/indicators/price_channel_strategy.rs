@@ -1,19 +1,22 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::methods::{Highest, Lowest};
 
 /// Price Channel Strategy
 ///
 /// Calculates price channel by highes high and lowest low for last `period` candles.
 ///
+/// Also calculates exit price channel the same way for last `period2` candles (classic turtle 20-in/10-out setup).
+///
 /// ## Links
 ///
 /// * <https://www.investopedia.com/terms/p/price-channel.asp>
 ///
-/// # 2 values
+/// # 4 values
 ///
 /// * `Upper bound` value
 ///
@@ -23,20 +26,40 @@ use crate::methods::{Highest, Lowest};
 ///
 /// Range of values is the same as the range of the source values.
 ///
-/// # 1 signal
+/// * `Exit upper bound` value
+///
+/// Range of values is the same as the range of the source values.
+///
+/// * `Exit lower bound` value
+///
+/// Range of values is the same as the range of the source values.
 ///
-/// When current `high` price touches `upper bound`, returns full buy signal.
-/// When current `low` price touches `lower bound`, returns full sell signal.
-/// When both touches occure, or no toucher, then returns no signal.
+/// # 2 signals
+///
+/// * Entry signal.
+///   When current `high` price touches `upper bound`, returns full buy signal.
+///   When current `low` price touches `lower bound`, returns full sell signal.
+///   When both touches occure, or no toucher, then returns no signal.
+///
+/// * Exit signal.
+///   When current `high` price touches `exit upper bound`, returns full buy signal (exit short position).
+///   When current `low` price touches `exit lower bound`, returns full sell signal (exit long position).
+///   When both touches occure, or no toucher, then returns no signal.
 ///
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PriceChannelStrategy {
 	/// Main period length. Default is `20`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period: PeriodType,
 
+	/// Exit channel period length. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
 	/// Relative channel size. Default is `1.0`.
 	///
 	/// Range in \(`0.0`; `1.0`\]
@@ -48,6 +71,16 @@ impl IndicatorConfig for PriceChannelStrategy {
 
 	const NAME: &'static str = "PriceChannelStrategy";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+			ValueRange::Source,
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -57,22 +90,33 @@ impl IndicatorConfig for PriceChannelStrategy {
 		Ok(Self::Instance {
 			highest: Highest::new(cfg.period, candle.high())?,
 			lowest: Lowest::new(cfg.period, candle.low())?,
+			exit_highest: Highest::new(cfg.period2, candle.high())?,
+			exit_lowest: Lowest::new(cfg.period2, candle.low())?,
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.sigma > 0. && self.sigma <= 1.0
+		self.period > 1
+			&& self.period2 > 1
+			&& self.period < PeriodType::MAX
+			&& self.period2 < PeriodType::MAX
+			&& self.sigma > 0.
+			&& self.sigma <= 1.0
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
+			"period2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period2 = value,
+			},
 			"sigma" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.sigma = value,
 			},
 
@@ -85,7 +129,7 @@ impl IndicatorConfig for PriceChannelStrategy {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(2, 1)
+		(4, 2)
 	}
 }
 
@@ -93,17 +137,33 @@ impl Default for PriceChannelStrategy {
 	fn default() -> Self {
 		Self {
 			period: 20,
+			period2: 10,
 			sigma: 1.0,
 		}
 	}
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PriceChannelStrategyInstance {
 	cfg: PriceChannelStrategy,
 
 	highest: Highest,
 	lowest: Lowest,
+	exit_highest: Highest,
+	exit_lowest: Lowest,
+}
+
+impl PriceChannelStrategyInstance {
+	fn bounds(&self, highest: ValueType, lowest: ValueType) -> (ValueType, ValueType) {
+		let middle = (highest + lowest) * 0.5;
+		let delta = highest - middle;
+
+		let upper = delta.mul_add(self.cfg.sigma, middle);
+		let lower = middle - delta * self.cfg.sigma;
+
+		(upper, lower)
+	}
 }
 
 impl IndicatorInstance for PriceChannelStrategyInstance {
@@ -113,22 +173,75 @@ impl IndicatorInstance for PriceChannelStrategyInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.highest.heap_size()
+			+ self.lowest.heap_size()
+			+ self.exit_highest.heap_size()
+			+ self.exit_lowest.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
 		let highest = self.highest.next(high);
 		let lowest = self.lowest.next(low);
+		let (upper, lower) = self.bounds(highest, lowest);
 
-		let middle = (highest + lowest) * 0.5;
-		let delta = highest - middle;
-
-		let upper = delta.mul_add(self.cfg.sigma, middle);
-		let lower = middle - delta * self.cfg.sigma;
+		let exit_highest = self.exit_highest.next(high);
+		let exit_lowest = self.exit_lowest.next(low);
+		let (exit_upper, exit_lower) = self.bounds(exit_highest, exit_lowest);
 
-		let signal_up = (candle.high() >= upper) as i8;
-		let signal_down = (candle.low() <= lower) as i8;
+		let signal_up = (high >= upper) as i8;
+		let signal_down = (low <= lower) as i8;
 
 		let signal = signal_up - signal_down;
 
-		IndicatorResult::new(&[upper, lower], &[signal.into()])
+		let exit_signal = (high >= exit_upper) as i8 - (low <= exit_lower) as i8;
+
+		IndicatorResult::new(
+			&[upper, lower, exit_upper, exit_lower],
+			&[signal.into(), exit_signal.into()],
+		)
+	}
+}
+
+impl Checkpoint for PriceChannelStrategyInstance {
+	type State = (
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.highest.save(),
+			self.lowest.save(),
+			self.exit_highest.save(),
+			self.exit_lowest.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest.restore(&state.0);
+		self.lowest.restore(&state.1);
+		self.exit_highest.restore(&state.2);
+		self.exit_lowest.restore(&state.3);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_price_channel_strategy_legacy_format() {
+		use super::PriceChannelStrategy;
+
+		let cfg: PriceChannelStrategy =
+			serde_json::from_str(r#"{"period":30,"sigma":0.5}"#).unwrap();
+
+		assert_eq!(cfg.period, 30);
+		assert_eq!(cfg.period2, PriceChannelStrategy::default().period2);
 	}
 }
@@ -0,0 +1,245 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::methods::{Cross, EMA, RMA};
+use std::mem::replace;
+
+/// Quantitative Qualitative Estimation
+///
+/// [`RSI`](crate::indicators::RSI) of period `rsi_period`, smoothed by [`EMA`] of period `smoothing`,
+/// with a trailing level, which follows the smoothed RSI at the distance of its own volatility.
+///
+/// Volatility is an average true range of the smoothed RSI: absolute change of the smoothed RSI,
+/// double smoothed by [`EMA`] of period `2` * `rsi_period` - `1` and multiplied by `factor`.
+///
+/// While the smoothed RSI is above the trailing level, the level may only go up. While it is below, the level may only go down.
+/// When the smoothed RSI crosses the level, the level jumps to the other side.
+///
+/// RSI is calculated using Wilder's smoothing ([`RMA`]).
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/script/tJ6vtBBe-QQE/>
+///
+/// # 2 values
+///
+/// * `main` value (smoothed RSI)
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * `trailing level` value
+///
+/// Range is unbounded, but usually is about the same as of the `main` value
+///
+/// # 1 signal
+///
+/// * When `main` value crosses `trailing level` upwards, returns full buy signal.
+///   When `main` value crosses `trailing level` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuantitativeQualitativeEstimation {
+	/// RSI period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType) / `2`\]
+	pub rsi_period: PeriodType,
+
+	/// RSI smoothing period. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub smoothing: PeriodType,
+
+	/// Volatility multiplier for the trailing level. Default is `4.236`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub factor: ValueType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for QuantitativeQualitativeEstimation {
+	type Instance = QuantitativeQualitativeEstimationInstance;
+
+	const NAME: &'static str = "QuantitativeQualitativeEstimation";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Bounded(0.0, 1.0), ValueRange::Unbounded],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let volatility_period = cfg.rsi_period * 2 - 1;
+
+		Ok(Self::Instance {
+			previous_input: src,
+			posma: RMA::new(cfg.rsi_period, 0.)?,
+			negma: RMA::new(cfg.rsi_period, 0.)?,
+			rsi_ma: EMA::new(cfg.smoothing, 0.5)?,
+			delta_ma1: EMA::new(volatility_period, 0.)?,
+			delta_ma2: EMA::new(volatility_period, 0.)?,
+			previous_rsi: 0.5,
+			long_band: 0.5,
+			short_band: 0.5,
+			trend: 1,
+			cross: Cross::new((), (0.5, 0.5))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.rsi_period > 0
+			&& self.rsi_period <= PeriodType::MAX / 2
+			&& self.smoothing > 0
+			&& self.smoothing < PeriodType::MAX
+			&& self.factor > 0.
+			&& self.factor.is_finite()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"rsi_period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.rsi_period = value,
+			},
+			"smoothing" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.smoothing = value,
+			},
+			"factor" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.factor = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for QuantitativeQualitativeEstimation {
+	fn default() -> Self {
+		Self {
+			rsi_period: 14,
+			smoothing: 5,
+			factor: 4.236,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`QuantitativeQualitativeEstimation`]
+pub type QQE = QuantitativeQualitativeEstimation;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuantitativeQualitativeEstimationInstance {
+	cfg: QuantitativeQualitativeEstimation,
+
+	previous_input: ValueType,
+	posma: RMA,
+	negma: RMA,
+	rsi_ma: EMA,
+	delta_ma1: EMA,
+	delta_ma2: EMA,
+	previous_rsi: ValueType,
+	long_band: ValueType,
+	short_band: ValueType,
+	trend: i8,
+	cross: Cross,
+}
+
+impl IndicatorInstance for QuantitativeQualitativeEstimationInstance {
+	type Config = QuantitativeQualitativeEstimation;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let change = src - replace(&mut self.previous_input, src);
+		let pos = self.posma.next(change.max(0.));
+		let neg = -self.negma.next(change.min(0.));
+
+		let rsi = if pos != 0. || neg != 0. {
+			pos / (pos + neg)
+		} else {
+			0.5
+		};
+
+		let value = self.rsi_ma.next(rsi);
+		let previous = replace(&mut self.previous_rsi, value);
+
+		let delta = self.delta_ma1.next((value - previous).abs());
+		let distance = self.delta_ma2.next(delta) * self.cfg.factor;
+
+		let new_long_band = value - distance;
+		let new_short_band = value + distance;
+
+		let long_band = if previous > self.long_band && value > self.long_band {
+			self.long_band.max(new_long_band)
+		} else {
+			new_long_band
+		};
+
+		let short_band = if previous < self.short_band && value < self.short_band {
+			self.short_band.min(new_short_band)
+		} else {
+			new_short_band
+		};
+
+		if previous <= self.short_band && value > self.short_band {
+			self.trend = 1;
+		} else if previous >= self.long_band && value < self.long_band {
+			self.trend = -1;
+		}
+
+		self.long_band = long_band;
+		self.short_band = short_band;
+
+		let level = if self.trend > 0 {
+			long_band
+		} else {
+			short_band
+		};
+		let signal = self.cross.next((value, level));
+
+		IndicatorResult::new(&[value, level], &[signal])
+	}
+}
+
+impl Checkpoint for QuantitativeQualitativeEstimationInstance {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
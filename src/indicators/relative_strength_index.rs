@@ -1,10 +1,12 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
+use crate::core::{Preset, Presets};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::Cross;
+use crate::methods::{Cross, Divergence};
 use std::mem::replace;
 
 /// Relative Strength Index
@@ -32,6 +34,16 @@ use std::mem::replace;
 /// When main value crosses upper zone downwards, returns full sell signal.
 /// When main value crosses lower zone upwards, returns full buy signal.
 /// Otherwise returns no signal.
+///
+/// When `divergence` is `true`, there is a third signal:
+///
+/// * Signal #3 on divergence.
+///
+/// When `source` value makes a new low over `divergence_period` candles, but main value does not, returns full buy signal.
+/// When `source` value makes a new high over `divergence_period` candles, but main value does not, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// See also [`Divergence`](crate::methods::Divergence).
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RelativeStrengthIndex {
@@ -50,6 +62,14 @@ pub struct RelativeStrengthIndex {
 
 	/// Moving average method. Default is [`EMA`](crate::methods::EMA).
 	pub method: RegularMethods,
+
+	/// Enables divergence signal. Default is `false`.
+	pub divergence: bool,
+
+	/// Divergence search period. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub divergence_period: PeriodType,
 }
 
 impl IndicatorConfig for RelativeStrengthIndex {
@@ -57,6 +77,11 @@ impl IndicatorConfig for RelativeStrengthIndex {
 
 	const NAME: &'static str = "RelativeStrengthIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Bounded(0.0, 1.0)],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -71,32 +96,49 @@ impl IndicatorConfig for RelativeStrengthIndex {
 			negma: method(cfg.method, cfg.period, 0.)?,
 			cross_upper: Cross::new((), (0.5, 1.0 - cfg.zone))?,
 			cross_lower: Cross::new((), (0.5, cfg.zone))?,
+			divergence: if cfg.divergence {
+				Some(Divergence::new(cfg.divergence_period, (src, 0.5))?)
+			} else {
+				None
+			},
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 2 && self.zone > 0. && self.zone <= 0.5
+		self.period > 2
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+			&& self.divergence_period > 0
+			&& self.divergence_period < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
+			"divergence" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.divergence = value,
+			},
+			"divergence_period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.divergence_period = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -107,8 +149,47 @@ impl IndicatorConfig for RelativeStrengthIndex {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 2)
+		if self.divergence {
+			(1, 3)
+		} else {
+			(1, 2)
+		}
+	}
+}
+
+impl Oscillator for RelativeStrengthIndex {
+	const MAIN: usize = 0;
+
+	fn overbought(&self) -> ValueType {
+		1.0 - self.zone
 	}
+
+	fn oversold(&self) -> ValueType {
+		self.zone
+	}
+}
+
+impl Presets for RelativeStrengthIndex {
+	const PRESETS: &'static [Preset<Self>] = &[
+		Preset::new("classic", "14 periods, 70/30 zones", Self::default),
+		Preset::new(
+			"wilder",
+			"14 periods smoothed by Wilder's average, 70/30 zones",
+			|| Self {
+				method: RegularMethods::WSMA,
+				..Self::default()
+			},
+		),
+		Preset::new("short_term", "9 periods, 70/30 zones", || Self {
+			period: 9,
+			..Self::default()
+		}),
+		Preset::new("crypto_fast", "7 periods, 80/20 zones", || Self {
+			period: 7,
+			zone: 0.2,
+			..Self::default()
+		}),
+	];
 }
 
 impl Default for RelativeStrengthIndex {
@@ -118,11 +199,14 @@ impl Default for RelativeStrengthIndex {
 			zone: 0.3,
 			method: RegularMethods::EMA,
 			source: Source::Close,
+			divergence: false,
+			divergence_period: 14,
 		}
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RelativeStrengthIndexInstance {
 	cfg: RelativeStrengthIndex,
 
@@ -131,6 +215,7 @@ pub struct RelativeStrengthIndexInstance {
 	negma: RegularMethod,
 	cross_upper: Cross,
 	cross_lower: Cross,
+	divergence: Option<Divergence>,
 }
 
 /// Just an alias for `RelativeStrengthIndex`
@@ -143,6 +228,12 @@ impl IndicatorInstance for RelativeStrengthIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.posma.heap_size()
+			+ self.negma.heap_size()
+			+ self.divergence.as_ref().map_or(0, Divergence::heap_size)
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -164,6 +255,96 @@ impl IndicatorInstance for RelativeStrengthIndexInstance {
 		let signal1 = (oversold < 0) as i8 - (overbought > 0) as i8;
 		let signal2 = (oversold > 0) as i8 - (overbought < 0) as i8;
 
+		if let Some(divergence) = self.divergence.as_mut() {
+			let signal3 = divergence.next((src, value));
+
+			return IndicatorResult::new(&[value], &[signal1.into(), signal2.into(), signal3]);
+		}
+
 		IndicatorResult::new(&[value], &[signal1.into(), signal2.into()])
 	}
 }
+
+impl Checkpoint for RelativeStrengthIndexInstance {
+	type State = (
+		ValueType,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Option<Divergence> as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.previous_input,
+			self.posma.save(),
+			self.negma.save(),
+			self.cross_upper.save(),
+			self.cross_lower.save(),
+			self.divergence.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.previous_input = state.0;
+		self.posma.restore(&state.1);
+		self.negma.restore(&state.2);
+		self.cross_upper.restore(&state.3);
+		self.cross_lower.restore(&state.4);
+		self.divergence.restore(&state.5);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::RelativeStrengthIndex;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+
+	#[test]
+	fn test_relative_strength_index_divergence() {
+		let candle = |close: ValueType| Candle {
+			close,
+			..Candle::default()
+		};
+
+		let plain = RelativeStrengthIndex {
+			period: 3,
+			method: RegularMethods::SMA,
+			divergence_period: 2,
+			..RelativeStrengthIndex::default()
+		};
+		let cfg = RelativeStrengthIndex {
+			divergence: true,
+			..plain
+		};
+
+		let mut plain = plain.init(&candle(10.0)).unwrap();
+		let mut instance = cfg.init(&candle(10.0)).unwrap();
+
+		let expected = [
+			(11.0, 1.0, Action::None),
+			(13.0, 1.0, Action::None),
+			(12.0, 0.75, Action::None),
+			// new price high, but RSI is below 1.0
+			(14.0, 0.8, Action::SELL_ALL),
+			(11.0, 1.0 / 3.0, Action::None),
+			(9.0, 2.0 / 7.0, Action::None),
+			(10.0, 1.0 / 6.0, Action::None),
+			// new price low, but RSI is above 1/6
+			(8.5, 2.0 / 9.0, Action::BUY_ALL),
+		];
+
+		for &(close, value, divergence) in &expected {
+			let plain = plain.next(&candle(close));
+			let result = instance.next(&candle(close));
+
+			assert_eq_float(value, result.value(0));
+			assert_eq!(plain.signals(), &result.signals()[..2]);
+			assert_eq!(divergence, result.signal(2));
+		}
+	}
+}
@@ -1,10 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Cross, SMA, SWMA};
+use crate::methods::{Cross, SMA};
 
 /// Relative Vigor Index
 ///
@@ -12,7 +13,7 @@ use crate::methods::{Cross, SMA, SWMA};
 ///
 /// * <https://www.investopedia.com/terms/r/relative_vigor_index.asp>
 ///
-/// # 2 values
+/// # 3 values
 ///
 /// * `main` value
 ///
@@ -22,6 +23,10 @@ use crate::methods::{Cross, SMA, SWMA};
 ///
 /// Range in \[`-0.5`; `0.5`\]
 ///
+/// * `histogram` value (`main` - `signal line`)
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
 /// # 2 signals
 ///
 /// * Signal #1 on `main` value crosses `signal line` value.
@@ -43,11 +48,14 @@ pub struct RelativeVigorIndex {
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period1: PeriodType,
 
-	/// SWMA period. Default is `4`.
+	/// Smoothing MA period. Default is `4`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub period2: PeriodType,
 
+	/// Smoothing MA method. Default is [`SWMA`](crate::methods::SWMA).
+	pub smooth_method: RegularMethods,
+
 	/// Signal line MA period. Default is `4`.
 	///
 	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
@@ -67,6 +75,15 @@ impl IndicatorConfig for RelativeVigorIndex {
 
 	const NAME: &'static str = "RelativeVigorIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[
+			ValueRange::Bounded(-0.5, 0.5),
+			ValueRange::Bounded(-0.5, 0.5),
+			ValueRange::Bounded(-1.0, 1.0),
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -79,9 +96,9 @@ impl IndicatorConfig for RelativeVigorIndex {
 
 		Ok(Self::Instance {
 			prev_close: candle.open(),
-			swma1: SWMA::new(cfg.period2, d_close)?,
+			smooth1: method(cfg.smooth_method, cfg.period2, d_close)?,
 			sma1: SMA::new(cfg.period1, d_close)?,
-			swma2: SWMA::new(cfg.period2, d_hl)?,
+			smooth2: method(cfg.smooth_method, cfg.period2, d_hl)?,
 			sma2: SMA::new(cfg.period1, d_hl)?,
 			ma: method(cfg.method, cfg.period3, rvi)?,
 			cross: Cross::default(),
@@ -100,23 +117,27 @@ impl IndicatorConfig for RelativeVigorIndex {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
+			"smooth_method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.smooth_method = value,
+			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 
@@ -129,7 +150,7 @@ impl IndicatorConfig for RelativeVigorIndex {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(2, 2)
+		(3, 2)
 	}
 }
 
@@ -138,6 +159,7 @@ impl Default for RelativeVigorIndex {
 		Self {
 			period1: 10,
 			period2: 4,
+			smooth_method: RegularMethods::SWMA,
 			period3: 4,
 			method: RegularMethods::SWMA,
 			zone: 0.25,
@@ -145,14 +167,15 @@ impl Default for RelativeVigorIndex {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RelativeVigorIndexInstance {
 	cfg: RelativeVigorIndex,
 
 	prev_close: ValueType,
-	swma1: SWMA,
+	smooth1: RegularMethod,
 	sma1: SMA,
-	swma2: SWMA,
+	smooth2: RegularMethod,
 	sma2: SMA,
 	ma: RegularMethod,
 	cross: Cross,
@@ -166,6 +189,14 @@ impl IndicatorInstance for RelativeVigorIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.smooth1.heap_size()
+			+ self.sma1.heap_size()
+			+ self.smooth2.heap_size()
+			+ self.sma2.heap_size()
+			+ self.ma.heap_size()
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let close_open = candle.close() - self.prev_close;
@@ -173,10 +204,10 @@ impl IndicatorInstance for RelativeVigorIndexInstance {
 
 		self.prev_close = candle.close();
 
-		let swma1 = self.swma1.next(close_open);
-		let sma1 = self.sma1.next(swma1);
-		let swma2 = self.swma2.next(high_low);
-		let sma2 = self.sma2.next(swma2);
+		let smooth1 = self.smooth1.next(close_open);
+		let sma1 = self.sma1.next(smooth1);
+		let smooth2 = self.smooth2.next(high_low);
+		let sma2 = self.sma2.next(smooth2);
 
 		let rvi = if sma2 == 0. { 0. } else { sma1 / sma2 };
 		let sig: ValueType = self.ma.next(rvi);
@@ -195,6 +226,95 @@ impl IndicatorInstance for RelativeVigorIndexInstance {
 		let s2 = (s1 < 0 && rvi > self.cfg.zone && sig > self.cfg.zone) as i8
 			- (s1 > 0 && rvi < -self.cfg.zone && sig < -self.cfg.zone) as i8;
 
-		IndicatorResult::new(&[rvi, sig], &[s1.into(), s2.into()])
+		IndicatorResult::new(&[rvi, sig, rvi - sig], &[s1.into(), s2.into()])
+	}
+}
+
+impl Checkpoint for RelativeVigorIndexInstance {
+	type State = (
+		ValueType,
+		<RegularMethod as Checkpoint>::State,
+		<SMA as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<SMA as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.prev_close,
+			self.smooth1.save(),
+			self.sma1.save(),
+			self.smooth2.save(),
+			self.sma2.save(),
+			self.ma.save(),
+			self.cross.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.prev_close = state.0;
+		self.smooth1.restore(&state.1);
+		self.sma1.restore(&state.2);
+		self.smooth2.restore(&state.3);
+		self.sma2.restore(&state.4);
+		self.ma.restore(&state.5);
+		self.cross.restore(&state.6);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::RelativeVigorIndex;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::{assert_eq_float, RegularMethods};
+
+	#[test]
+	fn test_relative_vigor_index_histogram() {
+		let cfg = RelativeVigorIndex {
+			period1: 2,
+			period2: 2,
+			smooth_method: RegularMethods::SMA,
+			period3: 2,
+			method: RegularMethods::SMA,
+			zone: 0.25,
+		};
+
+		let candles = [
+			Candle {
+				open: 10.0,
+				high: 11.0,
+				low: 9.0,
+				close: 10.0,
+				..Candle::default()
+			},
+			Candle {
+				open: 10.0,
+				high: 13.0,
+				low: 11.0,
+				close: 12.0,
+				..Candle::default()
+			},
+			Candle {
+				open: 12.0,
+				high: 13.0,
+				low: 9.0,
+				close: 11.0,
+				..Candle::default()
+			},
+		];
+
+		let mut instance = cfg.init(&candles[0]).unwrap();
+
+		// main = SMA(SMA(close - prev close)) / SMA(SMA(high - low))
+		for (candle, &(main, signal)) in candles[1..].iter().zip(&[(0.25, 0.125), (0.3, 0.275)]) {
+			let result = instance.next(candle);
+			assert_eq_float(main, result.value(0));
+			assert_eq_float(signal, result.value(1));
+			assert_eq_float(main - signal, result.value(2));
+		}
 	}
 }
@@ -0,0 +1,314 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::methods::{CrossAbove, CrossUnder, Highest, Lowest};
+
+/// Schaff Trend Cycle
+///
+/// [`MACD`](crate::indicators::MACD) line is passed through the stochastic calculation over `cycle` candles
+/// and smoothed by `factor`, then the result is passed through the same stochastic calculation again.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/articles/forex/10/schaff-trend-cycle-indicator.asp>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// # 1 signal
+///
+/// * When `main` value leaves lower zone (crosses `zone` upwards), returns full buy signal.
+///   When `main` value leaves upper zone (crosses `1.0 - zone` downwards), returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SchaffTrendCycle {
+	/// Fast MA period of MACD. Default is `23`.
+	///
+	/// Range in \[`2`; `period2`\)
+	pub period1: PeriodType,
+
+	/// Slow MA period of MACD. Default is `50`.
+	///
+	/// Range in \(`period1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period2: PeriodType,
+
+	/// MA method of MACD. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Cycle period for both stochastic calculations. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub cycle: PeriodType,
+
+	/// Smoothing factor of both stochastic calculations. Default is `0.5`.
+	///
+	/// Range in \(`0.0`; `1.0`\]
+	pub factor: ValueType,
+
+	/// Overbought/oversell relative zone. Default is `0.25`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for SchaffTrendCycle {
+	type Instance = SchaffTrendCycleInstance;
+
+	const NAME: &'static str = "SchaffTrendCycle";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Bounded(0.0, 1.0)],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ma1: method(cfg.method, cfg.period1, src)?,
+			ma2: method(cfg.method, cfg.period2, src)?,
+			stoch1: StochasticCycle::new(cfg.cycle, 0.0)?,
+			stoch2: StochasticCycle::new(cfg.cycle, 0.5)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period1 > 1
+			&& self.period1 < self.period2
+			&& self.period2 < PeriodType::MAX
+			&& self.cycle > 1
+			&& self.cycle < PeriodType::MAX
+			&& self.factor > 0.0
+			&& self.factor <= 1.0
+			&& self.zone > 0.0
+			&& self.zone <= 0.5
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period1" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period1 = value,
+			},
+			"period2" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period2 = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+			"cycle" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.cycle = value,
+			},
+			"factor" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.factor = value,
+			},
+			"zone" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Oscillator for SchaffTrendCycle {
+	const MAIN: usize = 0;
+
+	fn overbought(&self) -> ValueType {
+		1.0 - self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		self.zone
+	}
+}
+
+impl Default for SchaffTrendCycle {
+	fn default() -> Self {
+		Self {
+			period1: 23,
+			period2: 50,
+			method: RegularMethods::EMA,
+			cycle: 10,
+			factor: 0.5,
+			zone: 0.25,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`SchaffTrendCycle`]
+pub type STC = SchaffTrendCycle;
+
+/// Stochastic of the value over `cycle` values, smoothed by `factor`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct StochasticCycle {
+	highest: Highest,
+	lowest: Lowest,
+	k: ValueType,
+	value: ValueType,
+}
+
+impl StochasticCycle {
+	fn new(cycle: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			highest: Highest::new(cycle, value)?,
+			lowest: Lowest::new(cycle, value)?,
+			k: 0.5,
+			value: 0.5,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.highest.heap_size() + self.lowest.heap_size()
+	}
+
+	fn next(&mut self, value: ValueType, factor: ValueType) -> ValueType {
+		let highest = self.highest.next(value);
+		let lowest = self.lowest.next(value);
+
+		// when there is no range, the previous stochastic value is kept
+		if highest > lowest {
+			self.k = (value - lowest) / (highest - lowest);
+		}
+
+		self.value += factor * (self.k - self.value);
+		self.value
+	}
+}
+
+type StochasticCycleCheckpoint = (
+	<Highest as Checkpoint>::State,
+	<Lowest as Checkpoint>::State,
+	ValueType,
+	ValueType,
+);
+
+impl Checkpoint for StochasticCycle {
+	type State = StochasticCycleCheckpoint;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.highest.save(), self.lowest.save(), self.k, self.value)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest.restore(&state.0);
+		self.lowest.restore(&state.1);
+		self.k = state.2;
+		self.value = state.3;
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SchaffTrendCycleInstance {
+	cfg: SchaffTrendCycle,
+
+	ma1: RegularMethod,
+	ma2: RegularMethod,
+	stoch1: StochasticCycle,
+	stoch2: StochasticCycle,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for SchaffTrendCycleInstance {
+	type Config = SchaffTrendCycle;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma1.heap_size()
+			+ self.ma2.heap_size()
+			+ self.stoch1.heap_size()
+			+ self.stoch2.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let macd = self.ma1.next(src) - self.ma2.next(src);
+
+		let smoothed = self.stoch1.next(macd, self.cfg.factor);
+		let value = self.stoch2.next(smoothed, self.cfg.factor);
+
+		let signal = self.cross_above.next((value, self.cfg.zone))
+			- self.cross_under.next((value, 1.0 - self.cfg.zone));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
+
+impl Checkpoint for SchaffTrendCycleInstance {
+	type State = (
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		StochasticCycleCheckpoint,
+		StochasticCycleCheckpoint,
+		<CrossAbove as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.ma1.save(),
+			self.ma2.save(),
+			self.stoch1.save(),
+			self.stoch2.save(),
+			self.cross_above.save(),
+			self.cross_under.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma1.restore(&state.0);
+		self.ma2.restore(&state.1);
+		self.stoch1.restore(&state.2);
+		self.stoch2.restore(&state.3);
+		self.cross_above.restore(&state.4);
+		self.cross_under.restore(&state.5);
+	}
+}
@@ -1,10 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
 use crate::helpers::{method, RegularMethod, RegularMethods};
-use crate::methods::{Cross, TSI};
+use crate::methods::{Cross, CrossAbove, CrossUnder, TSI};
 
 /// SMI Ergodic Indicator
 ///
@@ -23,17 +24,23 @@ use crate::methods::{Cross, TSI};
 ///
 /// Range in \[`-1.0`; `1.0`\]
 ///
-/// * `Oscillator` value
+/// * `Oscillator` (histogram) value: `SMI` - `Signal line`
 ///
 /// Range in \[`-2.0`; `2.0`\]
 ///
-/// # 1 signals
+/// # 2 signals
 ///
 /// * Signal #1 on `SMI` crosses `Signal`
 ///
 /// When `Signal line` value is below `-zone` and `SMI` value crosses `Signal line` upwards, returns full buy signal.
 /// When `Signal line` value is above `+zone` and `SMI` value crosses `Signal line` downwards, returns full sell signal.
 /// Otherwise returns no signal.
+///
+/// * Signal #2 on `SMI` leaves oversold/overbought zones
+///
+/// When `SMI` value crosses `-zone` upwards (leaves oversold zone), returns full buy signal.
+/// When `SMI` value crosses `+zone` downwards (leaves overbought zone), returns full sell signal.
+/// Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SMIErgodicIndicator {
@@ -69,6 +76,15 @@ impl IndicatorConfig for SMIErgodicIndicator {
 
 	const NAME: &'static str = "SMIErgodicIndicator";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Bounded(-2.0, 2.0),
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -81,6 +97,8 @@ impl IndicatorConfig for SMIErgodicIndicator {
 			tsi: TSI::new(cfg.period2, cfg.period1, src)?,
 			ma: method(cfg.method, cfg.period3, 0.)?,
 			cross: Cross::default(),
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
 			cfg,
 		})
 	}
@@ -98,23 +116,27 @@ impl IndicatorConfig for SMIErgodicIndicator {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"method" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method = value,
 			},
+			"zone" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.zone = value,
+			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -127,7 +149,20 @@ impl IndicatorConfig for SMIErgodicIndicator {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(3, 1)
+		(3, 2)
+	}
+}
+
+impl Oscillator for SMIErgodicIndicator {
+	const MAIN: usize = 0;
+	const SIGNAL_LINE: Option<usize> = Some(1);
+
+	fn overbought(&self) -> ValueType {
+		self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		-self.zone
 	}
 }
 
@@ -144,13 +179,16 @@ impl Default for SMIErgodicIndicator {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SMIErgodicIndicatorInstance {
 	cfg: SMIErgodicIndicator,
 
 	tsi: TSI,
 	ma: RegularMethod,
 	cross: Cross,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
 }
 
 impl IndicatorInstance for SMIErgodicIndicatorInstance {
@@ -160,6 +198,10 @@ impl IndicatorInstance for SMIErgodicIndicatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.tsi.heap_size() + self.ma.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let tsi = self.tsi.next(src);
@@ -170,6 +212,39 @@ impl IndicatorInstance for SMIErgodicIndicatorInstance {
 		let s1 =
 			(cross > 0 && sig < -self.cfg.zone) as i8 - (cross < 0 && sig > self.cfg.zone) as i8;
 
-		IndicatorResult::new(&[tsi, sig, tsi - sig], &[s1.into()])
+		let s2 = self.cross_above.next((tsi, -self.cfg.zone))
+			- self.cross_under.next((tsi, self.cfg.zone));
+
+		IndicatorResult::new(&[tsi, sig, tsi - sig], &[s1.into(), s2])
+	}
+}
+
+impl Checkpoint for SMIErgodicIndicatorInstance {
+	type State = (
+		<TSI as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.tsi.save(),
+			self.ma.save(),
+			self.cross.save(),
+			self.cross_above.save(),
+			self.cross_under.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.tsi.restore(&state.0);
+		self.ma.restore(&state.1);
+		self.cross.restore(&state.2);
+		self.cross_above.restore(&state.3);
+		self.cross_under.restore(&state.4);
 	}
 }
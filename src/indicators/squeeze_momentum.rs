@@ -0,0 +1,245 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::methods::{Highest, LinReg, Lowest, StDev, SMA, TR};
+
+/// Squeeze Momentum (TTM Squeeze)
+///
+/// Compares [`BollingerBands`](crate::indicators::BollingerBands) against [`KeltnerChannel`](crate::indicators::KeltnerChannel) of the same `period`.
+/// When Bollinger Bands are completely inside Keltner Channel, volatility is low and the squeeze is *on*.
+/// When Bollinger Bands are completely outside Keltner Channel, the squeeze is *off*.
+///
+/// Momentum is the [linear regression](LinReg) of the difference between the `source` value
+/// and the average of the Donchian channel middle and the simple moving average over `period` candles.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:ttm_squeeze>
+///
+/// # 2 values
+///
+/// * `squeeze` state: `1.0` when the squeeze is on, `-1.0` when the squeeze is off and `0.0` otherwise
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `momentum` histogram value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// * When the squeeze is released (`squeeze` state changes from `1.0` to any other) and `momentum` is positive, returns full buy signal.
+///   When the squeeze is released and `momentum` is negative, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SqueezeMomentum {
+	/// Period for Bollinger Bands, Keltner Channel and momentum. Default is `20`.
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Bollinger Bands standard deviation multiplier. Default is `2.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub bb_sigma: ValueType,
+
+	/// Keltner Channel true range multiplier. Default is `1.5`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub kc_sigma: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for SqueezeMomentum {
+	type Instance = SqueezeMomentumInstance;
+
+	const NAME: &'static str = "SqueezeMomentum";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volatility,
+		&[ValueRange::Bounded(-1.0, 1.0), ValueRange::Unbounded],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			st_dev: StDev::new(cfg.period, src)?,
+			tr: TR::new(candle)?,
+			tr_sma: SMA::new(cfg.period, candle.high() - candle.low())?,
+			highest: Highest::new(cfg.period, candle.high())?,
+			lowest: Lowest::new(cfg.period, candle.low())?,
+			lin_reg: LinReg::new(cfg.period, 0.0)?,
+			squeeze: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 2
+			&& self.period < PeriodType::MAX
+			&& self.bb_sigma > 0.0
+			&& self.kc_sigma > 0.0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"bb_sigma" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.bb_sigma = value,
+			},
+			"kc_sigma" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.kc_sigma = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for SqueezeMomentum {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			bb_sigma: 2.0,
+			kc_sigma: 1.5,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for [`SqueezeMomentum`]
+pub type TTMSqueeze = SqueezeMomentum;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SqueezeMomentumInstance {
+	cfg: SqueezeMomentum,
+
+	st_dev: StDev,
+	tr: TR,
+	tr_sma: SMA,
+	highest: Highest,
+	lowest: Lowest,
+	lin_reg: LinReg,
+	squeeze: i8,
+}
+
+impl IndicatorInstance for SqueezeMomentumInstance {
+	type Config = SqueezeMomentum;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.st_dev.heap_size()
+			+ self.tr_sma.heap_size()
+			+ self.highest.heap_size()
+			+ self.lowest.heap_size()
+			+ self.lin_reg.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let st_dev = self.st_dev.next(src);
+		let mean = self.st_dev.get_mean();
+		let range = self.tr_sma.next(self.tr.next(candle));
+
+		// both channels have the same middle line, so only their half-widths may be compared
+		let bb_width = st_dev * self.cfg.bb_sigma;
+		let kc_width = range * self.cfg.kc_sigma;
+
+		let squeeze = if bb_width < kc_width {
+			1
+		} else if bb_width > kc_width {
+			-1
+		} else {
+			0
+		};
+
+		let donchian_middle =
+			(self.highest.next(candle.high()) + self.lowest.next(candle.low())) * 0.5;
+		let momentum = self
+			.lin_reg
+			.next((donchian_middle + mean).mul_add(-0.5, src));
+
+		let is_released = self.squeeze == 1 && squeeze != 1;
+
+		let signal = if is_released && momentum > 0.0 {
+			Action::BUY_ALL
+		} else if is_released && momentum < 0.0 {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+		self.squeeze = squeeze;
+
+		IndicatorResult::new(&[squeeze.into(), momentum], &[signal])
+	}
+}
+
+impl Checkpoint for SqueezeMomentumInstance {
+	type State = (
+		<StDev as Checkpoint>::State,
+		<TR as Checkpoint>::State,
+		<SMA as Checkpoint>::State,
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<LinReg as Checkpoint>::State,
+		i8,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.st_dev.save(),
+			self.tr.save(),
+			self.tr_sma.save(),
+			self.highest.save(),
+			self.lowest.save(),
+			self.lin_reg.save(),
+			self.squeeze,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.st_dev.restore(&state.0);
+		self.tr.restore(&state.1);
+		self.tr_sma.restore(&state.2);
+		self.highest.restore(&state.3);
+		self.lowest.restore(&state.4);
+		self.lin_reg.restore(&state.5);
+		self.squeeze = state.6;
+	}
+}
@@ -1,8 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
+use crate::core::{Preset, Presets};
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
 
@@ -41,6 +43,24 @@ use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
 /// When `main` value crosses `signal line` upwards, returns full buy signal.
 /// When `main` value crosses `signal line` downwards, returns full sell signal.
 /// Otherwise returns no signal.
+///
+/// # Presets
+///
+/// Trading platforms usually name parameter combinations as fast, slow and full stochastic.
+/// They may be created by [`fast`](StochasticOscillator::fast), [`slow`](StochasticOscillator::slow)
+/// and [`full`](StochasticOscillator::full) (or by [`preset`](StochasticOscillator::preset) with [`StochasticMode`]).
+/// Smoothing method of both lines may be changed by [`with_method`](StochasticOscillator::with_method).
+///
+/// ```
+/// use yata::helpers::RegularMethods;
+/// use yata::indicators::{StochasticMode, StochasticOscillator};
+///
+/// let slow = StochasticOscillator::slow();
+/// assert_eq!((slow.period, slow.smooth_k, slow.smooth_d), (14, 3, 3));
+///
+/// let fast = StochasticOscillator::preset(StochasticMode::Fast).with_method(RegularMethods::EMA);
+/// assert_eq!((fast.smooth_k, fast.method_k, fast.method_d), (1, RegularMethods::EMA, RegularMethods::EMA));
+/// ```
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StochasticOscillator {
@@ -51,9 +71,9 @@ pub struct StochasticOscillator {
 
 	/// Period for smoothing `main` value. Default is `14`.
 	///
-	/// Usually it is equal to `period`.
+	/// Usually it is equal to `period`. `1` means no smoothing (fast stochastic).
 	///
-	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub smooth_k: PeriodType,
 
 	/// MA method for smoothing `main` value. Default is [`SMA`](crate::methods::SMA).
@@ -61,7 +81,7 @@ pub struct StochasticOscillator {
 
 	/// Period for smoothing `signal line` value. Default is `3`.
 	///
-	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
 	pub smooth_d: PeriodType,
 
 	/// MA method for smoothing `signal line` value. Default is [`SMA`](crate::methods::SMA).
@@ -78,6 +98,11 @@ impl IndicatorConfig for StochasticOscillator {
 
 	const NAME: &'static str = "StochasticOscillator";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Bounded(0.0, 1.0), ValueRange::Bounded(0.0, 1.0)],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -108,33 +133,40 @@ impl IndicatorConfig for StochasticOscillator {
 	}
 
 	fn validate(&self) -> bool {
-		self.period > 1 && self.zone >= 0.0 && self.zone <= 0.5
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.smooth_k > 0
+			&& self.smooth_k < PeriodType::MAX
+			&& self.smooth_d > 0
+			&& self.smooth_d < PeriodType::MAX
+			&& self.zone >= 0.0
+			&& self.zone <= 0.5
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"smooth_k" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.smooth_k = value,
 			},
 			"smooth_d" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.smooth_d = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 			"method_k" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method_k = value,
 			},
 			"method_d" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method_d = value,
 			},
 			_ => {
@@ -150,6 +182,36 @@ impl IndicatorConfig for StochasticOscillator {
 	}
 }
 
+impl Oscillator for StochasticOscillator {
+	const MAIN: usize = 0;
+	const SIGNAL_LINE: Option<usize> = Some(1);
+
+	fn overbought(&self) -> ValueType {
+		1.0 - self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		self.zone
+	}
+}
+
+// Inherent `StochasticOscillator::preset` takes `StochasticMode`, so these are available by name through `Presets::preset`
+impl Presets for StochasticOscillator {
+	const PRESETS: &'static [Preset<Self>] = &[
+		Preset::new("fast", "Fast stochastic: raw %K", Self::fast),
+		Preset::new(
+			"slow",
+			"Slow stochastic: %K smoothed by 3 periods",
+			Self::slow,
+		),
+		Preset::new(
+			"full",
+			"Full stochastic: %K smoothed by 14 periods",
+			Self::full,
+		),
+	];
+}
+
 impl Default for StochasticOscillator {
 	fn default() -> Self {
 		Self {
@@ -163,7 +225,71 @@ impl Default for StochasticOscillator {
 	}
 }
 
-#[derive(Debug)]
+impl StochasticOscillator {
+	/// Creates config of the specified `mode` with `period` of `14`, [`SMA`](crate::methods::SMA) smoothing and `zone` of `0.2`
+	#[must_use]
+	pub fn preset(mode: StochasticMode) -> Self {
+		let smooth_k = match mode {
+			StochasticMode::Fast => 1,
+			StochasticMode::Slow => 3,
+			StochasticMode::Full => 14,
+		};
+
+		Self {
+			smooth_k,
+			..Self::default()
+		}
+	}
+
+	/// Fast stochastic: raw `main` value (%K) and its `3`-period average as `signal line` (%D)
+	#[must_use]
+	pub fn fast() -> Self {
+		Self::preset(StochasticMode::Fast)
+	}
+
+	/// Slow stochastic: `main` value is the fast %D (`3`-period smoothed %K) and `signal line` is its `3`-period average
+	#[must_use]
+	pub fn slow() -> Self {
+		Self::preset(StochasticMode::Slow)
+	}
+
+	/// Full stochastic: %K smoothing equals `period` (the same as [`StochasticOscillator::default`])
+	#[must_use]
+	pub fn full() -> Self {
+		Self::preset(StochasticMode::Full)
+	}
+
+	/// Sets `method` for smoothing both `main` value and `signal line` value
+	#[must_use]
+	pub const fn with_method(self, method: RegularMethods) -> Self {
+		Self {
+			method_k: method,
+			method_d: method,
+			..self
+		}
+	}
+}
+
+/// Stochastic Oscillator parameter combinations preset
+///
+/// See [`StochasticOscillator::preset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum StochasticMode {
+	/// `smooth_k` is `1`, `smooth_d` is `3`
+	Fast,
+
+	/// `smooth_k` is `3`, `smooth_d` is `3`
+	Slow,
+
+	/// `smooth_k` is `14` (equal to `period`), `smooth_d` is `3`
+	Full,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StochasticOscillatorInstance {
 	cfg: StochasticOscillator,
 
@@ -186,6 +312,13 @@ impl IndicatorInstance for StochasticOscillatorInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.highest.heap_size()
+			+ self.lowest.heap_size()
+			+ self.ma1.heap_size()
+			+ self.ma2.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (close, high, low) = (candle.close(), candle.high(), candle.low());
 
@@ -214,3 +347,127 @@ impl IndicatorInstance for StochasticOscillatorInstance {
 		IndicatorResult::new(&[f1, f2], &[s1, s2, s3])
 	}
 }
+
+impl Checkpoint for StochasticOscillatorInstance {
+	type State = (
+		<Highest as Checkpoint>::State,
+		<Lowest as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.highest.save(),
+			self.lowest.save(),
+			self.ma1.save(),
+			self.ma2.save(),
+			self.cross_over.save(),
+			self.cross_above1.save(),
+			self.cross_under1.save(),
+			self.cross_above2.save(),
+			self.cross_under2.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest.restore(&state.0);
+		self.lowest.restore(&state.1);
+		self.ma1.restore(&state.2);
+		self.ma2.restore(&state.3);
+		self.cross_over.restore(&state.4);
+		self.cross_above1.restore(&state.5);
+		self.cross_under1.restore(&state.6);
+		self.cross_above2.restore(&state.7);
+		self.cross_under2.restore(&state.8);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{StochasticMode, StochasticOscillator};
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn test_values(cfg: StochasticOscillator, expected: &[(ValueType, ValueType)]) {
+		let candle = |high: ValueType, low: ValueType, close: ValueType| Candle {
+			high,
+			low,
+			close,
+			..Candle::default()
+		};
+
+		// raw %K over 2 candles is 1, 1/3 and 1/6
+		let candles = [
+			candle(12.0, 10.0, 12.0),
+			candle(13.0, 11.0, 11.0),
+			candle(12.0, 10.0, 10.5),
+		];
+
+		let cfg = StochasticOscillator { period: 2, ..cfg };
+		let mut instance = cfg.init(&candle(11.0, 9.0, 10.0)).unwrap();
+
+		for (candle, &(k, d)) in candles.iter().zip(expected) {
+			let result = instance.next(candle);
+			assert_eq_float(k, result.value(0));
+			assert_eq_float(d, result.value(1));
+		}
+	}
+
+	#[test]
+	fn test_stochastic_oscillator_fast() {
+		test_values(
+			StochasticOscillator::fast(),
+			&[(1.0, 2.0 / 3.0), (1.0 / 3.0, 11.0 / 18.0), (1.0 / 6.0, 0.5)],
+		);
+	}
+
+	#[test]
+	fn test_stochastic_oscillator_slow() {
+		test_values(
+			StochasticOscillator::slow(),
+			&[
+				(2.0 / 3.0, 5.0 / 9.0),
+				(11.0 / 18.0, 16.0 / 27.0),
+				(0.5, 16.0 / 27.0),
+			],
+		);
+	}
+
+	#[test]
+	fn test_stochastic_oscillator_full() {
+		test_values(
+			StochasticOscillator::full(),
+			&[
+				(15.0 / 28.0, 43.0 / 84.0),
+				(11.0 / 21.0, 131.0 / 252.0),
+				(0.5, 131.0 / 252.0),
+			],
+		);
+	}
+
+	#[test]
+	fn test_stochastic_oscillator_modes() {
+		for &mode in &[
+			StochasticMode::Fast,
+			StochasticMode::Slow,
+			StochasticMode::Full,
+		] {
+			let cfg = StochasticOscillator::preset(mode);
+			assert!(cfg.validate());
+			assert_eq!((cfg.period, cfg.smooth_d), (14, 3));
+		}
+
+		assert_eq!(
+			StochasticOscillator::full().smooth_k,
+			StochasticOscillator::default().smooth_k
+		);
+	}
+}
@@ -2,7 +2,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, WindowCheckpoint};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::{CrossAbove, CrossUnder, ReversalSignal, WMA};
 
@@ -52,6 +54,9 @@ impl IndicatorConfig for TrendStrengthIndex {
 
 	const NAME: &'static str = "TrendStrengthIndex";
 
+	const METADATA: IndicatorMetadata =
+		IndicatorMetadata::new(IndicatorCategory::Trend, &[ValueRange::Bounded(-1.0, 1.0)]);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if self.validate() {
 			let cfg = self;
@@ -99,19 +104,19 @@ impl IndicatorConfig for TrendStrengthIndex {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 			"reverse_offset" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.reverse_offset = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			_ => {
@@ -139,6 +144,7 @@ impl Default for TrendStrengthIndex {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TrendStrengthIndexInstance {
 	cfg: TrendStrengthIndex,
 	period: ValueType,
@@ -161,6 +167,10 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.wma.heap_size() + self.reverse.heap_size() + self.window.heap_size()
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
@@ -174,9 +184,10 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 		let p = (self.wma.next(src) - sma) * self.sx;
 
 		// sy2 is always greater than sma * sy, so q is always positive
+		// except for constant values, when it may be zero or even slightly negative because of rounding errors
 		let q = self.k * (self.sy2 - sma * self.sy);
 
-		let value = p / q.sqrt();
+		let value = if q > 0. { p / q.sqrt() } else { 0. };
 
 		let cross_signal = self.cross_under.next((value, self.cfg.zone))
 			- self.cross_above.next((value, -self.cfg.zone));
@@ -189,3 +200,58 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 		IndicatorResult::new(&[value], &[cross_signal, reverse_signal.into()])
 	}
 }
+
+impl Checkpoint for TrendStrengthIndexInstance {
+	type State = (
+		ValueType,
+		ValueType,
+		<WMA as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<ReversalSignal as Checkpoint>::State,
+		WindowCheckpoint<ValueType>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.sy,
+			self.sy2,
+			self.wma.save(),
+			self.cross_under.save(),
+			self.cross_above.save(),
+			self.reverse.save(),
+			self.window.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sy = state.0;
+		self.sy2 = state.1;
+		self.wma.restore(&state.2);
+		self.cross_under.restore(&state.3);
+		self.cross_above.restore(&state.4);
+		self.reverse.restore(&state.5);
+		self.window.restore(&state.6);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::TrendStrengthIndex;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+
+	#[test]
+	fn test_trend_strength_index_const() {
+		let candle = Candle {
+			close: 10.1,
+			..Candle::default()
+		};
+		let mut instance = TrendStrengthIndex::default().init(&candle).unwrap();
+
+		for _ in 0..50 {
+			assert_eq!(instance.next(&candle).values(), &[0.0]);
+		}
+	}
+}
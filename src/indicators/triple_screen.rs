@@ -0,0 +1,224 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::helpers::{method, RegularMethod, RegularMethods};
+use crate::indicators::EldersForceIndex;
+use crate::methods::CollapseTimeframe;
+
+/// Elder's Triple Screen trading system
+///
+/// The first screen is the trend of the higher timeframe. Every `timeframe` candles are collapsed into a single higher timeframe candle
+/// (f.e. `5` daily candles into a weekly one) and the trend is the direction of the moving average over these collapsed candles.
+///
+/// The second screen is the short [`EldersForceIndex`] (its `period3` value) on the current timeframe.
+/// It is used to find pullbacks against the trend of the higher timeframe.
+///
+/// The third screen (placing the orders) is up to the trader.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/articles/trading/03/040903.asp>
+///
+/// # 2 values
+///
+/// * `trend` of the higher timeframe: `1.0` when the moving average rises, `-1.0` when it falls and `0.0` otherwise
+///
+/// Range in \[`-1.0`; `1.0`\]
+///
+/// * `short force index` value
+///
+/// Range in \(`-inf`; `+inf`\)
+///
+/// # 1 signal
+///
+/// * When `trend` is rising and `short force index` crosses zero line downwards, returns full buy signal.
+///   When `trend` is falling and `short force index` crosses zero line upwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TripleScreen {
+	/// Count of candles in a single candle of the higher timeframe. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub timeframe: PeriodType,
+
+	/// Trend MA period on the higher timeframe. Default is `13`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Trend MA method. Default is [`EMA`](crate::methods::EMA).
+	pub method: RegularMethods,
+
+	/// Trend source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+
+	/// Force index configuration. Default is [`EldersForceIndex::default`].
+	pub force_index: EldersForceIndex,
+}
+
+impl IndicatorConfig for TripleScreen {
+	type Instance = TripleScreenInstance;
+
+	const NAME: &'static str = "TripleScreen";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[ValueRange::Bounded(-1.0, 1.0), ValueRange::Unbounded],
+	)
+	.with_volume();
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			collapse: CollapseTimeframe::new(cfg.timeframe as usize, candle)?,
+			ma: method(cfg.method, cfg.period, src)?,
+			last_ma: src,
+			trend: 0,
+			force_index: cfg.force_index.init(candle)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.timeframe > 0
+			&& self.timeframe < PeriodType::MAX
+			&& self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.force_index.validate()
+	}
+
+	/// Sets `timeframe`, `period`, `method` and `source` of the trend. Any other parameter is passed to the inner `force_index`.
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"timeframe" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.timeframe = value,
+			},
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"method" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.method = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => return self.force_index.set(name, value),
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for TripleScreen {
+	fn default() -> Self {
+		Self {
+			timeframe: 5,
+			period: 13,
+			method: RegularMethods::EMA,
+			source: Source::Close,
+			force_index: EldersForceIndex::default(),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TripleScreenInstance {
+	cfg: TripleScreen,
+
+	collapse: CollapseTimeframe,
+	ma: RegularMethod,
+	last_ma: ValueType,
+	trend: i8,
+	force_index: <EldersForceIndex as IndicatorConfig>::Instance,
+}
+
+impl IndicatorInstance for TripleScreenInstance {
+	type Config = TripleScreen;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size() + self.force_index.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		if let Some(higher) = self.collapse.next(candle) {
+			let ma = self.ma.next(higher.source(self.cfg.source));
+
+			self.trend = if ma > self.last_ma {
+				1
+			} else if ma < self.last_ma {
+				-1
+			} else {
+				0
+			};
+			self.last_ma = ma;
+		}
+
+		let force_index = self.force_index.next(candle);
+		let short = force_index.value(1);
+		let short_cross = force_index.signal(1).analog();
+
+		let signal = if self.trend > 0 && short_cross < 0 {
+			Action::BUY_ALL
+		} else if self.trend < 0 && short_cross > 0 {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[self.trend.into(), short], &[signal])
+	}
+}
+
+impl Checkpoint for TripleScreenInstance {
+	type State = (
+		<CollapseTimeframe as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		ValueType,
+		i8,
+		<<EldersForceIndex as IndicatorConfig>::Instance as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.collapse.save(),
+			self.ma.save(),
+			self.last_ma,
+			self.trend,
+			self.force_index.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.collapse.restore(&state.0);
+		self.ma.restore(&state.1);
+		self.last_ma = state.2;
+		self.trend = state.3;
+		self.force_index.restore(&state.4);
+	}
+}
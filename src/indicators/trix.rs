@@ -1,5 +1,6 @@
 use crate::core::{
-	Error, IndicatorConfig, IndicatorInstance, IndicatorResult, Method, PeriodType, Source, OHLCV,
+	Checkpoint, Error, IndicatorCategory, IndicatorConfig, IndicatorInstance, IndicatorMetadata,
+	IndicatorResult, Method, PeriodType, Source, ValueRange, OHLCV,
 };
 use crate::helpers::{method, RegularMethod, RegularMethods};
 use crate::methods::{Change, Cross, ReversalSignal, TMA};
@@ -13,7 +14,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// * <https://en.wikipedia.org/wiki/Trix_(technical_analysis)>
 ///
-/// # 2 values
+/// # 3 values
 ///
 /// * `main` value
 ///
@@ -23,6 +24,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// Range is \(`-inf`; `+inf`\)
 ///
+/// * `histogram` value (`main` - `signal line`)
+///
+/// Range is \(`-inf`; `+inf`\)
+///
 /// # 3 signals
 ///
 /// * When `main` value changes direction upwards, returns full buy signal.
@@ -61,6 +66,15 @@ impl IndicatorConfig for Trix {
 
 	const NAME: &'static str = "Trix";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[
+			ValueRange::Unbounded,
+			ValueRange::Unbounded,
+			ValueRange::Unbounded,
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if self.validate() {
 			let src = candle.source(self.source);
@@ -88,19 +102,19 @@ impl IndicatorConfig for Trix {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"method2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.method2 = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 			_ => {
@@ -112,7 +126,7 @@ impl IndicatorConfig for Trix {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(2, 3)
+		(3, 3)
 	}
 }
 
@@ -127,7 +141,8 @@ impl Default for Trix {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TRIXInstance {
 	cfg: Trix,
 
@@ -146,6 +161,10 @@ impl IndicatorInstance for TRIXInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.sig.heap_size() + self.change.heap_size() + self.reverse.heap_size()
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
@@ -156,9 +175,44 @@ impl IndicatorInstance for TRIXInstance {
 
 		let sigline = self.sig.next(value);
 
+		let histogram = value - sigline;
+
 		let signal2 = self.cross1.next((value, sigline));
 		let signal3 = self.cross2.next((value, 0.));
 
-		IndicatorResult::new(&[value, sigline], &[signal1, signal2, signal3])
+		IndicatorResult::new(&[value, sigline, histogram], &[signal1, signal2, signal3])
+	}
+}
+
+impl Checkpoint for TRIXInstance {
+	type State = (
+		<TMA as Checkpoint>::State,
+		<RegularMethod as Checkpoint>::State,
+		<Change as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<Cross as Checkpoint>::State,
+		<ReversalSignal as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.tma.save(),
+			self.sig.save(),
+			self.change.save(),
+			self.cross1.save(),
+			self.cross2.save(),
+			self.reverse.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.tma.restore(&state.0);
+		self.sig.restore(&state.1);
+		self.change.restore(&state.2);
+		self.cross1.restore(&state.3);
+		self.cross2.restore(&state.4);
+		self.reverse.restore(&state.5);
 	}
 }
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Checkpoint, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, Oscillator};
 use crate::methods::{Cross, CrossAbove, CrossUnder, EMA, TSI};
 
 /// True Strength Index
@@ -70,6 +71,14 @@ impl IndicatorConfig for TrueStrengthIndex {
 
 	const NAME: &'static str = "TrueStrengthIndex";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[
+			ValueRange::Bounded(-1.0, 1.0),
+			ValueRange::Bounded(-1.0, 1.0),
+		],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -102,23 +111,23 @@ impl IndicatorConfig for TrueStrengthIndex {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"period3" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period3 = value,
 			},
 			"zone" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.zone = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -135,6 +144,19 @@ impl IndicatorConfig for TrueStrengthIndex {
 	}
 }
 
+impl Oscillator for TrueStrengthIndex {
+	const MAIN: usize = 0;
+	const SIGNAL_LINE: Option<usize> = Some(1);
+
+	fn overbought(&self) -> ValueType {
+		self.zone
+	}
+
+	fn oversold(&self) -> ValueType {
+		-self.zone
+	}
+}
+
 impl Default for TrueStrengthIndex {
 	fn default() -> Self {
 		Self {
@@ -148,6 +170,7 @@ impl Default for TrueStrengthIndex {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TrueStrengthIndexInstance {
 	cfg: TrueStrengthIndex,
 
@@ -166,6 +189,10 @@ impl IndicatorInstance for TrueStrengthIndexInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.tsi.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -181,3 +208,17 @@ impl IndicatorInstance for TrueStrengthIndexInstance {
 		IndicatorResult::new(&[tsi, sig], &[s1, s2, s3])
 	}
 }
+
+impl Checkpoint for TrueStrengthIndexInstance {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
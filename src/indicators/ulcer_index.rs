@@ -0,0 +1,187 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::methods::{CrossAbove, CrossUnder, Drawdown, SMA};
+
+/// Ulcer Index
+///
+/// Root mean square of the relative [`Drawdown`]s of the `source` value from its highest value over `period` candles.
+/// Unlike standard deviation, it measures only the downside volatility: the deeper and the longer price falls, the higher the index.
+///
+/// Values are relative (`0.05` means `5%`), so the index of different assets may be compared directly.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Ulcer_index>
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:ulcer_index>
+///
+/// # 1 value
+///
+/// * `main` value
+///
+/// Range in \[`0.0`; `1.0`\)
+///
+/// # 1 signal
+///
+/// * When `threshold` is `0.0`, returns no signal.
+///
+/// When `main` value crosses `threshold` upwards, returns full sell signal.
+/// When `main` value crosses `threshold` downwards, returns full buy signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UlcerIndex {
+	/// Period for searching the highest value and for averaging squared drawdowns. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Signal threshold. Default is `0.0` (no signals).
+	///
+	/// Range in \[`0.0`; `1.0`\)
+	pub threshold: ValueType,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl IndicatorConfig for UlcerIndex {
+	type Instance = UlcerIndexInstance;
+
+	const NAME: &'static str = "UlcerIndex";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volatility,
+		&[ValueRange::Bounded(0.0, 1.0)],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			drawdown: Drawdown::new(cfg.period, src)?,
+			sma: SMA::new(cfg.period, 0.0)?,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1
+			&& self.period < PeriodType::MAX
+			&& self.threshold >= 0.0
+			&& self.threshold < 1.0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"threshold" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.threshold = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for UlcerIndex {
+	fn default() -> Self {
+		Self {
+			period: 14,
+			threshold: 0.0,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UlcerIndexInstance {
+	cfg: UlcerIndex,
+
+	drawdown: Drawdown,
+	sma: SMA,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl IndicatorInstance for UlcerIndexInstance {
+	type Config = UlcerIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.drawdown.heap_size() + self.sma.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let drawdown = self.drawdown.next(candle.source(self.cfg.source));
+
+		// SMA of non-negative values may become slightly negative because of the float rounding errors
+		let value = self.sma.next(drawdown * drawdown).max(0.0).sqrt();
+
+		let signal = if self.cfg.threshold > 0.0 {
+			self.cross_under.next((value, self.cfg.threshold))
+				- self.cross_above.next((value, self.cfg.threshold))
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
+
+impl Checkpoint for UlcerIndexInstance {
+	type State = (
+		<Drawdown as Checkpoint>::State,
+		<SMA as Checkpoint>::State,
+		<CrossAbove as Checkpoint>::State,
+		<CrossUnder as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.drawdown.save(),
+			self.sma.save(),
+			self.cross_above.save(),
+			self.cross_under.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.drawdown.restore(&state.0);
+		self.sma.restore(&state.1);
+		self.cross_above.restore(&state.2);
+		self.cross_under.restore(&state.3);
+	}
+}
@@ -0,0 +1,360 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, WindowCheckpoint};
+use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::Cross;
+use std::collections::BTreeMap;
+use std::mem::size_of;
+
+/// Volume Profile
+///
+/// Bins traded volume by price levels over the last `period` candles or over all the candles since the start, when `period` is `0`.
+/// Every candle's volume goes to the single price level of its `source` value.
+///
+/// In anchored mode (`period` is `0`) the count of stored price levels grows with the price range since the anchor point.
+/// Call [`reset`](VolumeProfileInstance::reset) to start a new profile (f.e. at the start of every session).
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Market_profile>
+///
+/// # 3 values
+///
+/// * `Point of control` (POC)
+///
+/// Middle of the price level with the largest traded volume.
+///
+/// * `Value area high` (VAH)
+/// * `Value area low` (VAL)
+///
+/// Upper and lower bounds of the price levels around POC, which hold `value_area` part of the total volume.
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 3 signals
+///
+/// * When `source` value crosses `POC` upwards, returns full buy signal.
+///   When `source` value crosses `POC` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// * The same for `VAH`.
+///
+/// * The same for `VAL`.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeProfile {
+	/// Rolling window length. Default is `0`, which means anchored profile over all the candles since the start.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Price level size relative to the first candle's `source` value. Default is `0.001`.
+	///
+	/// Range in \(`0.0`; `1.0`\)
+	pub level_size: ValueType,
+
+	/// Part of the total volume in the value area. Default is `0.7`.
+	///
+	/// Range in \(`0.0`; `1.0`\]
+	pub value_area: ValueType,
+
+	/// Source type of values. Default is [`TP`](crate::core::Source::TP)
+	pub source: Source,
+}
+
+impl IndicatorConfig for VolumeProfile {
+	type Instance = VolumeProfileInstance;
+
+	const NAME: &'static str = "VolumeProfile";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Volume,
+		&[ValueRange::Source, ValueRange::Source, ValueRange::Source],
+	)
+	.with_volume();
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		Self::METADATA.check(candle)?;
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let step = src.abs() * cfg.level_size;
+
+		if !step.is_normal() {
+			return Err(Error::InvalidCandles);
+		}
+
+		let mut instance = Self::Instance {
+			step,
+			window: Window::new(cfg.period, (0, 0.)),
+			levels: BTreeMap::new(),
+			crosses: [Cross::default(); 3],
+			buffer: Vec::new(),
+			cfg,
+		};
+
+		let level = instance.level(src);
+		for _ in 0..cfg.period {
+			instance.push(level, candle.volume());
+		}
+
+		Ok(instance)
+	}
+
+	fn validate(&self) -> bool {
+		self.period < PeriodType::MAX
+			&& self.level_size > 0.0
+			&& self.level_size < 1.0
+			&& self.value_area > 0.0
+			&& self.value_area <= 1.0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+			"level_size" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.level_size = value,
+			},
+			"value_area" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.value_area = value,
+			},
+			"source" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 3)
+	}
+}
+
+impl Default for VolumeProfile {
+	fn default() -> Self {
+		Self {
+			period: 0,
+			level_size: 0.001,
+			value_area: 0.7,
+			source: Source::TP,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeProfileInstance {
+	cfg: VolumeProfile,
+
+	step: ValueType,
+	window: Window<(i64, ValueType)>,
+	levels: BTreeMap<i64, (usize, ValueType)>,
+	crosses: [Cross; 3],
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	buffer: Vec<(i64, ValueType)>,
+}
+
+impl VolumeProfileInstance {
+	/// Drops all the accumulated volume, so the next candle becomes the new anchor point
+	///
+	/// In rolling mode the profile grows from the anchor point until the window is full again.
+	pub fn reset(&mut self) {
+		self.levels.clear();
+
+		if !self.window.is_empty() {
+			// `i64::MIN` is never a real level, so these placeholders leave the profile untouched when they are pushed out
+			self.window = Window::new(self.cfg.period, (i64::MIN, 0.));
+		}
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn level(&self, price: ValueType) -> i64 {
+		(price / self.step).floor() as i64
+	}
+
+	fn push(&mut self, level: i64, volume: ValueType) {
+		let entry = self.levels.entry(level).or_insert((0, 0.));
+		entry.0 += 1;
+		entry.1 += volume;
+
+		if !self.window.is_empty() {
+			let (past_level, past_volume) = self.window.push((level, volume));
+
+			if let Some(entry) = self.levels.get_mut(&past_level) {
+				entry.0 -= 1;
+				entry.1 -= past_volume;
+
+				if entry.0 == 0 {
+					self.levels.remove(&past_level);
+				}
+			}
+		}
+	}
+
+	/// Returns POC level and the lowest and the highest levels of the value area
+	fn value_area(&mut self) -> (i64, i64, i64) {
+		let levels = &mut self.buffer;
+		levels.clear();
+		levels.extend(
+			self.levels
+				.iter()
+				.map(|(&level, &(_, volume))| (level, volume)),
+		);
+
+		let total: ValueType = levels.iter().map(|&(_, volume)| volume).sum();
+		let poc = levels.iter().enumerate().fold(
+			0,
+			|max, (i, &(_, volume))| {
+				if volume > levels[max].1 {
+					i
+				} else {
+					max
+				}
+			},
+		);
+
+		let target = total * self.cfg.value_area;
+		let (mut low, mut high) = (poc, poc);
+		let mut volume = levels[poc].1;
+
+		while volume < target && (low > 0 || high + 1 < levels.len()) {
+			let below = low.checked_sub(1).map(|i| levels[i].1);
+			let above = levels.get(high + 1).map(|&(_, volume)| volume);
+
+			match (below, above) {
+				(Some(below), Some(above)) if above >= below => {
+					high += 1;
+					volume += above;
+				}
+				(Some(below), _) => {
+					low -= 1;
+					volume += below;
+				}
+				(None, Some(above)) => {
+					high += 1;
+					volume += above;
+				}
+				(None, None) => break,
+			}
+		}
+
+		(levels[poc].0, levels[low].0, levels[high].0)
+	}
+}
+
+impl IndicatorInstance for VolumeProfileInstance {
+	type Config = VolumeProfile;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+			+ self.levels.len() * size_of::<(i64, (usize, ValueType))>()
+			+ self.buffer.capacity() * size_of::<(i64, ValueType)>()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+		let level = self.level(src);
+
+		self.push(level, candle.volume_or_zero());
+
+		let (poc, low, high) = self.value_area();
+
+		let poc = (poc as ValueType + 0.5) * self.step;
+		let vah = (high + 1) as ValueType * self.step;
+		let val = low as ValueType * self.step;
+
+		let signals = [
+			self.crosses[0].next((src, poc)),
+			self.crosses[1].next((src, vah)),
+			self.crosses[2].next((src, val)),
+		];
+
+		IndicatorResult::new(&[poc, vah, val], &signals)
+	}
+}
+
+impl Checkpoint for VolumeProfileInstance {
+	// levels are not restorable from a single window slot, so the checkpoint holds a copy of them
+	type State = (
+		WindowCheckpoint<(i64, ValueType)>,
+		BTreeMap<i64, (usize, ValueType)>,
+		[Cross; 3],
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.window.save(), self.levels.clone(), self.crosses)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(&state.0);
+		self.levels.clone_from(&state.1);
+		self.crosses = state.2;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::VolumeProfile;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, Source, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_volume_profile_reset() {
+		let candle = |close: ValueType, volume: ValueType| Candle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			volume,
+		};
+
+		for &period in &[0, 2] {
+			let cfg = VolumeProfile {
+				period,
+				level_size: 0.01,
+				source: Source::Close,
+				..VolumeProfile::default()
+			};
+
+			let mut instance = cfg.init(&candle(100.0, 100.0)).unwrap();
+			instance.next(&candle(100.5, 100.0));
+			instance.next(&candle(105.5, 300.0));
+
+			instance.reset();
+
+			let result = instance.next(&candle(110.5, 10.0));
+			assert_eq_float(110.5, result.value(0));
+			assert_eq_float(111.0, result.value(1));
+			assert_eq_float(110.0, result.value(2));
+
+			let result = instance.next(&candle(120.5, 20.0));
+			assert_eq_float(120.5, result.value(0));
+		}
+	}
+}
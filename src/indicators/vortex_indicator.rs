@@ -0,0 +1,185 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
+use crate::methods::{Cross, Integral, TR};
+
+/// Vortex Indicator
+///
+/// Positive vortex movement is |*high* - previous *low*|, negative vortex movement is |*low* - previous *high*|.
+/// `+VI` and `-VI` are sums of positive and negative vortex movements over `period` candles divided by the sum of [`TR`] over the same candles.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Vortex_indicator>
+/// * <https://www.investopedia.com/terms/v/vortex-indicator-vi.asp>
+///
+/// # 2 values
+///
+/// * `+VI` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// * `-VI` value
+///
+/// Range in \[`0.0`; `+inf`\)
+///
+/// # 1 signal
+///
+/// * When `+VI` crosses `-VI` upwards, returns full buy signal.
+///   When `+VI` crosses `-VI` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VortexIndicator {
+	/// Period for sums of vortex movements and true ranges. Default is `14`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+}
+
+impl IndicatorConfig for VortexIndicator {
+	type Instance = VortexIndicatorInstance;
+
+	const NAME: &'static str = "VortexIndicator";
+
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Trend,
+		&[
+			ValueRange::Bounded(0.0, ValueType::INFINITY),
+			ValueRange::Bounded(0.0, ValueType::INFINITY),
+		],
+	);
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let range = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			tr: TR::new(candle)?,
+			tr_sum: Integral::new(cfg.period, range)?,
+			vm_plus: Integral::new(cfg.period, range)?,
+			vm_minus: Integral::new(cfg.period, range)?,
+			prev_high: candle.high(),
+			prev_low: candle.low(),
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
+				Ok(value) => self.period = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for VortexIndicator {
+	fn default() -> Self {
+		Self { period: 14 }
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VortexIndicatorInstance {
+	cfg: VortexIndicator,
+
+	tr: TR,
+	tr_sum: Integral,
+	vm_plus: Integral,
+	vm_minus: Integral,
+	prev_high: ValueType,
+	prev_low: ValueType,
+	cross: Cross,
+}
+
+impl IndicatorInstance for VortexIndicatorInstance {
+	type Config = VortexIndicator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn heap_size(&self) -> usize {
+		self.tr_sum.heap_size() + self.vm_plus.heap_size() + self.vm_minus.heap_size()
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let tr_sum = self.tr_sum.next(self.tr.next(candle));
+		let movement_plus = self.vm_plus.next((candle.high() - self.prev_low).abs());
+		let movement_minus = self.vm_minus.next((candle.low() - self.prev_high).abs());
+
+		self.prev_high = candle.high();
+		self.prev_low = candle.low();
+
+		let (vi_plus, vi_minus) = if tr_sum > 0.0 {
+			(movement_plus / tr_sum, movement_minus / tr_sum)
+		} else {
+			(0.0, 0.0)
+		};
+
+		let signal = self.cross.next((vi_plus, vi_minus));
+
+		IndicatorResult::new(&[vi_plus, vi_minus], &[signal])
+	}
+}
+
+impl Checkpoint for VortexIndicatorInstance {
+	type State = (
+		<TR as Checkpoint>::State,
+		<Integral as Checkpoint>::State,
+		<Integral as Checkpoint>::State,
+		<Integral as Checkpoint>::State,
+		ValueType,
+		ValueType,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.tr.save(),
+			self.tr_sum.save(),
+			self.vm_plus.save(),
+			self.vm_minus.save(),
+			self.prev_high,
+			self.prev_low,
+			self.cross.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.tr.restore(&state.0);
+		self.tr_sum.restore(&state.1);
+		self.vm_plus.restore(&state.2);
+		self.vm_minus.restore(&state.3);
+		self.prev_high = state.4;
+		self.prev_low = state.5;
+		self.cross.restore(&state.6);
+	}
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::{Checkpoint, IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{IndicatorCategory, IndicatorMetadata, ValueRange};
 use crate::helpers::signi;
 use crate::methods::{Cross, CCI};
 
@@ -50,6 +51,11 @@ impl IndicatorConfig for WoodiesCCI {
 
 	const NAME: &'static str = "WoodiesCCI";
 
+	const METADATA: IndicatorMetadata = IndicatorMetadata::new(
+		IndicatorCategory::Momentum,
+		&[ValueRange::Unbounded, ValueRange::Unbounded],
+	);
+
 	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
 		if !self.validate() {
 			return Err(Error::WrongConfig);
@@ -77,19 +83,19 @@ impl IndicatorConfig for WoodiesCCI {
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
 		match name {
 			"period1" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period1 = value,
 			},
 			"period2" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.period2 = value,
 			},
 			"s1_lag" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.s1_lag = value,
 			},
 			"source" => match value.parse() {
-				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Err(error) => return Err(Error::parameter_parse(name, &value, error)),
 				Ok(value) => self.source = value,
 			},
 
@@ -118,6 +124,7 @@ impl Default for WoodiesCCI {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WoodiesCCIInstance {
 	cfg: WoodiesCCI,
 
@@ -134,6 +141,10 @@ impl IndicatorInstance for WoodiesCCIInstance {
 		&self.cfg
 	}
 
+	fn heap_size(&self) -> usize {
+		self.turbo.heap_size() + self.trend.heap_size()
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -154,3 +165,30 @@ impl IndicatorInstance for WoodiesCCIInstance {
 		IndicatorResult::new(&[turbo, trend], &[s1.into()])
 	}
 }
+
+impl Checkpoint for WoodiesCCIInstance {
+	type State = (
+		<CCI as Checkpoint>::State,
+		<CCI as Checkpoint>::State,
+		isize,
+		<Cross as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.turbo.save(),
+			self.trend.save(),
+			self.s1_count,
+			self.s1_cross.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.turbo.restore(&state.0);
+		self.trend.restore(&state.1);
+		self.s1_count = state.2;
+		self.s1_cross.restore(&state.3);
+	}
+}
@@ -53,6 +53,8 @@ limitations under the License.
 //! - [Simple moving median (SMM)](crate::methods::SMM);
 //! - [Linear regression moving average (LSMA)](crate::methods::LinReg);
 //! - [Volume weighted moving average (VWMA)](crate::methods::VWMA);
+//! - [Volume weighted average price (VWAP)](crate::methods::VWAP), rolling or anchored;
+//! - [Rolling VWAP](crate::methods::RollingVWAP) of any price with standard deviation bands;
 //! - [Symmetrically weighted moving average (SWMA)](crate::methods::SWMA);
 //! - [Hull moving average (HMA)](crate::methods::HMA);
 //! - [Running Moving Average (RMA)](crate::methods::RMA);
@@ -73,19 +75,29 @@ limitations under the License.
 //! ## Some commonly used **methods**:
 //!
 //! - [Accumulation-distribution index](crate::methods::ADI);
+//! - [ATR trailing stop](crate::methods::ATRTrailingStop);
 //! - [Commodity channel index](crate::methods::CCI);
+//! - [Constant](crate::methods::Constant) / [Identity](crate::methods::Identity) placeholders;
 //! - [`Cross`](crate::methods::Cross) / [`CrossAbove`](crate::methods::CrossAbove) / [`CrossUnder`](crate::methods::CrossUnder);
 //! - [Derivative](crate::methods::Derivative) (differential);
+//! - [Divergence](crate::methods::Divergence);
+//! - [Drawdown](crate::methods::Drawdown) from the highest value;
+//! - [Envelope](crate::methods::EnvelopeOf) of any moving average;
+//! - [Dominant cycle](crate::methods::DominantCycle) period estimation;
 //! - [Highest](crate::methods::Highest) / [Lowest](crate::methods::Lowest) / [Highest-Lowest Delta](crate::methods::HighestLowestDelta);
 //! - [Highest Index](crate::methods::HighestIndex) / [Lowest Index](crate::methods::LowestIndex);
 //! - [Integral](crate::methods::Integral) (sum);
+//! - [Linear regression](crate::methods::LinRegFull) (slope, intercept, R², standard error);
 //! - [Mean absolute deviation](crate::methods::MeanAbsDev);
 //! - [Median absolute deviation](crate::methods::MedianAbsDev);
 //! - [Momentum](crate::methods::Momentum);
 //! - [Past](crate::methods::Past);
+//! - [Percent rank](crate::methods::PercentRank);
 //! - [Rate Of Change](crate::methods::RateOfChange) (ROC);
 //! - [Reversal points](crate::methods::ReversalSignal);
-//! - [Standard Deviation](crate::methods::StDev);
+//! - [Streak](crate::methods::Streak) of rising or falling values;
+//! - [Intraday seasonality profile](crate::methods::SeasonalProfile);
+//! - [Standard Deviation](crate::methods::StDev) / [Volume weighted Standard Deviation](crate::methods::VWStDev);
 //! - [True Range](crate::methods::TR);
 //! - [True Strength Index](crate::methods::TSI);
 //! - [Volatility](crate::methods::LinearVolatility);
@@ -95,22 +107,36 @@ limitations under the License.
 //! ## Some commonly used **indicators**:
 //!
 //! - Average Directional Index;
+//! - Average True Range;
 //! - Awesome Oscillator;
+//! - Balance Of Power;
 //! - Bollinger Bands;
 //! - Commodity Channel Index;
+//! - Connors RSI;
 //! - Detrended Price Oscillator;
 //! - Ease Of Movement;
+//! - Elder Ray;
 //! - Elders Force Index;
 //! - Envelopes;
 //! - Fisher Transform;
+//! - Guppy Multiple Moving Averages (GMMA);
+//! - Heikin Ashi Trend;
 //! - Ichimoku Cloud;
 //! - Keltner Channels;
+//! - Moving Average Cross;
+//! - Moving Average Ribbon;
 //! - Moving Average Convergence Divergence (MACD);
 //! - Money Flow Index;
 //! - Price Channel Strategy;
+//! - Quantitative Qualitative Estimation (QQE);
 //! - Relative Strength Index (RSI);
+//! - Schaff Trend Cycle;
+//! - Squeeze Momentum (TTM Squeeze);
 //! - Stochastic Oscillator;
+//! - Triple Screen;
 //! - Trix;
+//! - Ulcer Index;
+//! - Vortex Indicator;
 //! - Woodies CCI;
 //!
 //! And many others: [See Full list](crate::indicators#structs)
@@ -178,7 +204,7 @@ pub mod methods;
 /// Contains main traits you need to start using this library
 pub mod prelude {
 	pub use super::core::{
-		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Sequence, OHLCV,
+		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Peekable, Sequence, OHLCV,
 	};
 
 	/// Dynamically dispatchable traits for indicators creation
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window, OHLCV};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -25,10 +25,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// When `length == 0`, `ADI` becomes windowless. That means full `ADI` value accumulation over time.
 ///
+/// Returns [`Error::NoVolume`] when the initial candle [has no volume](OHLCV::has_volume).
+/// Further candles without volume are considered as candles with zero volume.
+///
 /// When `length > 0`, `ADI` will be calculated over the last `length` values.
 ///
 /// # Input type
@@ -70,15 +73,23 @@ use serde::{Deserialize, Serialize};
 /// [`volume`]: crate::core::OHLCV::volume
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 /// [`CLV`]: crate::core::OHLCV::clv
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct ADI {
+pub struct ADI<P: Period = PeriodType> {
 	cmf_sum: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
 impl ADI {
+	/// Creates new instance of `ADI` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: &dyn OHLCV) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> ADI<P> {
 	/// Returns last calculated value
 	#[must_use]
 	pub const fn get_value(&self) -> ValueType {
@@ -86,27 +97,35 @@ impl ADI {
 	}
 }
 
-impl<'a> Method<'a> for ADI {
-	type Params = PeriodType;
+impl<'a, P: Period> Method<'a> for ADI<P> {
+	type Params = P;
 	type Input = &'a dyn OHLCV;
 	type Output = ValueType;
 
 	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		if !candle.has_volume() {
+			return Err(Error::NoVolume);
+		}
+
 		let mut cmf_sum = 0.0;
-		let window = if length > 0 {
+		let window = if length > P::ZERO {
 			let clvv = candle.clv() * candle.volume();
-			cmf_sum = clvv * length as ValueType;
-			Window::new(length, clvv)
+			cmf_sum = clvv * length.to_usize() as ValueType;
+			Window::with_size(length, clvv)
 		} else {
-			Window::empty()
+			Window::default()
 		};
 
 		Ok(Self { cmf_sum, window })
 	}
 
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, candle: Self::Input) -> Self::Output {
-		let clvv = candle.clv() * candle.volume();
+		let clvv = candle.clv() * candle.volume_or_zero();
 		self.cmf_sum += clvv;
 
 		if !self.window.is_empty() {
@@ -117,6 +136,21 @@ impl<'a> Method<'a> for ADI {
 	}
 }
 
+impl<P: Period> Checkpoint for ADI<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.cmf_sum, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.cmf_sum = state.0;
+		self.window.restore(&state.1);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
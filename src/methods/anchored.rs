@@ -0,0 +1,162 @@
+use crate::core::{Checkpoint, Error, Method};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Resets any other method whenever an external trigger fires
+///
+/// When trigger is `true`, the inner method is created again with the same parameters and the current input value,
+/// so the current value becomes the first (anchor) value for the inner method.
+/// If the inner method cannot be created with the new anchor value, the trigger is ignored and the previous inner method keeps going.
+///
+/// It is useful with cumulative methods, e.g. [`Integral`] with `length` `0` or [`VWMA`],
+/// for calculating anchored or per-session statistics (anchored VWAP, session volume and etc.).
+///
+/// # Parameters
+///
+/// Has the same parameters as the inner method `M`
+///
+/// # Input type
+///
+/// Input type is (`value`: `M::Input`, `trigger`: [`bool`])
+///
+/// # Output type
+///
+/// Output type is `M::Output`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Anchored, Integral};
+///
+/// // cumulative sum, which resets at every new session
+/// let values = [1.0,   2.0,   3.0,  4.0,   5.0,   6.0 ];
+/// let new_session = [false, false, true, false, false, true];
+/// let r = [1.0,   3.0,   3.0,  7.0,   12.0,  6.0 ];
+///
+/// let mut session_sum = Anchored::<Integral, _>::new(0, (values[0], true)).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(session_sum.next((values[i], new_session[i])), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1) plus performance of the inner method. Every reset costs as much as creating a new inner method.
+///
+/// [`Integral`]: crate::methods::Integral
+/// [`VWMA`]: crate::methods::VWMA
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Anchored<M, P> {
+	method: M,
+	parameters: P,
+}
+
+impl<M, P> Anchored<M, P> {
+	/// Returns a reference to the inner method
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for Anchored<M, M::Params>
+where
+	M: Method<'a>,
+	M::Params: Clone + fmt::Debug,
+	M::Input: Copy,
+{
+	type Params = M::Params;
+	type Input = (M::Input, bool);
+	type Output = M::Output;
+
+	fn new(parameters: Self::Params, (value, _): Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(parameters.clone(), value)?,
+			parameters,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.method.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, (value, trigger): Self::Input) -> Self::Output {
+		if trigger {
+			if let Ok(method) = M::new(self.parameters.clone(), value) {
+				self.method = method;
+			}
+		}
+
+		self.method.next(value)
+	}
+}
+
+/// Because the inner method may be created again at any step, checkpoint holds a full copy of the inner method.
+impl<M: Clone, P> Checkpoint for Anchored<M, P> {
+	type State = M;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.method.clone()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.method.clone_from(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Anchored, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{Integral, SMA};
+
+	#[test]
+	fn test_anchored_no_trigger() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let mut anchored = Anchored::<SMA, _>::new(length, (src[0], false)).unwrap();
+			let mut method = SMA::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq_float(method.next(x), anchored.next((x, false)));
+			}
+		}
+	}
+
+	#[test]
+	fn test_anchored() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for period in 1..50 {
+			let mut anchored = Anchored::<Integral, _>::new(0, (src[0], true)).unwrap();
+			let mut sum = 0.0;
+
+			for (i, &x) in src.iter().enumerate() {
+				let trigger = i % period == 0;
+
+				if trigger {
+					sum = 0.0;
+				}
+
+				sum += x;
+
+				assert_eq_float(sum, anchored.next((x, trigger)));
+			}
+		}
+	}
+}
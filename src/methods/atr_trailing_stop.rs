@@ -0,0 +1,215 @@
+use crate::core::{Checkpoint, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::RMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Direction of the position, protected by [`ATRTrailingStop`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PositionSide {
+	/// Long position: stop level is below the price and may only move upwards
+	Long,
+
+	/// Short position: stop level is above the price and may only move downwards
+	Short,
+}
+
+/// Trailing stop level at the distance of `k`·[ATR](https://en.wikipedia.org/wiki/Average_true_range) from the *close* price
+///
+/// ATR is calculated as [`RMA`] of [`TR`].
+///
+/// For [`PositionSide::Long`] the stop level is `close` - `k`·ATR, but it never moves downwards.
+///
+/// For [`PositionSide::Short`] the stop level is `close` + `k`·ATR, but it never moves upwards.
+///
+/// It is a building block for indicators like *Chandelier Exit* or *`SuperTrend`*, so it does not flip the side on its own.
+/// If you need to restart the stop (f.e. after the position is closed), just create a new instance.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters (`length`: [`PeriodType`], `k`: [`ValueType`], `side`: [`PositionSide`])
+///
+/// `length` should be > `0`. It is the period of ATR.
+///
+/// `k` should be >= `0`
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Candle;
+/// use yata::methods::{ATRTrailingStop, PositionSide};
+///
+/// let candles = [
+///     Candle { open: 10.0, high: 11.0, low: 9.0, close: 10.0, volume: 1.0 },
+///     Candle { open: 10.0, high: 13.0, low: 11.0, close: 12.0, volume: 1.0 },
+///     Candle { open: 12.0, high: 12.0, low: 8.0, close: 8.0, volume: 1.0 },
+/// ];
+///
+/// let mut stop = ATRTrailingStop::new((1, 1.0, PositionSide::Long), &candles[0]).unwrap();
+///
+/// assert_eq!(stop.next(&candles[0]), 8.0);
+/// assert_eq!(stop.next(&candles[1]), 9.0);
+/// // stop level does not move downwards
+/// assert_eq!(stop.next(&candles[2]), 9.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`TR`], [`RMA`], [`GapDetector`](crate::methods::GapDetector)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`TR`]: crate::methods::TR
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ATRTrailingStop {
+	k: ValueType,
+	side: PositionSide,
+	atr: RMA,
+	prev_close: ValueType,
+	stop: ValueType,
+}
+
+impl ATRTrailingStop {
+	/// Returns side of the protected position
+	#[must_use]
+	pub const fn side(&self) -> PositionSide {
+		self.side
+	}
+
+	/// Returns last calculated stop level
+	#[must_use]
+	pub const fn get_value(&self) -> ValueType {
+		self.stop
+	}
+
+	fn level(&self, close: ValueType, atr: ValueType) -> ValueType {
+		match self.side {
+			PositionSide::Long => atr.mul_add(-self.k, close),
+			PositionSide::Short => atr.mul_add(self.k, close),
+		}
+	}
+}
+
+impl<'a> Method<'a> for ATRTrailingStop {
+	type Params = (PeriodType, ValueType, PositionSide);
+	type Input = &'a dyn OHLCV;
+	type Output = ValueType;
+
+	fn new((length, k, side): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		if k >= 0. {
+			let tr = candle.tr_close(candle.close());
+			let mut method = Self {
+				k,
+				side,
+				atr: RMA::new(length, tr)?,
+				prev_close: candle.close(),
+				stop: 0.,
+			};
+			method.stop = method.level(candle.close(), tr);
+
+			Ok(method)
+		} else {
+			Err(Error::wrong_method_parameter("k", ">= 0", k))
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let atr = self.atr.next(candle.tr_close(self.prev_close));
+		let level = self.level(candle.close(), atr);
+		self.prev_close = candle.close();
+
+		self.stop = match self.side {
+			PositionSide::Long => self.stop.max(level),
+			PositionSide::Short => self.stop.min(level),
+		};
+
+		self.stop
+	}
+}
+
+impl Checkpoint for ATRTrailingStop {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ATRTrailingStop as TestingMethod, Method, PositionSide};
+	use crate::core::Candle;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{RMA, TR};
+
+	#[test]
+	fn test_atr_trailing_stop_const() {
+		let input = RandomCandles::default().first();
+
+		for &side in &[PositionSide::Long, PositionSide::Short] {
+			for length in 1..255 {
+				let mut method = TestingMethod::new((length, 2.0, side), &input).unwrap();
+				let output = method.next(&input);
+
+				test_const_float(&mut method, &input, output);
+			}
+		}
+	}
+
+	#[test]
+	fn test_atr_trailing_stop() {
+		let src: Vec<Candle> = RandomCandles::default().take(300).collect();
+
+		for &k in &[0.0, 0.5, 1.0, 3.0] {
+			for length in 1..255 {
+				let mut long =
+					TestingMethod::new((length, k, PositionSide::Long), &src[0]).unwrap();
+				let mut short =
+					TestingMethod::new((length, k, PositionSide::Short), &src[0]).unwrap();
+				let mut tr = TR::new(&src[0]).unwrap();
+				let mut atr = RMA::new(length, src[0].high - src[0].low).unwrap();
+
+				let initial_atr = src[0].high - src[0].low;
+				let mut long_stop = k.mul_add(-initial_atr, src[0].close);
+				let mut short_stop = k.mul_add(initial_atr, src[0].close);
+
+				for c in &src {
+					let atr = atr.next(tr.next(c));
+					long_stop = long_stop.max(k.mul_add(-atr, c.close));
+					short_stop = short_stop.min(k.mul_add(atr, c.close));
+
+					assert_eq_float(long_stop, long.next(c));
+					assert_eq_float(short_stop, short.next(c));
+				}
+			}
+		}
+
+		assert!(TestingMethod::new((14, -1.0, PositionSide::Long), &src[0]).is_err());
+		assert!(TestingMethod::new((0, 1.0, PositionSide::Long), &src[0]).is_err());
+	}
+}
@@ -1,14 +1,18 @@
 #![allow(unused_imports)]
-use crate::core::Method;
+use crate::core::{Checkpoint, Method, Period};
 use crate::core::{Error, PeriodType, ValueType, Window};
-use crate::methods::MeanAbsDev;
+use crate::methods::{MeanAbsDev, SMA};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// [Commodity channel index](https://en.wikipedia.org/wiki/Commodity_channel_index) of specified `length` for time series of type [`ValueType`]
 ///
-/// In the original formula there is constant coefficient `K = 1/0.015`. This implementation does not include this coefficient (tl;dr it is `1.0`).
+/// In the original formula there is constant coefficient `K = 1/0.015`. By default this implementation does not include this coefficient (tl;dr it is `1.0`).
+/// The constant may be set by [`with_constant`](CCI::with_constant).
+///
+/// The center line is [`SMA`] by default. Any other moving average may be used instead, e.g. `CCI::<EMA>` gives a smoothed CCI variant.
+/// Mean absolute deviation is always calculated around the center line.
 ///
 /// # Parameters
 ///
@@ -24,47 +28,125 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{CCI, EMA};
+///
+/// let mut cci = CCI::new(4, 1.0).unwrap().with_constant(0.015).unwrap();
+/// cci.next(2.0);
+/// cci.next(3.0);
+/// assert!((cci.next(10.0) - 6.0 / 3.0 / 0.015).abs() < 1e-9);
+///
+/// // CCI over EMA center line
+/// let mut smoothed_cci = CCI::<EMA>::new(4, 1.0).unwrap();
+/// smoothed_cci.next(2.0);
+/// ```
+///
 /// # Performance
 ///
-/// O(`length`)
+/// O(`length`) plus performance of the center line moving average
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
-
+/// [`SMA`]: crate::methods::SMA
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct CCI(MeanAbsDev);
+pub struct CCI<M = SMA, P: Period = PeriodType> {
+	ma: M,
+	mean_abs_dev: MeanAbsDev<P>,
+	factor: ValueType,
+}
+
+impl CCI {
+	/// Creates new instance of `CCI` with [`SMA`](crate::methods::SMA) center line
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<M, P: Period> CCI<M, P> {
+	/// Sets the constant of the original formula, which is usually `0.015`
+	///
+	/// `constant` should be > `0`
+	pub fn with_constant(self, constant: ValueType) -> Result<Self, Error> {
+		if constant > 0. && constant.is_finite() {
+			Ok(Self {
+				factor: constant.recip(),
+				..self
+			})
+		} else {
+			Err(Error::wrong_method_parameter("constant", "> 0", constant))
+		}
+	}
 
-impl Method<'_> for CCI {
-	type Params = PeriodType;
+	/// Returns a reference to the center line moving average
+	#[must_use]
+	pub const fn get_ma(&self) -> &M {
+		&self.ma
+	}
+}
+
+impl<'a, M, P> Method<'a> for CCI<M, P>
+where
+	M: Method<'a, Params = P, Input = ValueType, Output = ValueType>,
+	P: Period,
+{
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self(MeanAbsDev::new(length, value)?)),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			ma: M::new(length, value)?,
+			mean_abs_dev: Method::new(length, value)?,
+			factor: 1.0,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.ma.heap_size() + self.mean_abs_dev.heap_size()
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		let mean = self.0.next(value);
-		let ma = self.0.get_sma().get_last_value();
+		let ma = self.ma.next(value);
+		let mean_dev = self.mean_abs_dev.next_with_center(value, ma);
 
-		if mean > 0.0 {
-			(value - ma) / mean
+		if mean_dev > 0.0 {
+			(value - ma) / mean_dev * self.factor
 		} else {
 			0.
 		}
 	}
 }
 
+impl<M: Checkpoint, P: Period> Checkpoint for CCI<M, P> {
+	type State = (M::State, <MeanAbsDev<P> as Checkpoint>::State);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.ma.save(), self.mean_abs_dev.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.ma.restore(&state.0);
+		self.mean_abs_dev.restore(&state.1);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Method, CCI as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::EMA;
 
 	#[test]
 	fn test_cci_const() {
@@ -122,4 +204,44 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_cci_ema() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::<EMA>::new(length, src[0])
+				.unwrap()
+				.with_constant(0.015)
+				.unwrap();
+			let mut ema = EMA::new(length, src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let ma = ema.next(x);
+
+				let mut dev_sum = 0.0;
+				for j in 0..length {
+					dev_sum += (src[i.saturating_sub(j as usize)] - ma).abs();
+				}
+
+				let mean_dev = dev_sum / length as ValueType;
+
+				let q = if mean_dev == 0.0 {
+					0.0
+				} else {
+					(x - ma) / (0.015 * mean_dev)
+				};
+
+				let value = method.next(x);
+				assert_eq_float(q, value);
+			});
+		});
+
+		assert!(TestingMethod::new(10, src[0])
+			.unwrap()
+			.with_constant(0.0)
+			.is_err());
+	}
 }
@@ -0,0 +1,196 @@
+use crate::core::{Checkpoint, Error, Method, Peekable, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chains two methods, so the output of the first method is the input of the second one
+///
+/// It is a generalization of [`DMA`] (which is [`EMA`] over [`EMA`]) for any pair of methods.
+///
+/// The second method is created with the output of the first method over the initial value,
+/// so chain over the constant timeseries returns the same values as the inner methods do.
+///
+/// When the first method takes [`OHLCV`] as input, chain also implements [`Peekable`].
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`first`: `M1::Params`, `second`: `M2::Params`)
+///
+/// # Input type
+///
+/// Input type is `M1::Input`
+///
+/// # Output type
+///
+/// Output type is `M2::Output`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Chain, EMA, DMA};
+///
+/// let mut chain = Chain::<EMA, EMA>::new((3, 3), 1.0).unwrap();
+/// let mut dma = DMA::new(3, 1.0).unwrap();
+///
+/// for &x in &[1.0, 2.0, 3.0, 2.0, 5.0] {
+///     assert_eq!(chain.next(x), dma.next(x));
+/// }
+/// ```
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Peekable;
+/// use yata::helpers::RandomCandles;
+/// use yata::methods::{Chain, RMA, TR};
+///
+/// // Average True Range
+/// let mut candles = RandomCandles::new();
+/// let mut atr = Chain::<TR, RMA>::new(((), 14), &candles.first()).unwrap();
+///
+/// let candle = candles.next().unwrap();
+/// let peeked = atr.peek(&candle);
+///
+/// assert_eq!(atr.next(&candle), peeked);
+/// ```
+///
+/// # Performance
+///
+/// Sum of performances of the inner methods
+///
+/// [`DMA`]: crate::methods::DMA
+/// [`EMA`]: crate::methods::EMA
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Peekable`]: crate::core::Peekable
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chain<M1, M2> {
+	first: M1,
+	second: M2,
+}
+
+impl<M1, M2> Chain<M1, M2> {
+	/// Returns a reference to the first inner method
+	pub const fn first(&self) -> &M1 {
+		&self.first
+	}
+
+	/// Returns a reference to the second inner method
+	pub const fn second(&self) -> &M2 {
+		&self.second
+	}
+}
+
+impl<'a, M1, M2> Method<'a> for Chain<M1, M2>
+where
+	M1: Method<'a> + Clone,
+	M1::Input: Copy,
+	M2: Method<'a, Input = M1::Output>,
+{
+	type Params = (M1::Params, M2::Params);
+	type Input = M1::Input;
+	type Output = M2::Output;
+
+	fn new((first, second): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		let first = M1::new(first, value)?;
+		let initial = first.clone().next(value);
+
+		Ok(Self {
+			second: M2::new(second, initial)?,
+			first,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.first.heap_size() + self.second.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.second.next(self.first.next(value))
+	}
+}
+
+impl<M1, M2, V> Peekable<V> for Chain<M1, M2>
+where
+	M1: for<'b> Method<'b, Input = &'b dyn OHLCV> + Clone,
+	M2: for<'b> Method<'b, Input = <M1 as Method<'b>>::Output, Output = V> + Clone,
+{
+	#[inline]
+	fn peek<T: OHLCV>(&self, candle: &T) -> V {
+		self.clone().next(candle)
+	}
+}
+
+impl<M1: Checkpoint, M2: Checkpoint> Checkpoint for Chain<M1, M2> {
+	type State = (M1::State, M2::State);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.first.save(), self.second.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.first.restore(&state.0);
+		self.second.restore(&state.1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Chain, Method};
+	use crate::core::{Candle, Peekable, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{Derivative, RMA, SMA, TR, WMA};
+
+	#[test]
+	fn test_chain_const() {
+		for length in 1..255 {
+			let input = (length as ValueType + 56.0) / 16.3251;
+			let mut method = Chain::<SMA, WMA>::new((length, length), input).unwrap();
+
+			test_const_float(&mut method, input, input);
+
+			let mut method = Chain::<WMA, Derivative>::new((length, length), input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_chain() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let mut chain = Chain::<SMA, WMA>::new((length, length / 2 + 1), src[0]).unwrap();
+			let mut sma = SMA::new(length, src[0]).unwrap();
+			let mut wma = WMA::new(length / 2 + 1, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq_float(wma.next(sma.next(x)), chain.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_chain_peek() {
+		let candles: Vec<Candle> = RandomCandles::default().take(300).collect();
+
+		for length in 1..100 {
+			let mut chain = Chain::<TR, RMA>::new(((), length), &candles[0]).unwrap();
+
+			for candle in &candles {
+				chain.peek(&candles[0]);
+
+				let peeked = chain.peek(candle);
+
+				assert_eq!(peeked.to_bits(), chain.next(candle).to_bits());
+			}
+		}
+	}
+}
@@ -0,0 +1,141 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Limits values of timeseries of type [`ValueType`] to the `[min; max]` range
+///
+/// Useful for post-processing of indicators outputs before they drive any orders.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`min`: [`ValueType`], `max`: [`ValueType`])
+///
+/// `min` should be <= `max`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::Clamp;
+///
+/// let values = [0.5, -1.0, 2.0, -3.0, 0.0];
+/// let r      = [0.5, -1.0, 1.0, -1.0, 0.0];
+///
+/// let mut clamp = Clamp::new((-1.0, 1.0), values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(clamp.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RateLimit`](crate::methods::RateLimit)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Clamp {
+	min: ValueType,
+	max: ValueType,
+}
+
+impl Method<'_> for Clamp {
+	type Params = (ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((min, max): Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		if min <= max {
+			Ok(Self { min, max })
+		} else {
+			Err(Error::wrong_method_parameter("max", ">= min", max))
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		value.clamp(self.min, self.max)
+	}
+}
+
+impl Checkpoint for Clamp {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Clamp as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_clamp_const() {
+		let input = 123.456;
+
+		test_const(
+			&mut TestingMethod::new((0.0, 200.0), input).unwrap(),
+			input,
+			input,
+		);
+		test_const(
+			&mut TestingMethod::new((0.0, 100.0), input).unwrap(),
+			input,
+			100.0,
+		);
+		test_const(
+			&mut TestingMethod::new((150.0, 200.0), input).unwrap(),
+			input,
+			150.0,
+		);
+	}
+
+	#[test]
+	fn test_clamp() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let (min, max) = (src[0] * 0.9, src[0] * 1.1);
+		let mut method = TestingMethod::new((min, max), src[0]).unwrap();
+
+		for &x in &src {
+			let expected = if x < min {
+				min
+			} else if x > max {
+				max
+			} else {
+				x
+			};
+
+			assert_eq_float(expected, method.next(x));
+		}
+
+		assert!(TestingMethod::new((1.0, 0.0), 0.0).is_err());
+		assert!(TestingMethod::new((0.0, ValueType::NAN), 0.0).is_err());
+	}
+}
@@ -1,4 +1,4 @@
-use crate::core::{Candle, Error, Method, OHLCV};
+use crate::core::{Candle, Checkpoint, Error, Merge, MergePolicy, Method, ValueType, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,6 +11,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// `period` must be > `0`
 ///
+/// Candles are merged by the default [`MergePolicy`]. Other policy may be set by [`with_policy`](CollapseTimeframe::with_policy).
+///
 /// # Input type
 ///
 /// Input type is reference to [`OHLCV`]
@@ -41,6 +43,27 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(collapsed.volume(), 3000.0);
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{MergePolicy, VolumePolicy};
+/// use yata::methods::CollapseTimeframe;
+///
+/// // cumulative daily volume
+/// let timeframe = [
+///     (10.0, 15.0, 5.0, 12.0, 1000.0),
+///     (12.1, 17.0, 6.0, 13.0, 3000.0),
+/// ];
+///
+/// let policy = MergePolicy {
+///     volume: VolumePolicy::Last,
+///     ..MergePolicy::default()
+/// };
+/// let mut collapser = CollapseTimeframe::new(2, &timeframe[0]).unwrap().with_policy(policy);
+///
+/// collapser.next(&timeframe[0]);
+/// assert_eq!(collapser.next(&timeframe[1]).unwrap().volume(), 3000.0);
+/// ```
+///
 /// Output type is [`Candle`]
 ///
 /// # Performance
@@ -57,6 +80,16 @@ pub struct CollapseTimeframe {
 	current: Option<Candle>,
 	index: usize,
 	period: usize,
+	policy: MergePolicy,
+	previous_close: Option<ValueType>,
+}
+
+impl CollapseTimeframe {
+	/// Sets merging `policy` of the candles
+	#[must_use]
+	pub const fn with_policy(self, policy: MergePolicy) -> Self {
+		Self { policy, ..self }
+	}
 }
 
 impl<'a> Method<'a> for CollapseTimeframe {
@@ -66,7 +99,7 @@ impl<'a> Method<'a> for CollapseTimeframe {
 
 	fn new(period: Self::Params, _candle: Self::Input) -> Result<Self, Error> {
 		if period == 0 {
-			return Err(Error::WrongMethodParameters);
+			return Err(Error::wrong_method_parameter("period", "> 0", period));
 		}
 
 		Ok(Self {
@@ -76,15 +109,16 @@ impl<'a> Method<'a> for CollapseTimeframe {
 	}
 
 	fn next(&mut self, candle: Self::Input) -> Self::Output {
-		let current = self.current.map_or(candle.into(), |c2| Candle {
-			high: c2.high.max(candle.high()),
-			low: c2.low.min(candle.low()),
-			close: candle.close(),
-			volume: c2.volume + candle.volume(),
-			..c2
-		});
+		let current = match self.current {
+			Some(mut current) => {
+				current.merge(candle, &self.policy);
+				current
+			}
+			None => self.policy.start(candle, self.previous_close),
+		};
 
 		self.current = Some(current);
+		self.previous_close = Some(candle.close());
 
 		self.index += 1;
 
@@ -97,9 +131,24 @@ impl<'a> Method<'a> for CollapseTimeframe {
 	}
 }
 
+impl Checkpoint for CollapseTimeframe {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{Candle, CollapseTimeframe as TestingMethod, Method, OHLCV};
+	use super::{Candle, CollapseTimeframe as TestingMethod, MergePolicy, Method, OHLCV};
+	use crate::core::{NanMergePolicy, OpenPolicy, Sequence, VolumePolicy};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 
 	#[test]
@@ -159,6 +208,30 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn test_timeframe_collapse_policy() {
+		let candles = RandomCandles::new().take(100).collect::<Vec<_>>();
+		let policy = MergePolicy {
+			open: OpenPolicy::PreviousClose,
+			volume: VolumePolicy::Max,
+			nan: NanMergePolicy::Ignore,
+		};
+
+		for length in 1..10 {
+			let mut method = TestingMethod::new(length, &candles[0])
+				.unwrap()
+				.with_policy(policy);
+
+			let converted = candles.iter().filter_map(|x| method.next(x));
+
+			candles
+				.collapse_timeframe_with(length, false, policy)
+				.into_iter()
+				.zip(converted)
+				.for_each(|(a, b)| assert_eq!(a, b));
+		}
+	}
+
 	#[test]
 	#[should_panic]
 	fn test_timeframe_collapse_fail() {
@@ -0,0 +1,259 @@
+use crate::core::{Checkpoint, Error, Method, ValueType};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Merges outputs of two methods into a single value
+///
+/// It is implemented for [`Difference`], [`Ratio`], [`Sum`], [`Min`], [`Max`] and for any function pointer `fn(A, B) -> O`.
+pub trait Combinator<A, B>: fmt::Debug {
+	/// Merged value type
+	type Output;
+
+	/// Merges two values
+	fn combine(&self, a: A, b: B) -> Self::Output;
+}
+
+impl<A, B, O> Combinator<A, B> for fn(A, B) -> O {
+	type Output = O;
+
+	#[inline]
+	fn combine(&self, a: A, b: B) -> Self::Output {
+		self(a, b)
+	}
+}
+
+/// Returns `a - b`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Difference;
+
+impl Combinator<ValueType, ValueType> for Difference {
+	type Output = ValueType;
+
+	#[inline]
+	fn combine(&self, a: ValueType, b: ValueType) -> Self::Output {
+		a - b
+	}
+}
+
+/// Returns `a / b`
+///
+/// There is no special handling of `b == 0.0`, so result may be infinite or `NaN`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ratio;
+
+impl Combinator<ValueType, ValueType> for Ratio {
+	type Output = ValueType;
+
+	#[inline]
+	fn combine(&self, a: ValueType, b: ValueType) -> Self::Output {
+		a / b
+	}
+}
+
+/// Returns `a + b`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sum;
+
+impl Combinator<ValueType, ValueType> for Sum {
+	type Output = ValueType;
+
+	#[inline]
+	fn combine(&self, a: ValueType, b: ValueType) -> Self::Output {
+		a + b
+	}
+}
+
+/// Returns minimum of `a` and `b`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Min;
+
+impl Combinator<ValueType, ValueType> for Min {
+	type Output = ValueType;
+
+	#[inline]
+	fn combine(&self, a: ValueType, b: ValueType) -> Self::Output {
+		a.min(b)
+	}
+}
+
+/// Returns maximum of `a` and `b`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Max;
+
+impl Combinator<ValueType, ValueType> for Max {
+	type Output = ValueType;
+
+	#[inline]
+	fn combine(&self, a: ValueType, b: ValueType) -> Self::Output {
+		a.max(b)
+	}
+}
+
+/// Runs two methods on the same input and merges their outputs by [`Combinator`]
+///
+/// Allows to declare simple composite oscillators without writing a new structure each time.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters (`first`: `M1::Params`, `second`: `M2::Params`, `combinator`: `F`)
+///
+/// `first` and `second` are passed to the inner methods as is.
+///
+/// `combinator` may be any of [`Difference`], [`Ratio`], [`Sum`], [`Min`], [`Max`] or a function pointer `fn(M1::Output, M2::Output) -> O`.
+///
+/// # Input type
+///
+/// Input type is `M1::Input`, which must be the same as `M2::Input`
+///
+/// # Output type
+///
+/// Output type is `F::Output`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::methods::{EMA, SMA};
+/// use yata::methods::combine::{Combine, Difference};
+///
+/// // MACD line
+/// let mut macd = Combine::<EMA, EMA, _>::new((12, 26, Difference), 1.0).unwrap();
+/// assert_eq!(macd.next(1.0), 0.0);
+///
+/// // custom function
+/// let f: fn(ValueType, ValueType) -> bool = |a, b| a > b;
+/// let mut above = Combine::<SMA, SMA, _>::new((2, 4, f), 1.0).unwrap();
+/// assert!(above.next(5.0));
+/// ```
+///
+/// # Performance
+///
+/// Sum of performances of the inner methods
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Combine<M1, M2, F> {
+	first: M1,
+	second: M2,
+	combinator: F,
+}
+
+impl<M1, M2, F> Combine<M1, M2, F> {
+	/// Returns a reference to the first inner method
+	pub const fn first(&self) -> &M1 {
+		&self.first
+	}
+
+	/// Returns a reference to the second inner method
+	pub const fn second(&self) -> &M2 {
+		&self.second
+	}
+}
+
+impl<'a, M1, M2, F> Method<'a> for Combine<M1, M2, F>
+where
+	M1: Method<'a>,
+	M2: Method<'a, Input = M1::Input>,
+	M1::Input: Copy,
+	F: Combinator<M1::Output, M2::Output>,
+{
+	type Params = (M1::Params, M2::Params, F);
+	type Input = M1::Input;
+	type Output = F::Output;
+
+	fn new((first, second, combinator): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			first: M1::new(first, value)?,
+			second: M2::new(second, value)?,
+			combinator,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.first.heap_size() + self.second.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.combinator
+			.combine(self.first.next(value), self.second.next(value))
+	}
+}
+
+impl<M1: Checkpoint, M2: Checkpoint, F> Checkpoint for Combine<M1, M2, F> {
+	type State = (M1::State, M2::State);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.first.save(), self.second.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.first.restore(&state.0);
+		self.second.restore(&state.1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Combine, Difference, Max, Method, Min, Ratio, Sum};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{EMA, SMA};
+
+	#[test]
+	fn test_combine_const() {
+		for length in 1..255 {
+			let input = (length as ValueType + 56.0) / 16.3251;
+			let mut method =
+				Combine::<SMA, EMA, _>::new((length, length, Difference), input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_combine() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let params = (length, length / 2 + 1);
+			let mut difference =
+				Combine::<SMA, EMA, _>::new((params.0, params.1, Difference), src[0]).unwrap();
+			let mut ratio =
+				Combine::<SMA, EMA, _>::new((params.0, params.1, Ratio), src[0]).unwrap();
+			let mut sum = Combine::<SMA, EMA, _>::new((params.0, params.1, Sum), src[0]).unwrap();
+			let mut min = Combine::<SMA, EMA, _>::new((params.0, params.1, Min), src[0]).unwrap();
+			let mut max = Combine::<SMA, EMA, _>::new((params.0, params.1, Max), src[0]).unwrap();
+			let f: fn(ValueType, ValueType) -> ValueType = |a, b| a * b;
+			let mut product = Combine::<SMA, EMA, _>::new((params.0, params.1, f), src[0]).unwrap();
+
+			let mut sma = SMA::new(params.0, src[0]).unwrap();
+			let mut ema = EMA::new(params.1, src[0]).unwrap();
+
+			for &x in &src {
+				let a = sma.next(x);
+				let b = ema.next(x);
+
+				assert_eq_float(a - b, difference.next(x));
+				assert_eq_float(a / b, ratio.next(x));
+				assert_eq_float(a + b, sum.next(x));
+				assert_eq_float(a.min(b), min.next(x));
+				assert_eq_float(a.max(b), max.next(x));
+				assert_eq_float(a * b, product.next(x));
+			}
+		}
+	}
+}
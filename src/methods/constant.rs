@@ -0,0 +1,123 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Always returns the initial value, ignoring any input values
+///
+/// It is a placeholder for pipelines and combinators, where some method is required, but its output should be fixed
+/// (f.e. a constant threshold line for [`Cross`](crate::methods::Cross) or [`Combine`](crate::methods::Combine)).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` is ignored, so it may be any value. It exists only to make [`Constant`] a [regular method](crate::helpers::RegularMethod).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Constant;
+///
+/// let mut constant = Constant::new(10, 1.0).unwrap();
+///
+/// assert_eq!(constant.next(1.0), 1.0);
+/// assert_eq!(constant.next(2.0), 1.0);
+/// assert_eq!(constant.next(-3.0), 1.0);
+/// assert_eq!(constant.get_value(), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Identity`](crate::methods::Identity)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Constant(ValueType);
+
+impl Constant {
+	/// Returns the constant value
+	#[must_use]
+	pub const fn get_value(&self) -> ValueType {
+		self.0
+	}
+}
+
+impl Method<'_> for Constant {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(_length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self(value))
+	}
+
+	#[inline]
+	fn next(&mut self, _value: Self::Input) -> Self::Output {
+		self.0
+	}
+}
+
+impl Checkpoint for Constant {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Constant as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_constant_const() {
+		for i in 0..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_constant() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(0..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq_float(src[0], method.next(x));
+			}
+		});
+	}
+}
@@ -1,5 +1,6 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use std::mem::size_of;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -60,10 +61,18 @@ impl Method<'_> for Conv {
 					wsum_invert,
 				})
 			}
-			_ => Err(Error::WrongMethodParameters),
+			length => Err(Error::wrong_method_parameter(
+				"weights",
+				"length in [1; PeriodType::MAX]",
+				length,
+			)),
 		}
 	}
 
+	fn heap_size(&self) -> usize {
+		self.weights.capacity() * size_of::<ValueType>() + self.window.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.window.push(value);
@@ -76,6 +85,20 @@ impl Method<'_> for Conv {
 	}
 }
 
+impl Checkpoint for Conv {
+	type State = WindowCheckpoint<ValueType>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.window.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(state);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
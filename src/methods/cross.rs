@@ -1,9 +1,43 @@
-use crate::core::Method;
-use crate::core::{Action, Error, ValueType};
+use crate::core::{Action, Error, PeriodType, ValueType};
+use crate::core::{Checkpoint, Method};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Minimum distance between two timeseries lines, which is required to register a cross
+///
+/// Used by [`Cross`], [`CrossAbove`] and [`CrossUnder`] to filter out phantom crosses of noisy series.
+///
+/// Default is `Absolute(0.0)`, which means any cross is registered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum CrossTolerance {
+	/// Absolute distance between `value` and `base`
+	Absolute(ValueType),
+
+	/// Distance between `value` and `base` in percents of `base`
+	Percent(ValueType),
+}
+
+impl CrossTolerance {
+	/// Returns absolute minimum distance for the `base` value
+	#[inline]
+	#[must_use]
+	pub fn distance(self, base: ValueType) -> ValueType {
+		match self {
+			Self::Absolute(distance) => distance,
+			Self::Percent(percent) => base.abs() * percent / 100.,
+		}
+	}
+}
+
+impl Default for CrossTolerance {
+	fn default() -> Self {
+		Self::Absolute(0.0)
+	}
+}
+
 /// Searches for two timeseries lines of type [`ValueType`] cross each other.
 ///
 /// If `value` crossed `base` upwards, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL)
@@ -14,7 +48,11 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has no parameters
+/// Has no parameters.
+///
+/// Optionally minimum crossing distance may be set by [`with_tolerance`](Cross::with_tolerance)
+/// and count of confirmation bars may be set by [`with_confirmation`](Cross::with_confirmation).
+/// See more at [`CrossAbove`] and [`CrossUnder`].
 ///
 /// # Input type
 ///
@@ -62,6 +100,37 @@ pub struct Cross {
 	down: CrossUnder,
 }
 
+impl Cross {
+	/// Creates new `Cross` in `const` context
+	///
+	/// Does the same as [`Method::new`], but may be used to initialize `const` and `static` items.
+	#[must_use]
+	pub const fn new_const(value: (ValueType, ValueType)) -> Self {
+		Self {
+			up: CrossAbove::new_const(value),
+			down: CrossUnder::new_const(value),
+		}
+	}
+
+	/// Sets minimum crossing distance for both directions
+	#[must_use]
+	pub const fn with_tolerance(self, tolerance: CrossTolerance) -> Self {
+		Self {
+			up: self.up.with_tolerance(tolerance),
+			down: self.down.with_tolerance(tolerance),
+		}
+	}
+
+	/// Sets count of confirmation bars for both directions
+	#[must_use]
+	pub const fn with_confirmation(self, bars: PeriodType) -> Self {
+		Self {
+			up: self.up.with_confirmation(bars),
+			down: self.down.with_confirmation(bars),
+		}
+	}
+}
+
 impl Method<'_> for Cross {
 	type Params = ();
 	type Input = (ValueType, ValueType);
@@ -71,10 +140,7 @@ impl Method<'_> for Cross {
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			up: CrossAbove::new((), value).unwrap(),
-			down: CrossUnder::new((), value).unwrap(),
-		})
+		Ok(Self::new_const(value))
 	}
 
 	#[inline]
@@ -86,6 +152,128 @@ impl Method<'_> for Cross {
 	}
 }
 
+impl Checkpoint for Cross {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+/// Searches for timeseries of type [`ValueType`] crosses zero line (changes its sign)
+///
+/// It is the same as [`Cross`] with the constant `base` equal to `0.0`, which is useful for oscillators.
+///
+/// If `value` crossed zero upwards, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL)
+///
+/// If `value` crossed zero downwards, then returns [`Action::SELL_ALL`](crate::core::Action::SELL_ALL)
+///
+/// Else returns [`Action::None`](crate::core::Action::None)
+///
+/// # Parameters
+///
+/// Has no parameters.
+///
+/// Optionally minimum crossing distance may be set by [`with_tolerance`](ZeroCross::with_tolerance)
+/// and count of confirmation bars may be set by [`with_confirmation`](ZeroCross::with_confirmation).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ZeroCross;
+///
+/// let values = [-2.0, -1.0, 0.5, 1.0, 0.2, -0.5, 3.0];
+/// let r      = [  0,    0,   1,   0,   0,   -1,   1 ];
+///
+/// let mut zero_cross = ZeroCross::new((), values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(zero_cross.next(values[i]).analog(), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Cross`], [`CrossAbove`], [`CrossUnder`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZeroCross {
+	cross: Cross,
+}
+
+impl ZeroCross {
+	/// Sets minimum crossing distance for both directions
+	///
+	/// [`CrossTolerance::Percent`] makes no sense here, because the base line is always zero.
+	#[must_use]
+	pub const fn with_tolerance(self, tolerance: CrossTolerance) -> Self {
+		Self {
+			cross: self.cross.with_tolerance(tolerance),
+		}
+	}
+
+	/// Sets count of confirmation bars for both directions
+	#[must_use]
+	pub const fn with_confirmation(self, bars: PeriodType) -> Self {
+		Self {
+			cross: self.cross.with_confirmation(bars),
+		}
+	}
+}
+
+impl Method<'_> for ZeroCross {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Action;
+
+	fn new((): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			cross: Cross::new((), (value, 0.0))?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.cross.next((value, 0.0))
+	}
+}
+
+impl Checkpoint for ZeroCross {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 /// Searches for `value` timeseries line crosses `base` line upwards
 ///
 /// If `value` crossed `base` upwards, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL)
@@ -94,7 +282,15 @@ impl Method<'_> for Cross {
 ///
 /// # Parameters
 ///
-/// Has no parameters
+/// Has no parameters.
+///
+/// Optionally minimum crossing distance may be set by `with_tolerance` (see [`CrossTolerance`]).
+/// Then the cross is registered only when `value` gets behind `base` by at least that distance.
+///
+/// Optionally count of confirmation bars may be set by `with_confirmation`.
+/// Then the signal is returned only after `value` stays behind `base` (by at least minimum distance) for that count of bars after the cross.
+///
+/// Non-finite distance between `value` and `base` (f.e. when any of them is `NaN`) is skipped and leaves the state untouched.
 ///
 /// # Input type
 ///
@@ -138,20 +334,70 @@ impl Method<'_> for Cross {
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossAbove {
-	last_delta: ValueType,
+	armed: bool,
+	count: PeriodType,
+	tolerance: CrossTolerance,
+	confirmation: PeriodType,
 }
 
 impl CrossAbove {
+	/// Creates new `CrossAbove` in `const` context
+	///
+	/// Does the same as [`Method::new`], but may be used to initialize `const` and `static` items.
+	#[must_use]
+	pub const fn new_const(value: (ValueType, ValueType)) -> Self {
+		Self {
+			armed: value.0 < value.1,
+			count: 0,
+			tolerance: CrossTolerance::Absolute(0.0),
+			confirmation: 0,
+		}
+	}
+
+	/// Sets minimum crossing distance. Default is [`CrossTolerance::Absolute(0.0)`](CrossTolerance::Absolute).
+	#[must_use]
+	pub const fn with_tolerance(self, tolerance: CrossTolerance) -> Self {
+		Self { tolerance, ..self }
+	}
+
+	/// Sets count of confirmation bars, for which `value` must stay behind `base` after the cross. Default is `0`.
+	#[must_use]
+	pub const fn with_confirmation(self, bars: PeriodType) -> Self {
+		Self {
+			confirmation: bars,
+			..self
+		}
+	}
+
 	/// Returns `true` when value1 crosses `value2` timeseries upwards
 	/// Otherwise returns `false`
 	#[inline]
 	pub fn binary(&mut self, value1: ValueType, value2: ValueType) -> bool {
-		let last_delta = self.last_delta;
-		let current_delta = value1 - value2;
+		let delta = value1 - value2;
+
+		if !delta.is_finite() {
+			return false;
+		}
+
+		let tolerance = self.tolerance.distance(value2);
 
-		self.last_delta = current_delta;
+		if delta < 0. {
+			self.armed = true;
+			self.count = 0;
+		} else if self.armed && delta >= tolerance {
+			self.count = self.count.saturating_add(1);
 
-		last_delta < 0. && current_delta >= 0.
+			if self.count > self.confirmation {
+				self.armed = false;
+				self.count = 0;
+
+				return true;
+			}
+		} else {
+			self.count = 0;
+		}
+
+		false
 	}
 }
 
@@ -164,9 +410,7 @@ impl Method<'_> for CrossAbove {
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			last_delta: value.0 - value.1,
-		})
+		Ok(Self::new_const(value))
 	}
 
 	#[inline]
@@ -175,6 +419,20 @@ impl Method<'_> for CrossAbove {
 	}
 }
 
+impl Checkpoint for CrossAbove {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 /// Searches for `value` timeseries line crosses `base` line downwards
 ///
 /// If `value` crossed `base` downwards, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL)
@@ -183,7 +441,15 @@ impl Method<'_> for CrossAbove {
 ///
 /// # Parameters
 ///
-/// Has no parameters
+/// Has no parameters.
+///
+/// Optionally minimum crossing distance may be set by `with_tolerance` (see [`CrossTolerance`]).
+/// Then the cross is registered only when `value` gets behind `base` by at least that distance.
+///
+/// Optionally count of confirmation bars may be set by `with_confirmation`.
+/// Then the signal is returned only after `value` stays behind `base` (by at least minimum distance) for that count of bars after the cross.
+///
+/// Non-finite distance between `value` and `base` (f.e. when any of them is `NaN`) is skipped and leaves the state untouched.
 ///
 /// # Input type
 ///
@@ -227,20 +493,70 @@ impl Method<'_> for CrossAbove {
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CrossUnder {
-	last_delta: ValueType,
+	armed: bool,
+	count: PeriodType,
+	tolerance: CrossTolerance,
+	confirmation: PeriodType,
 }
 
 impl CrossUnder {
+	/// Creates new `CrossUnder` in `const` context
+	///
+	/// Does the same as [`Method::new`], but may be used to initialize `const` and `static` items.
+	#[must_use]
+	pub const fn new_const(value: (ValueType, ValueType)) -> Self {
+		Self {
+			armed: value.0 > value.1,
+			count: 0,
+			tolerance: CrossTolerance::Absolute(0.0),
+			confirmation: 0,
+		}
+	}
+
+	/// Sets minimum crossing distance. Default is [`CrossTolerance::Absolute(0.0)`](CrossTolerance::Absolute).
+	#[must_use]
+	pub const fn with_tolerance(self, tolerance: CrossTolerance) -> Self {
+		Self { tolerance, ..self }
+	}
+
+	/// Sets count of confirmation bars, for which `value` must stay behind `base` after the cross. Default is `0`.
+	#[must_use]
+	pub const fn with_confirmation(self, bars: PeriodType) -> Self {
+		Self {
+			confirmation: bars,
+			..self
+		}
+	}
+
 	/// Returns `true` when value1 crosses `value2` timeseries downwards
 	/// Otherwise returns `false`
 	#[inline]
 	pub fn binary(&mut self, value1: ValueType, value2: ValueType) -> bool {
-		let last_delta = self.last_delta;
-		let current_delta = value1 - value2;
+		let delta = value1 - value2;
 
-		self.last_delta = current_delta;
+		if !delta.is_finite() {
+			return false;
+		}
+
+		let tolerance = self.tolerance.distance(value2);
+
+		if delta > 0. {
+			self.armed = true;
+			self.count = 0;
+		} else if self.armed && delta <= -tolerance {
+			self.count = self.count.saturating_add(1);
+
+			if self.count > self.confirmation {
+				self.armed = false;
+				self.count = 0;
+
+				return true;
+			}
+		} else {
+			self.count = 0;
+		}
 
-		last_delta > 0. && current_delta <= 0.
+		false
 	}
 }
 
@@ -253,9 +569,7 @@ impl Method<'_> for CrossUnder {
 	where
 		Self: Sized,
 	{
-		Ok(Self {
-			last_delta: value.0 - value.1,
-		})
+		Ok(Self::new_const(value))
 	}
 
 	#[inline]
@@ -264,6 +578,20 @@ impl Method<'_> for CrossUnder {
 	}
 }
 
+impl Checkpoint for CrossUnder {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(unused_imports)]
@@ -308,6 +636,35 @@ mod tests {
 			assert_eq!(value1, value2, "{}, {} at index {}", value2, value1, i);
 		});
 	}
+	#[test]
+	fn test_zero_cross_const() {
+		use super::ZeroCross as TestingMethod;
+
+		for &input in &[-7.0, 0.0, 7.0] {
+			let mut method = TestingMethod::new((), input).unwrap();
+			let output = method.next(input);
+
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_zero_cross() {
+		use super::{Cross, ZeroCross as TestingMethod};
+
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let avg = src.iter().sum::<ValueType>() / src.len() as ValueType;
+
+		let mut cross = Cross::new((), (src[0], avg)).unwrap();
+		let mut method = TestingMethod::new((), src[0] - avg).unwrap();
+
+		for &x in &src {
+			assert_eq!(method.next(x - avg), cross.next((x - avg, 0.0)));
+		}
+	}
+
 	#[test]
 	fn test_cross_above_const() {
 		use super::CrossAbove as TestingMethod;
@@ -377,4 +734,77 @@ mod tests {
 			assert_eq!(value1, value2, "{}, {} at index {}", value2, value1, i);
 		});
 	}
+
+	#[test]
+	fn test_cross_tolerance() {
+		use super::{Cross as TestingMethod, CrossTolerance};
+
+		let base = 100.0;
+		let src = [
+			99.0, 100.5, 101.0, 102.0, 101.5, 99.5, 98.5, 97.0, 99.0, 102.5,
+		];
+		let absolute = [0, 0, 0, 1, 0, 0, -1, 0, 0, 1];
+		let percent = [0, 0, 0, 0, 0, 0, 0, -1, 0, 1];
+
+		let mut cross = TestingMethod::new((), (src[0], base))
+			.unwrap()
+			.with_tolerance(CrossTolerance::Absolute(1.5));
+
+		for (&x, &r) in src.iter().zip(absolute.iter()) {
+			assert_eq!(cross.next((x, base)).analog(), r);
+		}
+
+		let mut cross = TestingMethod::new((), (src[0], base))
+			.unwrap()
+			.with_tolerance(CrossTolerance::Percent(2.5));
+
+		for (&x, &r) in src.iter().zip(percent.iter()) {
+			assert_eq!(cross.next((x, base)).analog(), r);
+		}
+	}
+
+	#[test]
+	fn test_cross_confirmation() {
+		use super::Cross as TestingMethod;
+
+		let base = 100.0;
+		let src = [
+			99.0, 101.0, 99.0, 101.0, 102.0, 103.0, 104.0, 99.0, 98.0, 97.0,
+		];
+		let r = [0, 0, 0, 0, 0, 1, 0, 0, 0, -1];
+
+		let mut cross = TestingMethod::new((), (src[0], base))
+			.unwrap()
+			.with_confirmation(2);
+
+		for (&x, &r) in src.iter().zip(r.iter()) {
+			assert_eq!(cross.next((x, base)).analog(), r);
+		}
+	}
+
+	#[test]
+	fn test_cross_nan() {
+		use super::Cross as TestingMethod;
+
+		let base = 100.0;
+		let src = [99.0, 101.0, ValueType::NAN, 99.0, ValueType::NAN, 101.0];
+		let r = [0, 1, 0, -1, 0, 1];
+
+		let mut cross = TestingMethod::new((), (src[0], base)).unwrap();
+
+		for (&x, &r) in src.iter().zip(r.iter()) {
+			assert_eq!(cross.next((x, base)).analog(), r);
+		}
+
+		let src = [99.0, 101.0, ValueType::NAN, 102.0, 103.0, 104.0];
+		let r = [0, 0, 0, 0, 1, 0];
+
+		let mut cross = TestingMethod::new((), (src[0], base))
+			.unwrap()
+			.with_confirmation(2);
+
+		for (&x, &r) in src.iter().zip(r.iter()) {
+			assert_eq!(cross.next((x, base)).analog(), r);
+		}
+	}
 }
@@ -0,0 +1,317 @@
+use crate::core::{Action, Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use crate::core::{Checkpoint, Method, Period};
+use crate::methods::Cross;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Counts how many times two timeseries lines of type [`ValueType`] crossed each other over the last `length` values
+///
+/// Crosses in both directions are counted. Crosses are detected by [`Cross`] method.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is the same as the period type
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::CrossCount;
+///
+/// let t1 = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let t2 = [5.0, 3.0, 1.8, 2.9, 4.1, 5.6, 6.1];
+/// let r  = [ 0,   0,   1,   1,   2,   1,   1 ];
+///
+/// let mut cross_count = CrossCount::new(3, (t1[0], t2[0])).unwrap();
+///
+/// for i in 0..t1.len() {
+///     assert_eq!(cross_count.next((t1[i], t2[i])), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`BarsSinceCross`], [`Cross`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CrossCount<P: Period = PeriodType> {
+	cross: Cross,
+	count: P,
+	window: Window<bool, P>,
+}
+
+impl CrossCount {
+	/// Creates new instance of `CrossCount` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: (ValueType, ValueType)) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> CrossCount<P> {
+	/// Replaces inner [`Cross`] method, so it may be configured by [`Cross::with_tolerance`] and [`Cross::with_confirmation`]
+	#[must_use]
+	pub fn with_cross(self, cross: Cross) -> Self {
+		Self { cross, ..self }
+	}
+}
+
+impl<P: Period> Method<'_> for CrossCount<P> {
+	type Params = P;
+	type Input = (ValueType, ValueType);
+	type Output = P;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
+		}
+
+		Ok(Self {
+			cross: Cross::new((), value)?,
+			count: P::ZERO,
+			window: Window::with_size(length, false),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let crossed = self.cross.next(value).is_some();
+		let left_crossed = self.window.push(crossed);
+
+		self.count =
+			P::from_usize(self.count.to_usize() + crossed as usize - left_crossed as usize);
+
+		self.count
+	}
+}
+
+impl<P: Period> Checkpoint for CrossCount<P> {
+	type State = (Cross, P, WindowCheckpoint<bool, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.cross, self.count, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.cross = state.0;
+		self.count = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
+/// Counts bars since the last cross of two timeseries lines of type [`ValueType`]
+///
+/// Returns `Some(0)` when lines cross each other at the current bar and `None` until the first cross happens.
+/// Crosses are detected by [`Cross`] method.
+///
+/// Direction of the last cross is available by [`BarsSinceCross::last_cross`].
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `base`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`Option`]<[`usize`]>
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Action, Method};
+/// use yata::methods::BarsSinceCross;
+///
+/// let t1 = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let t2 = [5.0, 3.0, 1.8, 2.9, 4.1, 5.6, 6.1];
+/// let r  = [None, None, Some(0), Some(1), Some(0), Some(1), Some(2)];
+///
+/// let mut bars_since_cross = BarsSinceCross::new((), (t1[0], t2[0])).unwrap();
+///
+/// for i in 0..t1.len() {
+///     assert_eq!(bars_since_cross.next((t1[i], t2[i])), r[i]);
+/// }
+///
+/// assert_eq!(bars_since_cross.last_cross(), Action::SELL_ALL);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`CrossCount`], [`Cross`]
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BarsSinceCross {
+	cross: Cross,
+	bars: Option<usize>,
+	last_cross: Action,
+}
+
+impl BarsSinceCross {
+	/// Replaces inner [`Cross`] method, so it may be configured by [`Cross::with_tolerance`] and [`Cross::with_confirmation`]
+	#[must_use]
+	pub const fn with_cross(self, cross: Cross) -> Self {
+		Self { cross, ..self }
+	}
+
+	/// Returns direction of the last cross or [`Action::None`](crate::core::Action::None) if there were no crosses yet
+	#[must_use]
+	pub const fn last_cross(&self) -> Action {
+		self.last_cross
+	}
+}
+
+impl Method<'_> for BarsSinceCross {
+	type Params = ();
+	type Input = (ValueType, ValueType);
+	type Output = Option<usize>;
+
+	fn new((): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			cross: Cross::new((), value)?,
+			bars: None,
+			last_cross: Action::None,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let signal = self.cross.next(value);
+
+		if signal.is_some() {
+			self.last_cross = signal;
+			self.bars = Some(0);
+		} else {
+			self.bars = self.bars.map(|bars| bars.saturating_add(1));
+		}
+
+		self.bars
+	}
+}
+
+impl Checkpoint for BarsSinceCross {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BarsSinceCross, CrossCount};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+	use crate::methods::Cross;
+
+	fn series() -> Vec<(ValueType, ValueType)> {
+		RandomCandles::default()
+			.take(300)
+			.map(|c| c.close)
+			.zip(RandomCandles::default().skip(15).take(300).map(|c| c.close))
+			.collect()
+	}
+
+	#[test]
+	fn test_cross_count_const() {
+		let input = (7.0, 1.0);
+
+		for length in 1..255 {
+			let mut method = CrossCount::new(length, input).unwrap();
+			let output = method.next(input);
+
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_cross_count() {
+		let src = series();
+
+		for length in 1..255 {
+			let mut cross = Cross::new((), src[0]).unwrap();
+			let mut method = CrossCount::new(length, src[0]).unwrap();
+			let mut crosses = Vec::with_capacity(src.len());
+
+			for (i, &x) in src.iter().enumerate() {
+				crosses.push(cross.next(x).is_some());
+
+				let from = (i + 1).saturating_sub(length as usize);
+				let count = crosses[from..].iter().filter(|&&c| c).count();
+
+				assert_eq!(method.next(x) as usize, count);
+			}
+		}
+	}
+
+	#[test]
+	fn test_bars_since_cross_const() {
+		let input = (7.0, 1.0);
+		let mut method = BarsSinceCross::new((), input).unwrap();
+
+		for _ in 0..100 {
+			assert_eq!(method.next(input), None);
+		}
+	}
+
+	#[test]
+	fn test_bars_since_cross() {
+		let src = series();
+
+		let mut cross = Cross::new((), src[0]).unwrap();
+		let mut method = BarsSinceCross::new((), src[0]).unwrap();
+		let mut last_cross = None;
+
+		for (i, &x) in src.iter().enumerate() {
+			let signal = cross.next(x);
+
+			if signal.is_some() {
+				last_cross = Some((i, signal));
+			}
+
+			assert_eq!(method.next(x), last_cross.map(|(index, _)| i - index));
+
+			if let Some((_, signal)) = last_cross {
+				assert_eq!(method.last_cross(), signal);
+			}
+		}
+	}
+}
@@ -1,5 +1,6 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use crate::methods::Chain;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,12 +9,17 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
 /// Default is `1`
 ///
+/// By default spacing between values is `1.0`. Constant spacing may be set by [`with_spacing`](Derivative::with_spacing).
+/// For irregular timeseries spacing of each value may be passed by [`next_with_spacing`](Derivative::next_with_spacing).
+///
+/// Smoothed input is available by [`SmoothedDerivative`].
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -39,6 +45,22 @@ use serde::{Deserialize, Serialize};
 /// });
 /// ```
 ///
+/// ### Spacing
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Derivative;
+///
+/// // each value is 5 units of time apart
+/// let mut derivative = Derivative::new(2, 0.0).unwrap().with_spacing(5.0).unwrap();
+/// derivative.next(0.0);
+/// derivative.next(10.0);
+/// assert_eq!(derivative.next(20.0), 2.0); // (20 - 0) / (2 * 5)
+///
+/// // irregular spacing
+/// assert_eq!(derivative.next_with_spacing(40.0, 10.0), 2.0); // (40 - 10) / (5 + 10)
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -49,38 +71,162 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Derivative {
+pub struct Derivative<P: Period = PeriodType> {
+	spacing: ValueType,
 	divider: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
+	// total spacing and spacings history, allocated only when irregular spacing is used
+	spacings: Option<(ValueType, Window<ValueType, P>)>,
+}
+
+impl Derivative {
+	/// Creates new instance of `Derivative` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
 }
 
 /// Just an alias for Derivative
 pub type Differential = Derivative;
 
-impl Method<'_> for Derivative {
-	type Params = PeriodType;
+/// [`Derivative`] over the input smoothed by any other method `M`
+///
+/// It is just an alias for [`Chain`]<`M`, [`Derivative`]>, so it has a tuple of 2 parameters (`M::Params`, `length`).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{SmoothedDerivative, SMA};
+///
+/// let mut derivative = SmoothedDerivative::<SMA>::new((2, 1), 1.0).unwrap();
+/// derivative.next(1.0);
+/// assert_eq!(derivative.next(3.0), 1.0);
+/// assert_eq!(derivative.next(3.0), 1.0);
+/// ```
+///
+/// [`Chain`]: crate::methods::Chain
+pub type SmoothedDerivative<M> = Chain<M, Derivative>;
+
+impl<P: Period> Derivative<P> {
+	/// Sets constant `spacing` between values, so the output is scaled by it
+	///
+	/// `spacing` should be > `0.0`
+	///
+	/// It resets spacings history, so it should be called right after the method is created.
+	pub fn with_spacing(self, spacing: ValueType) -> Result<Self, Error> {
+		if spacing > 0. && spacing.is_finite() {
+			Ok(Self {
+				spacing,
+				divider: (spacing * self.window.len().to_usize() as ValueType).recip(),
+				spacings: None,
+				..self
+			})
+		} else {
+			Err(Error::wrong_method_parameter("spacing", "> 0", spacing))
+		}
+	}
+
+	/// Returns constant spacing between values
+	#[must_use]
+	pub const fn spacing(&self) -> ValueType {
+		self.spacing
+	}
+
+	/// Calculates next value, when current `value` is `spacing` apart from the previous one
+	///
+	/// Useful for irregular timeseries. Spacings history is allocated on the first call.
+	#[inline]
+	pub fn next_with_spacing(&mut self, value: ValueType, spacing: ValueType) -> ValueType {
+		let length = self.window.len();
+		let constant = self.spacing;
+
+		let (total_spacing, spacings) = self.spacings.get_or_insert_with(|| {
+			(
+				constant * length.to_usize() as ValueType,
+				Window::with_size(length, constant),
+			)
+		});
+
+		*total_spacing += spacing - spacings.push(spacing);
+
+		let prev_value = self.window.push(value);
+		(value - prev_value) / *total_spacing
+	}
+}
+
+impl<P: Period> Method<'_> for Derivative<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				divider: (length as ValueType).recip(),
-				window: Window::new(length, value),
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			spacing: 1.,
+			divider: (length.to_usize() as ValueType).recip(),
+			window: Window::with_size(length, value),
+			spacings: None,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+			+ self
+				.spacings
+				.as_ref()
+				.map_or(0, |(_, spacings)| spacings.heap_size())
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
+		if self.spacings.is_some() {
+			return self.next_with_spacing(value, self.spacing);
+		}
+
 		let prev_value = self.window.push(value);
 		(value - prev_value) * self.divider
 	}
 }
 
+impl<P: Period> Checkpoint for Derivative<P> {
+	type State = (
+		WindowCheckpoint<ValueType, P>,
+		Option<(ValueType, WindowCheckpoint<ValueType, P>)>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		let spacings = self
+			.spacings
+			.as_ref()
+			.map(|(total_spacing, spacings)| (*total_spacing, spacings.save()));
+
+		(self.window.save(), spacings)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(&state.0);
+
+		match (&mut self.spacings, &state.1) {
+			(Some((total_spacing, spacings)), Some((saved_total, saved_spacings))) => {
+				*total_spacing = *saved_total;
+				spacings.restore(saved_spacings);
+			}
+			// spacings history was allocated after the checkpoint
+			(spacings, None) => *spacings = None,
+			(None, Some(_)) => unreachable!("spacings history is never dropped"),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(unused_imports)]
@@ -131,4 +277,80 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_derivative_spacing() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+		let spacings: Vec<ValueType> = (0..src.len()).map(|i| (i % 5 + 1) as ValueType).collect();
+
+		(1..255).for_each(|length| {
+			let mut constant = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_spacing(2.5)
+				.unwrap();
+			let mut irregular = TestingMethod::new(length, src[0]).unwrap();
+
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let delta = x - src[i.saturating_sub(length)];
+				let total_spacing: ValueType = (0..length)
+					.map(|j| i.checked_sub(j).map_or(1.0, |k| spacings[k]))
+					.sum();
+
+				assert_eq_float(delta / (2.5 * length as ValueType), constant.next(x));
+				assert_eq_float(
+					delta / total_spacing,
+					irregular.next_with_spacing(x, spacings[i]),
+				);
+			});
+		});
+
+		assert!(TestingMethod::new(3, 1.0)
+			.unwrap()
+			.with_spacing(0.0)
+			.is_err());
+	}
+
+	#[test]
+	fn test_derivative_spacing_checkpoint() {
+		use crate::core::Checkpoint;
+
+		let mut derivative = TestingMethod::new(3, 1.0).unwrap();
+		let mut reference = derivative.clone();
+		assert_eq!(derivative.heap_size(), reference.heap_size());
+
+		let checkpoint = derivative.save();
+		derivative.next_with_spacing(10.0, 4.0);
+		assert!(derivative.heap_size() > reference.heap_size());
+
+		derivative.restore(&checkpoint);
+		assert_eq!(derivative.heap_size(), reference.heap_size());
+
+		for x in &[2.0, 5.0, 3.0, 8.0] {
+			assert_eq!(derivative.next(*x).to_bits(), reference.next(*x).to_bits());
+		}
+	}
+
+	#[test]
+	fn test_smoothed_derivative() {
+		use super::SmoothedDerivative;
+		use crate::methods::EMA;
+
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut smoothed = SmoothedDerivative::<EMA>::new((length, 1), src[0]).unwrap();
+			let mut ema = EMA::new(length, src[0]).unwrap();
+			let mut derivative = TestingMethod::new(1, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq_float(derivative.next(ema.next(x)), smoothed.next(x));
+			}
+		});
+	}
 }
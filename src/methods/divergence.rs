@@ -0,0 +1,204 @@
+use crate::core::{Action, Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use crate::core::{Checkpoint, Method, Period};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Searches for divergence between `price` timeseries and `value` (oscillator) timeseries over the last `length` values
+///
+/// If `price` makes a new low over the last `length` values, but `value` does not, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL) (bullish divergence)
+///
+/// If `price` makes a new high over the last `length` values, but `value` does not, then returns [`Action::SELL_ALL`](crate::core::Action::SELL_ALL) (bearish divergence)
+///
+/// Else returns [`Action::None`](crate::core::Action::None)
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is (`price`: [`ValueType`], `value`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Divergence;
+///
+/// let price = [5.0, 4.0, 3.0, 4.0, 5.0, 6.0, 2.0];
+/// let value = [0.5, 0.4, 0.2, 0.4, 0.6, 0.5, 0.45];
+/// let r     = [ 0,   0,   0,   0,   0,  -1,   1  ];
+///
+/// let mut divergence = Divergence::new(3, (price[0], value[0])).unwrap();
+///
+/// (0..price.len()).for_each(|i| {
+///     assert_eq!(divergence.next((price[i], value[i])).analog(), r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Highest`](crate::methods::Highest), [`Lowest`](crate::methods::Lowest)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Divergence<P: Period = PeriodType> {
+	window: Window<(ValueType, ValueType), P>,
+}
+
+impl Divergence {
+	/// Creates new instance of `Divergence` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: (ValueType, ValueType)) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for Divergence<P> {
+	type Params = P;
+	type Input = (ValueType, ValueType);
+	type Output = Action;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
+		}
+
+		Ok(Self {
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, (price, value): Self::Input) -> Self::Output {
+		let (max_price, max_value, min_price, min_value) = self.window.iter().fold(
+			(
+				ValueType::NEG_INFINITY,
+				ValueType::NEG_INFINITY,
+				ValueType::INFINITY,
+				ValueType::INFINITY,
+			),
+			|(max_p, max_v, min_p, min_v), (p, v)| {
+				(max_p.max(p), max_v.max(v), min_p.min(p), min_v.min(v))
+			},
+		);
+
+		self.window.push((price, value));
+
+		let bearish = price > max_price && value < max_value;
+		let bullish = price < min_price && value > min_value;
+
+		Action::from(bullish as i8 - bearish as i8)
+	}
+}
+
+impl<P: Period> Checkpoint for Divergence<P> {
+	type State = WindowCheckpoint<(ValueType, ValueType), P>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.window.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Divergence as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_divergence_const() {
+		for i in 1..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, 0.5);
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_divergence_same_series() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, (src[0], src[0])).unwrap();
+
+			for &x in &src {
+				assert_eq!(method.next((x, x)).analog(), 0);
+			}
+		});
+	}
+
+	#[test]
+	fn test_divergence() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<(ValueType, ValueType)> =
+			candles.take(300).map(|x| (x.close, x.volume)).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &(price, value))| {
+				let from = i.saturating_sub(length);
+				let prev: Vec<_> = (from..i)
+					.map(|j| src[j])
+					.chain((0..(length + from).saturating_sub(i)).map(|_| src[0]))
+					.collect();
+
+				let max_price = prev
+					.iter()
+					.map(|x| x.0)
+					.fold(ValueType::NEG_INFINITY, ValueType::max);
+				let max_value = prev
+					.iter()
+					.map(|x| x.1)
+					.fold(ValueType::NEG_INFINITY, ValueType::max);
+				let min_price = prev
+					.iter()
+					.map(|x| x.0)
+					.fold(ValueType::INFINITY, ValueType::min);
+				let min_value = prev
+					.iter()
+					.map(|x| x.1)
+					.fold(ValueType::INFINITY, ValueType::min);
+
+				let bearish = price > max_price && value < max_value;
+				let bullish = price < min_price && value > min_value;
+
+				let expected = bullish as i8 - bearish as i8;
+
+				assert_eq!(method.next((price, value)).analog(), expected);
+			});
+		});
+	}
+}
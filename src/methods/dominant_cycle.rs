@@ -0,0 +1,360 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use std::mem::size_of;
+
+#[cfg(feature = "value_type_f32")]
+use std::f32::consts::PI;
+#[cfg(not(feature = "value_type_f32"))]
+use std::f64::consts::PI;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const AVG_LENGTH: PeriodType = 3;
+
+/// Dominant cycle period estimation by Ehlers [Autocorrelation Periodogram](https://www.mesasoftware.com/papers/) for timeseries of type [`ValueType`]
+///
+/// The timeseries is prefiltered by a high-pass filter with `max_period` cutoff and a super smoother with `min_period` cutoff.
+/// Then the autocorrelation of the filtered timeseries is transformed into the power spectrum over periods from `min_period` to `max_period`.
+/// The output is the center of gravity of the spectrum over periods, which power is at least a half of the maximum one.
+///
+/// Output may be rounded and used as a period for adaptive-period methods and indicators.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`min_period`: [`PeriodType`], `max_period`: [`PeriodType`])
+///
+/// `min_period` should be > `2`
+///
+/// `max_period` should be > `min_period`
+///
+/// Commonly used values are `(10, 48)`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`] in range \[`min_period`; `max_period`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{PeriodType, ValueType};
+/// use yata::methods::DominantCycle;
+///
+/// let pi = std::f64::consts::PI as ValueType;
+/// let wave = |i: usize| 100.0 + (i as ValueType * 2.0 * pi / 20.0).sin();
+///
+/// let mut dominant_cycle = DominantCycle::new((10, 48), wave(0)).unwrap();
+/// let mut period = 0.0;
+///
+/// for i in 1..500 {
+///     period = dominant_cycle.next(wave(i));
+/// }
+///
+/// assert!((period - 20.0).abs() < 1.0);
+///
+/// let period = period.round() as PeriodType;
+/// ```
+///
+/// # Performance
+///
+/// O(`max_period`²)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DominantCycle {
+	min_period: PeriodType,
+	hp_k: (ValueType, ValueType, ValueType),
+	ss_k: (ValueType, ValueType, ValueType),
+	prices: (ValueType, ValueType),
+	hp: (ValueType, ValueType),
+	filt: Window<ValueType>,
+	cos_table: Vec<ValueType>,
+	sin_table: Vec<ValueType>,
+	correlation: Vec<ValueType>,
+	power: Vec<ValueType>,
+	max_power: ValueType,
+	period: ValueType,
+}
+
+impl DominantCycle {
+	fn max_period(&self) -> PeriodType {
+		self.filt.len() - AVG_LENGTH
+	}
+
+	fn filter(&mut self, value: ValueType) -> ValueType {
+		let (k1, k2, k3) = self.hp_k;
+		let hp = k1.mul_add(
+			self.prices.0.mul_add(-2.0, value) + self.prices.1,
+			k2.mul_add(self.hp.0, -k3 * self.hp.1),
+		);
+
+		let (c1, c2, c3) = self.ss_k;
+		let filt = (c1 * (hp + self.hp.0)).mul_add(
+			0.5,
+			c2.mul_add(
+				self.filt.newest(),
+				c3 * self.filt.get_back(1).unwrap_or_default(),
+			),
+		);
+
+		self.prices = (value, self.prices.0);
+		self.hp = (hp, self.hp.0);
+		self.filt.push(filt);
+
+		filt
+	}
+
+	fn update_correlation(&mut self) {
+		let m = AVG_LENGTH as ValueType;
+
+		for lag in 0..=self.max_period() {
+			let (mut sx, mut sy, mut sxx, mut syy, mut sxy) = (0., 0., 0., 0., 0.);
+
+			for count in 0..AVG_LENGTH {
+				let x = self.filt.get_back(count).unwrap_or_default();
+				let y = self.filt.get_back(lag + count).unwrap_or_default();
+
+				sx += x;
+				sy += y;
+				sxx += x * x;
+				syy += y * y;
+				sxy += x * y;
+			}
+
+			#[allow(clippy::suspicious_operation_groupings)]
+			// sx * sx looks suspicious, but it's not
+			let denominator = m.mul_add(sxx, -sx * sx) * m.mul_add(syy, -sy * sy);
+
+			self.correlation[lag as usize] = if denominator > 0. {
+				m.mul_add(sxy, -sx * sy) / denominator.sqrt()
+			} else {
+				0.
+			};
+		}
+	}
+
+	fn update_spectrum(&mut self) {
+		let lags = self.correlation.len() - 3;
+
+		self.max_power *= 0.995;
+
+		for (i, power) in self.power.iter_mut().enumerate() {
+			let cos_part: ValueType = self.cos_table[i * lags..(i + 1) * lags]
+				.iter()
+				.zip(&self.correlation[3..])
+				.map(|(k, c)| k * c)
+				.sum();
+			let sin_part: ValueType = self.sin_table[i * lags..(i + 1) * lags]
+				.iter()
+				.zip(&self.correlation[3..])
+				.map(|(k, c)| k * c)
+				.sum();
+
+			let sq_sum = cos_part.mul_add(cos_part, sin_part * sin_part);
+			*power = (0.2 * sq_sum).mul_add(sq_sum, 0.8 * *power);
+
+			if *power > self.max_power {
+				self.max_power = *power;
+			}
+		}
+	}
+}
+
+impl Method<'_> for DominantCycle {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = ValueType;
+
+	fn new((min_period, max_period): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if min_period <= 2 {
+			return Err(Error::wrong_method_parameter(
+				"min_period",
+				"> 2",
+				min_period,
+			));
+		}
+
+		if max_period <= min_period || max_period > PeriodType::MAX - AVG_LENGTH {
+			return Err(Error::wrong_method_parameter(
+				"max_period",
+				"> min_period",
+				max_period,
+			));
+		}
+
+		// high-pass filter
+		let omega = 0.707 * 2.0 * PI / max_period as ValueType;
+		let alpha = (omega.cos() + omega.sin() - 1.0) / omega.cos();
+		let hp_k = (
+			(1.0 - alpha * 0.5) * (1.0 - alpha * 0.5),
+			2.0 * (1.0 - alpha),
+			(1.0 - alpha) * (1.0 - alpha),
+		);
+
+		// super smoother
+		let a1 = (-1.414 * PI / min_period as ValueType).exp();
+		let b1 = 2.0 * a1 * (1.414 * PI / min_period as ValueType).cos();
+		let ss_k = (1.0 - b1 + a1 * a1, b1, -a1 * a1);
+
+		let lags = (3..=max_period).map(|lag| lag as ValueType);
+		let (cos_table, sin_table) = (min_period..=max_period)
+			.flat_map(|period| {
+				lags.clone()
+					.map(move |lag| 2.0 * PI * lag / period as ValueType)
+			})
+			.map(|angle| (angle.cos(), angle.sin()))
+			.unzip();
+
+		Ok(Self {
+			min_period,
+			hp_k,
+			ss_k,
+			prices: (value, value),
+			hp: (0.0, 0.0),
+			filt: Window::new(max_period + AVG_LENGTH, 0.0),
+			cos_table,
+			sin_table,
+			correlation: vec![0.0; max_period as usize + 1],
+			power: vec![0.0; (max_period - min_period) as usize + 1],
+			max_power: 0.0,
+			period: min_period as ValueType,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.filt.heap_size()
+			+ (self.cos_table.capacity()
+				+ self.sin_table.capacity()
+				+ self.correlation.capacity()
+				+ self.power.capacity())
+				* size_of::<ValueType>()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.filter(value);
+		self.update_correlation();
+		self.update_spectrum();
+
+		if self.max_power > 0.0 {
+			let (weighted, total) = self
+				.power
+				.iter()
+				.map(|power| power / self.max_power)
+				.zip(self.min_period..)
+				.filter(|(power, _)| *power >= 0.5)
+				.fold((0.0, 0.0), |(weighted, total), (power, period)| {
+					(power.mul_add(period as ValueType, weighted), total + power)
+				});
+
+			if total > 0.0 {
+				// weighted average may slightly go out of range because of float rounding
+				self.period = (weighted / total)
+					.clamp(self.min_period as ValueType, self.max_period() as ValueType);
+			}
+		}
+
+		self.period
+	}
+}
+
+impl Checkpoint for DominantCycle {
+	type State = (
+		(ValueType, ValueType),
+		(ValueType, ValueType),
+		WindowCheckpoint<ValueType>,
+		Vec<ValueType>,
+		ValueType,
+		ValueType,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.prices,
+			self.hp,
+			self.filt.save(),
+			self.power.clone(),
+			self.max_power,
+			self.period,
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.prices = state.0;
+		self.hp = state.1;
+		self.filt.restore(&state.2);
+		self.power.copy_from_slice(&state.3);
+		self.max_power = state.4;
+		self.period = state.5;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::PI;
+	use super::{DominantCycle as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_dominant_cycle_const() {
+		for max_period in 4..100 {
+			let input = (max_period as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((3, max_period), input).unwrap();
+
+			for _ in 0..100 {
+				assert_eq_float(3.0, method.next(input));
+			}
+		}
+	}
+
+	#[test]
+	fn test_dominant_cycle_sine() {
+		for &cycle in &[12.0, 15.0, 20.0, 25.0, 30.0] {
+			let mut method = TestingMethod::new((10, 48), 100.0).unwrap();
+			let mut period = 0.0;
+
+			for i in 1..1000 {
+				let value = 100.0 + (2.0 * PI * i as ValueType / cycle).sin();
+				period = method.next(value);
+			}
+
+			assert!(
+				(period - cycle).abs() < cycle * 0.1,
+				"cycle={}, period={}",
+				cycle,
+				period
+			);
+		}
+	}
+
+	#[test]
+	fn test_dominant_cycle_range() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for &(min_period, max_period) in &[(3, 4), (5, 20), (10, 48), (20, 100)] {
+			let mut method = TestingMethod::new((min_period, max_period), src[0]).unwrap();
+
+			for &x in &src {
+				let period = method.next(x);
+
+				assert!(period >= min_period as ValueType);
+				assert!(period <= max_period as ValueType);
+			}
+		}
+
+		assert!(TestingMethod::new((2, 48), src[0]).is_err());
+		assert!(TestingMethod::new((10, 10), src[0]).is_err());
+	}
+}
@@ -0,0 +1,153 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::Highest;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling relative [drawdown](https://en.wikipedia.org/wiki/Drawdown_(economics)) of the current value from the highest value over the last `length` values of timeseries of type [`ValueType`]
+///
+/// Output value is (`value` - `highest`) / `highest`, so it is `0.0` at the new highs and negative otherwise.
+/// F.e. `-0.25` means that current value is `25%` lower than the highest value over the window.
+///
+/// When `highest` value is `0.0`, returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// Input values are expected to be positive (f.e. prices).
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// For positive input values output value is always in range \(`-1.0`; `0.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Drawdown;
+///
+/// let mut drawdown = Drawdown::new(3, 10.0).unwrap();
+///
+/// assert_eq!(drawdown.next(8.0), -0.2);
+/// assert_eq!(drawdown.next(5.0), -0.5);
+/// // 10.0 has left the window, so the highest value is 8.0
+/// assert_eq!(drawdown.next(6.0), -0.25);
+/// assert_eq!(drawdown.next(12.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Highest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Drawdown<P: Period = PeriodType> {
+	highest: Highest<P>,
+}
+
+impl Drawdown {
+	/// Creates new instance of `Drawdown` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for Drawdown<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			highest: Method::new(length, value)?,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.highest.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let highest = self.highest.next(value);
+
+		if highest == 0.0 {
+			0.0
+		} else {
+			(value - highest) / highest
+		}
+	}
+}
+
+impl<P: Period> Checkpoint for Drawdown<P> {
+	type State = <Highest<P> as Checkpoint>::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.highest.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest.restore(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Drawdown as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_drawdown_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_drawdown() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 1..255 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+
+			for (i, &x) in src.iter().enumerate() {
+				let highest = (0..length as usize)
+					.map(|j| src[i.saturating_sub(j)])
+					.fold(ValueType::NEG_INFINITY, ValueType::max);
+
+				let value = method.next(x);
+
+				assert!(value <= 0.0);
+				assert_eq_float((x - highest) / highest, value);
+			}
+		}
+
+		assert!(TestingMethod::new(0, src[0]).is_err());
+	}
+}
@@ -1,4 +1,4 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method};
 use crate::core::{Error, PeriodType, ValueType};
 
 #[cfg(feature = "serde")]
@@ -52,6 +52,38 @@ pub struct EMA {
 	value: ValueType,
 }
 
+impl EMA {
+	/// Creates new `EMA` in `const` context
+	///
+	/// Unlike [`Method::new`], it may be used to initialize `const` and `static` items, so there is no startup cost at all.
+	///
+	/// # Panics
+	///
+	/// Panics if `length` is `0`. In `const` context it fails the compilation.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::methods::EMA;
+	///
+	/// const EMA3: EMA = EMA::new_const(3, 3.0);
+	///
+	/// let mut ema = EMA3;
+	/// ema.next(3.0);
+	/// ema.next(6.0);
+	///
+	/// assert_eq!(ema.next(9.0), 6.75);
+	/// ```
+	#[must_use]
+	pub const fn new_const(length: PeriodType, value: ValueType) -> Self {
+		assert!(length > 0, "EMA length must be > 0");
+
+		Self {
+			alpha: 2. / ((length + 1) as ValueType),
+			value,
+		}
+	}
+}
+
 impl Method<'_> for EMA {
 	type Params = PeriodType;
 	type Input = ValueType;
@@ -59,11 +91,8 @@ impl Method<'_> for EMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => {
-				let alpha = 2. / ((length + 1) as ValueType);
-				Ok(Self { alpha, value })
-			}
+			0 => Err(Error::wrong_method_parameter("length", "> 0", length)),
+			length => Ok(Self::new_const(length, value)),
 		}
 	}
 
@@ -75,6 +104,20 @@ impl Method<'_> for EMA {
 	}
 }
 
+impl Checkpoint for EMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 /// Simple shortcut for [EMA] over [EMA]
 ///
 /// # See also
@@ -94,7 +137,7 @@ impl Method<'_> for DMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::wrong_method_parameter("length", "> 0", length)),
 			length => Ok(Self {
 				ema: EMA::new(length, value)?,
 				dma: EMA::new(length, value)?,
@@ -108,6 +151,20 @@ impl Method<'_> for DMA {
 	}
 }
 
+impl Checkpoint for DMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 /// Simple shortcut for [EMA] over [EMA] over [EMA] (or [EMA] over [DMA], or [DMA] over [EMA])
 ///
 /// # See also
@@ -127,7 +184,7 @@ impl Method<'_> for TMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::wrong_method_parameter("length", "> 0", length)),
 			length => Ok(Self {
 				dma: DMA::new(length, value)?,
 				tma: EMA::new(length, value)?,
@@ -141,6 +198,20 @@ impl Method<'_> for TMA {
 	}
 }
 
+impl Checkpoint for TMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 /// [Double Exponential Moving Average](https://en.wikipedia.org/wiki/Double_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -197,7 +268,7 @@ impl Method<'_> for DEMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::wrong_method_parameter("length", "> 0", length)),
 			length => Ok(Self {
 				ema: EMA::new(length, value)?,
 				dma: EMA::new(length, value)?,
@@ -215,6 +286,20 @@ impl Method<'_> for DEMA {
 	}
 }
 
+impl Checkpoint for DEMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 /// [Triple Exponential Moving Average](https://en.wikipedia.org/wiki/Triple_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
 ///
 /// # Parameters
@@ -272,7 +357,7 @@ impl Method<'_> for TEMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::wrong_method_parameter("length", "> 0", length)),
 			length => Ok(Self {
 				ema: EMA::new(length, value)?,
 				dma: EMA::new(length, value)?,
@@ -292,6 +377,20 @@ impl Method<'_> for TEMA {
 	}
 }
 
+impl Checkpoint for TEMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
@@ -0,0 +1,246 @@
+use crate::core::{Checkpoint, Error, Method, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Builds percent-based bands around any moving average (or any other method with [`ValueType`] output)
+///
+/// `upper bound` is `middle` * (`1.0` + `k`) and `lower bound` is `middle` * (`1.0` - `k`), where `middle` is the output of the inner method `M`.
+/// Each bound may have its own relative size, set by [`with_upper`](EnvelopeOf::with_upper) and [`with_lower`](EnvelopeOf::with_lower).
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`params`: `M::Params`, `k`: [`ValueType`])
+///
+/// `params` are the parameters of the inner method `M`
+///
+/// `k` is the relative size of the bands. F.e. `0.1` means `10%`. `k` should be in range (`0.0`; `1.0`), so the lower bound does not go negative.
+/// Only the upper bound may be wider, see [`with_upper`](EnvelopeOf::with_upper).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is (`upper bound`: [`ValueType`], `middle`: [`ValueType`], `lower bound`: [`ValueType`])
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{EnvelopeOf, EMA, SMA};
+///
+/// let mut sma_envelope = EnvelopeOf::<SMA>::new((2, 0.1), 10.0).unwrap();
+///
+/// sma_envelope.next(10.0);
+/// let (upper, middle, lower) = sma_envelope.next(30.0);
+///
+/// assert_eq!(middle, 20.0);
+/// assert!((upper - 22.0).abs() < 1e-10);
+/// assert!((lower - 18.0).abs() < 1e-10);
+///
+/// // any other moving average with asymmetric bands
+/// let mut ema_envelope = EnvelopeOf::<EMA>::new((10, 0.1), 10.0).unwrap().with_lower(0.2).unwrap();
+///
+/// let (upper, _, lower) = ema_envelope.next(10.0);
+///
+/// assert!((upper - 11.0).abs() < 1e-10);
+/// assert!((lower - 8.0).abs() < 1e-10);
+/// ```
+///
+/// # Performance
+///
+/// O(1) plus performance of the inner method
+///
+/// # See also
+///
+/// [`Envelopes`](crate::indicators::Envelopes)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvelopeOf<M> {
+	method: M,
+	k_high: ValueType,
+	k_low: ValueType,
+}
+
+impl<M> EnvelopeOf<M> {
+	/// Sets relative size `k` of the upper band
+	///
+	/// `k` should be > `0.0`. By default it is the same as the relative size of the lower band.
+	pub fn with_upper(self, k: ValueType) -> Result<Self, Error> {
+		if k > 0.0 && k.is_finite() {
+			Ok(Self {
+				k_high: 1.0 + k,
+				..self
+			})
+		} else {
+			Err(Error::wrong_method_parameter("k", "> 0", k))
+		}
+	}
+
+	/// Sets relative size `k` of the lower band
+	///
+	/// `k` should be in range (`0.0`; `1.0`). By default it is the same as the relative size of the upper band.
+	pub fn with_lower(self, k: ValueType) -> Result<Self, Error> {
+		if k > 0.0 && k < 1.0 {
+			Ok(Self {
+				k_low: 1.0 - k,
+				..self
+			})
+		} else {
+			Err(Error::wrong_method_parameter("k", "in range (0; 1)", k))
+		}
+	}
+
+	/// Returns a reference to the inner method
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for EnvelopeOf<M>
+where
+	M: Method<'a, Input = ValueType, Output = ValueType>,
+{
+	type Params = (M::Params, ValueType);
+	type Input = ValueType;
+	type Output = (ValueType, ValueType, ValueType);
+
+	fn new((params, k): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if k > 0.0 && k < 1.0 {
+			Ok(Self {
+				method: M::new(params, value)?,
+				k_high: 1.0 + k,
+				k_low: 1.0 - k,
+			})
+		} else {
+			Err(Error::wrong_method_parameter("k", "in range (0; 1)", k))
+		}
+	}
+
+	fn heap_size(&self) -> usize {
+		self.method.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let middle = self.method.next(value);
+
+		(middle * self.k_high, middle, middle * self.k_low)
+	}
+}
+
+impl<M: Checkpoint> Checkpoint for EnvelopeOf<M> {
+	type State = M::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.method.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.method.restore(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{EnvelopeOf, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{EMA, SMA};
+
+	#[test]
+	fn test_envelope_of() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for &k in &[0.01, 0.1, 0.5, 0.99] {
+			for length in 1..255 {
+				let mut envelope = EnvelopeOf::<SMA>::new((length, k), src[0]).unwrap();
+				let mut method = SMA::new(length, src[0]).unwrap();
+
+				for &x in &src {
+					let middle = method.next(x);
+					let (upper, mid, lower) = envelope.next(x);
+
+					assert_eq_float(middle, mid);
+					assert_eq_float(middle * (1.0 + k), upper);
+					assert_eq_float(middle * (1.0 - k), lower);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_envelope_of_lower() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let mut envelope = EnvelopeOf::<EMA>::new((length, 0.1), src[0])
+				.unwrap()
+				.with_lower(0.3)
+				.unwrap();
+			let mut method = EMA::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				let middle = method.next(x);
+				let (upper, _, lower) = envelope.next(x);
+
+				assert_eq_float(middle * 1.1, upper);
+				assert_eq_float(middle * 0.7, lower);
+			}
+		}
+
+		assert!(EnvelopeOf::<SMA>::new((10, 0.0), src[0]).is_err());
+		assert!(EnvelopeOf::<SMA>::new((10, -0.1), src[0]).is_err());
+		assert!(EnvelopeOf::<SMA>::new((10, 1.0), src[0]).is_err());
+		assert!(EnvelopeOf::<SMA>::new((0, 0.1), src[0]).is_err());
+		assert!(EnvelopeOf::<SMA>::new((10, 0.1), src[0])
+			.unwrap()
+			.with_lower(0.0)
+			.is_err());
+		assert!(EnvelopeOf::<SMA>::new((10, 0.1), src[0])
+			.unwrap()
+			.with_lower(1.0)
+			.is_err());
+	}
+
+	#[test]
+	fn test_envelope_of_upper() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let mut envelope = EnvelopeOf::<SMA>::new((length, 0.2), src[0])
+				.unwrap()
+				.with_upper(1.5)
+				.unwrap();
+			let mut method = SMA::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				let middle = method.next(x);
+				let (upper, _, lower) = envelope.next(x);
+
+				assert_eq_float(middle * 2.5, upper);
+				assert_eq_float(middle * 0.8, lower);
+			}
+		}
+
+		assert!(EnvelopeOf::<SMA>::new((10, 0.1), src[0])
+			.unwrap()
+			.with_upper(0.0)
+			.is_err());
+	}
+}
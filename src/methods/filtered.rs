@@ -0,0 +1,158 @@
+use crate::core::{Checkpoint, Error, Method};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Passes values to any other method only when the gate is open
+///
+/// When gate is `true`, the value is passed to the inner method and its output is returned.
+/// When gate is `false`, the value is skipped and the last output of the inner method is returned.
+///
+/// Signals of type [`Action`] may be used as a gate by [`Action::is_some`](crate::core::Action::is_some).
+///
+/// # Parameters
+///
+/// Has the same parameters as the inner method `M`
+///
+/// # Input type
+///
+/// Input type is (`value`: `M::Input`, `gate`: [`bool`])
+///
+/// # Output type
+///
+/// Output type is [`Option`]<`M::Output`>
+///
+/// Returns `None` until the gate is opened for the first time.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Filtered, Integral};
+///
+/// // accumulate volume on up-bars only
+/// let closes  = [1.0, 2.0, 1.5, 1.0, 3.0];
+/// let volumes = [5.0, 4.0, 3.0, 2.0, 1.0];
+/// let r = [None, Some(4.0), Some(4.0), Some(4.0), Some(5.0)];
+///
+/// let mut up_volume = Filtered::<Integral, _>::new(0, (volumes[0], false)).unwrap();
+///
+/// for i in 0..closes.len() {
+///     let is_up = i > 0 && closes[i] > closes[i - 1];
+///     assert_eq!(up_volume.next((volumes[i], is_up)), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1) plus performance of the inner method
+///
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Filtered<M, O> {
+	method: M,
+	last_output: Option<O>,
+}
+
+impl<M, O> Filtered<M, O> {
+	/// Returns a reference to the inner method
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<'a, M> Method<'a> for Filtered<M, M::Output>
+where
+	M: Method<'a>,
+	M::Output: Copy + fmt::Debug,
+{
+	type Params = M::Params;
+	type Input = (M::Input, bool);
+	type Output = Option<M::Output>;
+
+	fn new(parameters: Self::Params, (value, _): Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(parameters, value)?,
+			last_output: None,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.method.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, (value, gate): Self::Input) -> Self::Output {
+		if gate {
+			self.last_output = Some(self.method.next(value));
+		}
+
+		self.last_output
+	}
+}
+
+impl<M: Checkpoint, O: Copy> Checkpoint for Filtered<M, O> {
+	type State = (M::State, Option<O>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.method.save(), self.last_output)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.method.restore(&state.0);
+		self.last_output = state.1;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Filtered, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::SMA;
+
+	#[test]
+	fn test_filtered_open() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let mut filtered = Filtered::<SMA, _>::new(length, (src[0], true)).unwrap();
+			let mut method = SMA::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq_float(method.next(x), filtered.next((x, true)).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn test_filtered() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let mut filtered = Filtered::<SMA, _>::new(length, (src[0], false)).unwrap();
+			let mut method = SMA::new(length, src[0]).unwrap();
+			let mut last = None;
+
+			for (i, &x) in src.iter().enumerate() {
+				let gate = i % 3 == 1;
+
+				if gate {
+					last = Some(method.next(x));
+				}
+
+				assert_eq!(filtered.next((x, gate)), last);
+			}
+		}
+	}
+}
@@ -0,0 +1,199 @@
+use crate::core::{Action, Checkpoint, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::RMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Detects opening gaps, which are larger than `k`·[ATR](https://en.wikipedia.org/wiki/Average_true_range)
+///
+/// Gap is a difference between current candle's `open` and previous candle's `close`.
+/// ATR is calculated as [`RMA`] of [`TR`] over the previous candles, so the gap candle itself does not affect the threshold.
+///
+/// If gap is upwards and larger than the threshold, then returns [`Action::BUY_ALL`](crate::core::Action::BUY_ALL)
+///
+/// If gap is downwards and larger than the threshold, then returns [`Action::SELL_ALL`](crate::core::Action::SELL_ALL)
+///
+/// Else returns [`Action::None`](crate::core::Action::None)
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`length`: [`PeriodType`], `k`: [`ValueType`])
+///
+/// `length` should be > `0`. It is the period of ATR.
+///
+/// `k` should be >= `0`
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is (`signal`: [`Action`], `gap`: [`ValueType`])
+///
+/// `gap` is signed and is returned even when it is less than the threshold.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Action, Candle};
+/// use yata::methods::GapDetector;
+///
+/// let candles = [
+///     Candle { open: 10.0, high: 11.0, low: 9.0, close: 10.0, volume: 1.0 },
+///     Candle { open: 10.5, high: 11.5, low: 9.5, close: 11.0, volume: 1.0 },
+///     Candle { open: 14.0, high: 15.0, low: 13.0, close: 14.0, volume: 1.0 },
+///     Candle { open: 11.0, high: 12.0, low: 10.0, close: 11.5, volume: 1.0 },
+/// ];
+///
+/// let mut gap_detector = GapDetector::new((14, 1.0), &candles[0]).unwrap();
+///
+/// assert_eq!(gap_detector.next(&candles[0]), (Action::None, 0.0));
+/// assert_eq!(gap_detector.next(&candles[1]), (Action::None, 0.5));
+/// assert_eq!(gap_detector.next(&candles[2]), (Action::BUY_ALL, 3.0));
+/// assert_eq!(gap_detector.next(&candles[3]), (Action::SELL_ALL, -3.0));
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`TR`], [`RMA`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Action`]: crate::core::Action
+/// [`TR`]: crate::methods::TR
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GapDetector {
+	k: ValueType,
+	atr: RMA,
+	last_atr: ValueType,
+	prev_close: ValueType,
+}
+
+impl<'a> Method<'a> for GapDetector {
+	type Params = (PeriodType, ValueType);
+	type Input = &'a dyn OHLCV;
+	type Output = (Action, ValueType);
+
+	fn new((length, k): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		if k >= 0. {
+			let tr = candle.tr_close(candle.close());
+
+			Ok(Self {
+				k,
+				atr: RMA::new(length, tr)?,
+				last_atr: tr,
+				prev_close: candle.close(),
+			})
+		} else {
+			Err(Error::wrong_method_parameter("k", ">= 0", k))
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let gap = candle.open() - self.prev_close;
+		let threshold = self.k * self.last_atr;
+
+		let signal = if gap > threshold {
+			Action::BUY_ALL
+		} else if gap < -threshold {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		self.last_atr = self.atr.next(candle.tr_close(self.prev_close));
+		self.prev_close = candle.close();
+
+		(signal, gap)
+	}
+}
+
+impl Checkpoint for GapDetector {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{GapDetector as TestingMethod, Method, OHLCV};
+	use crate::core::{Action, Candle};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{RMA, TR};
+
+	#[test]
+	fn test_gap_detector_const() {
+		let input = Candle {
+			open: 10.0,
+			close: 10.0,
+			..RandomCandles::default().first()
+		};
+
+		for length in 1..255 {
+			let mut method = TestingMethod::new((length, 0.5), &input).unwrap();
+			let output = method.next(&input);
+
+			test_const(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_gap_detector() {
+		let candles = RandomCandles::default();
+		let src: Vec<Candle> = candles.take(300).collect();
+
+		for &k in &[0.0, 0.1, 0.5, 1.0] {
+			for length in 1..255 {
+				let mut method = TestingMethod::new((length, k), &src[0]).unwrap();
+				let mut tr = TR::new(&src[0]).unwrap();
+				let mut atr = RMA::new(length, src[0].high - src[0].low).unwrap();
+				let mut last_atr = src[0].high - src[0].low;
+				let mut prev_close = src[0].close;
+
+				for c in &src {
+					let gap = c.open - prev_close;
+					let (signal, value) = method.next(c);
+
+					assert_eq_float(gap, value);
+
+					if gap.abs() > k * last_atr {
+						assert_eq!(
+							signal,
+							if gap > 0. {
+								Action::BUY_ALL
+							} else {
+								Action::SELL_ALL
+							}
+						);
+					} else {
+						assert_eq!(signal, Action::None);
+					}
+
+					last_atr = atr.next(tr.next(c));
+					prev_close = c.close();
+				}
+			}
+		}
+
+		assert!(TestingMethod::new((14, -1.0), &src[0]).is_err());
+		assert!(TestingMethod::new((0, 1.0), &src[0]).is_err());
+	}
+}
@@ -1,9 +1,14 @@
-use crate::core::{Candle, Error, Method, OHLCV};
+use crate::core::{Candle, Checkpoint, Error, Method, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Converts default `OHLCV`s into [Heikin Ashi](https://en.wikipedia.org/wiki/Candlestick_chart#Heikin-Ashi_candlesticks) `OHLCV`s
+///
+/// *open* of every converted candle is the middle of the previous converted candle's body.
+/// The first converted candle uses the initial candle instead.
+///
+/// See also [`HeikinAshiTrend`](crate::indicators::HeikinAshiTrend) indicator.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HeikinAshi {
@@ -26,13 +31,29 @@ impl<'a> Method<'a> for HeikinAshi {
 		let open = (self.prev.open() + self.prev.close()) * 0.5;
 		let close = value.ohlc4();
 
-		Candle {
+		self.prev = Candle {
 			open,
 			high: value.high().max(open),
 			low: value.low().min(open),
 			close,
 			volume: value.volume(),
-		}
+		};
+
+		self.prev
+	}
+}
+
+impl Checkpoint for HeikinAshi {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
 	}
 }
 
@@ -50,7 +71,7 @@ mod tests {
 		let first = candles.first();
 		let mut heikin_ashi = HeikinAshi::new((), &first).unwrap();
 
-		let prev = candles.first();
+		let mut prev = candles.first();
 
 		candles
 			.take(100)
@@ -65,10 +86,11 @@ mod tests {
 					close,
 					..candle
 				};
+				prev = tested;
 
 				(tested, heikin_ashi.next(&candle))
 			})
-			.inspect(|(original, ha)| assert_eq_float(original.close(), ha.close()))
+			.inspect(|(original, ha)| assert_eq_float(original.open(), ha.open()))
 			.inspect(|(original, ha)| assert_eq_float(original.high(), ha.high()))
 			.inspect(|(original, ha)| assert_eq_float(original.low(), ha.low()))
 			.inspect(|(original, ha)| assert_eq_float(original.close(), ha.close()))
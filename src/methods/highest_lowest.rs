@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -51,20 +51,28 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 /// [`HighestIndex`]: crate::methods::HighestIndex
 /// [`LowestIndex`]: crate::methods::LowestIndex
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct HighestLowestDelta {
+pub struct HighestLowestDelta<P: Period = PeriodType> {
 	// highest: Highest,
 	// lowest: Lowest,
 	highest: ValueType,
 	lowest: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
-impl Method<'_> for HighestLowestDelta {
-	type Params = PeriodType;
+impl HighestLowestDelta {
+	/// Creates new instance of `HighestLowestDelta` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for HighestLowestDelta<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
@@ -76,14 +84,19 @@ impl Method<'_> for HighestLowestDelta {
 			return Err(Error::InvalidCandles);
 		}
 
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				highest: value,
-				lowest: value,
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			window: Window::with_size(length, value),
+			highest: value,
+			lowest: value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -118,11 +131,27 @@ impl Method<'_> for HighestLowestDelta {
 	}
 }
 
+impl<P: Period> Checkpoint for HighestLowestDelta<P> {
+	type State = (ValueType, ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.highest, self.lowest, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest = state.0;
+		self.lowest = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
 /// Returns highest value over the last `length` values for timeseries of type [`ValueType`]
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > 0
 ///
@@ -167,13 +196,20 @@ impl Method<'_> for HighestLowestDelta {
 /// [`LowestIndex`]: crate::methods::LowestIndex
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Highest {
+pub struct Highest<P: Period = PeriodType> {
 	value: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
+}
+
+impl Highest {
+	/// Creates new instance of `Highest` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
 }
 
-impl Method<'_> for Highest {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for Highest<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
@@ -182,13 +218,18 @@ impl Method<'_> for Highest {
 			return Err(Error::InvalidCandles);
 		}
 
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				value,
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			window: Window::with_size(length, value),
+			value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -211,11 +252,26 @@ impl Method<'_> for Highest {
 	}
 }
 
+impl<P: Period> Checkpoint for Highest<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.value, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.value = state.0;
+		self.window.restore(&state.1);
+	}
+}
+
 /// Returns lowest value over the last `length` values for timeseries of type [`ValueType`]
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > 0
 ///
@@ -260,13 +316,20 @@ impl Method<'_> for Highest {
 /// [`LowestIndex`]: crate::methods::LowestIndex
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Lowest {
+pub struct Lowest<P: Period = PeriodType> {
 	value: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
-impl Method<'_> for Lowest {
-	type Params = PeriodType;
+impl Lowest {
+	/// Creates new instance of `Lowest` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for Lowest<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
@@ -275,13 +338,18 @@ impl Method<'_> for Lowest {
 			return Err(Error::InvalidCandles);
 		}
 
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				value,
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			window: Window::with_size(length, value),
+			value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -304,6 +372,21 @@ impl Method<'_> for Lowest {
 	}
 }
 
+impl<P: Period> Checkpoint for Lowest<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.value, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.value = state.0;
+		self.window.restore(&state.1);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Highest, HighestLowestDelta, Lowest};
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -51,35 +51,48 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 /// [`Highest`]: crate::methods::Highest
 /// [`Lowest`]: crate::methods::Lowest
 /// [`HighestLowestDelta`]: crate::methods::HighestLowestDelta
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct HighestIndex {
-	index: PeriodType,
+pub struct HighestIndex<P: Period = PeriodType> {
+	index: P,
 	value: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
-impl Method<'_> for HighestIndex {
-	type Params = PeriodType;
+impl HighestIndex {
+	/// Creates new instance of `HighestIndex` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for HighestIndex<P> {
+	type Params = P;
 	type Input = ValueType;
-	type Output = PeriodType;
+	type Output = P;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		if !value.is_finite() {
 			return Err(Error::InvalidCandles);
 		}
 
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				index: 0,
-				value,
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			window: Window::with_size(length, value),
+			index: P::ZERO,
+			value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -90,12 +103,11 @@ impl Method<'_> for HighestIndex {
 		);
 
 		self.window.push(value);
-		self.index += 1;
+		self.index = P::from_usize(self.index.to_usize() + 1);
 
-		#[allow(clippy::cast_possible_truncation)]
 		if value >= self.value {
 			self.value = value;
-			self.index = 0;
+			self.index = P::ZERO;
 		} else if self.index == self.window.len() {
 			let (index, value) =
 				self.window.iter().enumerate().fold(
@@ -109,7 +121,7 @@ impl Method<'_> for HighestIndex {
 					},
 				);
 
-			self.index = index as PeriodType; // self.window.len() - index as PeriodType - 1;
+			self.index = P::from_usize(index); // self.window.len() - index as PeriodType - 1;
 			self.value = value;
 		}
 
@@ -117,13 +129,29 @@ impl Method<'_> for HighestIndex {
 	}
 }
 
+impl<P: Period> Checkpoint for HighestIndex<P> {
+	type State = (P, ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.index, self.value, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.index = state.0;
+		self.value = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
 /// Returns lowest value index over the last `length` values for timeseries of type [`ValueType`]
 ///
 /// If period has more than one minimum values, then returns the index of the newest value (e.g. the smallest index)
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > 0
 ///
@@ -169,30 +197,42 @@ impl Method<'_> for HighestIndex {
 /// [`HighestLowestDelta`]: crate::methods::HighestLowestDelta
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct LowestIndex {
-	index: PeriodType,
+pub struct LowestIndex<P: Period = PeriodType> {
+	index: P,
 	value: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
+}
+
+impl LowestIndex {
+	/// Creates new instance of `LowestIndex` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
 }
 
-impl Method<'_> for LowestIndex {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for LowestIndex<P> {
+	type Params = P;
 	type Input = ValueType;
-	type Output = PeriodType;
+	type Output = P;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		if !value.is_finite() {
 			return Err(Error::InvalidCandles);
 		}
 
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-				index: 0,
-				value,
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			window: Window::with_size(length, value),
+			index: P::ZERO,
+			value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -203,12 +243,11 @@ impl Method<'_> for LowestIndex {
 		);
 
 		self.window.push(value);
-		self.index += 1;
+		self.index = P::from_usize(self.index.to_usize() + 1);
 
-		#[allow(clippy::cast_possible_truncation)]
 		if value <= self.value {
 			self.value = value;
-			self.index = 0;
+			self.index = P::ZERO;
 		} else if self.index == self.window.len() {
 			let (index, value) =
 				self.window.iter().enumerate().fold(
@@ -222,7 +261,7 @@ impl Method<'_> for LowestIndex {
 					},
 				);
 
-			self.index = index as PeriodType; // self.window.len() - index as PeriodType - 1;
+			self.index = P::from_usize(index); // self.window.len() - index as PeriodType - 1;
 			self.value = value;
 		}
 
@@ -230,6 +269,78 @@ impl Method<'_> for LowestIndex {
 	}
 }
 
+impl<P: Period> Checkpoint for LowestIndex<P> {
+	type State = (P, ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.index, self.value, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.index = state.0;
+		self.value = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
+/// Returns how many bars passed since the highest value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// It is just a more descriptive name for [`HighestIndex`], so it has the same parameters, input and output types.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::BarsSinceHighest;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [ 0,   0,   0,   1,   2,   2,   0,   0 ];
+///
+/// let mut bars_since_highest = BarsSinceHighest::new(3, values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(bars_since_highest.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # See also
+///
+/// [`BarsSinceLowest`], [`NewHighest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`NewHighest`]: crate::methods::NewHighest
+pub type BarsSinceHighest = HighestIndex;
+
+/// Returns how many bars passed since the lowest value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// It is just a more descriptive name for [`LowestIndex`], so it has the same parameters, input and output types.
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::BarsSinceLowest;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [ 0,   1,   2,   0,   0,   0,   1,   2 ];
+///
+/// let mut bars_since_lowest = BarsSinceLowest::new(3, values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(bars_since_lowest.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # See also
+///
+/// [`BarsSinceHighest`], [`NewLowest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`NewLowest`]: crate::methods::NewLowest
+pub type BarsSinceLowest = LowestIndex;
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1,5 +1,6 @@
 use super::WMA;
-use crate::core::{Error, Method, PeriodType, ValueType};
+use crate::core::Period;
+use crate::core::{Checkpoint, Error, Method, PeriodType, ValueType};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -10,7 +11,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `1`
 ///
@@ -50,30 +51,47 @@ use serde::{Deserialize, Serialize};
 /// [`WMA`]: crate::methods::WMA
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct HMA {
-	wma1: WMA,
-	wma2: WMA,
-	wma3: WMA,
+pub struct HMA<P: Period = PeriodType> {
+	wma1: WMA<P>,
+	wma2: WMA<P>,
+	wma3: WMA<P>,
 }
 
-impl Method<'_> for HMA {
-	type Params = PeriodType;
+impl HMA {
+	/// Creates new instance of `HMA` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for HMA<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length.to_usize() < 2 {
+			return Err(Error::wrong_method_parameter("length", "> 1", length));
+		}
+
+		let length2 = length.to_usize();
+
 		#[allow(clippy::cast_possible_truncation)]
 		#[allow(clippy::cast_sign_loss)]
-		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				wma1: WMA::new(length / 2, value)?,
-				wma2: WMA::new(length, value)?,
-				wma3: WMA::new((length as ValueType).sqrt() as PeriodType, value)?,
-			}),
-		}
+		let length3 = (length2 as ValueType).sqrt() as usize;
+
+		Ok(Self {
+			wma1: Method::new(P::from_usize(length2 / 2), value)?,
+			wma2: Method::new(length, value)?,
+			wma3: Method::new(P::from_usize(length3), value)?,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.wma1.heap_size() + self.wma2.heap_size() + self.wma3.heap_size()
 	}
 
 	#[inline]
@@ -85,6 +103,26 @@ impl Method<'_> for HMA {
 	}
 }
 
+impl<P: Period> Checkpoint for HMA<P> {
+	type State = (
+		<WMA<P> as Checkpoint>::State,
+		<WMA<P> as Checkpoint>::State,
+		<WMA<P> as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.wma1.save(), self.wma2.save(), self.wma3.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.wma1.restore(&state.0);
+		self.wma2.restore(&state.1);
+		self.wma3.restore(&state.2);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{HMA as TestingMethod, WMA};
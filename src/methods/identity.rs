@@ -0,0 +1,114 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Just passes input values through without any changes
+///
+/// It is a placeholder for pipelines and combinators, where some method is required, but no transformation is needed.
+/// F.e. it explicitly disables smoothing of any indicator, which accepts [`RegularMethods`](crate::helpers::RegularMethods).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` is ignored, so it may be any value. It exists only to make [`Identity`] a [regular method](crate::helpers::RegularMethod).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Identity;
+///
+/// let mut identity = Identity::new(10, 1.0).unwrap();
+///
+/// assert_eq!(identity.next(1.0), 1.0);
+/// assert_eq!(identity.next(2.0), 2.0);
+/// assert_eq!(identity.next(-3.0), -3.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Constant`](crate::methods::Constant), [`Past`](crate::methods::Past)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Identity;
+
+impl Method<'_> for Identity {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(_length: Self::Params, _value: Self::Input) -> Result<Self, Error> {
+		Ok(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		value
+	}
+}
+
+impl Checkpoint for Identity {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Identity as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_identity_const() {
+		for i in 0..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			let output = method.next(input);
+			test_const(&mut method, input, output);
+		}
+	}
+
+	#[test]
+	fn test_identity() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(0..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				assert_eq_float(x, method.next(x));
+			}
+		});
+	}
+}
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,10 +8,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// If `length == 0`, then integrates since the beginning of timeseries
 ///
+/// Optionally values may be exponentially weighted by [`with_decay`](Integral::with_decay),
+/// so every previous value weighs `decay` times less than the next one.
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -68,6 +71,20 @@ use serde::{Deserialize, Serialize};
 /// });
 /// ```
 ///
+/// ### Exponentially weighted sum
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Integral;
+///
+/// let mut integral = Integral::new(3, 0.0).unwrap().with_decay(0.5).unwrap();
+///
+/// integral.next(4.0);
+/// integral.next(2.0);
+/// assert_eq!(integral.next(1.0), 3.0); // 1 + 2 * 0.5 + 4 * 0.25
+/// assert_eq!(integral.next(2.0), 3.0); // 2 + 1 * 0.5 + 2 * 0.25
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -78,40 +95,113 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Integral {
+pub struct Integral<P: Period = PeriodType> {
 	value: ValueType,
-	window: Window<ValueType>,
+	decay: ValueType,
+	left_decay: ValueType,
+	window: Window<ValueType, P>,
+}
+
+impl Integral {
+	/// Creates new instance of `Integral` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Integral<P> {
+	/// Sets `decay` factor, so every previous value weighs `decay` times less than the next one
+	///
+	/// `decay` should be in range (`0.0`; `1.0`]. Default is `1.0`, which means no decay.
+	///
+	/// When `length` > `0`, current sum is recalculated over the window values.
+	/// When `length` == `0`, current sum is kept as is and decay applies to the next values only.
+	pub fn with_decay(self, decay: ValueType) -> Result<Self, Error> {
+		if decay > 0. && decay <= 1. {
+			let value = if self.window.is_empty() {
+				self.value
+			} else {
+				self.window
+					.iter()
+					.fold((0., 1.), |(sum, weight), x| {
+						(x.mul_add(weight, sum), weight * decay)
+					})
+					.0
+			};
+
+			Ok(Self {
+				value,
+				decay,
+				left_decay: decay.powf(self.window.len().to_usize() as ValueType),
+				..self
+			})
+		} else {
+			Err(Error::wrong_method_parameter(
+				"decay",
+				"in (0.0; 1.0]",
+				decay,
+			))
+		}
+	}
+
+	/// Returns current `decay` factor
+	#[must_use]
+	pub const fn decay(&self) -> ValueType {
+		self.decay
+	}
 }
 
 /// Just an alias for Integral
 pub type Sum = Integral;
 
-impl Method<'_> for Integral {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for Integral<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		Ok(Self {
-			window: Window::new(length, value),
-			value: value * length as ValueType,
+			window: Window::with_size(length, value),
+			value: value * length.to_usize() as ValueType,
+			decay: 1.,
+			left_decay: 1.,
 		})
 	}
 
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		self.value += value;
+		self.value = self.value.mul_add(self.decay, value);
 
 		if !self.window.is_empty() {
-			self.value -= self.window.push(value);
+			self.value -= self.left_decay * self.window.push(value);
 		}
 
 		self.value
 	}
 }
 
+impl<P: Period> Checkpoint for Integral<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.value, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.value = state.0;
+		self.window.restore(&state.1);
+	}
+}
+
 impl Default for Integral {
 	fn default() -> Self {
 		Self::new(0, 0.0).unwrap()
@@ -199,4 +289,45 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_integral_decay() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for &decay in &[0.1, 0.5, 0.9, 0.99, 1.0] {
+			for length in 0..255 {
+				let mut ma = TestingMethod::new(length, src[0])
+					.unwrap()
+					.with_decay(decay)
+					.unwrap();
+				let length = length as usize;
+				let mut padded = vec![src[0]; length];
+				padded.extend_from_slice(&src);
+
+				src.iter().enumerate().for_each(|(i, &x)| {
+					let window = if length == 0 {
+						&src[..=i]
+					} else {
+						&padded[i + 1..=i + length]
+					};
+
+					let value = window
+						.iter()
+						.rev()
+						.fold((0.0, 1.0), |(sum, weight), &v| {
+							(v.mul_add(weight, sum), weight * decay)
+						})
+						.0;
+
+					assert_eq_float(value, ma.next(x));
+				});
+			}
+		}
+
+		assert!(TestingMethod::new(3, 1.0).unwrap().with_decay(0.0).is_err());
+		assert!(TestingMethod::new(3, 1.0).unwrap().with_decay(1.5).is_err());
+	}
 }
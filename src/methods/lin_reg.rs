@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `1`
 ///
@@ -26,23 +26,31 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[doc(alias = "LSMA")]
-pub struct LinReg {
+pub struct LinReg<P: Period = PeriodType> {
 	s_xy: ValueType,
 	s_y: ValueType,
 	s_x: ValueType,
 	float_length: ValueType,
 	length_invert: ValueType,
 	divider: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
+}
+
+impl LinReg {
+	/// Creates new instance of `LinReg` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
 }
 
 /// Just an alias for `LinReg`.
 pub type LSMA = LinReg;
 
-impl LinReg {
+impl<P: Period> LinReg<P> {
 	/// Returns tangent of the current state line
 	#[inline]
 	#[must_use]
@@ -60,39 +68,42 @@ impl LinReg {
 	}
 }
 
-impl Method<'_> for LinReg {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for LinReg<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		#![allow(clippy::all)]
-		#[allow(clippy::suspicious_operation_groupings)] // s_x * s_x looks suspicious, but it's not
-		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
-			length => {
-				let l64 = length as usize;
-				let float_length = length as ValueType;
-				let length_invert = -float_length.recip();
-
-				let n_1 = l64 - 1;
-				let s_x = l64 * n_1 / 2;
-				let s_x2 = s_x * (2 * n_1 + 1) / 3;
-
-				let divider = ((l64 * s_x2 - s_x * s_x) as ValueType).recip();
-
-				let s_x = -(s_x as ValueType);
-				Ok(Self {
-					float_length,
-					length_invert,
-					divider,
-					s_x,
-					s_y: -value * float_length,
-					s_xy: value * s_x,
-					window: Window::new(length, value),
-				})
-			}
+		if length.to_usize() < 2 {
+			return Err(Error::wrong_method_parameter("length", "> 1", length));
 		}
+
+		let l64 = length.to_usize();
+		let float_length = l64 as ValueType;
+		let length_invert = -float_length.recip();
+
+		let n_1 = l64 - 1;
+		let s_x = l64 * n_1 / 2;
+		let s_x2 = s_x * (2 * n_1 + 1) / 3;
+
+		#[allow(clippy::suspicious_operation_groupings)] // s_x * s_x looks suspicious, but it's not
+		let divider = ((l64 * s_x2 - s_x * s_x) as ValueType).recip();
+
+		let s_x = -(s_x as ValueType);
+		Ok(Self {
+			float_length,
+			length_invert,
+			divider,
+			s_x,
+			s_y: -value * float_length,
+			s_xy: value * s_x,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -106,10 +117,199 @@ impl Method<'_> for LinReg {
 	}
 }
 
+impl<P: Period> Checkpoint for LinReg<P> {
+	type State = (ValueType, ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.s_xy, self.s_y, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.s_xy = state.0;
+		self.s_y = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
+/// [Linear regression](https://en.wikipedia.org/wiki/Linear_regression) line and its fit statistics for last `length` values of timeseries of type [`ValueType`]
+///
+/// Unlike [`LinReg`], which returns only the current value of the regression line, returns [`LinRegOutput`].
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`LinRegOutput`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::LinRegFull;
+///
+/// let mut lin_reg = LinRegFull::new(3, 1.0).unwrap();
+/// lin_reg.next(2.0);
+/// let output = lin_reg.next(3.0);
+///
+/// assert!((output.value - 3.0).abs() < 1e-9);
+/// assert!((output.slope - 1.0).abs() < 1e-9);
+/// assert!((output.intercept - 1.0).abs() < 1e-9);
+/// assert!((output.r_squared - 1.0).abs() < 1e-9);
+/// assert!(output.std_error.abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// Fit statistics are calculated over the whole window on every step, because rolling sums of squares lose precision
+/// when the values are large relative to their spread.
+///
+/// # See also
+///
+/// [`LinReg`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinRegFull<P: Period = PeriodType> {
+	lin_reg: LinReg<P>,
+}
+
+impl LinRegFull {
+	/// Creates new instance of `LinRegFull` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> LinRegFull<P> {
+	/// Returns a reference to the inner [`LinReg`]
+	#[must_use]
+	pub const fn lin_reg(&self) -> &LinReg<P> {
+		&self.lin_reg
+	}
+}
+
+/// Output of [`LinRegFull`] method
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinRegOutput {
+	/// Value of the regression line at the current bar (the same as [`LinReg`] output)
+	pub value: ValueType,
+
+	/// Slope of the regression line: change of the line value per bar
+	pub slope: ValueType,
+
+	/// Value of the regression line at the oldest bar of the window
+	pub intercept: ValueType,
+
+	/// [Coefficient of determination](https://en.wikipedia.org/wiki/Coefficient_of_determination) in range \[`0.0`; `1.0`\]
+	///
+	/// Equals `0.0` when all the values in the window are the same.
+	pub r_squared: ValueType,
+
+	/// [Standard error](https://en.wikipedia.org/wiki/Standard_error#Standard_error_of_the_estimate) of the regression:
+	/// square root of the residual sum of squares divided by (`length` - `2`)
+	///
+	/// Equals `0.0` when `length` is `2`.
+	pub std_error: ValueType,
+}
+
+impl<P: Period> Method<'_> for LinRegFull<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = LinRegOutput;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			lin_reg: Method::new(length, value)?,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.lin_reg.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let current = self.lin_reg.next(value);
+
+		let lin_reg = &self.lin_reg;
+		let n = lin_reg.float_length;
+		let ss_x = (lin_reg.divider * n).recip();
+
+		// x-axis goes from `0` at the oldest value to `length - 1` at the current one
+		let mean_x = (n - 1.0) * 0.5;
+		let mean_y = lin_reg.window.iter().sum::<ValueType>() / n;
+
+		let (ss_xy, ss_tot) = lin_reg.window.iter_rev().enumerate().fold(
+			(0.0, 0.0),
+			|(ss_xy, ss_tot): (ValueType, ValueType), (x, y)| {
+				let dy = y - mean_y;
+				(
+					(x as ValueType - mean_x).mul_add(dy, ss_xy),
+					dy.mul_add(dy, ss_tot),
+				)
+			},
+		);
+
+		let slope = ss_xy / ss_x;
+
+		// variance below the precision of the mean means the values are the same
+		let precision = mean_y * ValueType::EPSILON;
+		let (ss_res, r_squared) = if ss_tot > precision * precision * n {
+			let ss_res = slope.mul_add(-ss_xy, ss_tot).max(0.0);
+			(ss_res, (1.0 - ss_res / ss_tot).max(0.0))
+		} else {
+			(0.0, 0.0)
+		};
+
+		let std_error = if n > 2.0 {
+			(ss_res / (n - 2.0)).sqrt()
+		} else {
+			0.0
+		};
+
+		LinRegOutput {
+			value: current,
+			slope,
+			intercept: slope.mul_add(-mean_x, mean_y),
+			r_squared,
+			std_error,
+		}
+	}
+}
+
+impl<P: Period> Checkpoint for LinRegFull<P> {
+	type State = <LinReg<P> as Checkpoint>::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.lin_reg.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.lin_reg.restore(state);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
-	use super::{LinReg as TestingMethod, Method};
+	use super::{LinReg as TestingMethod, LinRegFull, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
@@ -167,4 +367,74 @@ mod tests {
 			});
 		}
 	}
+
+	#[test]
+	fn test_lin_reg_full_const() {
+		for i in 2..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = LinRegFull::new(i, input).unwrap();
+
+			for _ in 0..10 {
+				let output = method.next(input);
+
+				assert_eq_float(input, output.value);
+				assert_eq_float(input, output.intercept);
+				assert_eq_float(0.0, output.slope);
+				assert_eq_float(0.0, output.r_squared);
+				assert_eq_float(0.0, output.std_error);
+			}
+		}
+	}
+
+	#[test]
+	fn test_lin_reg_full() {
+		#![allow(clippy::similar_names)]
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 2..255 {
+			let mut method = LinRegFull::new(length, src[0]).unwrap();
+			let mut lin_reg = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+			let n = length as ValueType;
+
+			for (i, &x) in src.iter().enumerate() {
+				let output = method.next(x);
+
+				assert_eq_float(lin_reg.next(x), output.value);
+
+				// x-axis goes from `0` at the oldest value to `length - 1` at the current one
+				let points: Vec<(ValueType, ValueType)> = (0..length)
+					.map(|j| ((length - 1 - j) as ValueType, src[i.saturating_sub(j)]))
+					.collect();
+
+				let mean_x = points.iter().map(|p| p.0).sum::<ValueType>() / n;
+				let mean_y = points.iter().map(|p| p.1).sum::<ValueType>() / n;
+
+				let ss_xy: ValueType = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+				let ss_x: ValueType = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+				let ss_y: ValueType = points.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+
+				let slope = ss_xy / ss_x;
+				let intercept = mean_y - slope * mean_x;
+				let ss_res: ValueType = points
+					.iter()
+					.map(|p| (p.1 - intercept - slope * p.0).powi(2))
+					.sum();
+
+				assert_eq_float(slope, output.slope);
+				assert_eq_float(intercept, output.intercept);
+				if ss_y > 0.0 {
+					assert_eq_float(1.0 - ss_res / ss_y, output.r_squared);
+				} else {
+					assert_eq_float(0.0, output.r_squared);
+				}
+
+				if length > 2 {
+					assert_eq_float((ss_res / (n - 2.0)).sqrt(), output.std_error);
+				}
+			}
+		}
+	}
 }
@@ -1,15 +1,20 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method, Period};
 use crate::core::{Error, PeriodType, ValueType};
-use crate::methods::SMA;
+use crate::methods::{median_abs_dev::median, SMA};
+use std::mem::size_of;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// [Mean absolute deviation](https://en.wikipedia.org/wiki/Average_absolute_deviation) of specified `length` for timeseries of type [`ValueType`]
 ///
+/// By default deviations are taken around the mean of the last `length` values.
+/// The center may be switched to the median by [`with_center`](MeanAbsDev::with_center)
+/// or supplied from an external series by [`next_with_center`](MeanAbsDev::next_with_center).
+///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -21,56 +26,168 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{DeviationCenter, MeanAbsDev};
+///
+/// let mut mad = MeanAbsDev::new(4, 1.0).unwrap();
+/// mad.next(2.0);
+/// mad.next(3.0);
+/// assert_eq!(mad.next(10.0), 3.0); // mean is 4.0
+///
+/// let mut mad = MeanAbsDev::new(4, 1.0).unwrap().with_center(DeviationCenter::Median);
+/// mad.next(2.0);
+/// mad.next(3.0);
+/// assert_eq!(mad.next(10.0), 2.5); // median is 2.5
+///
+/// let mut mad = MeanAbsDev::new(4, 1.0).unwrap();
+/// mad.next(2.0);
+/// mad.next(3.0);
+/// assert_eq!(mad.next_with_center(10.0, 0.0), 4.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(`length`)
 ///
+/// With [`DeviationCenter::Median`] the median is found by selection over the internal buffer, which is reused between steps,
+/// so it takes O(`length`) on average too.
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct MeanAbsDev(SMA);
+pub struct MeanAbsDev<P: Period = PeriodType> {
+	sma: SMA<P>,
+	center: DeviationCenter,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	buffer: Vec<ValueType>,
+}
 
 impl MeanAbsDev {
+	/// Creates new instance of `MeanAbsDev` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> MeanAbsDev<P> {
 	/// Returns reference to inner SMA. Useful for implementing in other methods and indicators.
 	#[must_use]
-	pub const fn get_sma(&self) -> &SMA {
-		&self.0
+	pub const fn get_sma(&self) -> &SMA<P> {
+		&self.sma
+	}
+
+	/// Sets the center which deviations are taken around. Default is [`DeviationCenter::Mean`].
+	#[must_use]
+	pub fn with_center(self, center: DeviationCenter) -> Self {
+		Self { center, ..self }
+	}
+
+	/// Returns current deviation center
+	#[must_use]
+	pub const fn center(&self) -> DeviationCenter {
+		self.center
+	}
+
+	/// Pushes the next `value` and returns mean absolute deviation around the supplied `center` value
+	/// instead of the configured one.
+	///
+	/// Useful when the center comes from an external series, e.g. some moving average.
+	#[inline]
+	pub fn next_with_center(&mut self, value: ValueType, center: ValueType) -> ValueType {
+		self.sma.next(value);
+		self.deviation(center)
+	}
+
+	#[inline]
+	fn deviation(&self, center: ValueType) -> ValueType {
+		self.sma
+			.get_window()
+			.as_slice()
+			.iter()
+			.map(|x| x - center)
+			.map(ValueType::abs)
+			.sum::<ValueType>()
+			* self.sma.get_divider()
 	}
 }
 
-impl Method<'_> for MeanAbsDev {
-	type Params = PeriodType;
+/// Center of the deviations for [`MeanAbsDev`] and [`MedianAbsDev`](crate::methods::MedianAbsDev) methods
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum DeviationCenter {
+	/// Simple moving average of the last `length` values
+	Mean,
+
+	/// Median of the last `length` values
+	Median,
+}
+
+impl<P: Period> Method<'_> for MeanAbsDev<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self(SMA::new(length, value)?)),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			sma: Method::new(length, value)?,
+			center: DeviationCenter::Mean,
+			buffer: Vec::new(),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.sma.heap_size() + self.buffer.capacity() * size_of::<ValueType>()
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		let mean = self.0.next(value);
+		let mean = self.sma.next(value);
 
-		self.0
-			.get_window()
-			.as_slice()
-			.iter()
-			.map(|x| x - mean)
-			.map(ValueType::abs)
-			.sum::<ValueType>()
-			* self.0.get_divider()
+		let center = match self.center {
+			DeviationCenter::Mean => mean,
+			DeviationCenter::Median => {
+				self.buffer.clear();
+				self.buffer
+					.extend_from_slice(self.sma.get_window().as_slice());
+				median(&mut self.buffer)
+			}
+		};
+
+		self.deviation(center)
+	}
+}
+
+impl<P: Period> Checkpoint for MeanAbsDev<P> {
+	type State = <SMA<P> as Checkpoint>::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.sma.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sma.restore(state);
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{MeanAbsDev as TestingMethod, Method};
+	use super::{DeviationCenter, MeanAbsDev as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::median_abs_dev::median;
 
 	#[test]
 	fn test_mean_abs_dev_const() {
@@ -124,4 +241,34 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_mean_abs_dev_center() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_center(DeviationCenter::Median);
+			let mut external = TestingMethod::new(length, src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let slice: Vec<ValueType> = (0..length)
+					.map(|j| src[i.saturating_sub(j as usize)])
+					.collect();
+
+				let deviation = |center: ValueType| {
+					slice.iter().map(|v| (v - center).abs()).sum::<ValueType>()
+						/ length as ValueType
+				};
+
+				let center = median(&mut slice.clone());
+
+				assert_eq_float(deviation(center), method.next(x));
+				assert_eq_float(deviation(x * 0.9), external.next_with_center(x, x * 0.9));
+			});
+		});
+	}
 }
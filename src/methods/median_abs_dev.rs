@@ -1,15 +1,23 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method, Period};
 use crate::core::{Error, PeriodType, ValueType};
-use crate::methods::SMM;
+use crate::methods::{DeviationCenter, SMM};
+use std::mem::size_of;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// [Median absolute deviation](https://en.wikipedia.org/wiki/Average_absolute_deviation) of specified `length` for timeseries of type [`ValueType`]
 ///
+/// By default returns the mean of absolute deviations around the median of the last `length` values.
+/// The center may be switched to the mean by [`with_center`](MedianAbsDev::with_center)
+/// or supplied from an external series by [`next_with_center`](MedianAbsDev::next_with_center).
+///
+/// The formal median absolute deviation, which is the median of absolute deviations around the median,
+/// is calculated when [`with_median_of_deviations`](MedianAbsDev::with_median_of_deviations) is set.
+///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `1`
 ///
@@ -21,62 +29,185 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MedianAbsDev;
+///
+/// let mut mad = MedianAbsDev::new(4, 1.0).unwrap();
+/// mad.next(2.0);
+/// mad.next(3.0);
+/// assert_eq!(mad.next(10.0), 2.5); // median is 2.5
+///
+/// let mut mad = MedianAbsDev::new(4, 1.0).unwrap().with_median_of_deviations(true);
+/// mad.next(2.0);
+/// mad.next(3.0);
+/// assert_eq!(mad.next(10.0), 1.0); // median of [1.5, 0.5, 0.5, 7.5]
+/// ```
+///
 /// # Performance
 ///
 /// O(`length`)
 ///
+/// With [`with_median_of_deviations`](MedianAbsDev::with_median_of_deviations) the median is found by selection
+/// over the internal buffer, which is reused between steps, so it takes O(`length`) on average too.
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct MedianAbsDev {
-	smm: SMM,
+pub struct MedianAbsDev<P: Period = PeriodType> {
+	smm: SMM<P>,
 	divider: ValueType,
+	center: DeviationCenter,
+	median_of_deviations: bool,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	buffer: Vec<ValueType>,
 }
 
 impl MedianAbsDev {
+	/// Creates new instance of `MedianAbsDev` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> MedianAbsDev<P> {
 	/// Returns reference to inner SMA. Useful for implementing in other methods and indicators.
 	#[must_use]
-	pub const fn get_smm(&self) -> &SMM {
+	pub const fn get_smm(&self) -> &SMM<P> {
 		&self.smm
 	}
+
+	/// Sets the center which deviations are taken around. Default is [`DeviationCenter::Median`].
+	#[must_use]
+	pub fn with_center(self, center: DeviationCenter) -> Self {
+		Self { center, ..self }
+	}
+
+	/// Returns current deviation center
+	#[must_use]
+	pub const fn center(&self) -> DeviationCenter {
+		self.center
+	}
+
+	/// Switches aggregation of absolute deviations from the mean to the median
+	#[must_use]
+	pub fn with_median_of_deviations(self, median_of_deviations: bool) -> Self {
+		Self {
+			median_of_deviations,
+			..self
+		}
+	}
+
+	/// Pushes the next `value` and returns absolute deviation around the supplied `center` value
+	/// instead of the configured one.
+	///
+	/// Useful when the center comes from an external series, e.g. some moving average.
+	#[inline]
+	pub fn next_with_center(&mut self, value: ValueType, center: ValueType) -> ValueType {
+		self.smm.next(value);
+		self.deviation(center)
+	}
+
+	#[inline]
+	fn deviation(&mut self, center: ValueType) -> ValueType {
+		let deviations = self
+			.smm
+			.get_window()
+			.as_slice()
+			.iter()
+			.map(|x| x - center)
+			.map(ValueType::abs);
+
+		if self.median_of_deviations {
+			self.buffer.clear();
+			self.buffer.extend(deviations);
+			median(&mut self.buffer)
+		} else {
+			deviations.sum::<ValueType>() * self.divider
+		}
+	}
+}
+
+/// Returns median of the `values`, reordering them in place
+pub(super) fn median(values: &mut [ValueType]) -> ValueType {
+	let len = values.len();
+	let half = len / 2;
+	let (lower, &mut upper, _) =
+		values.select_nth_unstable_by(half, |a, b| a.partial_cmp(b).unwrap());
+
+	if len % 2 == 1 {
+		upper
+	} else {
+		let lower = lower
+			.iter()
+			.copied()
+			.fold(ValueType::NEG_INFINITY, ValueType::max);
+		(lower + upper) * 0.5
+	}
 }
 
-impl Method<'_> for MedianAbsDev {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for MedianAbsDev<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				smm: SMM::new(length, value)?,
-				divider: (length as ValueType).recip(),
-			}),
+		if length.to_usize() < 2 {
+			return Err(Error::wrong_method_parameter("length", "> 1", length));
 		}
+
+		Ok(Self {
+			smm: Method::new(length, value)?,
+			divider: (length.to_usize() as ValueType).recip(),
+			center: DeviationCenter::Median,
+			median_of_deviations: false,
+			buffer: Vec::new(),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.smm.heap_size() + self.buffer.capacity() * size_of::<ValueType>()
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		let smm = self.smm.next(value);
 
-		self.smm
-			.get_window()
-			.as_slice()
-			.iter()
-			.map(|x| x - smm)
-			.map(ValueType::abs)
-			.sum::<ValueType>()
-			* self.divider
+		let center = match self.center {
+			DeviationCenter::Mean => {
+				self.smm.get_window().as_slice().iter().sum::<ValueType>() * self.divider
+			}
+			DeviationCenter::Median => smm,
+		};
+
+		self.deviation(center)
+	}
+}
+
+impl<P: Period> Checkpoint for MedianAbsDev<P> {
+	type State = <SMM<P> as Checkpoint>::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.smm.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.smm.restore(state);
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{MedianAbsDev as TestingMethod, Method};
+	use super::{median, MedianAbsDev as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::DeviationCenter;
 	use std::cmp::Ordering;
 
 	#[test]
@@ -145,4 +276,52 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_median_abs_dev_formal() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).for_each(|length| {
+			let mut method = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_median_of_deviations(true);
+			let mut mean_center = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_center(DeviationCenter::Mean);
+			let mut external = TestingMethod::new(length, src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let mut slice: Vec<ValueType> = (0..length)
+					.map(|j| src[i.saturating_sub(j as usize)])
+					.collect();
+
+				let mean = slice.iter().sum::<ValueType>() / length as ValueType;
+				let mean_deviation = |center: ValueType| {
+					slice.iter().map(|v| (v - center).abs()).sum::<ValueType>()
+						/ length as ValueType
+				};
+
+				assert_eq_float(mean_deviation(mean), mean_center.next(x));
+				assert_eq_float(
+					mean_deviation(x * 0.9),
+					external.next_with_center(x, x * 0.9),
+				);
+
+				slice.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+				let smm = (slice[(length as usize) / 2] + slice[((length - 1) as usize) / 2]) * 0.5;
+
+				let mut deviations: Vec<ValueType> =
+					slice.iter().map(|v| (v - smm).abs()).collect();
+				deviations.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+				let mad = (deviations[(length as usize) / 2]
+					+ deviations[((length - 1) as usize) / 2])
+					* 0.5;
+
+				assert_eq_float(mad, method.next(x));
+				assert_eq_float(mad, median(&mut deviations));
+			});
+		});
+	}
 }
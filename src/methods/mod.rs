@@ -47,12 +47,18 @@ mod hma;
 pub use hma::*;
 mod lin_reg;
 pub use lin_reg::*;
+mod slope;
+pub use slope::*;
 mod swma;
 pub use swma::*;
 mod conv;
 pub use conv::*;
 mod vwma;
 pub use vwma::*;
+mod vwap;
+pub use vwap::VWAP;
+mod rolling_vwap;
+pub use rolling_vwap::RollingVWAP;
 mod trima;
 pub use trima::*;
 //
@@ -60,14 +66,22 @@ mod derivative;
 pub use derivative::*;
 mod integral;
 pub use integral::*;
+mod streak;
+pub use streak::*;
 mod momentum;
 pub use momentum::*;
 mod rate_of_change;
 pub use rate_of_change::*;
+mod percent_rank;
+pub use percent_rank::PercentRank;
+mod drawdown;
+pub use drawdown::Drawdown;
 mod tsi;
 pub use tsi::*;
 mod st_dev;
 pub use st_dev::*;
+mod vw_st_dev;
+pub use vw_st_dev::*;
 mod volatility;
 pub use volatility::*;
 mod cci;
@@ -78,9 +92,15 @@ mod median_abs_dev;
 pub use median_abs_dev::*;
 mod vidya;
 pub use vidya::*;
+mod dominant_cycle;
+pub use dominant_cycle::*;
 
 mod cross;
 pub use cross::*;
+mod cross_count;
+pub use cross_count::*;
+mod divergence;
+pub use divergence::*;
 mod reversal;
 pub use reversal::*;
 mod highest_lowest;
@@ -89,12 +109,24 @@ mod adi;
 mod highest_lowest_index;
 pub use adi::*;
 pub use highest_lowest_index::*;
+mod new_highest_lowest;
+pub use new_highest_lowest::*;
 mod past;
 pub use past::*;
+mod identity;
+pub use identity::Identity;
+mod constant;
+pub use constant::Constant;
 mod heikin_ashi;
 pub use heikin_ashi::HeikinAshi;
 mod tr;
 pub use tr::TR;
+mod gap_detector;
+pub use gap_detector::GapDetector;
+mod atr_trailing_stop;
+pub use atr_trailing_stop::{ATRTrailingStop, PositionSide};
+mod seasonal_profile;
+pub use seasonal_profile::SeasonalProfile;
 /// Renko implementation entities
 ///
 /// For more information see [`Renko`](crate::methods::Renko)
@@ -103,11 +135,34 @@ pub mod renko;
 pub use renko::Renko;
 mod collapse_timeframe;
 pub use collapse_timeframe::CollapseTimeframe;
+mod nan_guard;
+pub use nan_guard::NanGuard;
+mod anchored;
+pub use anchored::Anchored;
+mod filtered;
+pub use filtered::Filtered;
+mod chain;
+pub use chain::Chain;
+mod envelope_of;
+pub use envelope_of::EnvelopeOf;
+mod source_method;
+pub use source_method::SourceMethod;
+/// Pairwise methods combinator entities
+///
+/// For more information see [`Combine`](crate::methods::Combine)
+pub mod combine;
+#[doc(inline)]
+pub use combine::Combine;
+mod clamp;
+pub use clamp::Clamp;
+mod rate_limit;
+pub use rate_limit::RateLimit;
 
 #[cfg(test)]
 mod tests {
-	use crate::core::{Method, ValueType};
-	use crate::helpers::assert_eq_float;
+	use super::*;
+	use crate::core::{Candle, Checkpoint, Method, NanPolicy, Source, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
 	use std::fmt::Debug;
 
 	pub(super) fn test_const<P, I: Copy, O: Copy + Debug + PartialEq>(
@@ -130,4 +185,349 @@ mod tests {
 			assert_eq_float(output, method.next(input));
 		}
 	}
+
+	fn test_checkpoint<'a, M>(mut method: M, inputs: &[M::Input])
+	where
+		M: Method<'a> + Checkpoint + Clone,
+		M::Input: Copy,
+		M::Output: Debug,
+	{
+		let mut reference = method.clone();
+		let last = inputs.len() - 1;
+
+		for (i, &input) in inputs.iter().enumerate() {
+			let checkpoint = method.save();
+
+			// speculative steps must not change the state after restoring
+			method.next(inputs[last - i]);
+			method.restore(&checkpoint);
+			method.next(inputs[i / 2]);
+			method.restore(&checkpoint);
+
+			assert_eq!(
+				format!("{:?}", method.next(input)),
+				format!("{:?}", reference.next(input))
+			);
+		}
+	}
+
+	#[test]
+	#[allow(clippy::too_many_lines)]
+	fn test_checkpoint_methods() {
+		let candles: Vec<Candle> = RandomCandles::default().take(300).collect();
+		let values: Vec<ValueType> = candles.iter().map(|c| c.close).collect();
+		let pairs: Vec<(ValueType, ValueType)> =
+			candles.iter().map(|c| (c.close, c.volume)).collect();
+		let ohlcv: Vec<&dyn OHLCV> = candles.iter().map(|c| -> &dyn OHLCV { c }).collect();
+		let gated: Vec<(ValueType, bool)> = values
+			.iter()
+			.enumerate()
+			.map(|(i, &x)| (x, i % 7 == 0))
+			.collect();
+		let bucketed: Vec<(usize, &dyn OHLCV)> = ohlcv.iter().copied().enumerate().collect();
+		let v = values[0];
+
+		for length in &[2, 3, 10, 51, 100] {
+			let length = *length;
+
+			test_checkpoint(SMA::new(length, v).unwrap(), &values);
+			test_checkpoint(HMA::new(length, v).unwrap(), &values);
+			test_checkpoint(WSMA::new(length, v).unwrap(), &values);
+			test_checkpoint(Vidya::new(length, v).unwrap(), &values);
+			test_checkpoint(WMA::new(length, v).unwrap(), &values);
+			test_checkpoint(EMA::new(length, v).unwrap(), &values);
+			test_checkpoint(DMA::new(length, v).unwrap(), &values);
+			test_checkpoint(DEMA::new(length, v).unwrap(), &values);
+			test_checkpoint(TMA::new(length, v).unwrap(), &values);
+			test_checkpoint(TEMA::new(length, v).unwrap(), &values);
+			test_checkpoint(RMA::new(length, v).unwrap(), &values);
+			test_checkpoint(SWMA::new(length, v).unwrap(), &values);
+			test_checkpoint(TRIMA::new(length, v).unwrap(), &values);
+			test_checkpoint(LinReg::new(length, v).unwrap(), &values);
+			test_checkpoint(LinRegFull::new(length, v).unwrap(), &values);
+			test_checkpoint(Slope::new(length, v).unwrap(), &values);
+			test_checkpoint(StDev::new(length, v).unwrap(), &values);
+			test_checkpoint(MeanAbsDev::new(length, v).unwrap(), &values);
+			test_checkpoint(SMM::new(length, v).unwrap(), &values);
+			test_checkpoint(MedianAbsDev::new(length, v).unwrap(), &values);
+			test_checkpoint(
+				MeanAbsDev::new(length, v)
+					.unwrap()
+					.with_center(DeviationCenter::Median),
+				&values,
+			);
+			test_checkpoint(CCI::new(length, v).unwrap(), &values);
+			test_checkpoint(CCI::<EMA>::new(length, v).unwrap(), &values);
+			test_checkpoint(Momentum::new(length, v).unwrap(), &values);
+			test_checkpoint(RateOfChange::new(length, v).unwrap(), &values);
+			test_checkpoint(PercentRank::new(length, v).unwrap(), &values);
+			test_checkpoint(Drawdown::new(length, v).unwrap(), &values);
+			test_checkpoint(Derivative::new(length, v).unwrap(), &values);
+			test_checkpoint(Integral::new(length, v).unwrap(), &values);
+			test_checkpoint(LinearVolatility::new(length, v).unwrap(), &values);
+			test_checkpoint(Highest::new(length, v).unwrap(), &values);
+			test_checkpoint(Lowest::new(length, v).unwrap(), &values);
+			test_checkpoint(HighestLowestDelta::new(length, v).unwrap(), &values);
+			test_checkpoint(HighestIndex::new(length, v).unwrap(), &values);
+			test_checkpoint(LowestIndex::new(length, v).unwrap(), &values);
+			test_checkpoint(NewHighest::new(length, v).unwrap(), &values);
+			test_checkpoint(NewLowest::new(length, v).unwrap(), &values);
+			test_checkpoint(Past::new(length, v).unwrap(), &values);
+			test_checkpoint(Conv::new(vec![1.0; length as usize], v).unwrap(), &values);
+			test_checkpoint(ReversalSignal::new(length, length, v).unwrap(), &values);
+			test_checkpoint(
+				UpperReversalSignal::new(length, length, v).unwrap(),
+				&values,
+			);
+			test_checkpoint(
+				LowerReversalSignal::new(length, length, v).unwrap(),
+				&values,
+			);
+			test_checkpoint(
+				NanGuard::<SMA>::new((length, NanPolicy::Skip), v).unwrap(),
+				&values,
+			);
+			test_checkpoint(Anchored::<SMA, _>::new(length, (v, false)).unwrap(), &gated);
+			test_checkpoint(Filtered::<SMA, _>::new(length, (v, false)).unwrap(), &gated);
+			test_checkpoint(
+				Combine::<SMA, EMA, _>::new((length, length, combine::Difference), v).unwrap(),
+				&values,
+			);
+			test_checkpoint(
+				Chain::<SMA, WMA>::new((length, length), v).unwrap(),
+				&values,
+			);
+			test_checkpoint(EnvelopeOf::<EMA>::new((length, 0.1), v).unwrap(), &values);
+			test_checkpoint(VWMA::new(length, pairs[0]).unwrap(), &pairs);
+			test_checkpoint(VWStDev::new(length, pairs[0]).unwrap(), &pairs);
+			test_checkpoint(
+				RollingVWAP::new(length, pairs[0])
+					.unwrap()
+					.with_bands(2.0)
+					.unwrap(),
+				&pairs,
+			);
+			test_checkpoint(Divergence::new(length, pairs[0]).unwrap(), &pairs);
+			test_checkpoint(CrossCount::new(length, pairs[0]).unwrap(), &pairs);
+			test_checkpoint(ADI::new(length, ohlcv[0]).unwrap(), &ohlcv);
+			test_checkpoint(
+				CollapseTimeframe::new(length as usize, ohlcv[0]).unwrap(),
+				&ohlcv,
+			);
+		}
+
+		test_checkpoint(TSI::new(3, 7, v).unwrap(), &values);
+		test_checkpoint(TSI::<WMA, SMA>::new((3, 7), v).unwrap(), &values);
+		test_checkpoint(Cross::default(), &pairs);
+		test_checkpoint(CrossAbove::default(), &pairs);
+		test_checkpoint(CrossUnder::default(), &pairs);
+		test_checkpoint(Streak::new((), v).unwrap(), &values);
+		test_checkpoint(DominantCycle::new((10, 48), v).unwrap(), &values);
+		test_checkpoint(ZeroCross::new((), v - 1.0).unwrap(), &values);
+		test_checkpoint(Clamp::new((v * 0.99, v * 1.01), v).unwrap(), &values);
+		test_checkpoint(Identity::new(1, v).unwrap(), &values);
+		test_checkpoint(Constant::new(1, v).unwrap(), &values);
+		test_checkpoint(RateLimit::new(v * 0.001, v).unwrap(), &values);
+		test_checkpoint(BarsSinceCross::new((), pairs[0]).unwrap(), &pairs);
+		test_checkpoint(TR::new(ohlcv[0]).unwrap(), &ohlcv);
+		test_checkpoint(GapDetector::new((14, 0.5), ohlcv[0]).unwrap(), &ohlcv);
+		test_checkpoint(VWAP::new(14, ohlcv[0]).unwrap(), &ohlcv);
+		test_checkpoint(VWAP::new(0, ohlcv[0]).unwrap(), &ohlcv);
+		test_checkpoint(
+			ATRTrailingStop::new((14, 2.0, PositionSide::Long), ohlcv[0]).unwrap(),
+			&ohlcv,
+		);
+		test_checkpoint(
+			ATRTrailingStop::new((14, 2.0, PositionSide::Short), ohlcv[0]).unwrap(),
+			&ohlcv,
+		);
+		test_checkpoint(SeasonalProfile::new(24, bucketed[0]).unwrap(), &bucketed);
+		test_checkpoint(
+			SourceMethod::<SMA>::new((14, Source::HL2), ohlcv[0]).unwrap(),
+			&ohlcv,
+		);
+		test_checkpoint(HeikinAshi::new((), ohlcv[0]).unwrap(), &ohlcv);
+		test_checkpoint(Renko::new((0.01, Source::Close), ohlcv[0]).unwrap(), &ohlcv);
+	}
+
+	mod properties {
+		use super::super::*;
+		use crate::core::{Method, PeriodType, ValueType};
+		use crate::helpers::naive;
+		use crate::helpers::testing::{
+			check_constant, check_finite, check_reference, finite_values,
+		};
+		use proptest::prelude::*;
+
+		#[cfg(feature = "serde")]
+		use crate::helpers::testing::check_serde_round_trip;
+
+		const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+			1e-2
+		} else {
+			1e-7
+		};
+
+		macro_rules! check_methods {
+			($length:expr, $values:expr, [$($method:ty),* $(,)?]) => {
+				$(
+					check_finite(<$method>::new($length, $values[0]).unwrap(), &$values)?;
+					check_constant::<$method>($length, $values[0], 100)?;
+					#[cfg(feature = "serde")]
+					check_serde_round_trip(<$method>::new($length, $values[0]).unwrap(), &$values)?;
+				)*
+			};
+		}
+
+		macro_rules! check_naive {
+			($length:expr, $values:expr, $sigma:expr, [$($method:ty => $naive:ident),* $(,)?]) => {
+				$(
+					check_reference(
+						<$method>::new($length, $values[0]).unwrap(),
+						&$values,
+						&naive::$naive(&$values, $length),
+						$sigma,
+					)?;
+				)*
+			};
+		}
+
+		proptest! {
+			#![proptest_config(ProptestConfig::with_cases(64))]
+
+			#[test]
+			fn test_methods_properties(length in 2..60_u8, values in finite_values(1..200)) {
+				let length = PeriodType::from(length);
+
+				check_methods!(length, values, [
+					SMA, WMA, EMA, DMA, DEMA, TMA, TEMA, RMA, WSMA, SMM, HMA, SWMA, TRIMA,
+					LinReg, Slope, Vidya, CCI, StDev, MeanAbsDev, MedianAbsDev, LinearVolatility,
+					Momentum, RateOfChange, PercentRank, Drawdown, Derivative, Integral,
+					Highest, Lowest, HighestLowestDelta, Identity, Constant,
+				]);
+			}
+
+			#[test]
+			fn test_methods_naive(length in 2..40_u8, values in finite_values(1..150)) {
+				let length = PeriodType::from(length);
+
+				// rolling sums lose about the precision of the biggest value on every step
+				let scale = values.iter().copied().fold(1.0, ValueType::max);
+				let sigma = SIGMA.max(ValueType::EPSILON * values.len() as ValueType * scale);
+
+				check_naive!(length, values, sigma, [
+					SMA => sma, WMA => wma, SWMA => swma, TRIMA => trima, EMA => ema, RMA => rma,
+					SMM => smm, LinReg => lin_reg, MeanAbsDev => mean_abs_dev,
+					Highest => highest, Lowest => lowest, Integral => integral,
+					Momentum => momentum, RateOfChange => rate_of_change, Derivative => derivative,
+				]);
+
+				// streaming `StDev` takes a square root of the difference of big rolling sums,
+				// so near zero its absolute error is about a square root of the lost precision
+				let st_dev_sigma = (ValueType::EPSILON * values.len() as ValueType).sqrt() * scale;
+
+				let mut st_dev = StDev::new(length, values[0]).unwrap();
+				for (&value, expected) in values.iter().zip(naive::st_dev(&values, length)) {
+					let output = st_dev.next(value);
+					prop_assert!(
+						(expected - output).abs() <= sigma.mul_add(expected, st_dev_sigma),
+						"StDev: expected={}, output={}",
+						expected,
+						output
+					);
+				}
+
+				check_naive!(0, values, sigma, [Integral => integral]);
+			}
+		}
+	}
+
+	fn assert_send_sync<T: Send + Sync>() {}
+
+	#[test]
+	fn test_send_sync() {
+		use crate::core::{Error, IndicatorResult, PeriodType, Window, WindowCheckpoint};
+		use crate::helpers::RegularMethod;
+
+		assert_send_sync::<ADI>();
+		assert_send_sync::<Anchored<SMA, PeriodType>>();
+		assert_send_sync::<BarsSinceCross>();
+		assert_send_sync::<CCI>();
+		assert_send_sync::<CCI<EMA>>();
+		assert_send_sync::<Chain<SMA, WMA>>();
+		assert_send_sync::<Clamp>();
+		assert_send_sync::<Constant>();
+		assert_send_sync::<CollapseTimeframe>();
+		assert_send_sync::<Combine<SMA, EMA, combine::Difference>>();
+		assert_send_sync::<Conv>();
+		assert_send_sync::<Cross>();
+		assert_send_sync::<CrossAbove>();
+		assert_send_sync::<CrossCount>();
+		assert_send_sync::<CrossUnder>();
+		assert_send_sync::<DEMA>();
+		assert_send_sync::<DMA>();
+		assert_send_sync::<Derivative>();
+		assert_send_sync::<Divergence>();
+		assert_send_sync::<Drawdown>();
+		assert_send_sync::<DominantCycle>();
+		assert_send_sync::<EMA>();
+		assert_send_sync::<EnvelopeOf<SMA>>();
+		assert_send_sync::<Filtered<SMA, ValueType>>();
+		assert_send_sync::<GapDetector>();
+		assert_send_sync::<ATRTrailingStop>();
+		assert_send_sync::<SeasonalProfile>();
+		assert_send_sync::<HeikinAshi>();
+		assert_send_sync::<Highest>();
+		assert_send_sync::<HighestIndex>();
+		assert_send_sync::<HighestLowestDelta>();
+		assert_send_sync::<HMA>();
+		assert_send_sync::<Identity>();
+		assert_send_sync::<Integral>();
+		assert_send_sync::<LinReg>();
+		assert_send_sync::<LinRegFull>();
+		assert_send_sync::<LinearVolatility>();
+		assert_send_sync::<Lowest>();
+		assert_send_sync::<LowestIndex>();
+		assert_send_sync::<MeanAbsDev>();
+		assert_send_sync::<MedianAbsDev>();
+		assert_send_sync::<Momentum>();
+		assert_send_sync::<NanGuard<SMA>>();
+		assert_send_sync::<NewHighest>();
+		assert_send_sync::<NewLowest>();
+		assert_send_sync::<Past<ValueType>>();
+		assert_send_sync::<PercentRank>();
+		assert_send_sync::<RateLimit>();
+		assert_send_sync::<RateOfChange>();
+		assert_send_sync::<Renko>();
+		assert_send_sync::<ReversalSignal>();
+		assert_send_sync::<LowerReversalSignal>();
+		assert_send_sync::<UpperReversalSignal>();
+		assert_send_sync::<RMA>();
+		assert_send_sync::<RollingVWAP>();
+		assert_send_sync::<SMA>();
+		assert_send_sync::<Slope>();
+		assert_send_sync::<SMM>();
+		assert_send_sync::<SourceMethod<SMA>>();
+		assert_send_sync::<StDev>();
+		assert_send_sync::<Streak>();
+		assert_send_sync::<SWMA>();
+		assert_send_sync::<TEMA>();
+		assert_send_sync::<TMA>();
+		assert_send_sync::<TR>();
+		assert_send_sync::<TRIMA>();
+		assert_send_sync::<TSI>();
+		assert_send_sync::<Vidya>();
+		assert_send_sync::<VWAP>();
+		assert_send_sync::<VWMA>();
+		assert_send_sync::<VWStDev>();
+		assert_send_sync::<WMA>();
+		assert_send_sync::<WSMA>();
+		assert_send_sync::<ZeroCross>();
+
+		assert_send_sync::<RegularMethod>();
+		assert_send_sync::<Window<ValueType>>();
+		assert_send_sync::<WindowCheckpoint<ValueType>>();
+		assert_send_sync::<IndicatorResult>();
+		assert_send_sync::<Error>();
+	}
 }
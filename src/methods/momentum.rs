@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -9,10 +9,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
+/// Output may be normalized by [`with_mode`](Momentum::with_mode). Default mode is [`MomentumMode::Difference`].
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -51,6 +53,18 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(change.next(2.0), derivative.next(2.0));
 /// ```
 ///
+/// ### Log-change output
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::methods::{Momentum, MomentumMode};
+///
+/// let mut change = Momentum::new(1, 1.0).unwrap().with_mode(MomentumMode::Log);
+/// change.next(1.0);
+/// assert!((change.next(std::f64::consts::E as ValueType) - 1.0).abs() < 1e-6);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -61,12 +75,76 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 ///
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Momentum {
-	window: Window<ValueType>,
+pub struct Momentum<P: Period = PeriodType> {
+	mode: MomentumMode,
+	sum: ValueType,
+	window: Window<ValueType, P>,
+}
+
+impl Momentum {
+	/// Creates new instance of `Momentum` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Momentum<P> {
+	/// Sets output mode
+	#[must_use]
+	pub fn with_mode(self, mode: MomentumMode) -> Self {
+		Self { mode, ..self }
+	}
+
+	/// Returns current output mode
+	#[must_use]
+	pub const fn mode(&self) -> MomentumMode {
+		self.mode
+	}
+}
+
+/// Output mode for [`Momentum`] and [`RateOfChange`](crate::methods::RateOfChange) methods
+///
+/// Every mode except [`MomentumMode::Difference`] is scale-free, so it may be used for comparing momentum across different assets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum MomentumMode {
+	/// `value` - `n_th_value`
+	Difference,
+
+	/// (`value` - `n_th_value`) / `n_th_value`
+	Relative,
+
+	/// ln(`value` / `n_th_value`)
+	Log,
+
+	/// (`value` - `n_th_value`) / `mean`, where `mean` is an average of the last `length` values
+	MeanNormalized,
+}
+
+impl MomentumMode {
+	/// `sum` is a rolling sum of the last `length` values. It is only used by [`MomentumMode::MeanNormalized`]
+	#[inline]
+	pub(crate) fn apply(
+		self,
+		value: ValueType,
+		n_th_value: ValueType,
+		sum: ValueType,
+		length: usize,
+	) -> ValueType {
+		match self {
+			Self::Difference => value - n_th_value,
+			Self::Relative => (value - n_th_value) / n_th_value,
+			Self::Log => (value / n_th_value).ln(),
+			Self::MeanNormalized => (value - n_th_value) * length as ValueType / sum,
+		}
+	}
 }
 
 /// Just an alias for [Momentum] method
@@ -75,29 +153,55 @@ pub type Change = Momentum;
 /// Just an alias for [Momentum] method
 pub type MTM = Momentum;
 
-impl Method<'_> for Momentum {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for Momentum<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, value),
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			mode: MomentumMode::Difference,
+			sum: value * length.to_usize() as ValueType,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		value - self.window.push(value)
+		let n_th_value = self.window.push(value);
+		self.sum += value - n_th_value;
+
+		self.mode
+			.apply(value, n_th_value, self.sum, self.window.len().to_usize())
+	}
+}
+
+impl<P: Period> Checkpoint for Momentum<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.sum, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sum = state.0;
+		self.window.restore(&state.1);
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{Method, Momentum as TestingMethod};
+	use super::{Method, Momentum as TestingMethod, MomentumMode};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
@@ -142,4 +246,37 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_momentum_modes() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut relative = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_mode(MomentumMode::Relative);
+			let mut log = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_mode(MomentumMode::Log);
+			let mut normalized = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_mode(MomentumMode::MeanNormalized);
+
+			let length = length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let n_th_value = src[i.saturating_sub(length)];
+				let mean = (0..length)
+					.map(|j| src[i.saturating_sub(j)])
+					.sum::<ValueType>()
+					/ length as ValueType;
+
+				assert_eq_float((x - n_th_value) / n_th_value, relative.next(x));
+				assert_eq_float((x / n_th_value).ln(), log.next(x));
+				assert_eq_float((x - n_th_value) / mean, normalized.next(x));
+			});
+		});
+	}
 }
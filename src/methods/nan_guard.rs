@@ -0,0 +1,227 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, NanPolicy, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Guards any other method from non-finite (`NaN` or infinite) input values
+///
+/// Every finite input value is passed to the inner method as is.
+/// Every non-finite input value is handled according to [`NanPolicy`]:
+///
+/// * [`NanPolicy::Skip`] — value is not passed to the inner method, last output is returned.
+///   If there were no outputs yet, the last finite value is passed instead;
+/// * [`NanPolicy::CarryLast`] — the last finite value is passed to the inner method instead;
+/// * [`NanPolicy::Error`] — value is not passed to the inner method, [`Error::NonFinite`] is returned.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`parameters`: `M::Params`, `policy`: [`NanPolicy`])
+///
+/// `parameters` are passed to the inner method as is.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// Initial value must be finite, otherwise [`Error::NonFinite`] is returned.
+///
+/// # Output type
+///
+/// Output type is `Result<ValueType, Error>`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{NanPolicy, ValueType};
+/// use yata::methods::{NanGuard, SMA};
+///
+/// let mut sma = NanGuard::<SMA>::new((2, NanPolicy::Skip), 1.0).unwrap();
+///
+/// assert_eq!(sma.next(3.0).unwrap(), 2.0);
+/// assert_eq!(sma.next(ValueType::NAN).unwrap(), 2.0);
+/// assert_eq!(sma.next(5.0).unwrap(), 4.0);
+///
+/// let mut sma = NanGuard::<SMA>::new((2, NanPolicy::Error), 1.0).unwrap();
+///
+/// assert!(sma.next(ValueType::INFINITY).is_err());
+/// assert_eq!(sma.next(3.0).unwrap(), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) plus performance of the inner method
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`NanPolicy`]: crate::core::NanPolicy
+/// [`NanPolicy::Skip`]: crate::core::NanPolicy::Skip
+/// [`NanPolicy::CarryLast`]: crate::core::NanPolicy::CarryLast
+/// [`NanPolicy::Error`]: crate::core::NanPolicy::Error
+/// [`Error`]: crate::core::Error
+/// [`Error::NonFinite`]: crate::core::Error::NonFinite
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NanGuard<M> {
+	method: M,
+	policy: NanPolicy,
+	last_input: ValueType,
+	last_output: Option<ValueType>,
+}
+
+impl<M> NanGuard<M> {
+	/// Returns a reference to the inner method
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns current [`NanPolicy`](crate::core::NanPolicy)
+	pub const fn policy(&self) -> NanPolicy {
+		self.policy
+	}
+}
+
+impl<'a, M> Method<'a> for NanGuard<M>
+where
+	M: Method<'a, Input = ValueType, Output = ValueType>,
+{
+	type Params = (M::Params, NanPolicy);
+	type Input = ValueType;
+	type Output = Result<ValueType, Error>;
+
+	fn new((parameters, policy): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::NonFinite);
+		}
+
+		Ok(Self {
+			method: M::new(parameters, value)?,
+			policy,
+			last_input: value,
+			last_output: None,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.method.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let input = if value.is_finite() {
+			self.last_input = value;
+			value
+		} else {
+			match (self.policy, self.last_output) {
+				(NanPolicy::Skip, Some(output)) => return Ok(output),
+				(NanPolicy::Error, _) => return Err(Error::NonFinite),
+				_ => self.last_input,
+			}
+		};
+
+		let output = self.method.next(input);
+		self.last_output = Some(output);
+
+		Ok(output)
+	}
+}
+
+impl<M: Checkpoint> Checkpoint for NanGuard<M> {
+	type State = (M::State, ValueType, Option<ValueType>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.method.save(), self.last_input, self.last_output)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.method.restore(&state.0);
+		self.last_input = state.1;
+		self.last_output = state.2;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, NanGuard};
+	use crate::core::{NanPolicy, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{Highest, SMA};
+
+	fn with_nans() -> Vec<ValueType> {
+		RandomCandles::default()
+			.take(300)
+			.enumerate()
+			.map(|(i, x)| match i % 7 {
+				3 => ValueType::NAN,
+				5 => ValueType::INFINITY,
+				_ => x.close,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_nan_guard_non_finite_initial() {
+		assert!(NanGuard::<SMA>::new((3, NanPolicy::Skip), ValueType::NAN).is_err());
+		assert!(NanGuard::<SMA>::new((3, NanPolicy::Skip), ValueType::NEG_INFINITY).is_err());
+	}
+
+	#[test]
+	fn test_nan_guard_skip() {
+		let src = with_nans();
+
+		for length in 1..255 {
+			let mut guard = NanGuard::<Highest>::new((length, NanPolicy::Skip), src[0]).unwrap();
+			let mut method = Highest::new(length, src[0]).unwrap();
+			let mut last = src[0];
+
+			for &x in &src {
+				if x.is_finite() {
+					last = method.next(x);
+				}
+
+				assert_eq_float(last, guard.next(x).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn test_nan_guard_carry_last() {
+		let src = with_nans();
+
+		for length in 1..255 {
+			let mut guard = NanGuard::<SMA>::new((length, NanPolicy::CarryLast), src[0]).unwrap();
+			let mut method = SMA::new(length, src[0]).unwrap();
+			let mut last = src[0];
+
+			for &x in &src {
+				if x.is_finite() {
+					last = x;
+				}
+
+				assert_eq_float(method.next(last), guard.next(x).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn test_nan_guard_error() {
+		let src = with_nans();
+
+		for length in 1..255 {
+			let mut guard = NanGuard::<SMA>::new((length, NanPolicy::Error), src[0]).unwrap();
+			let mut method = SMA::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				let output = guard.next(x);
+
+				if x.is_finite() {
+					assert_eq_float(method.next(x), output.unwrap());
+				} else {
+					assert!(output.is_err());
+				}
+			}
+		}
+	}
+}
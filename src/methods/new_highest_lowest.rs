@@ -0,0 +1,248 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::{Highest, Lowest};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Checks whether the current value is a new highest value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// Returns `true` when the current value is strictly greater than each of the previous `length` values.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`bool`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::NewHighest;
+///
+/// let values = [1.0,   2.0,  3.0,   2.0,   1.0,   2.5,  2.5,   1.0  ];
+/// let r      = [false, true, true, false, false, false, false, false];
+///
+/// let mut new_highest = NewHighest::new(3, values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(new_highest.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// This method is relatively slow compare to the other methods.
+///
+/// # See also
+///
+/// [`NewLowest`], [`BarsSinceHighest`], [`Highest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+/// [`BarsSinceHighest`]: crate::methods::BarsSinceHighest
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NewHighest<P: Period = PeriodType> {
+	highest: Highest<P>,
+	previous: ValueType,
+}
+
+impl NewHighest {
+	/// Creates new instance of `NewHighest` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for NewHighest<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = bool;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			highest: Method::new(length, value)?,
+			previous: value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.highest.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let is_new = value > self.previous;
+		self.previous = self.highest.next(value);
+
+		is_new
+	}
+}
+
+impl<P: Period> Checkpoint for NewHighest<P> {
+	type State = (<Highest<P> as Checkpoint>::State, ValueType);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.highest.save(), self.previous)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.highest.restore(&state.0);
+		self.previous = state.1;
+	}
+}
+
+/// Checks whether the current value is a new lowest value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// Returns `true` when the current value is strictly less than each of the previous `length` values.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`bool`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::NewLowest;
+///
+/// let values = [3.0,   2.0,  1.0,   2.0,   3.0,   1.5,  1.5,   3.0  ];
+/// let r      = [false, true, true, false, false, false, false, false];
+///
+/// let mut new_lowest = NewLowest::new(3, values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(new_lowest.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// This method is relatively slow compare to the other methods.
+///
+/// # See also
+///
+/// [`NewHighest`], [`BarsSinceLowest`], [`Lowest`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`BarsSinceLowest`]: crate::methods::BarsSinceLowest
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NewLowest<P: Period = PeriodType> {
+	lowest: Lowest<P>,
+	previous: ValueType,
+}
+
+impl NewLowest {
+	/// Creates new instance of `NewLowest` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for NewLowest<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = bool;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			lowest: Method::new(length, value)?,
+			previous: value,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.lowest.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let is_new = value < self.previous;
+		self.previous = self.lowest.next(value);
+
+		is_new
+	}
+}
+
+impl<P: Period> Checkpoint for NewLowest<P> {
+	type State = (<Lowest<P> as Checkpoint>::State, ValueType);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.lowest.save(), self.previous)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.lowest.restore(&state.0);
+		self.previous = state.1;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{NewHighest, NewLowest};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_new_highest_lowest_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+
+			test_const(&mut NewHighest::new(i, input).unwrap(), input, false);
+			test_const(&mut NewLowest::new(i, input).unwrap(), input, false);
+		}
+	}
+
+	#[test]
+	fn test_new_highest_lowest() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 1..255 {
+			let mut new_highest = NewHighest::new(length, src[0]).unwrap();
+			let mut new_lowest = NewLowest::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, &x) in src.iter().enumerate() {
+				let previous = (1..=length).map(|j| src[i.saturating_sub(j)]);
+				let max = previous.clone().fold(ValueType::MIN, ValueType::max);
+				let min = previous.fold(ValueType::MAX, ValueType::min);
+
+				assert_eq!(new_highest.next(x), x > max);
+				assert_eq!(new_lowest.next(x), x < min);
+			}
+		}
+	}
+}
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, Window, WindowCheckpoint};
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -52,26 +52,44 @@ use serde::{Deserialize, Serialize};
 /// [`Window<T>`]: crate::core::Window
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Past<T>(Window<T>)
+pub struct Past<T, P = PeriodType>(Window<T, P>)
 where
-	T: Copy + fmt::Debug;
+	T: Copy + fmt::Debug,
+	P: Period;
 
-impl<'a, T> Method<'a> for Past<T>
+impl<T> Past<T>
 where
 	T: Copy + fmt::Debug,
 {
-	type Params = PeriodType;
+	/// Creates new instance of `Past` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: T) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<'a, T, P> Method<'a> for Past<T, P>
+where
+	T: Copy + fmt::Debug,
+	P: Period,
+{
+	type Params = P;
 	type Input = T;
 	type Output = T;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self(Window::new(length, value))),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self(Window::with_size(length, value)))
+	}
+
+	fn heap_size(&self) -> usize {
+		self.0.heap_size()
 	}
 
 	#[inline]
@@ -80,6 +98,24 @@ where
 	}
 }
 
+impl<T, P> Checkpoint for Past<T, P>
+where
+	T: Copy + fmt::Debug,
+	P: Period,
+{
+	type State = WindowCheckpoint<T, P>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.0.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.0.restore(state);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Method, Past as TestingMethod};
@@ -0,0 +1,152 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Percent rank](https://en.wikipedia.org/wiki/Percentile_rank) of the current value among the previous `length` values of timeseries of type [`ValueType`]
+///
+/// Returns the share of the previous `length` values, which are strictly less than the current value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::PercentRank;
+///
+/// let mut percent_rank = PercentRank::new(4, 1.0).unwrap();
+/// percent_rank.next(2.0);
+/// percent_rank.next(3.0);
+/// percent_rank.next(4.0);
+///
+/// // previous values are 1.0, 2.0, 3.0 and 4.0
+/// assert_eq!(percent_rank.next(3.5), 0.75);
+/// // previous values are 2.0, 3.0, 4.0 and 3.5
+/// assert_eq!(percent_rank.next(1.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentRank<P: Period = PeriodType> {
+	divider: ValueType,
+	window: Window<ValueType, P>,
+}
+
+impl PercentRank {
+	/// Creates new instance of `PercentRank` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for PercentRank<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
+		}
+
+		Ok(Self {
+			divider: (length.to_usize() as ValueType).recip(),
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let count = self
+			.window
+			.as_slice()
+			.iter()
+			.filter(|&&x| x < value)
+			.count();
+		self.window.push(value);
+
+		count as ValueType * self.divider
+	}
+}
+
+impl<P: Period> Checkpoint for PercentRank<P> {
+	type State = WindowCheckpoint<ValueType, P>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.window.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.window.restore(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PercentRank as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_percent_rank_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_percent_rank() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 1..255 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+
+			for (i, &x) in src.iter().enumerate() {
+				let count = (1..=length as usize)
+					.map(|j| src[i.saturating_sub(j)])
+					.filter(|&prev| prev < x)
+					.count();
+
+				let value = method.next(x);
+
+				assert!((0.0..=1.0).contains(&value));
+				assert_eq_float(count as ValueType / length as ValueType, value);
+			}
+		}
+	}
+}
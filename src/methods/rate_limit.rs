@@ -0,0 +1,144 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Limits how much values of timeseries of type [`ValueType`] may change per bar
+///
+/// Each output value moves towards the input value by at most `max_change` from the previous output value.
+///
+/// Useful for post-processing of indicators outputs before they drive any orders.
+///
+/// # Parameters
+///
+/// Has a single parameter `max_change`: [`ValueType`]
+///
+/// `max_change` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::RateLimit;
+///
+/// let values = [1.0, 1.5, 4.0, 4.0, 4.0, 0.0];
+/// let r      = [1.0, 1.5, 2.5, 3.5, 4.0, 3.0];
+///
+/// let mut rate_limit = RateLimit::new(1.0, values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(rate_limit.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Clamp`](crate::methods::Clamp)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RateLimit {
+	max_change: ValueType,
+	last_value: ValueType,
+}
+
+impl Method<'_> for RateLimit {
+	type Params = ValueType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(max_change: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if max_change > 0. {
+			Ok(Self {
+				max_change,
+				last_value: value,
+			})
+		} else {
+			Err(Error::wrong_method_parameter(
+				"max_change",
+				"> 0",
+				max_change,
+			))
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let change = (value - self.last_value).clamp(-self.max_change, self.max_change);
+		self.last_value += change;
+
+		self.last_value
+	}
+}
+
+impl Checkpoint for RateLimit {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, RateLimit as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_rate_limit_const() {
+		let input = 123.456;
+		let mut method = TestingMethod::new(0.5, input).unwrap();
+
+		test_const_float(&mut method, input, input);
+	}
+
+	#[test]
+	fn test_rate_limit() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for &max_change in &[0.01, 0.1, 1.0, 10.0, 1000.0] {
+			let mut method = TestingMethod::new(max_change, src[0]).unwrap();
+			let mut prev = src[0];
+
+			for &x in &src {
+				let value = method.next(x);
+
+				// adding the change to the previous value rounds to the precision of the values
+				let tolerance = 2.0 * ValueType::EPSILON * value.abs().max(prev.abs());
+				assert!((value - prev).abs() <= max_change + tolerance);
+
+				if (x - prev).abs() <= max_change {
+					assert_eq_float(x, value);
+				}
+
+				prev = value;
+			}
+		}
+
+		assert!(TestingMethod::new(0.0, 1.0).is_err());
+		assert!(TestingMethod::new(ValueType::NAN, 1.0).is_err());
+	}
+}
@@ -1,5 +1,6 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use crate::methods::MomentumMode;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -16,10 +17,12 @@ pub type ROC = RateOfChange;
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
+/// Output may be changed by [`with_mode`](RateOfChange::with_mode). Default mode is [`MomentumMode::Relative`].
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -30,38 +33,114 @@ pub type ROC = RateOfChange;
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{MomentumMode, ROC};
+///
+/// let mut roc = ROC::new(2, 1.0).unwrap();
+/// roc.next(1.0);
+/// roc.next(2.0);
+/// assert_eq!(roc.next(3.0), 2.0);
+///
+/// // normalized by the average of last 2 values
+/// let mut roc = ROC::new(2, 1.0).unwrap().with_mode(MomentumMode::MeanNormalized);
+/// roc.next(1.0);
+/// roc.next(2.0);
+/// assert_eq!(roc.next(3.0), 0.8);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
 ///
+/// # Serialization
+///
+/// `RateOfChange` is serialized as a struct of `mode`, `sum` and `window` instead of a bare window as it was before output modes,
+/// so serialized states of the previous versions can not be deserialized.
+///
 /// # See Also
 ///
 /// [`Momentum`], [`Derivative`](crate::methods::Derivative)
 ///
 /// [`Momentum`]: crate::methods::Momentum
+/// [`MomentumMode::Relative`]: crate::methods::MomentumMode::Relative
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct RateOfChange(Window<ValueType>);
+pub struct RateOfChange<P: Period = PeriodType> {
+	mode: MomentumMode,
+	sum: ValueType,
+	window: Window<ValueType, P>,
+}
+
+impl RateOfChange {
+	/// Creates new instance of `RateOfChange` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
 
-impl Method<'_> for RateOfChange {
-	type Params = PeriodType;
+impl<P: Period> RateOfChange<P> {
+	/// Sets output mode
+	#[must_use]
+	pub fn with_mode(self, mode: MomentumMode) -> Self {
+		Self { mode, ..self }
+	}
+
+	/// Returns current output mode
+	#[must_use]
+	pub const fn mode(&self) -> MomentumMode {
+		self.mode
+	}
+}
+
+impl<P: Period> Method<'_> for RateOfChange<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self(Window::new(length, value))),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			mode: MomentumMode::Relative,
+			sum: value * length.to_usize() as ValueType,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
-		let prev_value = self.0.push(value);
+		let prev_value = self.window.push(value);
+		self.sum += value - prev_value;
 
-		(value - prev_value) / prev_value
+		self.mode
+			.apply(value, prev_value, self.sum, self.window.len().to_usize())
+	}
+}
+
+impl<P: Period> Checkpoint for RateOfChange<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.sum, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sum = state.0;
+		self.window.restore(&state.1);
 	}
 }
 
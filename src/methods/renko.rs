@@ -1,4 +1,4 @@
-use crate::core::{Error, Method, Source, ValueType, OHLCV};
+use crate::core::{Checkpoint, Error, Method, Source, ValueType, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -325,7 +325,11 @@ impl<'a> Method<'a> for Renko {
 				volume: 0.0,
 			})
 		} else {
-			Err(Error::WrongMethodParameters)
+			Err(Error::wrong_method_parameter(
+				"brick_size",
+				"in [ValueType::EPSILON; 1.0)",
+				brick_size,
+			))
 		}
 	}
 
@@ -390,6 +394,20 @@ impl<'a> Method<'a> for Renko {
 	}
 }
 
+impl Checkpoint for Renko {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::core::Source;
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Action, Error, PeriodType, ValueType, Window};
+use crate::core::{Action, Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use crate::core::{Checkpoint, Method};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -79,12 +79,34 @@ impl Method<'_> for ReversalSignal {
 		})
 	}
 
+	fn heap_size(&self) -> usize {
+		self.high.heap_size() + self.low.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.low.next(value) - self.high.next(value)
 	}
 }
 
+impl Checkpoint for ReversalSignal {
+	type State = (
+		<UpperReversalSignal as Checkpoint>::State,
+		<LowerReversalSignal as Checkpoint>::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.high.save(), self.low.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.high.restore(&state.0);
+		self.low.restore(&state.1);
+	}
+}
+
 /// Searches for upper reversal points over last `left`+`right`+1 values of type [`ValueType`]
 ///
 /// # Parameters
@@ -159,8 +181,16 @@ impl Method<'_> for UpperReversalSignal {
 	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		let (left, right) = params;
 
-		if left == 0 || right == 0 || left.saturating_add(right) == PeriodType::MAX {
-			return Err(Error::WrongMethodParameters);
+		if left == 0 {
+			return Err(Error::wrong_method_parameter("left", "> 0", left));
+		}
+
+		if right == 0 || left.saturating_add(right) == PeriodType::MAX {
+			return Err(Error::wrong_method_parameter(
+				"right",
+				"in [1; PeriodType::MAX - left)",
+				right,
+			));
 		}
 
 		Ok(Self {
@@ -173,6 +203,10 @@ impl Method<'_> for UpperReversalSignal {
 		})
 	}
 
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.window.push(value);
@@ -216,6 +250,33 @@ impl Method<'_> for UpperReversalSignal {
 	}
 }
 
+impl Checkpoint for UpperReversalSignal {
+	type State = (
+		ValueType,
+		PeriodType,
+		PeriodType,
+		WindowCheckpoint<ValueType>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.max_value,
+			self.max_index,
+			self.index,
+			self.window.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.max_value = state.0;
+		self.max_index = state.1;
+		self.index = state.2;
+		self.window.restore(&state.3);
+	}
+}
+
 /// Searches for lower reversal points over last `left`+`right`+1 values of type [`ValueType`]
 ///
 /// # Parameters
@@ -293,8 +354,16 @@ impl Method<'_> for LowerReversalSignal {
 	fn new(params: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		let (left, right) = params;
 
-		if left == 0 || right == 0 || left.saturating_add(right) == PeriodType::MAX {
-			return Err(Error::WrongMethodParameters);
+		if left == 0 {
+			return Err(Error::wrong_method_parameter("left", "> 0", left));
+		}
+
+		if right == 0 || left.saturating_add(right) == PeriodType::MAX {
+			return Err(Error::wrong_method_parameter(
+				"right",
+				"in [1; PeriodType::MAX - left)",
+				right,
+			));
 		}
 
 		Ok(Self {
@@ -307,6 +376,10 @@ impl Method<'_> for LowerReversalSignal {
 		})
 	}
 
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.window.push(value);
@@ -350,6 +423,33 @@ impl Method<'_> for LowerReversalSignal {
 	}
 }
 
+impl Checkpoint for LowerReversalSignal {
+	type State = (
+		ValueType,
+		PeriodType,
+		PeriodType,
+		WindowCheckpoint<ValueType>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.min_value,
+			self.min_index,
+			self.index,
+			self.window.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.min_value = state.0;
+		self.min_index = state.1;
+		self.index = state.2;
+		self.window.restore(&state.3);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1,4 +1,4 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method};
 use crate::core::{Error, PeriodType, ValueType};
 
 #[cfg(feature = "serde")]
@@ -68,7 +68,7 @@ impl Method<'_> for RMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		match length {
-			0 => Err(Error::WrongMethodParameters),
+			0 => Err(Error::wrong_method_parameter("length", "> 0", length)),
 			length => {
 				let alpha = (length as ValueType).recip();
 				Ok(Self {
@@ -89,6 +89,20 @@ impl Method<'_> for RMA {
 	}
 }
 
+impl Checkpoint for RMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
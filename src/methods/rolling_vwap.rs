@@ -0,0 +1,209 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::VWStDev;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [Volume Weighted Average Price](https://en.wikipedia.org/wiki/Volume-weighted_average_price) of specified `length`
+/// over pairs of (`price`, `volume`) with optional standard deviation bands
+///
+/// Unlike [`VWAP`](crate::methods::VWAP), it is never anchored: it always averages over the last `length` pairs,
+/// and the price may be any value (not only [typical price](crate::core::OHLCV::tp)).
+///
+/// Bands are enabled by [`with_bands`](RollingVWAP::with_bands). Upper band is `VWAP` + `k` * `stdev` and lower band is `VWAP` - `k` * `stdev`,
+/// where `stdev` is the volume weighted [standard deviation](VWStDev<P>) of the prices over the same window.
+/// Bands and `stdev` of the last pushed pair are available by [`get_bands`](RollingVWAP::get_bands) and [`get_st_dev`](RollingVWAP::get_st_dev).
+///
+/// When total volume over the window is `0.0`, returns the previous value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `1`
+///
+/// Returns [`Error::NoVolume`](crate::core::Error::NoVolume) when the initial `volume` is `NaN`.
+///
+/// # Input type
+///
+/// Input type is (`price`: [`ValueType`], `volume`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::methods::RollingVWAP;
+///
+/// let mut vwap = RollingVWAP::new(2, (1.0, 1.0)).unwrap().with_bands(2.0).unwrap();
+///
+/// // (1.0 * 1.0 + 5.0 * 3.0) / 4.0 = 4.0
+/// assert_eq!(vwap.next((5.0, 3.0)), 4.0);
+///
+/// let st_dev = vwap.get_st_dev();
+/// assert_eq!(st_dev, ValueType::sqrt(3.0));
+/// assert_eq!(vwap.get_bands(), Some((4.0 + 2.0 * st_dev, 4.0 - 2.0 * st_dev)));
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`VWAP`](crate::methods::VWAP), [`VWMA`](crate::methods::VWMA), [`VWStDev`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingVWAP<P: Period = PeriodType> {
+	k: Option<ValueType>,
+	st_dev: ValueType,
+	vw_st_dev: VWStDev<P>,
+}
+
+impl RollingVWAP {
+	/// Creates new instance of `RollingVWAP` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: (ValueType, ValueType)) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> RollingVWAP<P> {
+	/// Enables bands of `k` volume weighted standard deviations around `VWAP`
+	///
+	/// `k` should be > `0.0`.
+	pub fn with_bands(self, k: ValueType) -> Result<Self, Error> {
+		if k > 0.0 {
+			Ok(Self { k: Some(k), ..self })
+		} else {
+			Err(Error::wrong_method_parameter("k", "> 0", k))
+		}
+	}
+
+	/// Returns current volume weighted standard deviation of the prices
+	#[must_use]
+	pub const fn get_st_dev(&self) -> ValueType {
+		self.st_dev
+	}
+
+	/// Returns current (`upper`, `lower`) bands or `None`, if bands are not enabled
+	#[must_use]
+	pub fn get_bands(&self) -> Option<(ValueType, ValueType)> {
+		let mean = self.vw_st_dev.get_mean();
+
+		self.k
+			.map(|k| (k.mul_add(self.st_dev, mean), k.mul_add(-self.st_dev, mean)))
+	}
+}
+
+impl<P: Period> Method<'_> for RollingVWAP<P> {
+	type Params = P;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			k: None,
+			st_dev: 0.0,
+			vw_st_dev: Method::new(length, value)?,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.vw_st_dev.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.st_dev = self.vw_st_dev.next(value);
+		self.vw_st_dev.get_mean()
+	}
+}
+
+impl<P: Period> Checkpoint for RollingVWAP<P> {
+	type State = (ValueType, <VWStDev<P> as Checkpoint>::State);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.st_dev, self.vw_st_dev.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.st_dev = state.0;
+		self.vw_st_dev.restore(&state.1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, RollingVWAP as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{VWStDev, VWMA};
+
+	#[test]
+	fn test_rolling_vwap_const() {
+		for i in 2..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, (i as ValueType) * 3.3);
+			let mut method = TestingMethod::new(i, input)
+				.unwrap()
+				.with_bands(2.0)
+				.unwrap();
+
+			test_const_float(&mut method, input, input.0);
+
+			assert_eq_float(0.0, method.get_st_dev());
+		}
+	}
+
+	#[test]
+	fn test_rolling_vwap() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<(ValueType, ValueType)> = candles
+			.take(300)
+			.map(|x| (x.close, x.volume.abs()))
+			.collect();
+
+		for length in 2..255 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let k: ValueType = 1.5;
+			let mut banded = TestingMethod::new(length, src[0])
+				.unwrap()
+				.with_bands(k)
+				.unwrap();
+			let mut vwma = VWMA::new(length, src[0]).unwrap();
+			let mut vw_st_dev = VWStDev::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				let value = method.next(x);
+				let st_dev = vw_st_dev.next(x);
+
+				assert_eq_float(vwma.next(x), value);
+				assert_eq_float(value, banded.next(x));
+				assert_eq_float(st_dev, banded.get_st_dev());
+				assert!(method.get_bands().is_none());
+
+				let (upper, lower) = banded.get_bands().unwrap();
+				assert_eq_float(k.mul_add(st_dev, value), upper);
+				assert_eq_float(k.mul_add(-st_dev, value), lower);
+			}
+		}
+
+		assert!(TestingMethod::new(1, src[0]).is_err());
+		assert!(TestingMethod::new(10, src[0])
+			.unwrap()
+			.with_bands(0.0)
+			.is_err());
+	}
+}
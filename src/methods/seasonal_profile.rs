@@ -0,0 +1,277 @@
+use crate::core::{Checkpoint, Error, Method, ValueType, OHLCV};
+use std::mem::size_of;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Intraday seasonality profile, which accumulates statistics of returns and volumes for every time-of-day bucket
+///
+/// Candles in this crate do not carry timestamps, so the bucket index is provided along with every candle.
+/// Bucket index is taken modulo `buckets`, so it is enough to pass `timestamp / bucket_duration`,
+/// when `buckets` is the count of buckets per day (e.g. `timestamp_in_minutes / 5` and `288` buckets of 5 minutes).
+///
+/// For every candle returns [z-scores](https://en.wikipedia.org/wiki/Standard_score) of its return (relative change of `close`)
+/// and its volume against all the previous candles of the same bucket, so the current candle does not affect its own score.
+/// Volume z-score is useful as a relative volume filter, which takes into account usual intraday volume distribution.
+///
+/// Z-score is `0.0` until the bucket has at least `2` previous candles or when all the previous values in the bucket are the same.
+///
+/// Candles without volume are considered as candles with zero volume.
+///
+/// # Parameters
+///
+/// Has a single parameter `buckets`: [`usize`]
+///
+/// `buckets` should be > `0`
+///
+/// # Input type
+///
+/// Input type is (`bucket`: [`usize`], `candle`: [`OHLCV`])
+///
+/// # Output type
+///
+/// Output type is (`return_z_score`: [`ValueType`], `volume_z_score`: [`ValueType`])
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, ValueType};
+/// use yata::methods::SeasonalProfile;
+///
+/// let candle = |close: ValueType, volume: ValueType| Candle { close, volume, ..Candle::default() };
+///
+/// // 2 buckets per "day"
+/// let mut profile = SeasonalProfile::new(2, (0, &candle(10.0, 100.0))).unwrap();
+///
+/// for day in 0..3 {
+///     profile.next((day * 2, &candle(10.0, 100.0 + day as ValueType)));
+///     profile.next((day * 2 + 1, &candle(10.0, 10.0 + day as ValueType)));
+/// }
+///
+/// assert_eq!(profile.mean_volume(0), 101.0);
+/// assert_eq!(profile.mean_volume(1), 11.0);
+///
+/// // volume is usual for the first bucket
+/// let (_, volume_z_score) = profile.next((6, &candle(10.0, 101.0)));
+/// assert_eq!(volume_z_score, 0.0);
+///
+/// // but it is much higher than usual for the second one
+/// let (_, volume_z_score) = profile.next((7, &candle(10.0, 101.0)));
+/// assert_eq!(volume_z_score, 90.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`OHLCV`]: crate::core::OHLCV
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeasonalProfile {
+	prev_close: ValueType,
+	returns: Vec<Stats>,
+	volumes: Vec<Stats>,
+}
+
+impl SeasonalProfile {
+	/// Returns count of buckets
+	#[must_use]
+	pub const fn buckets(&self) -> usize {
+		self.returns.len()
+	}
+
+	/// Returns average return of the `bucket`
+	#[must_use]
+	pub fn mean_return(&self, bucket: usize) -> ValueType {
+		self.returns[bucket % self.buckets()].mean
+	}
+
+	/// Returns average volume of the `bucket`
+	#[must_use]
+	pub fn mean_volume(&self, bucket: usize) -> ValueType {
+		self.volumes[bucket % self.buckets()].mean
+	}
+}
+
+impl<'a> Method<'a> for SeasonalProfile {
+	type Params = usize;
+	type Input = (usize, &'a dyn OHLCV);
+	type Output = (ValueType, ValueType);
+
+	fn new(buckets: Self::Params, (_, candle): Self::Input) -> Result<Self, Error> {
+		match buckets {
+			0 => Err(Error::wrong_method_parameter("buckets", "> 0", buckets)),
+			buckets => Ok(Self {
+				prev_close: candle.close(),
+				returns: vec![Stats::default(); buckets],
+				volumes: vec![Stats::default(); buckets],
+			}),
+		}
+	}
+
+	fn heap_size(&self) -> usize {
+		(self.returns.capacity() + self.volumes.capacity()) * size_of::<Stats>()
+	}
+
+	#[inline]
+	fn next(&mut self, (bucket, candle): Self::Input) -> Self::Output {
+		let bucket = bucket % self.buckets();
+
+		let ret = if self.prev_close == 0. {
+			0.
+		} else {
+			candle.close() / self.prev_close - 1.
+		};
+		self.prev_close = candle.close();
+
+		let returns = &mut self.returns[bucket];
+		let return_z_score = returns.z_score(ret);
+		returns.push(ret);
+
+		let volumes = &mut self.volumes[bucket];
+		let volume = candle.volume_or_zero();
+		let volume_z_score = volumes.z_score(volume);
+		volumes.push(volume);
+
+		(return_z_score, volume_z_score)
+	}
+}
+
+impl Checkpoint for SeasonalProfile {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.clone()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.clone_from(state);
+	}
+}
+
+/// Running mean and variance by Welford's algorithm
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Stats {
+	count: ValueType,
+	mean: ValueType,
+	m2: ValueType,
+}
+
+impl Stats {
+	fn push(&mut self, value: ValueType) {
+		self.count += 1.;
+
+		let delta = value - self.mean;
+		self.mean += delta / self.count;
+		self.m2 = delta.mul_add(value - self.mean, self.m2);
+	}
+
+	fn z_score(&self, value: ValueType) -> ValueType {
+		if self.count < 2. || self.m2 <= 0. {
+			return 0.;
+		}
+
+		let std_dev = (self.m2 / (self.count - 1.)).sqrt();
+
+		(value - self.mean) / std_dev
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SeasonalProfile as TestingMethod, OHLCV};
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	fn z_score(history: &[ValueType], value: ValueType) -> ValueType {
+		if history.len() < 2 {
+			return 0.;
+		}
+
+		let n = history.len() as ValueType;
+		let mean = history.iter().sum::<ValueType>() / n;
+		let variance = history
+			.iter()
+			.map(|x| (x - mean).powi(2))
+			.sum::<ValueType>()
+			/ (n - 1.);
+
+		if variance > 0. {
+			(value - mean) / variance.sqrt()
+		} else {
+			0.
+		}
+	}
+
+	#[test]
+	fn test_seasonal_profile_const() {
+		let candle = RandomCandles::default().first();
+
+		for buckets in 1..50 {
+			let mut method = TestingMethod::new(buckets, (0, &candle)).unwrap();
+
+			for i in 0..200 {
+				assert_eq!(method.next((i, &candle)), (0., 0.));
+			}
+
+			assert_eq_float(candle.volume(), method.mean_volume(0));
+			assert_eq_float(0., method.mean_return(buckets - 1));
+		}
+	}
+
+	#[test]
+	fn test_seasonal_profile() {
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+
+		for buckets in 1..30 {
+			let mut method = TestingMethod::new(buckets, (0, &candles[0])).unwrap();
+			let mut returns = vec![Vec::new(); buckets];
+			let mut volumes = vec![Vec::new(); buckets];
+			let mut prev_close = candles[0].close();
+
+			for (i, candle) in candles.iter().enumerate() {
+				let bucket = i % buckets;
+				let ret = candle.close() / prev_close - 1.;
+				prev_close = candle.close();
+
+				let (return_z_score, volume_z_score) = method.next((i + 7 * buckets, candle));
+
+				assert_eq_float(z_score(&returns[bucket], ret), return_z_score);
+				assert_eq_float(z_score(&volumes[bucket], candle.volume()), volume_z_score);
+
+				returns[bucket].push(ret);
+				volumes[bucket].push(candle.volume());
+			}
+		}
+
+		assert!(TestingMethod::new(0, (0, &candles[0])).is_err());
+	}
+
+	#[test]
+	fn test_seasonal_profile_no_volume() {
+		let candle = Candle {
+			close: 10.0,
+			volume: 100.0,
+			..Candle::default()
+		};
+
+		let mut method = TestingMethod::new(1, (0, &candle)).unwrap();
+
+		method.next((0, &candle));
+		method.next((0, &candle.without_volume()));
+		assert_eq_float(50.0, method.mean_volume(0));
+
+		for _ in 0..5 {
+			let (return_z_score, volume_z_score) = method.next((0, &candle));
+			assert!(return_z_score.is_finite());
+			assert!(volume_z_score.is_finite());
+		}
+
+		assert!(method.mean_volume(0).is_finite());
+	}
+}
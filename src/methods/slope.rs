@@ -0,0 +1,206 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::LinReg;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Normalized slope of the [linear regression](crate::methods::LinReg) line for last `length` values of timeseries of type [`ValueType`]
+///
+/// By default returns the slope divided by the current linear regression value, e.g. `0.01` means the line is rising by `1%` per bar.
+/// When `length` is `2`, it is just the relative change of the series over the last bar.
+///
+/// When angle mode is set by [`with_angle`](Slope::with_angle), returns the slope angle in degrees instead,
+/// which is calculated for the given `price_per_bar` chart scale.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Slope;
+///
+/// let mut slope = Slope::new(3, 100.0).unwrap();
+/// slope.next(102.0);
+/// slope.next(104.0);
+///
+/// assert_eq!(slope.next(106.0), 2.0 / 106.0);
+///
+/// // angle for the scale of 2.0 price units per bar
+/// let mut angle = Slope::new(3, 100.0).unwrap().with_angle(2.0).unwrap();
+/// angle.next(102.0);
+/// angle.next(104.0);
+///
+/// assert!((angle.next(106.0) - 45.0).abs() < 1e-9);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`LinReg`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Slope<P: Period = PeriodType> {
+	lin_reg: LinReg<P>,
+	price_per_bar: Option<ValueType>,
+}
+
+impl Slope {
+	/// Creates new instance of `Slope` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Slope<P> {
+	/// Switches output to the slope angle in degrees for the chart scale of `price_per_bar` price units per bar.
+	///
+	/// `price_per_bar` should be > `0`
+	pub fn with_angle(self, price_per_bar: ValueType) -> Result<Self, Error> {
+		if price_per_bar > 0. && price_per_bar.is_finite() {
+			Ok(Self {
+				price_per_bar: Some(price_per_bar),
+				..self
+			})
+		} else {
+			Err(Error::wrong_method_parameter(
+				"price_per_bar",
+				"> 0",
+				price_per_bar,
+			))
+		}
+	}
+
+	/// Returns a reference to the inner [`LinReg`]
+	#[must_use]
+	pub const fn lin_reg(&self) -> &LinReg<P> {
+		&self.lin_reg
+	}
+}
+
+impl<P: Period> Method<'_> for Slope<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			lin_reg: Method::new(length, value)?,
+			price_per_bar: None,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.lin_reg.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let current = self.lin_reg.next(value);
+		let tan = self.lin_reg.tan();
+
+		match self.price_per_bar {
+			Some(price_per_bar) => (tan / price_per_bar).atan().to_degrees(),
+			None if current == 0. => 0.,
+			None => tan / current.abs(),
+		}
+	}
+}
+
+impl<P: Period> Checkpoint for Slope<P> {
+	type State = <LinReg<P> as Checkpoint>::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.lin_reg.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.lin_reg.restore(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Slope as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::LinReg;
+
+	#[test]
+	fn test_slope_const() {
+		for i in 2..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_slope_linear() {
+		for i in 2..255 {
+			let mut method = TestingMethod::new(i, 10.0).unwrap();
+			let mut angle = TestingMethod::new(i, 10.0)
+				.unwrap()
+				.with_angle(0.5)
+				.unwrap();
+
+			let mut value = 10.0;
+
+			for _ in 0..i {
+				value += 0.5;
+				method.next(value);
+				angle.next(value);
+			}
+
+			value += 0.5;
+			assert_eq_float(0.5 / value, method.next(value));
+			assert_eq_float(45.0, angle.next(value));
+		}
+	}
+
+	#[test]
+	fn test_slope() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 2..255 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let mut lin_reg = LinReg::new(length, src[0]).unwrap();
+
+			for &x in &src {
+				let current = lin_reg.next(x);
+
+				assert_eq_float(lin_reg.tan() / current.abs(), method.next(x));
+			}
+		}
+
+		assert!(TestingMethod::new(2, src[0])
+			.unwrap()
+			.with_angle(0.0)
+			.is_err());
+	}
+}
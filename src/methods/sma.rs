@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -34,6 +34,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// assert_eq!(sma.next(3.0), 2.0);
 /// assert_eq!(sma.next(4.0), 3.0);
+///
+/// // long lookback SMA of `u32` length at the same time
+/// let mut long_sma = SMA::<u32>::new(1000, 1.0).unwrap();
+/// assert_eq!(long_sma.next(1.0), 1.0);
 /// ```
 ///
 /// # Performance
@@ -42,20 +46,28 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SMA {
+pub struct SMA<P: Period = PeriodType> {
 	divider: ValueType,
 	value: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
 impl SMA {
+	/// Creates new instance of `SMA` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> SMA<P> {
 	/// Returns inner [`Window`](crate::core::Window). Useful for implementing in other methods and indicators.
 	#[inline]
 	#[must_use]
-	pub const fn get_window(&self) -> &Window<ValueType> {
+	pub const fn get_window(&self) -> &Window<ValueType, P> {
 		&self.window
 	}
 
@@ -74,20 +86,25 @@ impl SMA {
 	}
 }
 
-impl Method<'_> for SMA {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for SMA<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				divider: (length as ValueType).recip(),
-				value,
-				window: Window::new(length, value),
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			divider: (length.to_usize() as ValueType).recip(),
+			value,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -99,6 +116,21 @@ impl Method<'_> for SMA {
 	}
 }
 
+impl<P: Period> Checkpoint for SMA<P> {
+	type State = (ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.value, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.value = state.0;
+		self.window.restore(&state.1);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Method, SMA as TestingMethod};
@@ -154,4 +186,25 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_sma_period_types() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(1500).map(|x| x.close).collect();
+
+		let mut short = TestingMethod::new(200, src[0]).unwrap();
+		let mut wide = TestingMethod::<u32>::new(200, src[0]).unwrap();
+		let mut long = TestingMethod::<u32>::new(1000, src[0]).unwrap();
+
+		src.iter().enumerate().for_each(|(i, &x)| {
+			assert_eq_float(short.next(x), wide.next(x));
+
+			let value = long.next(x);
+			if i >= 999 {
+				let sum: ValueType = src[i - 999..=i].iter().sum();
+				assert_eq_float(sum / 1000., value);
+			}
+		});
+	}
 }
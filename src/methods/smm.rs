@@ -1,6 +1,6 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
-use std::{cmp::Ordering, slice::SliceIndex};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+use std::{cmp::Ordering, mem::size_of_val, slice::SliceIndex};
 
 #[cfg(feature = "serde")]
 use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
@@ -77,7 +77,7 @@ fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> u
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -113,19 +113,27 @@ fn find_insert_index(value: ValueType, slice: &[ValueType], padding: usize) -> u
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
-pub struct SMM {
-	half: PeriodType,
-	half_m1: PeriodType,
-	window: Window<ValueType>,
+pub struct SMM<P: Period = PeriodType> {
+	half: usize,
+	half_m1: usize,
+	window: Window<ValueType, P>,
 	slice: Box<[ValueType]>,
 }
 
 impl SMM {
+	/// Creates new instance of `SMM` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> SMM<P> {
 	/// Returns inner [`Window`](crate::core::Window). Useful for implementing in other methods and indicators.
 	#[inline]
 	#[must_use]
-	pub const fn get_window(&self) -> &Window<ValueType> {
+	pub const fn get_window(&self) -> &Window<ValueType, P> {
 		&self.window
 	}
 
@@ -133,45 +141,12 @@ impl SMM {
 	#[inline]
 	#[must_use]
 	pub fn get_last_value(&self) -> ValueType {
-		(get(&self.slice, self.half as usize) + get(&self.slice, self.half_m1 as usize)) * 0.5
-	}
-}
-
-impl Method<'_> for SMM {
-	type Params = PeriodType;
-	type Input = ValueType;
-	type Output = Self::Input;
-
-	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		if !value.is_finite() {
-			return Err(Error::InvalidCandles);
-		}
-
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => {
-				let half = length / 2;
-
-				let is_even = length % 2 == 0;
-				Ok(Self {
-					half,
-					half_m1: half.saturating_sub(is_even as PeriodType),
-					window: Window::new(length, value),
-					slice: vec![value; length as usize].into(),
-				})
-			}
-		}
+		(get(&self.slice, self.half) + get(&self.slice, self.half_m1)) * 0.5
 	}
 
+	// replaces `old_value` by `value` in the sorted slice
 	#[inline]
-	fn next(&mut self, value: Self::Input) -> Self::Output {
-		assert!(
-			value.is_finite(),
-			"SMM method cannot operate with NAN values"
-		);
-
-		let old_value = self.window.push(value);
-
+	fn replace(&mut self, old_value: ValueType, value: ValueType) {
 		let old_index = find_index(old_value, &self.slice, 0);
 		let index = find_insert_index(value, &self.slice, 0);
 
@@ -213,13 +188,73 @@ impl Method<'_> for SMM {
 			// inserting new value
 			self.slice[index] = value;
 		}
+	}
+}
+
+impl<P: Period> Method<'_> for SMM<P> {
+	type Params = P;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
+		}
+
+		let length2 = length.to_usize();
+		let half = length2 / 2;
+
+		let is_even = length2 % 2 == 0;
+		Ok(Self {
+			half,
+			half_m1: half.saturating_sub(is_even as usize),
+			window: Window::with_size(length, value),
+			slice: vec![value; length2].into(),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size() + size_of_val(&*self.slice)
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		assert!(
+			value.is_finite(),
+			"SMM method cannot operate with NAN values"
+		);
+
+		let old_value = self.window.push(value);
+		self.replace(old_value, value);
 
 		self.get_last_value()
 	}
 }
 
+impl<P: Period> Checkpoint for SMM<P> {
+	type State = WindowCheckpoint<ValueType, P>;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.window.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		if let Some((pushed, overwritten)) = self.window.pushed_since(state) {
+			self.replace(pushed, overwritten);
+		}
+
+		self.window.restore(state);
+	}
+}
+
 #[cfg(feature = "serde")]
-impl Serialize for SMM {
+impl<P: Period + Serialize> Serialize for SMM<P> {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
@@ -231,17 +266,17 @@ impl Serialize for SMM {
 }
 
 #[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for SMM {
+impl<'de, P: Period + Deserialize<'de>> Deserialize<'de> for SMM<P> {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
 		#[derive(Deserialize)]
-		struct DeserializedSMM {
-			window: Window<ValueType>,
+		struct DeserializedSMM<P: Period> {
+			window: Window<ValueType, P>,
 		}
 
-		let de = DeserializedSMM::deserialize(deserializer)?;
+		let de = DeserializedSMM::<P>::deserialize(deserializer)?;
 
 		let window = de.window;
 
@@ -264,12 +299,13 @@ impl<'de> Deserialize<'de> for SMM {
 			return Err(serde::de::Error::custom("SMM cannot operate NaN values"));
 		}
 
-		let half = window.len() / 2;
-		let is_even = window.len() % 2 == 0;
+		let length = window.len().to_usize();
+		let half = length / 2;
+		let is_even = length % 2 == 0;
 
 		let smm = Self {
 			half,
-			half_m1: half.saturating_sub(is_even as PeriodType),
+			half_m1: half.saturating_sub(is_even as usize),
 			window,
 			slice,
 		};
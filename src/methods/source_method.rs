@@ -0,0 +1,169 @@
+use crate::core::{Checkpoint, Error, Method, Peekable, Source, ValueType, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapts any method over [`ValueType`] timeseries to take [`OHLCV`] as input
+///
+/// Extracts configured [`Source`] value from each candle and passes it to the inner method,
+/// so pipelines over candles do not need to call [`OHLCV::source`] at every stage.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters (`parameters`: `M::Params`, `source`: [`Source`])
+///
+/// `parameters` are passed to the inner method as is.
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is `M::Output`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{Candle, Source};
+/// use yata::methods::{SourceMethod, SMA};
+///
+/// let candles = [
+///     Candle { open: 1.0, high: 3.0, low: 1.0, close: 2.0, volume: 1.0 },
+///     Candle { open: 2.0, high: 5.0, low: 1.0, close: 4.0, volume: 1.0 },
+/// ];
+///
+/// let mut sma_high = SourceMethod::<SMA>::new((2, Source::High), &candles[0]).unwrap();
+///
+/// assert_eq!(sma_high.next(&candles[0]), 3.0);
+/// assert_eq!(sma_high.next(&candles[1]), 4.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) plus performance of the inner method
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`OHLCV::source`]: crate::core::OHLCV::source
+/// [`Source`]: crate::core::Source
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceMethod<M> {
+	method: M,
+	source: Source,
+}
+
+impl<M> SourceMethod<M> {
+	/// Returns a reference to the inner method
+	pub const fn inner(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns configured [`Source`](crate::core::Source)
+	pub const fn source(&self) -> Source {
+		self.source
+	}
+}
+
+impl<'a, M> Method<'a> for SourceMethod<M>
+where
+	M: Method<'a, Input = ValueType>,
+{
+	type Params = (M::Params, Source);
+	type Input = &'a dyn OHLCV;
+	type Output = M::Output;
+
+	fn new((parameters, source): Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(parameters, candle.source(source))?,
+			source,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.method.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		self.method.next(candle.source(self.source))
+	}
+}
+
+impl<M, V> Peekable<V> for SourceMethod<M>
+where
+	M: for<'b> Method<'b, Input = ValueType, Output = V> + Clone,
+{
+	#[inline]
+	fn peek<T: OHLCV>(&self, candle: &T) -> V {
+		self.method.clone().next(candle.source(self.source))
+	}
+}
+
+impl<M: Checkpoint> Checkpoint for SourceMethod<M> {
+	type State = M::State;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		self.method.save()
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.method.restore(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SourceMethod};
+	use crate::core::{Candle, Peekable, Source, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{EMA, SMA};
+
+	#[test]
+	fn test_source_method_const() {
+		let input = RandomCandles::default().first();
+
+		for length in 1..255 {
+			let mut method = SourceMethod::<SMA>::new((length, Source::HL2), &input).unwrap();
+			let output = method.next(&input);
+
+			test_const(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_source_method() {
+		let candles: Vec<Candle> = RandomCandles::default().take(300).collect();
+		let sources = [
+			Source::Open,
+			Source::High,
+			Source::Low,
+			Source::Close,
+			Source::HL2,
+			Source::TP,
+			Source::Volume,
+		];
+
+		for &source in &sources {
+			for length in 1..100 {
+				let mut adapted = SourceMethod::<EMA>::new((length, source), &candles[0]).unwrap();
+				let mut method = EMA::new(length, candles[0].source(source)).unwrap();
+
+				for candle in &candles {
+					adapted.peek(&candles[0]);
+
+					let peeked = adapted.peek(candle);
+					let value = adapted.next(candle);
+
+					assert_eq_float(method.next(candle.source(source)), value);
+					assert_eq!(peeked.to_bits(), value.to_bits());
+				}
+			}
+		}
+	}
+}
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,10 +8,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `1`
 ///
+/// By default calculates sample standard deviation (divides by `length - 1`).
+/// Population standard deviation (divides by `length`) may be set by [`with_population`](StDev::with_population).
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -20,6 +23,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// Rolling mean, which is the same as [`SMA`](crate::methods::SMA) of the same `length`, is available by [`get_mean`](StDev::get_mean).
+///
 /// # Examples
 ///
 /// ```
@@ -34,6 +39,17 @@ use serde::{Deserialize, Serialize};
 ///
 /// assert_eq!(stdev.next(3.0), 1.0);
 /// assert_eq!(stdev.next(4.0), 1.0);
+/// assert_eq!(stdev.get_mean(), 3.0);
+/// ```
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::StDev;
+///
+/// // population StDev over the window with length=2
+/// let mut stdev = StDev::new(2, 1.0).unwrap().with_population(true);
+///
+/// assert_eq!(stdev.next(3.0), 1.0);
 /// ```
 ///
 /// # Performance
@@ -42,42 +58,70 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct StDev {
+pub struct StDev<P: Period = PeriodType> {
 	mean: ValueType,
 	val_sum: ValueType,
 	sq_val_sum: ValueType,
 	divider: ValueType,
 	k: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
+}
+
+impl StDev {
+	/// Creates new instance of `StDev` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
 }
 
-impl Method<'_> for StDev {
-	type Params = PeriodType;
+impl<P: Period> StDev<P> {
+	/// Switches between population (`true`) and sample (`false`) standard deviation
+	#[must_use]
+	pub fn with_population(self, population: bool) -> Self {
+		let length = self.window.len().to_usize() as ValueType;
+		let k = if population { length } else { length - 1. }.recip();
+
+		Self { k, ..self }
+	}
+
+	/// Returns current rolling mean
+	#[must_use]
+	pub fn get_mean(&self) -> ValueType {
+		-self.mean
+	}
+}
+
+impl<P: Period> Method<'_> for StDev<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 | 1 => Err(Error::WrongMethodParameters),
-			length => {
-				let k = ((length - 1) as ValueType).recip();
-
-				let float_length = length as ValueType;
-				let mean = -value;
-				let divider = -float_length.recip();
-
-				Ok(Self {
-					mean,
-					val_sum: value * float_length,
-					sq_val_sum: value * value * float_length,
-					divider,
-					k,
-					window: Window::new(length, value),
-				})
-			}
+		if length.to_usize() < 2 {
+			return Err(Error::wrong_method_parameter("length", "> 1", length));
 		}
+
+		let k = ((length.to_usize() - 1) as ValueType).recip();
+
+		let float_length = length.to_usize() as ValueType;
+		let mean = -value;
+		let divider = -float_length.recip();
+
+		Ok(Self {
+			mean,
+			val_sum: value * float_length,
+			sq_val_sum: value * value * float_length,
+			divider,
+			k,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -100,6 +144,28 @@ impl Method<'_> for StDev {
 	}
 }
 
+impl<P: Period> Checkpoint for StDev<P> {
+	type State = (
+		ValueType,
+		ValueType,
+		ValueType,
+		WindowCheckpoint<ValueType, P>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.mean, self.val_sum, self.sq_val_sum, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.mean = state.0;
+		self.val_sum = state.1;
+		self.sq_val_sum = state.2;
+		self.window.restore(&state.3);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
@@ -107,6 +173,7 @@ mod tests {
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const_float;
+	use crate::methods::SMA;
 
 	#[test]
 	fn test_st_dev_const() {
@@ -155,4 +222,34 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_st_dev_population() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(2..255).for_each(|ma_length| {
+			let mut ma = TestingMethod::new(ma_length, src[0])
+				.unwrap()
+				.with_population(true);
+			let mut sma = SMA::new(ma_length, src[0]).unwrap();
+			let ma_length = ma_length as usize;
+
+			src.iter().enumerate().for_each(|(i, &x)| {
+				let avg = (0..ma_length)
+					.map(|j| src[i.saturating_sub(j)])
+					.sum::<ValueType>()
+					/ ma_length as ValueType;
+
+				let diff_sq_sum = (0..ma_length)
+					.map(|j| (src[i.saturating_sub(j)] - avg).powi(2))
+					.sum::<ValueType>()
+					/ ma_length as ValueType;
+
+				assert_eq_float(diff_sq_sum.sqrt(), ma.next(x));
+				assert_eq!(sma.next(x).to_bits(), ma.get_mean().to_bits());
+			});
+		});
+	}
 }
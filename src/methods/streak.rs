@@ -0,0 +1,138 @@
+use crate::core::{Checkpoint, Method};
+use crate::core::{Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Counts consecutive rising or falling values of timeseries of type [`ValueType`]
+///
+/// Returns positive streak length when values are rising and negative streak length when values are falling.
+/// When the value is equal to the previous one, the streak is reset and `0` is returned.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`i32`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::Streak;
+///
+/// let values = [1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 2.0, 3.0];
+/// let r      = [ 0,   1,   2,   3,  -1,  -2,   0,   1 ];
+///
+/// let mut streak = Streak::new((), values[0]).unwrap();
+///
+/// for i in 0..values.len() {
+///     assert_eq!(streak.next(values[i]), r[i]);
+/// }
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Derivative`](crate::methods::Derivative)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Streak {
+	last_value: ValueType,
+	streak: i32,
+}
+
+impl Method<'_> for Streak {
+	type Params = ();
+	type Input = ValueType;
+	type Output = i32;
+
+	fn new((): Self::Params, value: Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			last_value: value,
+			streak: 0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		self.streak = if value > self.last_value {
+			self.streak.max(0).saturating_add(1)
+		} else if value < self.last_value {
+			self.streak.min(0).saturating_sub(1)
+		} else {
+			0
+		};
+
+		self.last_value = value;
+
+		self.streak
+	}
+}
+
+impl Checkpoint for Streak {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Streak as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::RandomCandles;
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_streak_const() {
+		let input = 123.456;
+		let mut method = TestingMethod::new((), input).unwrap();
+
+		test_const(&mut method, input, 0);
+	}
+
+	#[test]
+	fn test_streak() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let mut method = TestingMethod::new((), src[0]).unwrap();
+
+		for i in 0..src.len() {
+			let mut expected = 0;
+
+			for j in (1..=i).rev() {
+				let delta = src[j] - src[j - 1];
+
+				if delta > 0. && expected >= 0 {
+					expected += 1;
+				} else if delta < 0. && expected <= 0 {
+					expected -= 1;
+				} else {
+					break;
+				}
+			}
+
+			assert_eq!(method.next(src[i]), expected);
+		}
+	}
+}
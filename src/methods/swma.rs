@@ -1,4 +1,5 @@
-use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use crate::core::Period;
+use crate::core::{Checkpoint, Error, Method, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,7 +12,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -33,58 +34,66 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 ///
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SWMA {
+pub struct SWMA<P: Period = PeriodType> {
 	right_total: ValueType,
 	right_float_length: ValueType,
-	right_window: Window<ValueType>,
+	right_window: Window<ValueType, P>,
 
 	left_total: ValueType,
 	left_float_length: ValueType,
-	left_window: Window<ValueType>,
+	left_window: Window<ValueType, P>,
 
 	invert_sum: ValueType,
 	numerator: ValueType,
 }
 
-impl Method<'_> for SWMA {
-	type Params = PeriodType;
+impl SWMA {
+	/// Creates new instance of `SWMA` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for SWMA<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => {
-				let left_length = (length + 1) / 2;
-				let right_length = length / 2;
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
+		}
 
-				let right_length2 = right_length as usize;
-				let left_length2 = left_length as usize;
+		let right_length2 = length.to_usize() / 2;
+		let left_length2 = (length.to_usize() + 1) / 2;
 
-				let sum = ((left_length2 * (left_length2 + 1)) / 2
-					+ (right_length2 * (right_length2 + 1) / 2)) as ValueType;
+		let sum = ((left_length2 * (left_length2 + 1)) / 2
+			+ (right_length2 * (right_length2 + 1) / 2)) as ValueType;
 
-				let right_float_length = -(right_length as ValueType);
-				let left_float_length = left_length as ValueType;
+		let right_float_length = -(right_length2 as ValueType);
+		let left_float_length = left_length2 as ValueType;
 
-				Ok(Self {
-					left_total: -value * left_length2 as ValueType,
-					left_float_length,
-					left_window: Window::new(left_length, value),
+		Ok(Self {
+			left_total: -value * left_length2 as ValueType,
+			left_float_length,
+			left_window: Window::with_size(P::from_usize(left_length2), value),
 
-					right_total: value * right_length2 as ValueType,
-					right_float_length,
-					right_window: Window::new(right_length, value),
+			right_total: value * right_length2 as ValueType,
+			right_float_length,
+			right_window: Window::with_size(P::from_usize(right_length2), value),
 
-					invert_sum: sum.recip(),
+			invert_sum: sum.recip(),
 
-					numerator: value * sum,
-				})
-			}
-		}
+			numerator: value * sum,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.right_window.heap_size() + self.left_window.heap_size()
 	}
 
 	#[inline]
@@ -106,6 +115,36 @@ impl Method<'_> for SWMA {
 	}
 }
 
+impl<P: Period> Checkpoint for SWMA<P> {
+	type State = (
+		ValueType,
+		ValueType,
+		ValueType,
+		WindowCheckpoint<ValueType, P>,
+		WindowCheckpoint<ValueType, P>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.right_total,
+			self.left_total,
+			self.numerator,
+			self.right_window.save(),
+			self.left_window.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.right_total = state.0;
+		self.left_total = state.1;
+		self.numerator = state.2;
+		self.right_window.restore(&state.3);
+		self.left_window.restore(&state.4);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
@@ -1,4 +1,4 @@
-use crate::core::{Error, Method, ValueType, OHLCV};
+use crate::core::{Checkpoint, Error, Method, ValueType, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -66,6 +66,20 @@ impl<'a> Method<'a> for TR {
 	}
 }
 
+impl Checkpoint for TR {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Method, OHLCV, TR as TestingMethod};
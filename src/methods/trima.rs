@@ -1,4 +1,4 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method, Period};
 use crate::core::{Error, PeriodType, ValueType};
 use crate::methods::SMA;
 
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -43,32 +43,59 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct TRIMA {
-	sma1: SMA,
-	sma2: SMA,
+pub struct TRIMA<P: Period = PeriodType> {
+	sma1: SMA<P>,
+	sma2: SMA<P>,
 }
 
-impl Method<'_> for TRIMA {
-	type Params = PeriodType;
+impl TRIMA {
+	/// Creates new instance of `TRIMA` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for TRIMA<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		Ok(Self {
-			sma1: SMA::new(length, value)?,
-			sma2: SMA::new(length, value)?,
+			sma1: Method::new(length, value)?,
+			sma2: Method::new(length, value)?,
 		})
 	}
 
+	fn heap_size(&self) -> usize {
+		self.sma1.heap_size() + self.sma2.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.sma2.next(self.sma1.next(value))
 	}
 }
 
+impl<P: Period> Checkpoint for TRIMA<P> {
+	type State = (<SMA<P> as Checkpoint>::State, <SMA<P> as Checkpoint>::State);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.sma1.save(), self.sma2.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sma1.restore(&state.0);
+		self.sma2.restore(&state.1);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{Method, TRIMA as TestingMethod};
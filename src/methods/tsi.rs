@@ -1,4 +1,4 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method};
 use crate::core::{Error, PeriodType, ValueType};
 use crate::methods::EMA;
 
@@ -13,6 +13,9 @@ use serde::{Deserialize, Serialize};
 ///        EMA(EMA(ABS(momentum_1), long_period), short_period)
 /// ```
 ///
+/// By default both smoothing stages use [`EMA`], but any other regular method may be used instead:
+/// `M1` is a long period smoothing method and `M2` is a short period smoothing method.
+///
 /// # Parameters
 ///
 /// Tuple of \(`short_length`, `long_length`\) \([`PeriodType`], [`PeriodType`]\)
@@ -25,6 +28,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// Double smoothed momentum (the numerator) is available by [`get_smoothed_momentum`](TSI::get_smoothed_momentum).
+///
 /// # Examples
 ///
 /// ```
@@ -41,24 +46,40 @@ use serde::{Deserialize, Serialize};
 /// println!("{}", tsi.next(12.0));
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{TSI, SMA, WMA};
+///
+/// // TSI with WMA for long period smoothing and SMA for short period smoothing
+/// let mut tsi = TSI::<WMA, SMA>::new((3, 10), 3.0).unwrap();
+///
+/// tsi.next(3.0);
+/// tsi.next(6.0);
+///
+/// println!("{}", tsi.next(9.0));
+/// println!("{}", tsi.get_smoothed_momentum());
+/// ```
+///
 /// # Performance
 ///
-/// O\(1\)
+/// O\(1\) plus performance of the smoothing methods
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`EMA`]: crate::methods::EMA
 #[derive(Debug, Clone, Copy)]
 #[doc(alias = "TrueStrengthIndex")]
 #[doc(alias = "True")]
 #[doc(alias = "Strength")]
 #[doc(alias = "Index")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct TSI {
+pub struct TSI<M1 = EMA, M2 = EMA> {
 	last_value: ValueType,
-	ema11: EMA,
-	ema12: EMA,
-	ema21: EMA,
-	ema22: EMA,
+	smoothed_momentum: ValueType,
+	ma11: M1,
+	ma12: M2,
+	ma21: M1,
+	ma22: M2,
 }
 
 impl TSI {
@@ -72,7 +93,19 @@ impl TSI {
 	}
 }
 
-impl Method<'_> for TSI {
+impl<M1, M2> TSI<M1, M2> {
+	/// Returns last calculated double smoothed momentum
+	#[must_use]
+	pub const fn get_smoothed_momentum(&self) -> ValueType {
+		self.smoothed_momentum
+	}
+}
+
+impl<'a, M1, M2> Method<'a> for TSI<M1, M2>
+where
+	M1: Method<'a, Params = PeriodType, Input = ValueType, Output = ValueType>,
+	M2: Method<'a, Params = PeriodType, Input = ValueType, Output = ValueType>,
+{
 	type Params = (PeriodType, PeriodType);
 	type Input = ValueType;
 	type Output = Self::Input;
@@ -82,27 +115,130 @@ impl Method<'_> for TSI {
 
 		let m = Self {
 			last_value: value,
-			ema11: EMA::new(long_period, 0.0)?,
-			ema12: EMA::new(short_period, 0.0)?,
-			ema21: EMA::new(long_period, 0.0)?,
-			ema22: EMA::new(short_period, 0.0)?,
+			smoothed_momentum: 0.0,
+			ma11: M1::new(long_period, 0.0)?,
+			ma12: M2::new(short_period, 0.0)?,
+			ma21: M1::new(long_period, 0.0)?,
+			ma22: M2::new(short_period, 0.0)?,
 		};
 
 		Ok(m)
 	}
 
+	fn heap_size(&self) -> usize {
+		self.ma11.heap_size()
+			+ self.ma12.heap_size()
+			+ self.ma21.heap_size()
+			+ self.ma22.heap_size()
+	}
+
 	#[inline]
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		let momentum = value - self.last_value;
 		self.last_value = value;
 
-		let numerator = self.ema12.next(self.ema11.next(momentum));
-		let denominator = self.ema22.next(self.ema21.next(momentum.abs()));
+		self.smoothed_momentum = self.ma12.next(self.ma11.next(momentum));
+		let denominator = self.ma22.next(self.ma21.next(momentum.abs()));
 
 		if denominator > 0.0 {
-			numerator / denominator
+			self.smoothed_momentum / denominator
 		} else {
 			0.0
 		}
 	}
 }
+
+impl<M1: Checkpoint, M2: Checkpoint> Checkpoint for TSI<M1, M2> {
+	type State = (
+		ValueType,
+		ValueType,
+		M1::State,
+		M2::State,
+		M1::State,
+		M2::State,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.last_value,
+			self.smoothed_momentum,
+			self.ma11.save(),
+			self.ma12.save(),
+			self.ma21.save(),
+			self.ma22.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.last_value = state.0;
+		self.smoothed_momentum = state.1;
+		self.ma11.restore(&state.2);
+		self.ma12.restore(&state.3);
+		self.ma21.restore(&state.4);
+		self.ma22.restore(&state.5);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PeriodType, ValueType, TSI};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{EMA, SMA, WMA};
+
+	#[test]
+	fn test_tsi_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TSI::new(i, i, input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	fn test_tsi_with<M1, M2>(src: &[ValueType], short_period: PeriodType, long_period: PeriodType)
+	where
+		M1: for<'a> Method<'a, Params = PeriodType, Input = ValueType, Output = ValueType>,
+		M2: for<'a> Method<'a, Params = PeriodType, Input = ValueType, Output = ValueType>,
+	{
+		let mut tsi = TSI::<M1, M2>::new((short_period, long_period), src[0]).unwrap();
+		let mut ma11 = M1::new(long_period, 0.0).unwrap();
+		let mut ma12 = M2::new(short_period, 0.0).unwrap();
+		let mut ma21 = M1::new(long_period, 0.0).unwrap();
+		let mut ma22 = M2::new(short_period, 0.0).unwrap();
+		let mut prev = src[0];
+
+		for &x in src {
+			let momentum = x - prev;
+			prev = x;
+
+			let numerator = ma12.next(ma11.next(momentum));
+			let denominator = ma22.next(ma21.next(momentum.abs()));
+			let value = if denominator > 0.0 {
+				numerator / denominator
+			} else {
+				0.0
+			};
+
+			assert_eq_float(value, tsi.next(x));
+			assert_eq_float(numerator, tsi.get_smoothed_momentum());
+		}
+	}
+
+	#[test]
+	fn test_tsi() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for short_period in (1..100).step_by(7) {
+			for long_period in (1..255).step_by(11) {
+				test_tsi_with::<EMA, EMA>(&src, short_period, long_period);
+				test_tsi_with::<WMA, SMA>(&src, short_period, long_period);
+			}
+		}
+	}
+}
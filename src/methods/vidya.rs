@@ -1,4 +1,5 @@
-use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use crate::core::Period;
+use crate::core::{Checkpoint, Error, Method, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -7,10 +8,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` must be > `0`
 ///
+/// By default the same `length` is used as a period of [Chande Momentum Oscillator](https://www.investopedia.com/terms/c/chandemomentumoscillator.asp),
+/// which is used as a volatility index. It may be set separately by [`with_cmo_length`](Vidya::with_cmo_length).
+///
 /// # Input type
 ///
 /// Input type is [`ValueType`]
@@ -35,12 +39,26 @@ use serde::{Deserialize, Serialize};
 /// println!("{}", vidya.next(12.0));
 /// ```
 ///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Vidya;
+///
+/// // Vidya with period length=9 and CMO period length=5
+/// let mut vidya = Vidya::new(9, 1.0).unwrap().with_cmo_length(5).unwrap();
+///
+/// vidya.next(3.0);
+/// vidya.next(6.0);
+///
+/// println!("{}", vidya.next(9.0));
+/// ```
+///
 /// # Performance
 ///
 /// O\(1\)
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[doc(alias = "VariableIndexDynamicAverage")]
 #[doc(alias = "Variable")]
@@ -48,16 +66,51 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "Dynamic")]
 #[doc(alias = "Average")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Vidya {
+pub struct Vidya<P: Period = PeriodType> {
 	f: ValueType,
 	up_sum: ValueType,
 	dn_sum: ValueType,
 	last_input: ValueType,
 	last_output: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
 impl Vidya {
+	/// Creates new instance of `Vidya` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Vidya<P> {
+	/// Sets period length of Chande Momentum Oscillator, which is used as a volatility index
+	///
+	/// `cmo_length` must be > `0`
+	///
+	/// It resets accumulated volatility index state, so it should be called right after the method is created.
+	pub fn with_cmo_length(self, cmo_length: P) -> Result<Self, Error> {
+		if cmo_length == P::ZERO {
+			return Err(Error::wrong_method_parameter(
+				"cmo_length",
+				"> 0",
+				cmo_length,
+			));
+		}
+
+		Ok(Self {
+			up_sum: 0.,
+			dn_sum: 0.,
+			window: Window::with_size(cmo_length, 0.),
+			..self
+		})
+	}
+
+	/// Returns period length of Chande Momentum Oscillator
+	#[must_use]
+	pub fn cmo_length(&self) -> P {
+		self.window.len()
+	}
+
 	/// Returns last calculated value
 	#[must_use]
 	pub const fn get_last_value(&self) -> <Self as Method>::Output {
@@ -65,23 +118,32 @@ impl Vidya {
 	}
 }
 
-impl Method<'_> for Vidya {
-	type Params = PeriodType;
+impl<P: Period> Method<'_> for Vidya<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, input: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 | PeriodType::MAX => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				f: 2. / (1 + length) as ValueType,
-				up_sum: 0.,
-				dn_sum: 0.,
-				last_input: input,
-				last_output: input,
-				window: Window::new(length, 0.),
-			}),
+		if length == P::ZERO || length == P::MAX {
+			return Err(Error::wrong_method_parameter(
+				"length",
+				"in [1; PeriodType::MAX)",
+				length,
+			));
 		}
+
+		Ok(Self {
+			f: 2. / (1 + length.to_usize()) as ValueType,
+			up_sum: 0.,
+			dn_sum: 0.,
+			last_input: input,
+			last_output: input,
+			window: Window::with_size(length, 0.),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -109,6 +171,36 @@ impl Method<'_> for Vidya {
 	}
 }
 
+impl<P: Period> Checkpoint for Vidya<P> {
+	type State = (
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+		WindowCheckpoint<ValueType, P>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.up_sum,
+			self.dn_sum,
+			self.last_input,
+			self.last_output,
+			self.window.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.up_sum = state.0;
+		self.dn_sum = state.1;
+		self.last_input = state.2;
+		self.last_output = state.3;
+		self.window.restore(&state.4);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::Vidya as TestingMethod;
@@ -181,4 +273,60 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	#[allow(clippy::suboptimal_flops)]
+	fn test_vidya_cmo_length() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let change: Vec<_> = (0..1)
+			.map(|_| 0.0)
+			.chain(src.windows(2).map(|x| x[1] - x[0]))
+			.collect();
+
+		let pos_change: Vec<_> = change
+			.iter()
+			.map(|&x| if x > 0.0 { x } else { 0.0 })
+			.collect();
+		let neg_change: Vec<_> = change
+			.iter()
+			.map(|&x| if x < 0.0 { x.abs() } else { 0.0 })
+			.collect();
+
+		for &ma_length in &[1, 2, 9, 20, 100] {
+			(1..255).for_each(|cmo_length| {
+				let mut ma = TestingMethod::new(ma_length, src[0])
+					.unwrap()
+					.with_cmo_length(cmo_length)
+					.unwrap();
+				let ma_length = ma_length as usize;
+				let cmo_length = cmo_length as usize;
+
+				let mut value = src[0];
+				src.iter().enumerate().for_each(|(i, &x)| {
+					let from_slice = i.saturating_sub(cmo_length - 1);
+					let pos: ValueType = pos_change[from_slice..=i].iter().sum();
+					let neg: ValueType = neg_change[from_slice..=i].iter().sum();
+
+					value = if (pos + neg) == 0.0 {
+						x
+					} else {
+						let cmo = (pos - neg) / (pos + neg);
+						let f = 2.0 / (ma_length + 1) as ValueType;
+
+						x * f * cmo.abs() + value * (1.0 - f * cmo.abs())
+					};
+
+					assert_eq_float(value, ma.next(x));
+				});
+			});
+		}
+
+		assert!(TestingMethod::new(9, src[0])
+			.unwrap()
+			.with_cmo_length(0)
+			.is_err());
+	}
 }
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -45,28 +45,41 @@ use serde::{Deserialize, Serialize};
 /// [`Derivative`]: crate::methods::Derivative
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct LinearVolatility {
-	window: Window<ValueType>,
+pub struct LinearVolatility<P: Period = PeriodType> {
+	window: Window<ValueType, P>,
 	prev_value: ValueType,
 	volatility: ValueType,
 }
 
-impl Method<'_> for LinearVolatility {
-	type Params = PeriodType;
+impl LinearVolatility {
+	/// Creates new instance of `LinearVolatility` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for LinearVolatility<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				window: Window::new(length, 0.),
-				prev_value: value,
-				volatility: 0.,
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		Ok(Self {
+			window: Window::with_size(length, 0.),
+			prev_value: value,
+			volatility: 0.,
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -82,6 +95,22 @@ impl Method<'_> for LinearVolatility {
 	}
 }
 
+impl<P: Period> Checkpoint for LinearVolatility<P> {
+	type State = (ValueType, ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.prev_value, self.volatility, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.prev_value = state.0;
+		self.volatility = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::{LinearVolatility as TestingMethod, Method};
@@ -0,0 +1,228 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume weighted moving [Standard Deviation](https://en.wikipedia.org/wiki/Standard_deviation) of specified `length`
+///
+/// Every value is weighted by its volume, so the deviation is calculated around [`VWMA`](crate::methods::VWMA) of the same `length`.
+/// Volume weighted mean is available by [`get_mean`](VWStDev::get_mean).
+///
+/// Returns `0.0` when total volume over the window is `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// `length` should be > `1`
+///
+/// Returns [`Error::NoVolume`](crate::core::Error::NoVolume) when the initial `volume` is `NaN`.
+///
+/// # Input type
+///
+/// Input type is (`value`: [`ValueType`], `volume`: [`ValueType`])
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::methods::VWStDev;
+///
+/// let mut vw_st_dev = VWStDev::new(2, (1.0, 1.0)).unwrap();
+///
+/// // mean is (1.0 * 1.0 + 5.0 * 3.0) / 4.0 = 4.0
+/// assert_eq!(vw_st_dev.next((5.0, 3.0)), ValueType::sqrt(3.0));
+/// assert_eq!(vw_st_dev.get_mean(), 4.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`StDev`](crate::methods::StDev), [`VWMA`](crate::methods::VWMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VWStDev<P: Period = PeriodType> {
+	mean: ValueType,
+	sum: ValueType,
+	sq_sum: ValueType,
+	vol_sum: ValueType,
+	window: Window<(ValueType, ValueType), P>,
+}
+
+impl VWStDev {
+	/// Creates new instance of `VWStDev` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: (ValueType, ValueType)) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> VWStDev<P> {
+	/// Returns current volume weighted mean
+	#[must_use]
+	pub const fn get_mean(&self) -> ValueType {
+		self.mean
+	}
+}
+
+impl<P: Period> Method<'_> for VWStDev<P> {
+	type Params = P;
+	type Input = (ValueType, ValueType);
+	type Output = ValueType;
+
+	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
+		if length.to_usize() < 2 {
+			return Err(Error::wrong_method_parameter("length", "> 1", length));
+		}
+
+		if value.1.is_nan() {
+			return Err(Error::NoVolume);
+		}
+
+		let float_length = length.to_usize() as ValueType;
+		let (value, volume) = value;
+
+		Ok(Self {
+			mean: value,
+			sum: value * volume * float_length,
+			sq_sum: value * value * volume * float_length,
+			vol_sum: volume * float_length,
+			window: Window::with_size(length, (value, volume)),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, value: Self::Input) -> Self::Output {
+		let past_value = self.window.push(value);
+		let (value, volume) = value;
+		let (past_value, past_volume) = past_value;
+
+		let weighted = value * volume;
+		let past_weighted = past_value * past_volume;
+
+		self.vol_sum += volume - past_volume;
+		self.sum += weighted - past_weighted;
+		self.sq_sum += value.mul_add(weighted, -past_value * past_weighted);
+
+		if self.vol_sum > 0.0 {
+			self.mean = self.sum / self.vol_sum;
+
+			// sometimes float values may produce negative values, when variance is really near to zero value
+			self.mean
+				.mul_add(-self.mean, self.sq_sum / self.vol_sum)
+				.max(0.0)
+				.sqrt()
+		} else {
+			0.0
+		}
+	}
+}
+
+impl<P: Period> Checkpoint for VWStDev<P> {
+	type State = (
+		ValueType,
+		ValueType,
+		ValueType,
+		ValueType,
+		WindowCheckpoint<(ValueType, ValueType), P>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(
+			self.mean,
+			self.sum,
+			self.sq_sum,
+			self.vol_sum,
+			self.window.save(),
+		)
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.mean = state.0;
+		self.sum = state.1;
+		self.sq_sum = state.2;
+		self.vol_sum = state.3;
+		self.window.restore(&state.4);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, VWStDev as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_vw_st_dev_const() {
+		for i in 2..255 {
+			let input = ((i as ValueType + 56.0) / 16.3251, (i as ValueType) * 3.3);
+			let mut method = TestingMethod::new(i, input).unwrap();
+
+			test_const_float(&mut method, input, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_vw_st_dev() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<(ValueType, ValueType)> = candles
+			.take(300)
+			.map(|x| (x.close, x.volume.abs()))
+			.collect();
+
+		for length in 2..255 {
+			let mut method = TestingMethod::new(length, src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, &x) in src.iter().enumerate() {
+				let slice: Vec<(ValueType, ValueType)> =
+					(0..length).map(|j| src[i.saturating_sub(j)]).collect();
+
+				let vol_sum: ValueType = slice.iter().map(|v| v.1).sum();
+				let mean = slice.iter().map(|v| v.0 * v.1).sum::<ValueType>() / vol_sum;
+				let variance = slice
+					.iter()
+					.map(|v| (v.0 - mean).powi(2) * v.1)
+					.sum::<ValueType>()
+					/ vol_sum;
+
+				let value = method.next(x);
+
+				if vol_sum > 0.0 {
+					assert_eq_float(variance.sqrt(), value);
+					assert_eq_float(mean, method.get_mean());
+				} else {
+					assert_eq_float(0.0, value);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_vw_st_dev_zero_volume() {
+		let mut method = TestingMethod::new(3, (1.0, 0.0)).unwrap();
+
+		assert_eq_float(0.0, method.next((2.0, 0.0)));
+		assert_eq_float(1.0, method.get_mean());
+	}
+}
@@ -0,0 +1,318 @@
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Volume Weighted Average Price](https://en.wikipedia.org/wiki/Volume-weighted_average_price) of [typical price](crate::core::OHLCV::tp)
+///
+/// Works in two modes:
+///
+/// * *rolling* mode (`length` > `0`) averages over the last `length` candles;
+/// * *anchored* mode (`length` == `0`) averages cumulatively since the anchor point.
+///
+/// The anchor point is the first candle after the method is created or [`reset`](VWAP::reset) (f.e. at the start of every session).
+/// In rolling mode [`reset`](VWAP::reset) also drops all the candles in the window, so the average grows from the anchor point
+/// until the window is full again.
+///
+/// Unlike [`VWMA`](crate::methods::VWMA), it consumes whole candles, so it may be reset from the outside without recreating
+/// the method (see also [`Anchored`](crate::methods::Anchored)).
+///
+/// When there is no traded volume in the window (or since the anchor point), returns typical price of the current candle.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
+///
+/// If `length == 0`, then averages since the anchor point.
+///
+/// Returns [`Error::NoVolume`](crate::core::Error::NoVolume) when the initial candle has no volume.
+/// Later candles without volume are considered as candles with zero volume.
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Candle;
+/// use yata::methods::VWAP;
+///
+/// let candles = [
+///     Candle { high: 11.0, low: 9.0, close: 10.0, volume: 1.0, ..Candle::default() },
+///     Candle { high: 21.0, low: 19.0, close: 20.0, volume: 3.0, ..Candle::default() },
+///     Candle { high: 31.0, low: 29.0, close: 30.0, volume: 1.0, ..Candle::default() },
+/// ];
+///
+/// let mut vwap = VWAP::new(0, &candles[0]).unwrap();
+///
+/// assert_eq!(vwap.next(&candles[0]), 10.0);
+/// assert_eq!(vwap.next(&candles[1]), 17.5); // (10 * 1 + 20 * 3) / 4
+/// assert_eq!(vwap.next(&candles[2]), 20.0); // (10 * 1 + 20 * 3 + 30 * 1) / 5
+///
+/// // new session starts
+/// vwap.reset();
+/// assert_eq!(vwap.next(&candles[0]), 10.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1), but O(`length`) for candles without volume in rolling mode
+///
+/// # See also
+///
+/// [`VWMA`](crate::methods::VWMA), [`Anchored`](crate::methods::Anchored)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
+/// [`OHLCV`]: crate::core::OHLCV
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VWAP<P: Period = PeriodType> {
+	sum: ValueType,
+	vol_sum: ValueType,
+	window: Window<(ValueType, ValueType), P>,
+}
+
+impl VWAP {
+	/// Creates new instance of `VWAP` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: &dyn OHLCV) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> VWAP<P> {
+	/// Resets accumulated values, so the next candle becomes the new anchor point
+	pub fn reset(&mut self) {
+		self.sum = 0.0;
+		self.vol_sum = 0.0;
+
+		if !self.window.is_empty() {
+			self.window = Window::with_size(self.window.len(), (0.0, 0.0));
+		}
+	}
+}
+
+impl<'a, P: Period> Method<'a> for VWAP<P> {
+	type Params = P;
+	type Input = &'a dyn OHLCV;
+	type Output = ValueType;
+
+	fn new(length: Self::Params, candle: Self::Input) -> Result<Self, Error> {
+		if !candle.has_volume() {
+			return Err(Error::NoVolume);
+		}
+
+		let volume = candle.volume_or_zero();
+		let value = (candle.tp() * volume, volume);
+
+		Ok(Self {
+			sum: value.0 * length.to_usize() as ValueType,
+			vol_sum: value.1 * length.to_usize() as ValueType,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
+	}
+
+	#[inline]
+	fn next(&mut self, candle: Self::Input) -> Self::Output {
+		let volume = candle.volume_or_zero();
+		let value = (candle.tp() * volume, volume);
+
+		self.sum += value.0;
+		self.vol_sum += value.1;
+
+		if !self.window.is_empty() {
+			let past_value = self.window.push(value);
+
+			self.sum -= past_value.0;
+			self.vol_sum -= past_value.1;
+
+			// rolling sums are not exact, so the window without volume is checked directly
+			if volume == 0.0 && self.window.iter().all(|(_, volume)| volume == 0.0) {
+				self.sum = 0.0;
+				self.vol_sum = 0.0;
+			}
+		}
+
+		if self.vol_sum == 0.0 {
+			candle.tp()
+		} else {
+			self.sum / self.vol_sum
+		}
+	}
+}
+
+impl<P: Period> Checkpoint for VWAP<P> {
+	type State = (
+		ValueType,
+		ValueType,
+		WindowCheckpoint<(ValueType, ValueType), P>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.sum, self.vol_sum, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sum = state.0;
+		self.vol_sum = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, VWAP as TestingMethod};
+	use crate::core::{Candle, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::VWMA;
+
+	fn candles() -> Vec<Candle> {
+		RandomCandles::default()
+			.take(300)
+			.map(|c| Candle {
+				volume: c.volume.abs(),
+				..c
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_vwap_const() {
+		let input = candles()[0];
+
+		for length in 0..255 {
+			let mut method = TestingMethod::new(length, &input).unwrap();
+			let output = method.next(&input);
+
+			test_const_float(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_vwap_rolling() {
+		let src = candles();
+
+		for length in 1..255 {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let mut vwma = VWMA::new(length, (src[0].tp(), src[0].volume)).unwrap();
+
+			for c in &src {
+				assert_eq_float(vwma.next((c.tp(), c.volume)), method.next(c));
+			}
+		}
+	}
+
+	#[test]
+	fn test_vwap_anchored() {
+		let src = candles();
+
+		for session in 1..50 {
+			let mut method = TestingMethod::new(0, &src[0]).unwrap();
+			let mut sum = 0.0;
+			let mut vol_sum = 0.0;
+
+			for (i, c) in src.iter().enumerate() {
+				if i % session == 0 {
+					method.reset();
+					sum = 0.0;
+					vol_sum = 0.0;
+				}
+
+				sum = c.tp().mul_add(c.volume, sum);
+				vol_sum += c.volume;
+
+				assert_eq_float(sum / vol_sum, method.next(c));
+			}
+		}
+	}
+
+	#[test]
+	fn test_vwap_rolling_reset() {
+		let src = candles();
+		let anchor = 100;
+
+		for length in 1..50 {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let mut vwma = VWMA::new(length, (src[0].tp(), src[0].volume)).unwrap();
+
+			for (i, c) in src.iter().enumerate() {
+				if i < anchor {
+					assert_eq_float(vwma.next((c.tp(), c.volume)), method.next(c));
+					continue;
+				}
+
+				if i == anchor {
+					method.reset();
+				}
+
+				let from = (i + 1).saturating_sub(length as usize).max(anchor);
+				let (sum, vol_sum) = src[from..=i].iter().fold((0.0, 0.0), |(sum, vol_sum), c| {
+					(c.tp().mul_add(c.volume, sum), vol_sum + c.volume)
+				});
+
+				assert_eq_float(sum / vol_sum, method.next(c));
+			}
+		}
+	}
+
+	#[test]
+	fn test_vwap_no_volume() {
+		let candle = candles()[0].without_volume();
+
+		assert!(TestingMethod::new(14, &candle).is_err());
+		assert!(TestingMethod::new(0, &candle).is_err());
+	}
+
+	#[test]
+	fn test_vwap_later_no_volume() {
+		let src: Vec<Candle> = candles()
+			.iter()
+			.enumerate()
+			.map(|(i, c)| if i == 5 { c.without_volume() } else { *c })
+			.collect();
+
+		for length in 0..20 {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+
+			for (i, c) in src.iter().enumerate() {
+				let (from, init) = if length == 0 {
+					(0, (0.0, 0.0))
+				} else {
+					// rolling window is initially filled by the first candle
+					let copies = (length as usize).saturating_sub(i + 1) as ValueType;
+					let init = (src[0].tp() * src[0].volume * copies, src[0].volume * copies);
+
+					((i + 1).saturating_sub(length as usize), init)
+				};
+
+				let (sum, vol_sum) = src[from..=i].iter().fold(init, |(sum, vol_sum), c| {
+					let volume = c.volume_or_zero();
+					(c.tp().mul_add(volume, sum), vol_sum + volume)
+				});
+
+				let expected = if vol_sum == 0.0 {
+					c.tp()
+				} else {
+					sum / vol_sum
+				};
+
+				assert_eq_float(expected, method.next(c));
+			}
+		}
+	}
+}
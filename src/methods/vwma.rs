@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,7 +11,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// `length` should be > `0`
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Returns [`Error::NoVolume`](crate::core::Error::NoVolume) when the initial `volume` is `NaN`.
+///
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// # Input type
 ///
@@ -44,28 +46,45 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct VWMA {
+pub struct VWMA<P: Period = PeriodType> {
 	sum: ValueType,
 	vol_sum: ValueType,
-	window: Window<(ValueType, ValueType)>,
+	window: Window<(ValueType, ValueType), P>,
 }
 
-impl Method<'_> for VWMA {
-	type Params = PeriodType;
+impl VWMA {
+	/// Creates new instance of `VWMA` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: (ValueType, ValueType)) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for VWMA<P> {
+	type Params = P;
 	type Input = (ValueType, ValueType);
 	type Output = ValueType;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				sum: value.0 * value.1 * length as ValueType,
-				vol_sum: value.1 * length as ValueType,
-				window: Window::new(length, value),
-			}),
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		if value.1.is_nan() {
+			return Err(Error::NoVolume);
+		}
+
+		Ok(Self {
+			sum: value.0 * value.1 * length.to_usize() as ValueType,
+			vol_sum: value.1 * length.to_usize() as ValueType,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -79,6 +98,26 @@ impl Method<'_> for VWMA {
 	}
 }
 
+impl<P: Period> Checkpoint for VWMA<P> {
+	type State = (
+		ValueType,
+		ValueType,
+		WindowCheckpoint<(ValueType, ValueType), P>,
+	);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.sum, self.vol_sum, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.sum = state.0;
+		self.vol_sum = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
@@ -1,5 +1,5 @@
-use crate::core::Method;
-use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Checkpoint, Method, Period};
+use crate::core::{Error, PeriodType, ValueType, Window, WindowCheckpoint};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a single parameter `length`: [`PeriodType`] or any other [`Period`] type
 ///
 /// `length` should be > `0`
 ///
@@ -46,37 +46,48 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
+/// [`Period`]: crate::core::Period
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct WMA {
+pub struct WMA<P: Period = PeriodType> {
 	invert_sum: ValueType,
 	float_length: ValueType,
 	total: ValueType,
 	numerator: ValueType,
-	window: Window<ValueType>,
+	window: Window<ValueType, P>,
 }
 
-impl Method<'_> for WMA {
-	type Params = PeriodType;
+impl WMA {
+	/// Creates new instance of `WMA` of [`PeriodType`](crate::core::PeriodType) `length`
+	pub fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Method::new(length, value)
+	}
+}
+
+impl<P: Period> Method<'_> for WMA<P> {
+	type Params = P;
 	type Input = ValueType;
 	type Output = Self::Input;
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
-		match length {
-			0 => Err(Error::WrongMethodParameters),
-			length => {
-				let length2 = length as usize;
-				let sum = ((length2 * (length2 + 1)) / 2) as ValueType;
-				let float_length = length as ValueType;
-				Ok(Self {
-					invert_sum: sum.recip(),
-					float_length,
-					total: -value * float_length,
-					numerator: value * sum,
-					window: Window::new(length, value),
-				})
-			}
+		if length == P::ZERO {
+			return Err(Error::wrong_method_parameter("length", "> 0", length));
 		}
+
+		let length2 = length.to_usize();
+		let sum = ((length2 * (length2 + 1)) / 2) as ValueType;
+		let float_length = length.to_usize() as ValueType;
+		Ok(Self {
+			invert_sum: sum.recip(),
+			float_length,
+			total: -value * float_length,
+			numerator: value * sum,
+			window: Window::with_size(length, value),
+		})
+	}
+
+	fn heap_size(&self) -> usize {
+		self.window.heap_size()
 	}
 
 	#[inline]
@@ -90,6 +101,22 @@ impl Method<'_> for WMA {
 	}
 }
 
+impl<P: Period> Checkpoint for WMA<P> {
+	type State = (ValueType, ValueType, WindowCheckpoint<ValueType, P>);
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		(self.numerator, self.total, self.window.save())
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		self.numerator = state.0;
+		self.total = state.1;
+		self.window.restore(&state.2);
+	}
+}
+
 #[cfg(test)]
 #[allow(clippy::suboptimal_flops)]
 mod tests {
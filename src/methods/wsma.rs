@@ -1,4 +1,4 @@
-use crate::core::Method;
+use crate::core::{Checkpoint, Method};
 use crate::core::{Error, PeriodType, ValueType};
 use crate::methods::EMA;
 
@@ -57,7 +57,11 @@ impl Method<'_> for WSMA {
 
 	fn new(length: Self::Params, value: Self::Input) -> Result<Self, Error> {
 		if length > MAX_PERIOD {
-			return Err(Error::WrongMethodParameters);
+			return Err(Error::wrong_method_parameter(
+				"length",
+				"<= PeriodType::MAX / 2",
+				length,
+			));
 		}
 
 		Ok(Self(EMA::new(length * 2 - 1, value)?))
@@ -69,6 +73,20 @@ impl Method<'_> for WSMA {
 	}
 }
 
+impl Checkpoint for WSMA {
+	type State = Self;
+
+	#[inline]
+	fn save(&self) -> Self::State {
+		*self
+	}
+
+	#[inline]
+	fn restore(&mut self, state: &Self::State) {
+		*self = *state;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::core::Method;